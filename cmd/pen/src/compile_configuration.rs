@@ -8,6 +8,8 @@ pub const CROSS_COMPILE_TARGETS: &[&str] = &[
     "wasm32-wasi",
 ];
 
+pub const TARGET_RUNNERS: &[(&str, &str)] = &[("wasm32-wasi", "wasmtime")];
+
 pub static COMPILE_CONFIGURATION: Lazy<Arc<app::module_compiler::CompileConfiguration>> =
     Lazy::new(|| {
         app::module_compiler::CompileConfiguration {
@@ -25,6 +27,7 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<app::module_compiler::CompileConfigur
                     empty_list_function_name: "EmptyList".into(),
                     concatenate_function_name: "ConcatenateLists".into(),
                     equal_function_name: "EqualLists".into(),
+                    less_than_function_name: "LessThanLists".into(),
                     prepend_function_name: "PrependToList".into(),
                     deconstruct_function_name: "FirstRest".into(),
                     lazy_function_name: "LazyList".into(),
@@ -63,6 +66,7 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<app::module_compiler::CompileConfigur
                 },
                 string_type: app::module_compiler::StringTypeConfiguration {
                     equal_function_name: "EqualStrings".into(),
+                    less_than_function_name: "LessThanStrings".into(),
                 },
                 error_type: app::module_compiler::ErrorTypeConfiguration {
                     error_type_name: "Error".into(),
@@ -0,0 +1,10 @@
+use std::{
+    error::Error,
+    io::{stdout, Write},
+};
+
+pub fn explain(code: &str) -> Result<(), Box<dyn Error>> {
+    write!(stdout(), "{}", app::error_explainer::explain(code)?)?;
+
+    Ok(())
+}
@@ -1,14 +1,19 @@
 mod application_configuration;
+mod application_runner;
 mod compile_configuration;
 mod dependency_resolver;
 mod documentation_configuration;
+mod error_explainer;
 mod file_path_configuration;
 mod infrastructure;
+mod language_server;
 mod main_module_compiler;
 mod main_package_directory_finder;
+mod message_format;
 mod module_compiler;
 mod module_formatter;
 mod package_builder;
+mod package_checker;
 mod package_creator;
 mod package_documentation_generator;
 mod package_formatter;
@@ -18,13 +23,21 @@ mod test_configuration;
 mod test_linker;
 mod test_module_compiler;
 mod test_runner;
+mod warning_logger;
 
 use compile_configuration::CROSS_COMPILE_TARGETS;
 use std::ops::Deref;
 
 fn main() {
     if let Err(error) = run() {
-        infra::log_error(error.as_ref()).unwrap();
+        if message_format::is_json() {
+            for diagnostic in app::diagnostic_converter::convert(error.as_ref()) {
+                infra::log_diagnostic(&diagnostic).unwrap();
+            }
+        } else {
+            infra::log_error(error.as_ref()).unwrap();
+        }
+
         std::process::exit(1);
     }
 }
@@ -40,12 +53,43 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .global(true)
                 .help("Use verbose output"),
         )
-        .subcommand(clap::Command::new("build").about("Build a package").arg(
-            build_target_triple_argument().value_parser(clap::builder::PossibleValuesParser::new(
-                CROSS_COMPILE_TARGETS,
-            )),
-        ))
-        .subcommand(clap::Command::new("test").about("Test modules in a package"))
+        .subcommand(
+            clap::Command::new("build")
+                .about("Build a package")
+                .arg(build_target_triple_argument().value_parser(
+                    clap::builder::PossibleValuesParser::new(CROSS_COMPILE_TARGETS),
+                ))
+                .arg(message_format_argument()),
+        )
+        .subcommand(
+            clap::Command::new("run")
+                .about("Build and run an application")
+                .arg(build_target_triple_argument().value_parser(
+                    clap::builder::PossibleValuesParser::new(CROSS_COMPILE_TARGETS),
+                ))
+                .arg(message_format_argument())
+                .arg(
+                    clap::Arg::new("arguments")
+                        .multiple_values(true)
+                        .last(true)
+                        .help("Set arguments passed to an application"),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("check")
+                .about("Check modules in a package without building it")
+                .arg(
+                    clap::Arg::new("mir")
+                        .long("mir")
+                        .help("Check types of intermediate representation too"),
+                )
+                .arg(message_format_argument()),
+        )
+        .subcommand(
+            clap::Command::new("test")
+                .about("Test modules in a package")
+                .arg(message_format_argument()),
+        )
         .subcommand(
             clap::Command::new("create")
                 .about("Create a package")
@@ -102,10 +146,21 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                         .help("Set package description"),
                 ),
         )
+        .subcommand(
+            clap::Command::new("explain")
+                .about("Explain an error code")
+                .arg(
+                    clap::Arg::new("code")
+                        .required(true)
+                        .help("Set an error code, such as E0001"),
+                ),
+        )
+        .subcommand(clap::Command::new("lsp").about("Run a language server"))
         .subcommand(
             clap::Command::new("compile")
                 .hide(true)
                 .about("Compile a module")
+                .arg(package_directory_argument())
                 .arg(clap::Arg::new("source file").required(true))
                 .arg(clap::Arg::new("dependency file").required(true))
                 .arg(clap::Arg::new("object file").required(true))
@@ -124,6 +179,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                         .number_of_values(2)
                         .action(clap::ArgAction::Append),
                 )
+                .arg(package_directory_argument())
                 .arg(clap::Arg::new("source file").required(true))
                 .arg(clap::Arg::new("dependency file").required(true))
                 .arg(clap::Arg::new("object file").required(true))
@@ -152,13 +208,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
             clap::Command::new("resolve-dependency")
                 .hide(true)
                 .about("Resolve module dependency")
-                .arg(
-                    clap::Arg::new("package directory")
-                        .short('p')
-                        .long("package-directory")
-                        .required(true)
-                        .takes_value(true),
-                )
+                .arg(package_directory_argument())
                 .arg(
                     clap::Arg::new("output directory")
                         .short('o')
@@ -217,11 +267,37 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         .subcommand()
         .unwrap()
     {
-        ("build", matches) => package_builder::build(
-            matches.get_one::<String>("target").map(Deref::deref),
-            matches.contains_id("verbose"),
-        ),
-        ("test", _) => test_runner::run(),
+        ("build", matches) => {
+            message_format::set(matches.get_one::<String>("message format").unwrap());
+
+            package_builder::build(
+                matches.get_one::<String>("target").map(Deref::deref),
+                matches.contains_id("verbose"),
+            )
+        }
+        ("run", matches) => {
+            message_format::set(matches.get_one::<String>("message format").unwrap());
+
+            application_runner::run(
+                matches.get_one::<String>("target").map(Deref::deref),
+                &matches
+                    .get_many::<String>("arguments")
+                    .unwrap_or_default()
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                matches.contains_id("verbose"),
+            )
+        }
+        ("check", matches) => {
+            message_format::set(matches.get_one::<String>("message format").unwrap());
+
+            package_checker::check(matches.contains_id("mir"), matches.contains_id("verbose"))
+        }
+        ("test", matches) => {
+            message_format::set(matches.get_one::<String>("message format").unwrap());
+
+            test_runner::run()
+        }
         ("create", matches) => package_creator::create(
             matches.get_one::<String>("directory").unwrap(),
             matches.contains_id("library"),
@@ -238,7 +314,12 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
             matches.get_one::<String>("url").unwrap(),
             matches.get_one::<String>("description").unwrap(),
         ),
+        ("explain", matches) => {
+            error_explainer::explain(matches.get_one::<String>("code").unwrap())
+        }
+        ("lsp", _) => language_server::run(),
         ("compile", matches) => module_compiler::compile(
+            matches.get_one::<String>("package directory").unwrap(),
             matches.get_one::<String>("source file").unwrap(),
             matches.get_one::<String>("dependency file").unwrap(),
             matches.get_one::<String>("object file").unwrap(),
@@ -253,6 +334,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .collect::<Vec<_>>();
 
             main_module_compiler::compile(
+                matches.get_one::<String>("package directory").unwrap(),
                 matches.get_one::<String>("source file").unwrap(),
                 matches.get_one::<String>("dependency file").unwrap(),
                 matches.get_one::<String>("object file").unwrap(),
@@ -327,3 +409,22 @@ fn build_target_triple_argument() -> clap::Arg<'static> {
         .takes_value(true)
         .help("Set a target triple")
 }
+
+fn package_directory_argument() -> clap::Arg<'static> {
+    clap::Arg::new("package directory")
+        .short('p')
+        .long("package-directory")
+        .required(true)
+        .takes_value(true)
+}
+
+fn message_format_argument() -> clap::Arg<'static> {
+    clap::Arg::new("message format")
+        .long("message-format")
+        .takes_value(true)
+        .default_value(message_format::MESSAGE_FORMATS[0])
+        .value_parser(clap::builder::PossibleValuesParser::new(
+            message_format::MESSAGE_FORMATS,
+        ))
+        .help("Set an error message format")
+}
@@ -1,8 +1,11 @@
-use super::{compile_configuration::COMPILE_CONFIGURATION, main_package_directory_finder};
+use super::{
+    compile_configuration::COMPILE_CONFIGURATION, main_package_directory_finder, warning_logger,
+};
 use crate::{application_configuration::APPLICATION_CONFIGURATION, infrastructure};
 use std::{collections::BTreeMap, error::Error, sync::Arc};
 
 pub fn compile(
+    package_directory: &str,
     source_file: &str,
     dependency_file: &str,
     object_file: &str,
@@ -12,8 +15,9 @@ pub fn compile(
     let main_package_directory = main_package_directory_finder::find()?;
     let file_path_converter = Arc::new(infra::FilePathConverter::new(&main_package_directory));
 
-    app::module_compiler::compile_main(
+    let warnings = app::module_compiler::compile_main(
         &infrastructure::create(file_path_converter.clone(), &main_package_directory)?,
+        &file_path_converter.convert_to_file_path(package_directory)?,
         &file_path_converter.convert_to_file_path(source_file)?,
         &file_path_converter.convert_to_file_path(dependency_file)?,
         &file_path_converter.convert_to_file_path(object_file)?,
@@ -26,5 +30,7 @@ pub fn compile(
         &APPLICATION_CONFIGURATION,
     )?;
 
+    warning_logger::log(&warnings)?;
+
     Ok(())
 }
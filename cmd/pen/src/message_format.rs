@@ -0,0 +1,16 @@
+use std::env;
+
+// Child processes spawned by build scripts inherit a message format through
+// this environment variable.
+const MESSAGE_FORMAT_VARIABLE: &str = "PEN_MESSAGE_FORMAT";
+const JSON_MESSAGE_FORMAT: &str = "json";
+
+pub const MESSAGE_FORMATS: &[&str] = &["human", JSON_MESSAGE_FORMAT];
+
+pub fn set(format: &str) {
+    env::set_var(MESSAGE_FORMAT_VARIABLE, format);
+}
+
+pub fn is_json() -> bool {
+    env::var(MESSAGE_FORMAT_VARIABLE).as_deref() == Ok(JSON_MESSAGE_FORMAT)
+}
@@ -1,8 +1,11 @@
-use super::{compile_configuration::COMPILE_CONFIGURATION, main_package_directory_finder};
+use super::{
+    compile_configuration::COMPILE_CONFIGURATION, main_package_directory_finder, warning_logger,
+};
 use crate::infrastructure;
 use std::sync::Arc;
 
 pub fn compile(
+    package_directory: &str,
     source_file: &str,
     dependency_file: &str,
     object_file: &str,
@@ -11,9 +14,11 @@ pub fn compile(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let main_package_directory = main_package_directory_finder::find()?;
     let file_path_converter = Arc::new(infra::FilePathConverter::new(&main_package_directory));
+    let package_directory = file_path_converter.convert_to_file_path(package_directory)?;
 
-    app::module_compiler::compile(
+    let warnings = app::module_compiler::compile(
         &infrastructure::create(file_path_converter.clone(), &main_package_directory)?,
+        &package_directory,
         &file_path_converter.convert_to_file_path(source_file)?,
         &file_path_converter.convert_to_file_path(dependency_file)?,
         &file_path_converter.convert_to_file_path(object_file)?,
@@ -22,5 +27,10 @@ pub fn compile(
         &COMPILE_CONFIGURATION,
     )?;
 
+    // Warnings in external packages are not actionable for users.
+    if package_directory == file_path_converter.convert_to_file_path(&main_package_directory)? {
+        warning_logger::log(&warnings)?;
+    }
+
     Ok(())
 }
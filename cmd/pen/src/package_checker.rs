@@ -0,0 +1,132 @@
+use super::{main_package_directory_finder, warning_logger};
+use crate::{
+    application_configuration::APPLICATION_CONFIGURATION,
+    compile_configuration::COMPILE_CONFIGURATION,
+    file_path_configuration::{
+        DEFAULT_TARGET_DIRECTORY, FFI_PACKAGE_URL, OUTPUT_DIRECTORY, PRELUDE_PACKAGE_URL,
+    },
+    infrastructure,
+};
+use std::{error::Error, sync::Arc};
+
+pub fn check(mir: bool, verbose: bool) -> Result<(), Box<dyn Error>> {
+    let main_package_directory = main_package_directory_finder::find()?;
+    let file_path_converter = Arc::new(infra::FilePathConverter::new(
+        main_package_directory.clone(),
+    ));
+    let infrastructure =
+        infrastructure::create(file_path_converter.clone(), &main_package_directory)?;
+    let main_package_directory =
+        file_path_converter.convert_to_file_path(&main_package_directory)?;
+    let output_directory = main_package_directory.join(&app::infra::FilePath::new([
+        OUTPUT_DIRECTORY,
+        DEFAULT_TARGET_DIRECTORY,
+    ]));
+
+    if verbose {
+        infra::log_info("initializing external packages")?;
+    }
+
+    app::package_initializer::initialize(
+        &infrastructure,
+        &main_package_directory,
+        &output_directory,
+        &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+        &url::Url::parse(FFI_PACKAGE_URL)?,
+    )?;
+
+    if verbose {
+        infra::log_info("checking modules")?;
+    }
+
+    warning_logger::log(&app::package_checker::check(
+        &infrastructure,
+        &main_package_directory,
+        &output_directory,
+        &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+        mir.then(|| COMPILE_CONFIGURATION.as_ref()),
+        &APPLICATION_CONFIGURATION,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const LIBRARY_CONFIGURATION: &str = r#"{"type":"library","dependencies":{}}"#;
+
+    fn check_package(files: &[(&str, &str)]) -> Result<Vec<app::AnalysisWarning>, Box<dyn Error>> {
+        let directory = tempfile::tempdir().unwrap();
+
+        for (path, content) in [
+            ("pen.json", LIBRARY_CONFIGURATION),
+            (
+                ".pen/default/packages/pen_prelude/pen.json",
+                LIBRARY_CONFIGURATION,
+            ),
+        ]
+        .iter()
+        .chain(files)
+        {
+            let path = directory.path().join(path);
+
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        app::package_checker::check(
+            &infrastructure::create(
+                Arc::new(infra::FilePathConverter::new(directory.path())),
+                directory.path(),
+            )?,
+            &app::infra::FilePath::empty(),
+            &app::infra::FilePath::new([OUTPUT_DIRECTORY, DEFAULT_TARGET_DIRECTORY]),
+            &url::Url::parse(PRELUDE_PACKAGE_URL)?,
+            None,
+            &APPLICATION_CONFIGURATION,
+        )
+    }
+
+    #[test]
+    fn check_package_without_errors() {
+        assert!(check_package(&[
+            ("Foo.pen", "Foo = \\() none { none }"),
+            ("Bar.pen", "import 'Foo\n\nBar = \\() none { Foo'Foo() }"),
+        ])
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn report_errors_in_all_modules() {
+        assert_eq!(
+            app::diagnostic_converter::convert(
+                check_package(&[
+                    ("Foo.pen", "Foo = \\() none { 42 }"),
+                    ("Bar.pen", "Bar = \\() number { none }"),
+                ])
+                .unwrap_err()
+                .as_ref()
+            )
+            .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn report_error_in_dependency_module_once() {
+        assert_eq!(
+            app::diagnostic_converter::convert(
+                check_package(&[
+                    ("Foo.pen", "Foo = \\() none { ) }"),
+                    ("Bar.pen", "import 'Foo\n\nBar = \\() none { Foo'Foo() }"),
+                ])
+                .unwrap_err()
+                .as_ref()
+            )
+            .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use super::message_format;
+
+pub fn log(warnings: &[app::AnalysisWarning]) -> Result<(), Box<dyn std::error::Error>> {
+    for warning in warnings {
+        if message_format::is_json() {
+            infra::log_diagnostic(&app::diagnostic_converter::convert_warning(warning))?;
+        } else {
+            infra::log_warning(warning)?;
+        }
+    }
+
+    Ok(())
+}
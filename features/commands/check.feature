@@ -0,0 +1,62 @@
+Feature: Checking packages
+  Background:
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "library",
+      "dependencies": {
+        "Core": "pen:///core"
+      }
+    }
+    """
+
+  Scenario: Check a package
+    Given a file named "Foo.pen" with:
+    """pen
+    import Core'Number
+
+    Foo = \(x number) string {
+      Number'String(x)
+    }
+    """
+    And a file named "Bar.pen" with:
+    """pen
+    import 'Foo
+
+    Bar = \() string {
+      Foo'Foo(42)
+    }
+    """
+    When I run `pen check`
+    Then the exit status should be 0
+
+  Scenario: Check a package after building it
+    Given a file named "Foo.pen" with:
+    """pen
+    import Core'Number
+
+    Foo = \(x number) string {
+      Number'String(x)
+    }
+    """
+    And I successfully run `pen build`
+    When I run `pen check`
+    Then the exit status should be 0
+
+  Scenario: Report type errors in all modules
+    Given a file named "Foo.pen" with:
+    """pen
+    Foo = \() none {
+      42
+    }
+    """
+    And a file named "Bar.pen" with:
+    """pen
+    Bar = \() number {
+      none
+    }
+    """
+    When I run `pen check`
+    Then the exit status should not be 0
+    And the stderr should contain "Foo.pen"
+    And the stderr should contain "Bar.pen"
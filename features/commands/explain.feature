@@ -0,0 +1,20 @@
+Feature: Explaining error codes
+  Scenario: Explain an error code
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "library",
+      "dependencies": {}
+    }
+    """
+    And a file named "Foo.pen" with:
+    """pen
+    f = \() number {
+      "foo"
+    }
+    """
+    When I run `pen build`
+    Then the exit status should not be 0
+    And the stderr should contain "[E0024] types not matched"
+    When I successfully run `pen explain E0024`
+    Then the stdout should contain "A type of an expression does not match with the one expected."
@@ -34,3 +34,17 @@ Feature: Formatting module files
     """
     When I run `pen format --checked`
     Then the exit status should not be 0
+
+  Scenario: Report all syntax errors in a module file
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \() none {
+      x =
+    }
+
+    g = \() none { ) }
+    """
+    When I run `pen format`
+    Then the exit status should not be 0
+    And the stderr should contain "3:1:"
+    And the stderr should contain "5:16:"
@@ -0,0 +1,44 @@
+Feature: Running a language server
+  Background:
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "library",
+      "dependencies": {}
+    }
+    """
+    And a file named "lsp.sh" with:
+    """sh
+    message() {
+      printf 'Content-Length: %d\r\n\r\n%s' "$(printf %s "$1" | wc -c)" "$1"
+    }
+
+    {
+      message '{"jsonrpc":"2.0","id":0,"method":"initialize","params":{"capabilities":{}}}'
+      message '{"jsonrpc":"2.0","method":"initialized","params":{}}'
+      message '{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":{"uri":"file://'"$PWD"'/Foo.pen","languageId":"pen","version":0,"text":"'"$1"'"}}}'
+      message '{"jsonrpc":"2.0","id":1,"method":"textDocument/hover","params":{"textDocument":{"uri":"file://'"$PWD"'/Foo.pen"},"position":{"line":0,"character":25}}}'
+      message '{"jsonrpc":"2.0","id":2,"method":"shutdown"}'
+      message '{"jsonrpc":"2.0","method":"exit"}'
+    } | pen lsp
+    """
+
+  Scenario: Publish no diagnostics for a valid module
+    When I successfully run `sh lsp.sh 'f = \\(x number) number { x }'`
+    Then the stdout should contain "textDocument/publishDiagnostics"
+    And the stdout should contain:
+    """
+    "diagnostics":[]
+    """
+    And the stdout should contain:
+    """
+    "kind":"markdown"
+    """
+
+  Scenario: Publish diagnostics for a type error
+    When I successfully run `sh lsp.sh 'f = \\(x number) number { none }'`
+    Then the stdout should contain "textDocument/publishDiagnostics"
+    And the stdout should contain:
+    """
+    "code":"E0024"
+    """
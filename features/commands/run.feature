@@ -0,0 +1,69 @@
+Feature: Running applications
+  Background:
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "application",
+      "dependencies": {
+        "Core": "pen:///core",
+        "Os": "pen:///os"
+      }
+    }
+    """
+
+  Scenario: Run an application
+    Given a file named "main.pen" with:
+    """pen
+    import Os'Context { Context }
+    import Os'File
+
+    main = \(ctx context) none {
+      _ = File'Write(ctx.Os, File'StdOut(), "Hello, world!")
+
+      none
+    }
+    """
+    When I successfully run `pen run`
+    Then the stdout should contain "Hello, world!"
+
+  Scenario: Pass arguments to an application
+    Given a file named "main.pen" with:
+    """pen
+    import Core'String
+    import Os'Context { Context }
+    import Os'Environment
+    import Os'File
+
+    main = \(ctx context) none {
+      _ = File'Write(ctx.Os, File'StdOut(), String'Join(Environment'Arguments(ctx.Os), " "))
+
+      none
+    }
+    """
+    When I successfully run `pen run -- foo bar`
+    Then the stdout should contain "foo bar"
+
+  Scenario: Exit with a status code of an application
+    Given a file named "main.pen" with:
+    """pen
+    import Os'Context { Context }
+    import Os'Process
+
+    main = \(ctx context) none {
+      Process'Exit(ctx.Os, 42)
+    }
+    """
+    When I run `pen run`
+    Then the exit status should be 42
+
+  Scenario: Fail to run a library package
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "library",
+      "dependencies": {}
+    }
+    """
+    When I run `pen run`
+    Then the exit status should not be 0
+    And the stderr should contain "package is not an application"
@@ -121,3 +121,29 @@ Feature: Modules
     """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Call a function in a module as a method
+    Given a file named "Foo.pen" with:
+    """pen
+    type Foo {
+      x number
+    }
+
+    New = \() Foo {
+      Foo{x: 0}
+    }
+
+    Add = \(f Foo, y number) Foo {
+      Foo{x: f.x + y}
+    }
+    """
+    And a file named "Bar.pen" with:
+    """pen
+    import 'Foo
+
+    Bar = \() Foo'Foo {
+      Foo'New().Add(1).Add(2)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
@@ -36,6 +36,24 @@ Feature: Block
     When I run `pen build`
     Then the exit status should be 0
 
+  Scenario: Use pipe operator
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x number) number {
+      x
+    }
+
+    g = \(x number, y number) number {
+      x + y
+    }
+
+    h = \(x number) number {
+      x |> f |> g(1)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
   Scenario: Use if expression
     Given a file named "Foo.pen" with:
     """pen
@@ -59,3 +77,30 @@ Feature: Block
     """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Use match expression
+    Given a file named "Foo.pen" with:
+    """pen
+    type foo {
+      x number
+      y number | none
+    }
+
+    f = \(x foo | none) number {
+      match x {
+        {x: 0, y: y as number} as foo { y }
+        {x, y: _} as foo { x }
+        _ { 0 }
+      }
+    }
+
+    g = \(xs [number]) number {
+      match xs {
+        [y, ...ys] if y > 0 { y + g(ys) }
+        [] { 0 }
+        [_, ...ys] { g(ys) }
+      }
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
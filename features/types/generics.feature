@@ -0,0 +1,189 @@
+Feature: Generics
+  Background:
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "library",
+      "dependencies": {}
+    }
+    """
+
+  Scenario: Define a generic function
+    Given a file named "Foo.pen" with:
+    """pen
+    First<a> = \(xs [a], fallback a) a {
+      if [x, ..._] = xs {
+        x()
+      } else {
+        fallback
+      }
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Call a generic function
+    Given a file named "Foo.pen" with:
+    """pen
+    first<a> = \(xs [a], fallback a) a {
+      if [x, ..._] = xs {
+        x()
+      } else {
+        fallback
+      }
+    }
+
+    f = \(xs [number]) number {
+      first(xs, 0)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Call a generic function with a function argument
+    Given a file named "Foo.pen" with:
+    """pen
+    map<a, b> = \(xs [a], f \(a) b) [b] {
+      [b f(x()) for x in xs]
+    }
+
+    f = \(xs [number]) [string] {
+      map(xs, \(x number) string { "foo" })
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Import a generic function
+    Given a file named "Foo.pen" with:
+    """pen
+    Identity<a> = \(x a) a {
+      x
+    }
+    """
+    And a file named "Bar.pen" with:
+    """pen
+    import 'Foo
+
+    f = \() number {
+      Foo'Identity(42)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use a type variable as a concrete type
+    Given a file named "Foo.pen" with:
+    """pen
+    f<a> = \(x a) number {
+      x + 1
+    }
+    """
+    When I run `pen build`
+    Then the exit status should not be 0
+
+  Scenario: Use a generic function as a value
+    Given a file named "Foo.pen" with:
+    """pen
+    Identity<a> = \(x a) a {
+      x
+    }
+
+    f = \() \(number) number {
+      Identity
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use a generic function as a value of an any type
+    Given a file named "Foo.pen" with:
+    """pen
+    Identity<a> = \(x a) a {
+      x
+    }
+
+    f = \() any {
+      Identity
+    }
+    """
+    When I run `pen build`
+    Then the exit status should not be 0
+
+  Scenario: Call a generic function with a nested list
+    Given a file named "Foo.pen" with:
+    """pen
+    flatten<a> = \(xss [[a]]) [a] {
+      [a x() for xs in xss for x in xs()]
+    }
+
+    f = \(xss [[number]]) [number] {
+      flatten(xss)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Define a generic record
+    Given a file named "Foo.pen" with:
+    """pen
+    type Box<a> {
+      value a
+    }
+
+    unwrap<a> = \(b Box<a>) a {
+      b.value
+    }
+
+    f = \() number {
+      unwrap(Box{value: 42})
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Define a generic type alias
+    Given a file named "Foo.pen" with:
+    """pen
+    type Maybe<a> = a | none
+
+    f = \(x Maybe<number>) number {
+      if x = x as number {
+        x
+      } else {
+        0
+      }
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Define a generic type alias of an anonymous record
+    Given a file named "Foo.pen" with:
+    """pen
+    type Pair<a, b> = {x a, y b}
+
+    f = \() Pair<number, string> {
+      {x number, y string}{x: 42, y: "foo"}
+    }
+
+    g = \(p Pair<number, string>) number {
+      p.x
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use different instances of a generic record in a union
+    Given a file named "Foo.pen" with:
+    """pen
+    type Box<a> {
+      value a
+    }
+
+    f = \(x Box<number> | Box<string>) none {
+      none
+    }
+    """
+    When I run `pen build`
+    Then the exit status should not be 0
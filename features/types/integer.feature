@@ -0,0 +1,105 @@
+Feature: Integer
+  Background:
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "library",
+      "dependencies": {}
+    }
+    """
+
+  Scenario: Convert a number into integers
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \() byte {
+      byte(42)
+    }
+
+    g = \() i64 {
+      i64(42)
+    }
+
+    h = \() u64 {
+      u64(42)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use integer literals
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \() byte {
+      0xFFbyte
+    }
+
+    g = \(x i64) i64 {
+      x + -42i64
+    }
+
+    h = \() u64 {
+      18446744073709551615u64
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Fail to use an integer literal out of range
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \() byte {
+      256byte
+    }
+    """
+    When I run `pen build`
+    Then the exit status should not be 0
+
+  Scenario: Convert integers into a number
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x byte, y i64, z u64) number {
+      number(x) + number(y) + number(z)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use arithmetic operators
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x i64, y i64) i64 {
+      x + y - x * y / x
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use remainder and bitwise operators
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x u64, y u64) u64 {
+      (x % y) ||| (x &&& y) ^^^ (x <<< y >>> y)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use equality and order operators
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x u64, y u64) boolean {
+      x == y | x < y
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Fail to mix integers and numbers
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x i64) i64 {
+      x + 1
+    }
+    """
+    When I run `pen build`
+    Then the exit status should not be 0
@@ -0,0 +1,101 @@
+Feature: Interface
+  Background:
+    Given a file named "pen.json" with:
+    """json
+    {
+      "type": "library",
+      "dependencies": {}
+    }
+    """
+
+  Scenario: Define an interface
+    Given a file named "Foo.pen" with:
+    """pen
+    interface Show<a> {
+      String \(a) string
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Implement an interface
+    Given a file named "Foo.pen" with:
+    """pen
+    interface Show<a> {
+      String \(a) string
+    }
+
+    implement Show<number> {
+      String = \(x number) string {
+        "number"
+      }
+    }
+
+    f = \() string {
+      String(42)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Call a function with a constrained type parameter
+    Given a file named "Foo.pen" with:
+    """pen
+    interface Show<a> {
+      String \(a) string
+    }
+
+    implement Show<number> {
+      String = \(x number) string {
+        "number"
+      }
+    }
+
+    Strings<a: Show> = \(xs [a]) [string] {
+      [string String(x()) for x in xs]
+    }
+
+    f = \() [string] {
+      Strings([number 1, 2, 3])
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Import an implementation
+    Given a file named "Foo.pen" with:
+    """pen
+    interface Show<a> {
+      String \(a) string
+    }
+
+    implement Show<number> {
+      String = \(x number) string {
+        "number"
+      }
+    }
+    """
+    And a file named "Bar.pen" with:
+    """pen
+    import 'Foo
+
+    f = \() string {
+      Foo'String(42)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Call a method without its implementation
+    Given a file named "Foo.pen" with:
+    """pen
+    interface Show<a> {
+      String \(a) string
+    }
+
+    f = \() string {
+      String(42)
+    }
+    """
+    When I run `pen build`
+    Then the exit status should not be 0
@@ -92,6 +92,20 @@ Feature: List
     When I run `pen build`
     Then the exit status should be 0
 
+  Scenario: Use list comprehension with filters, nested loops and zipped lists
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(xs [number], ys [number]) [number] {
+      [number x() + y() for x in xs if x() > 0 for y in ys]
+    }
+
+    g = \(xs [number], ys [number]) [number] {
+      [number x() * y() for x, y in xs, ys]
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
   Scenario: Get a size of a list
     Given a file named "Foo.pen" with:
     """pen
@@ -101,3 +115,13 @@ Feature: List
     """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Use order operators
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(xs [number], ys [number]) boolean {
+      xs < ys
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
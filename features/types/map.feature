@@ -112,6 +112,20 @@ Feature: Map
     When I successfully run `pen build`
     Then the exit status should be 0
 
+  Scenario: Use map comprehension
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(xs [string]) {string: number} {
+      {string: number x(): 1 for x in xs}
+    }
+
+    g = \(m {string: number}) {string: number} {
+      {string: number k: v + 1 for k, v in m if v > 0}
+    }
+    """
+    When I successfully run `pen build`
+    Then the exit status should be 0
+
   Scenario: Get a size of a map
     Given a file named "Foo.pen" with:
     """pen
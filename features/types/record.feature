@@ -106,6 +106,21 @@ Feature: Record
     When I run `pen build`
     Then the exit status should be 0
 
+  Scenario: Use order operators
+    Given a file named "Foo.pen" with:
+    """pen
+    type r {
+      x number
+      y string
+    }
+
+    f = \(x r, y r) boolean {
+      x <= y
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
   Scenario: Propagate openness of a record
     Given a file named "Foo.pen" with:
     """pen
@@ -131,3 +146,116 @@ Feature: Record
     """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Use a newtype
+    Given a file named "Foo.pen" with:
+    """pen
+    newtype r {
+      x number
+    }
+
+    f = \(x number) r {
+      r{x: x}
+    }
+
+    g = \(x r) number {
+      x.x
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Convert newtypes to and from any
+    Given a file named "Foo.pen" with:
+    """pen
+    newtype r {
+      x number
+    }
+
+    newtype s {
+      x any
+    }
+
+    f = \(x r) any {
+      x
+    }
+
+    g = \(x any) number {
+      if x = x as r {
+        x.x
+      } else if s {
+        0
+      } else {
+        1
+      }
+    }
+
+    h = \(x s) any {
+      x
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Create an anonymous record
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \() {x number, y none} {
+      {x number, y none}{x: 42, y: none}
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Deconstruct a record into variables
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \() {x number, y number} {
+      {x number, y number}{x: 1, y: 2}
+    }
+
+    g = \() number {
+      {x, y} = f()
+
+      x + y
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use anonymous records across modules
+    Given a file named "Foo.pen" with:
+    """pen
+    Foo = \() {x number, y none} {
+      {x number, y none}{x: 42, y: none}
+    }
+    """
+    And a file named "Bar.pen" with:
+    """pen
+    import 'Foo
+
+    f = \() boolean {
+      Foo'Foo() == {y none, x number}{x: 42, y: none}
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Omit fields with default values
+    Given a file named "Foo.pen" with:
+    """pen
+    type Foo {
+      X number = 42
+      Y string
+    }
+    """
+    And a file named "Bar.pen" with:
+    """pen
+    import 'Foo
+
+    f = \() Foo'Foo {
+      Foo'Foo{Y: "foo"}
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
@@ -31,3 +31,23 @@ Feature: String
     """
     When I run `pen build`
     Then the exit status should be 0
+
+  Scenario: Use order operators
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x string, y string) boolean {
+      x < y
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
+
+  Scenario: Use string interpolation
+    Given a file named "Foo.pen" with:
+    """pen
+    f = \(x string, y string) string {
+      "foo ${x} bar ${y}"
+    }
+    """
+    When I run `pen build`
+    Then the exit status should be 0
@@ -0,0 +1,105 @@
+use crate::{
+    error::ApplicationError,
+    infra::{FilePath, Infrastructure},
+    package_builder, ApplicationConfiguration, PackageType,
+};
+use std::{env, error::Error};
+
+// Returns an exit code of an application or `None` if it is unknown.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    infrastructure: &Infrastructure,
+    main_package_directory: &FilePath,
+    output_directory: &FilePath,
+    target_triple: Option<&str>,
+    target_runners: &[(&str, &str)],
+    arguments: &[String],
+    prelude_package_url: &url::Url,
+    ffi_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Option<i32>, Box<dyn Error>> {
+    if infrastructure
+        .package_configuration_reader
+        .read(main_package_directory)?
+        .type_()
+        != PackageType::Application
+    {
+        return Err(ApplicationError::NotApplicationPackage.into());
+    }
+
+    let runner = target_triple
+        .map(|target_triple| resolve_runner(target_triple, target_runners))
+        .transpose()?
+        .flatten();
+
+    package_builder::build(
+        infrastructure,
+        main_package_directory,
+        output_directory,
+        target_triple,
+        prelude_package_url,
+        ffi_package_url,
+        application_configuration,
+    )?;
+
+    infrastructure.command_runner.run_application(
+        &main_package_directory.join(&FilePath::new([
+            &application_configuration.application_filename
+        ])),
+        runner,
+        arguments,
+    )
+}
+
+// Applications built for foreign targets are run by runners while ones for a
+// host are run directly.
+fn resolve_runner<'a>(
+    target_triple: &str,
+    target_runners: &[(&str, &'a str)],
+) -> Result<Option<&'a str>, ApplicationError> {
+    if let Some((_, runner)) = target_runners
+        .iter()
+        .find(|(target, _)| *target == target_triple)
+    {
+        Ok(Some(runner))
+    } else if target_triple.starts_with(env::consts::ARCH)
+        && target_triple.contains(env::consts::OS)
+    {
+        Ok(None)
+    } else {
+        Err(ApplicationError::TargetRunnerNotFound(target_triple.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_runner_for_foreign_target() {
+        assert_eq!(
+            resolve_runner("wasm32-wasi", &[("wasm32-wasi", "wasmtime")]).unwrap(),
+            Some("wasmtime")
+        );
+    }
+
+    #[test]
+    fn resolve_no_runner_for_host_target() {
+        assert_eq!(
+            resolve_runner(
+                &format!("{}-unknown-{}-musl", env::consts::ARCH, env::consts::OS),
+                &[("wasm32-wasi", "wasmtime")]
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn fail_to_resolve_runner_for_unknown_foreign_target() {
+        assert!(matches!(
+            resolve_runner("wasm32-unknown-unknown", &[("wasm32-wasi", "wasmtime")]),
+            Err(ApplicationError::TargetRunnerNotFound(target)) if target == "wasm32-unknown-unknown"
+        ));
+    }
+}
@@ -2,6 +2,7 @@ pub mod dependency_serializer;
 pub mod file_path_resolver;
 pub mod interface_serializer;
 pub mod module_id_calculator;
+pub mod module_parser;
 pub mod module_test_information_serializer;
 pub mod package_id_calculator;
 pub mod package_test_information_serializer;
@@ -0,0 +1,36 @@
+use parse::{parse_with_recovery, ParseErrors};
+
+// Parse a module reporting all syntax errors at once.
+pub fn parse(source: &str, path: &str) -> Result<ast::Module, ParseErrors> {
+    let (module, errors) = parse_with_recovery(source, path);
+
+    if errors.is_empty() {
+        Ok(module)
+    } else {
+        Err(ParseErrors::new(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_module() {
+        assert!(parse("x = \\() none { none }", "foo.pen").is_ok());
+    }
+
+    #[test]
+    fn report_all_errors() {
+        assert_eq!(
+            parse(
+                "f = \\() none {\n  x =\n}\n\ng = \\() none { ) }\n",
+                "foo.pen"
+            )
+            .unwrap_err()
+            .errors()
+            .len(),
+            2
+        );
+    }
+}
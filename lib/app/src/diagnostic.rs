@@ -0,0 +1,83 @@
+use position::Position;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Span {
+    position: Position,
+    label: Option<String>,
+}
+
+impl Span {
+    pub fn new(position: Position, label: Option<String>) -> Self {
+        Self { position, label }
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    severity: Severity,
+    code: Option<String>,
+    message: String,
+    primary_span: Option<Span>,
+    secondary_spans: Vec<Span>,
+    notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(
+        severity: Severity,
+        code: Option<String>,
+        message: impl Into<String>,
+        primary_span: Option<Span>,
+        secondary_spans: Vec<Span>,
+        notes: Vec<String>,
+    ) -> Self {
+        Self {
+            severity,
+            code,
+            message: message.into(),
+            primary_span,
+            secondary_spans,
+            notes,
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn primary_span(&self) -> Option<&Span> {
+        self.primary_span.as_ref()
+    }
+
+    pub fn secondary_spans(&self) -> &[Span] {
+        &self.secondary_spans
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+}
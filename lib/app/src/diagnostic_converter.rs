@@ -0,0 +1,344 @@
+use crate::{
+    diagnostic::{Diagnostic, Severity, Span},
+    error::ApplicationError,
+};
+use hir::{
+    analysis::{type_formatter, AnalysisError, AnalysisWarning},
+    types::Type,
+};
+use position::Position;
+use std::error::Error;
+
+pub fn convert(error: &(dyn Error + 'static)) -> Vec<Diagnostic> {
+    if let Some(error) = error.downcast_ref::<parse::ParseError>() {
+        vec![convert_parse_error(error)]
+    } else if let Some(error) = error.downcast_ref::<parse::ParseErrors>() {
+        error.errors().iter().map(convert_parse_error).collect()
+    } else if let Some(ApplicationError::Multiple(errors)) = error.downcast_ref() {
+        errors
+            .iter()
+            .flat_map(|error| convert(error.as_ref()))
+            .collect()
+    } else if let Some(error) = error.downcast_ref::<ast_hir::CompileError>() {
+        vec![convert_positioned_error(error, error.position())]
+    } else if let Some(error) = error.downcast_ref::<hir_mir::CompileError>() {
+        if let hir_mir::CompileError::Analysis(error) = error {
+            convert_analysis_error(error)
+        } else {
+            vec![convert_positioned_error(error, error.position())]
+        }
+    } else if let Some(error) = error.downcast_ref::<AnalysisError>() {
+        convert_analysis_error(error)
+    } else {
+        vec![Diagnostic::new(
+            Severity::Error,
+            None,
+            error.to_string(),
+            None,
+            vec![],
+            collect_source_messages(error),
+        )]
+    }
+}
+
+pub fn convert_warning(warning: &AnalysisWarning) -> Diagnostic {
+    let message = warning.to_string();
+
+    Diagnostic::new(
+        Severity::Warning,
+        Some(warning.code().into()),
+        message
+            .strip_prefix(&format!("[{}] ", warning.code()))
+            .unwrap_or(&message)
+            .lines()
+            .next()
+            .unwrap_or_default(),
+        Some(Span::new(warning.position().clone(), None)),
+        vec![],
+        vec![],
+    )
+}
+
+fn convert_parse_error(error: &parse::ParseError) -> Diagnostic {
+    Diagnostic::new(
+        Severity::Error,
+        None,
+        error.message(),
+        Some(Span::new(error.position().clone(), None)),
+        vec![],
+        if error.expected().is_empty() {
+            vec![]
+        } else {
+            vec![format!("expected: {}", error.expected().join(", "))]
+        },
+    )
+}
+
+fn convert_analysis_error(error: &AnalysisError) -> Vec<Diagnostic> {
+    match error {
+        AnalysisError::Multiple(errors) => errors.iter().flat_map(convert_analysis_error).collect(),
+        AnalysisError::CollectionExpected(type_)
+        | AnalysisError::FunctionExpected(type_)
+        | AnalysisError::ListExpected(type_)
+        | AnalysisError::MapExpected(type_)
+        | AnalysisError::RecordExpected(type_)
+        | AnalysisError::UnionExpected(type_)
+        | AnalysisError::VariantExpected(type_) => vec![create_diagnostic(
+            error,
+            error.code(),
+            Some(create_type_span(type_, "found")),
+            vec![],
+            vec![],
+        )],
+        AnalysisError::DuplicateFunctionNames(one, other)
+        | AnalysisError::DuplicateTypeNames(one, other) => vec![create_diagnostic(
+            error,
+            error.code(),
+            Some(Span::new(one.clone(), None)),
+            vec![Span::new(other.clone(), None)],
+            vec![],
+        )],
+        AnalysisError::MissingElseBlock(position, type_) => vec![create_diagnostic(
+            error,
+            error.code(),
+            Some(Span::new(position.clone(), None)),
+            vec![create_type_span(type_, "uncovered")],
+            vec![],
+        )],
+        AnalysisError::TypesNotMatched(lower, upper) => vec![create_diagnostic(
+            error,
+            error.code(),
+            Some(create_type_span(lower, "found")),
+            vec![create_type_span(upper, "expected")],
+            vec![],
+        )],
+        _ => vec![create_diagnostic(
+            error,
+            error.code(),
+            error
+                .position()
+                .map(|position| Span::new(position.clone(), None)),
+            vec![],
+            error.suggestion().into_iter().collect(),
+        )],
+    }
+}
+
+fn convert_positioned_error(error: &dyn Error, position: Option<&Position>) -> Diagnostic {
+    create_diagnostic(
+        error,
+        None,
+        position.map(|position| Span::new(position.clone(), None)),
+        vec![],
+        vec![],
+    )
+}
+
+// Errors with positions describe themselves in the first lines of their
+// messages followed by the positions. Error codes are prefixed to messages.
+fn create_diagnostic(
+    error: &dyn Error,
+    code: Option<&str>,
+    primary_span: Option<Span>,
+    secondary_spans: Vec<Span>,
+    notes: Vec<String>,
+) -> Diagnostic {
+    let message = error.to_string();
+    let message = if let Some(code) = code {
+        message
+            .strip_prefix(&format!("[{}] ", code))
+            .unwrap_or(&message)
+    } else {
+        &message
+    };
+
+    Diagnostic::new(
+        Severity::Error,
+        code.map(String::from),
+        if primary_span.is_some() {
+            message.lines().next().unwrap_or_default()
+        } else {
+            message
+        },
+        primary_span,
+        secondary_spans,
+        notes,
+    )
+}
+
+fn create_type_span(type_: &Type, verb: &str) -> Span {
+    Span::new(
+        type_.position().clone(),
+        Some(format!("{} `{}`", verb, type_formatter::format(type_))),
+    )
+}
+
+fn collect_source_messages(error: &dyn Error) -> Vec<String> {
+    let mut messages = vec![];
+    let mut source = error.source();
+
+    while let Some(error) = source {
+        messages.push(error.to_string());
+        source = error.source();
+    }
+
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hir::types;
+
+    #[test]
+    fn convert_parse_error() {
+        let diagnostics = convert(&parse::parse("x", "foo.pen").unwrap_err());
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].primary_span().unwrap().position().path(),
+            "foo.pen"
+        );
+        assert!(!diagnostics[0].notes().is_empty());
+    }
+
+    #[test]
+    fn convert_parse_errors() {
+        let diagnostics = convert(&parse::ParseErrors::new(vec![
+            parse::parse("x", "foo.pen").unwrap_err(),
+            parse::parse("x", "bar.pen").unwrap_err(),
+        ]));
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[1].primary_span().unwrap().position().path(),
+            "bar.pen"
+        );
+    }
+
+    #[test]
+    fn convert_analysis_error() {
+        assert_eq!(
+            convert(&hir_mir::CompileError::from(
+                AnalysisError::UnreachableCode(Position::new("foo.pen", 1, 1, "x"))
+            )),
+            vec![Diagnostic::new(
+                Severity::Error,
+                Some("E0027".into()),
+                "unreachable code",
+                Some(Span::new(Position::new("foo.pen", 1, 1, "x"), None)),
+                vec![],
+                vec![],
+            )]
+        );
+    }
+
+    #[test]
+    fn convert_missing_else_block_error() {
+        let position = Position::new("foo.pen", 1, 1, "x");
+
+        assert_eq!(
+            convert(&AnalysisError::MissingElseBlock(
+                position.clone(),
+                types::None::new(position.clone()).into(),
+            )),
+            vec![Diagnostic::new(
+                Severity::Error,
+                Some("E0012".into()),
+                "missing else block in if-type expression for uncovered types `none`",
+                Some(Span::new(position.clone(), None)),
+                vec![Span::new(position, Some("uncovered `none`".into()))],
+                vec![],
+            )]
+        );
+    }
+
+    #[test]
+    fn convert_unused_variable_warning() {
+        assert_eq!(
+            convert_warning(&AnalysisWarning::UnusedVariable(
+                "x".into(),
+                Position::new("foo.pen", 1, 1, "x")
+            )),
+            Diagnostic::new(
+                Severity::Warning,
+                Some("W0001".into()),
+                "unused variable \"x\"",
+                Some(Span::new(Position::new("foo.pen", 1, 1, "x"), None)),
+                vec![],
+                vec![],
+            )
+        );
+    }
+
+    #[test]
+    fn convert_types_not_matched_error() {
+        let position = Position::new("foo.pen", 1, 1, "x");
+
+        assert_eq!(
+            convert(&AnalysisError::TypesNotMatched(
+                types::None::new(position.clone()).into(),
+                types::Number::new(position.clone()).into(),
+            )),
+            vec![Diagnostic::new(
+                Severity::Error,
+                Some("E0024".into()),
+                "types not matched",
+                Some(Span::new(position.clone(), Some("found `none`".into()))),
+                vec![Span::new(position, Some("expected `number`".into()))],
+                vec![],
+            )]
+        );
+    }
+
+    #[test]
+    fn convert_analysis_error_with_suggestion() {
+        let position = Position::new("foo.pen", 1, 1, "fo");
+
+        assert_eq!(
+            convert(&AnalysisError::VariableNotFound(
+                hir::ir::Variable::new("fo", position.clone()),
+                vec!["foo".into()]
+            )),
+            vec![Diagnostic::new(
+                Severity::Error,
+                Some("E0029".into()),
+                "variable \"fo\" not found",
+                Some(Span::new(position, None)),
+                vec![],
+                vec!["did you mean \"foo\"?".into()],
+            )]
+        );
+    }
+
+    #[test]
+    fn convert_multiple_analysis_errors() {
+        let position = Position::new("foo.pen", 1, 1, "x");
+
+        assert_eq!(
+            convert(&AnalysisError::Multiple(vec![
+                AnalysisError::UnreachableCode(position.clone()),
+                AnalysisError::UnusedErrorValue(position),
+            ]))
+            .iter()
+            .map(Diagnostic::message)
+            .collect::<Vec<_>>(),
+            vec!["unreachable code", "unused error value"]
+        );
+    }
+
+    #[test]
+    fn convert_error_without_position() {
+        assert_eq!(
+            convert(&ApplicationError::SystemPackageNotFound),
+            vec![Diagnostic::new(
+                Severity::Error,
+                None,
+                "system package not found",
+                None,
+                vec![],
+                vec![],
+            )]
+        );
+    }
+}
@@ -4,12 +4,17 @@ use std::{error::Error, fmt::Display};
 pub enum ApplicationError {
     Build,
     ContextTypeNotFound,
+    ErrorCodeNotFound(String),
+    ModuleDependencyCycle,
     ModuleFilesNotFormatted(Vec<String>),
     ModuleNotFound(String),
+    Multiple(Vec<Box<dyn Error>>),
     NewContextFunctionNotFound,
+    NotApplicationPackage,
     PackageDependencyCycle,
     PackageNotFound(String),
     SystemPackageNotFound,
+    TargetRunnerNotFound(String),
     Test,
 }
 
@@ -22,6 +27,12 @@ impl Display for ApplicationError {
             Self::ContextTypeNotFound => {
                 write!(formatter, "context type not found")
             }
+            Self::ErrorCodeNotFound(code) => {
+                write!(formatter, "error code {} not found", code)
+            }
+            Self::ModuleDependencyCycle => {
+                write!(formatter, "module dependency cycle detected")
+            }
             Self::ModuleFilesNotFormatted(paths) => {
                 write!(
                     formatter,
@@ -32,9 +43,21 @@ impl Display for ApplicationError {
             Self::ModuleNotFound(module) => {
                 write!(formatter, "module {} not found", module)
             }
+            Self::Multiple(errors) => write!(
+                formatter,
+                "{}",
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            ),
             Self::NewContextFunctionNotFound => {
                 write!(formatter, "new context function not found")
             }
+            Self::NotApplicationPackage => {
+                write!(formatter, "package is not an application")
+            }
             Self::PackageDependencyCycle => {
                 write!(formatter, "package dependency cycle detected")
             }
@@ -44,6 +67,9 @@ impl Display for ApplicationError {
             Self::SystemPackageNotFound => {
                 write!(formatter, "system package not found")
             }
+            Self::TargetRunnerNotFound(target) => {
+                write!(formatter, "no runner for target {}", target)
+            }
             Self::Test => write!(formatter, "test failed"),
         }
     }
@@ -0,0 +1,32 @@
+use crate::error::ApplicationError;
+use std::error::Error;
+
+pub fn explain(code: &str) -> Result<&'static str, Box<dyn Error>> {
+    Ok(
+        hir::analysis::error_explanation::explain(&code.to_uppercase())
+            .ok_or_else(|| ApplicationError::ErrorCodeNotFound(code.into()))?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain_error_code() {
+        assert!(explain("E0001").is_ok());
+    }
+
+    #[test]
+    fn explain_lowercase_error_code() {
+        assert_eq!(explain("e0001").unwrap(), explain("E0001").unwrap());
+    }
+
+    #[test]
+    fn fail_to_explain_unknown_error_code() {
+        assert_eq!(
+            explain("E9999").unwrap_err().to_string(),
+            "error code E9999 not found"
+        );
+    }
+}
@@ -40,7 +40,11 @@ mod tests {
                 &[
                     (
                         url::Url::parse("file:///foo").unwrap(),
-                        PackageConfiguration::new(PackageType::Application, Default::default())
+                        PackageConfiguration::new(
+                            PackageType::Application,
+                            Default::default(),
+                            Default::default()
+                        )
                     ),
                     (
                         url::Url::parse("file:///bar").unwrap(),
@@ -52,6 +56,7 @@ mod tests {
                             ]
                             .into_iter()
                             .collect(),
+                            Default::default(),
                         )
                     ),
                     (
@@ -61,6 +66,7 @@ mod tests {
                             [("Foo".into(), url::Url::parse("file:///foo").unwrap()),]
                                 .into_iter()
                                 .collect(),
+                            Default::default(),
                         )
                     )
                 ]
@@ -3,4 +3,11 @@ use std::error::Error;
 
 pub trait CommandRunner {
     fn run(&self, executable_file: &FilePath) -> Result<(), Box<dyn Error>>;
+
+    fn run_application(
+        &self,
+        executable_file: &FilePath,
+        runner: Option<&str>,
+        arguments: &[String],
+    ) -> Result<Option<i32>, Box<dyn Error>>;
 }
@@ -1,5 +1,5 @@
 use super::FilePath;
-use std::error::Error;
+use std::{error::Error, time::SystemTime};
 
 pub trait FileSystem {
     fn exists(&self, path: &FilePath) -> bool;
@@ -7,5 +7,6 @@ pub trait FileSystem {
     fn read_directory(&self, path: &FilePath) -> Result<Vec<FilePath>, Box<dyn Error>>;
     fn read_to_string(&self, path: &FilePath) -> Result<String, Box<dyn Error>>;
     fn read_to_vec(&self, path: &FilePath) -> Result<Vec<u8>, Box<dyn Error>>;
+    fn modified_time(&self, path: &FilePath) -> Result<SystemTime, Box<dyn Error>>;
     fn write(&self, path: &FilePath, data: &[u8]) -> Result<(), Box<dyn Error>>;
 }
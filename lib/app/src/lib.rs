@@ -1,10 +1,16 @@
 mod application_configuration;
+pub mod application_runner;
 mod common;
+mod diagnostic;
+pub mod diagnostic_converter;
 mod error;
+pub mod error_explainer;
 mod external_package_configuration_reader;
 mod external_package_topological_sorter;
 mod file_finder;
 pub mod infra;
+mod main_module_configuration_qualifier;
+pub mod module_analyzer;
 pub mod module_compiler;
 pub mod module_dependency_resolver;
 mod module_finder;
@@ -12,6 +18,7 @@ pub mod module_formatter;
 mod module_target_source_resolver;
 mod package_build_script_compiler;
 pub mod package_builder;
+pub mod package_checker;
 mod package_configuration;
 pub mod package_creator;
 pub mod package_documentation_generator;
@@ -29,5 +36,7 @@ mod test_module_finder;
 pub mod test_runner;
 
 pub use application_configuration::{ApplicationConfiguration, MainModuleConfiguration};
+pub use diagnostic::{Diagnostic, Severity, Span};
+pub use hir::analysis::{AnalysisWarning, WarningConfiguration};
 pub use package_configuration::{PackageConfiguration, PackageType};
 pub use test_configuration::{TestConfiguration, TestModuleConfiguration};
@@ -0,0 +1,381 @@
+use crate::{
+    application_configuration::ApplicationConfiguration,
+    common::{file_path_resolver, interface_serializer, module_parser},
+    error::ApplicationError,
+    infra::{FilePath, Infrastructure},
+    main_module_configuration_qualifier,
+    module_compiler::PRELUDE_PREFIX,
+    module_finder,
+    package_configuration::PackageType,
+    system_package_finder,
+};
+use fnv::FnvHashMap;
+use std::{error::Error, slice};
+
+// Interfaces of a prelude package and dependency modules shared among analyses
+// of modules in a workspace.
+#[derive(Debug, Default)]
+pub struct InterfaceCache {
+    prelude_interfaces: Option<Vec<interface::Module>>,
+    interfaces: FnvHashMap<FilePath, interface::Module>,
+}
+
+impl InterfaceCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn invalidate(&mut self, output_directory: &FilePath) {
+        self.interfaces
+            .retain(|source_file, _| is_external_module(output_directory, source_file));
+    }
+}
+
+// Analyze a module from source files of its dependencies or their interface
+// files if they are built so that it does not require any build of packages.
+#[allow(clippy::too_many_arguments)]
+pub fn analyze(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    source_file: &FilePath,
+    module: &ast::Module,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+    cache: &mut InterfaceCache,
+) -> Result<hir::ir::Module, Box<dyn Error>> {
+    let (module, main_module_configuration) = compile_module(
+        infrastructure,
+        package_directory,
+        output_directory,
+        source_file,
+        module,
+        load_prelude_interfaces(
+            &mut cache.prelude_interfaces,
+            infrastructure,
+            output_directory,
+            prelude_package_url,
+        )?,
+        &mut cache.interfaces,
+        application_configuration,
+    )?;
+
+    Ok(if let Some(configuration) = &main_module_configuration {
+        hir_mir::analyze_main(&module, configuration)?
+    } else {
+        hir_mir::analyze(&module)?
+    })
+}
+
+// Compile a module into HIR without its analysis. A main module
+// configuration is returned only for a main module.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn compile_module(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    source_file: &FilePath,
+    module: &ast::Module,
+    prelude_interfaces: &[interface::Module],
+    interfaces: &mut FnvHashMap<FilePath, interface::Module>,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<(hir::ir::Module, Option<hir_mir::MainModuleConfiguration>), Box<dyn Error>> {
+    let module_interfaces = compile_module_interfaces(
+        infrastructure,
+        package_directory,
+        output_directory,
+        module,
+        prelude_interfaces,
+        interfaces,
+        slice::from_ref(source_file),
+    )?;
+
+    let context_interfaces = if is_main_module(
+        infrastructure,
+        package_directory,
+        source_file,
+        application_configuration,
+    )? {
+        Some(
+            system_package_finder::find(infrastructure, package_directory, output_directory)?
+                .into_iter()
+                .map(|(key, url)| {
+                    let package_directory =
+                        file_path_resolver::resolve_package_directory(output_directory, &url);
+
+                    Ok((
+                        key,
+                        compile_interface(
+                            infrastructure,
+                            &package_directory,
+                            output_directory,
+                            &file_path_resolver::resolve_source_file(
+                                &package_directory,
+                                slice::from_ref(&application_configuration.context_module_basename),
+                                &infrastructure.file_path_configuration,
+                            ),
+                            prelude_interfaces,
+                            interfaces,
+                            &[],
+                        )?,
+                    ))
+                })
+                .collect::<Result<FnvHashMap<_, _>, Box<dyn Error>>>()?,
+        )
+    } else {
+        None
+    };
+
+    Ok((
+        ast_hir::compile(
+            module,
+            &format!("{}:", source_file),
+            &module_interfaces,
+            &prelude_interfaces
+                .iter()
+                .cloned()
+                .chain(
+                    context_interfaces
+                        .iter()
+                        .flat_map(|interfaces| interfaces.values().cloned()),
+                )
+                .collect::<Vec<_>>(),
+        )?,
+        context_interfaces
+            .map(|interfaces| {
+                main_module_configuration_qualifier::qualify(
+                    &application_configuration.main_module,
+                    &interfaces,
+                )
+            })
+            .transpose()?,
+    ))
+}
+
+pub fn compile_interfaces(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    source_file: &FilePath,
+    module: &ast::Module,
+    prelude_package_url: &url::Url,
+    cache: &mut InterfaceCache,
+) -> Result<FnvHashMap<ast::ModulePath, interface::Module>, Box<dyn Error>> {
+    compile_module_interfaces(
+        infrastructure,
+        package_directory,
+        output_directory,
+        module,
+        load_prelude_interfaces(
+            &mut cache.prelude_interfaces,
+            infrastructure,
+            output_directory,
+            prelude_package_url,
+        )?,
+        &mut cache.interfaces,
+        slice::from_ref(source_file),
+    )
+}
+
+fn is_main_module(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    source_file: &FilePath,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<bool, Box<dyn Error>> {
+    Ok(infrastructure
+        .package_configuration_reader
+        .read(package_directory)?
+        .type_()
+        == PackageType::Application
+        && source_file
+            == &file_path_resolver::resolve_source_file(
+                package_directory,
+                slice::from_ref(&application_configuration.main_module_basename),
+                &infrastructure.file_path_configuration,
+            ))
+}
+
+pub(crate) fn compile_prelude_interfaces(
+    infrastructure: &Infrastructure,
+    output_directory: &FilePath,
+    prelude_package_url: &url::Url,
+) -> Result<Vec<interface::Module>, Box<dyn Error>> {
+    module_finder::find(
+        infrastructure,
+        &file_path_resolver::resolve_package_directory(output_directory, prelude_package_url),
+    )?
+    .iter()
+    .map(|source_file| {
+        if let Some(interface) = load_interface(infrastructure, output_directory, source_file)? {
+            return Ok(interface);
+        }
+
+        Ok(hir_mir::compile_interface(&ast_hir::compile_prelude(
+            &module_parser::parse(
+                &infrastructure.file_system.read_to_string(source_file)?,
+                &infrastructure.file_path_displayer.display(source_file),
+            )?,
+            PRELUDE_PREFIX,
+        )?)?)
+    })
+    .collect()
+}
+
+fn load_prelude_interfaces<'a>(
+    prelude_interfaces: &'a mut Option<Vec<interface::Module>>,
+    infrastructure: &Infrastructure,
+    output_directory: &FilePath,
+    prelude_package_url: &url::Url,
+) -> Result<&'a [interface::Module], Box<dyn Error>> {
+    Ok(match prelude_interfaces {
+        Some(interfaces) => interfaces,
+        None => prelude_interfaces.insert(compile_prelude_interfaces(
+            infrastructure,
+            output_directory,
+            prelude_package_url,
+        )?),
+    })
+}
+
+fn compile_module_interfaces(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    module: &ast::Module,
+    prelude_interfaces: &[interface::Module],
+    interfaces: &mut FnvHashMap<FilePath, interface::Module>,
+    source_files: &[FilePath],
+) -> Result<FnvHashMap<ast::ModulePath, interface::Module>, Box<dyn Error>> {
+    let package_configuration = infrastructure
+        .package_configuration_reader
+        .read(package_directory)?;
+
+    module
+        .imports()
+        .iter()
+        .map(|import| {
+            let package_directory = match import.module_path() {
+                ast::ModulePath::Internal(_) => package_directory.clone(),
+                ast::ModulePath::External(path) => file_path_resolver::resolve_package_directory(
+                    output_directory,
+                    package_configuration
+                        .dependencies()
+                        .get(path.package())
+                        .ok_or_else(|| ApplicationError::PackageNotFound(path.package().into()))?,
+                ),
+            };
+            let source_file = file_path_resolver::resolve_source_file(
+                &package_directory,
+                match import.module_path() {
+                    ast::ModulePath::Internal(path) => path.components(),
+                    ast::ModulePath::External(path) => path.components(),
+                },
+                &infrastructure.file_path_configuration,
+            );
+
+            if !infrastructure.file_system.exists(&source_file) {
+                return Err(
+                    ApplicationError::ModuleNotFound(import.module_path().to_string()).into(),
+                );
+            }
+
+            Ok((
+                import.module_path().clone(),
+                compile_interface(
+                    infrastructure,
+                    &package_directory,
+                    output_directory,
+                    &source_file,
+                    prelude_interfaces,
+                    interfaces,
+                    source_files,
+                )?,
+            ))
+        })
+        .collect()
+}
+
+fn compile_interface(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    source_file: &FilePath,
+    prelude_interfaces: &[interface::Module],
+    interfaces: &mut FnvHashMap<FilePath, interface::Module>,
+    source_files: &[FilePath],
+) -> Result<interface::Module, Box<dyn Error>> {
+    if let Some(interface) = interfaces.get(source_file) {
+        return Ok(interface.clone());
+    } else if source_files.contains(source_file) {
+        return Err(ApplicationError::ModuleDependencyCycle.into());
+    } else if let Some(interface) = load_interface(infrastructure, output_directory, source_file)? {
+        interfaces.insert(source_file.clone(), interface.clone());
+
+        return Ok(interface);
+    }
+
+    let module = module_parser::parse(
+        &infrastructure.file_system.read_to_string(source_file)?,
+        &infrastructure.file_path_displayer.display(source_file),
+    )?;
+    let interface = hir_mir::compile_interface(&ast_hir::compile(
+        &module,
+        &format!("{}:", source_file),
+        &compile_module_interfaces(
+            infrastructure,
+            package_directory,
+            output_directory,
+            &module,
+            prelude_interfaces,
+            interfaces,
+            &source_files
+                .iter()
+                .cloned()
+                .chain([source_file.clone()])
+                .collect::<Vec<_>>(),
+        )?,
+        prelude_interfaces,
+    )?)?;
+
+    interfaces.insert(source_file.clone(), interface.clone());
+
+    Ok(interface)
+}
+
+// Interface files of modules in external packages are reused if they are built
+// after their source files.
+fn load_interface(
+    infrastructure: &Infrastructure,
+    output_directory: &FilePath,
+    source_file: &FilePath,
+) -> Result<Option<interface::Module>, Box<dyn Error>> {
+    let interface_file = file_path_resolver::resolve_interface_file(
+        output_directory,
+        source_file,
+        &infrastructure.file_path_configuration,
+    );
+
+    Ok(
+        if is_external_module(output_directory, source_file)
+            && infrastructure.file_system.exists(&interface_file)
+            && infrastructure.file_system.modified_time(&interface_file)?
+                >= infrastructure.file_system.modified_time(source_file)?
+        {
+            Some(interface_serializer::deserialize(
+                &infrastructure.file_system.read_to_vec(&interface_file)?,
+            )?)
+        } else {
+            None
+        },
+    )
+}
+
+// Modules outside an output directory belong to a main package and can
+// change while the other ones are immutable.
+fn is_external_module(output_directory: &FilePath, source_file: &FilePath) -> bool {
+    source_file
+        .components()
+        .take(output_directory.components().count())
+        .eq(output_directory.components())
+}
@@ -1,11 +1,14 @@
 mod compile_configuration;
-mod main_module_configuration_qualifier;
-mod prelude_type_configuration_qualifier;
+pub(crate) mod prelude_type_configuration_qualifier;
 
 use crate::{
     application_configuration::ApplicationConfiguration,
-    common::{dependency_serializer, interface_serializer, module_test_information_serializer},
+    common::{
+        dependency_serializer, interface_serializer, module_parser,
+        module_test_information_serializer,
+    },
     infra::{FilePath, Infrastructure},
+    main_module_configuration_qualifier,
     test_configuration::TestModuleConfiguration,
 };
 pub use compile_configuration::{
@@ -14,21 +17,40 @@ pub use compile_configuration::{
     MirConfiguration, StringTypeConfiguration,
 };
 use fnv::FnvHashMap;
+use hir::analysis::AnalysisWarning;
 use std::{collections::BTreeMap, error::Error};
 
-const PRELUDE_PREFIX: &str = "prelude:";
+pub(crate) const PRELUDE_PREFIX: &str = "prelude:";
 
+#[allow(clippy::too_many_arguments)]
 pub fn compile(
     infrastructure: &Infrastructure,
+    package_directory: &FilePath,
     source_file: &FilePath,
     dependency_file: &FilePath,
     object_file: &FilePath,
     interface_file: &FilePath,
     target_triple: Option<&str>,
     compile_configuration: &CompileConfiguration,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<Vec<AnalysisWarning>, Box<dyn Error>> {
+    let ast_module = parse_module(infrastructure, source_file)?;
+    let module = compile_to_hir(
+        infrastructure,
+        &ast_module,
+        source_file,
+        dependency_file,
+        &[],
+    )?;
+    let warnings = collect_warnings(
+        infrastructure,
+        package_directory,
+        &ast_module,
+        &module,
+        None,
+    )?;
+
     let (module, module_interface) = hir_mir::compile(
-        &compile_to_hir(infrastructure, source_file, dependency_file, &[])?,
+        &module,
         &prelude_type_configuration_qualifier::qualify(&compile_configuration.hir, PRELUDE_PREFIX),
     )?;
 
@@ -44,12 +66,13 @@ pub fn compile(
         &interface_serializer::serialize(&module_interface)?,
     )?;
 
-    Ok(())
+    Ok(warnings)
 }
 
 #[allow(clippy::too_many_arguments)]
 pub fn compile_main(
     infrastructure: &Infrastructure,
+    package_directory: &FilePath,
     source_file: &FilePath,
     dependency_file: &FilePath,
     object_file: &FilePath,
@@ -57,7 +80,7 @@ pub fn compile_main(
     target_triple: Option<&str>,
     compile_configuration: &CompileConfiguration,
     application_configuration: &ApplicationConfiguration,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<Vec<AnalysisWarning>, Box<dyn Error>> {
     let context_interfaces = context_interface_files
         .iter()
         .map(|(key, file)| {
@@ -68,15 +91,30 @@ pub fn compile_main(
         })
         .collect::<Result<FnvHashMap<_, _>, Box<dyn Error>>>()?;
 
+    let ast_module = parse_module(infrastructure, source_file)?;
+    let module = compile_to_hir(
+        infrastructure,
+        &ast_module,
+        source_file,
+        dependency_file,
+        &context_interfaces.values().cloned().collect::<Vec<_>>(),
+    )?;
+    let warnings = collect_warnings(
+        infrastructure,
+        package_directory,
+        &ast_module,
+        &module,
+        Some(
+            &application_configuration
+                .main_module
+                .source_main_function_name,
+        ),
+    )?;
+
     compile_mir_module(
         infrastructure,
         &hir_mir::compile_main(
-            &compile_to_hir(
-                infrastructure,
-                source_file,
-                dependency_file,
-                &context_interfaces.values().cloned().collect::<Vec<_>>(),
-            )?,
+            &module,
             &prelude_type_configuration_qualifier::qualify(
                 &compile_configuration.hir,
                 PRELUDE_PREFIX,
@@ -91,7 +129,7 @@ pub fn compile_main(
         compile_configuration,
     )?;
 
-    Ok(())
+    Ok(warnings)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -106,7 +144,13 @@ pub fn compile_test(
     test_module_configuration: &TestModuleConfiguration,
 ) -> Result<(), Box<dyn Error>> {
     let (module, test_information) = hir_mir::compile_test(
-        &compile_to_hir(infrastructure, source_file, dependency_file, &[])?,
+        &compile_to_hir(
+            infrastructure,
+            &parse_module(infrastructure, source_file)?,
+            source_file,
+            dependency_file,
+            &[],
+        )?,
         &prelude_type_configuration_qualifier::qualify(&compile_configuration.hir, PRELUDE_PREFIX),
         test_module_configuration,
     )?;
@@ -127,8 +171,19 @@ pub fn compile_test(
     Ok(())
 }
 
+fn parse_module(
+    infrastructure: &Infrastructure,
+    source_file: &FilePath,
+) -> Result<ast::Module, Box<dyn Error>> {
+    Ok(module_parser::parse(
+        &infrastructure.file_system.read_to_string(source_file)?,
+        &infrastructure.file_path_displayer.display(source_file),
+    )?)
+}
+
 fn compile_to_hir(
     infrastructure: &Infrastructure,
+    ast_module: &ast::Module,
     source_file: &FilePath,
     dependency_file: &FilePath,
     context_interfaces: &[interface::Module],
@@ -137,13 +192,8 @@ fn compile_to_hir(
         &infrastructure.file_system.read_to_vec(dependency_file)?,
     )?;
 
-    let ast_module = parse::parse(
-        &infrastructure.file_system.read_to_string(source_file)?,
-        &infrastructure.file_path_displayer.display(source_file),
-    )?;
-
     Ok(ast_hir::compile(
-        &ast_module,
+        ast_module,
         &format!("{}:", source_file),
         &ast_module
             .imports()
@@ -169,6 +219,39 @@ fn compile_to_hir(
     )?)
 }
 
+// Main functions are never reported as unused although they are private.
+pub(crate) fn collect_warnings(
+    infrastructure: &Infrastructure,
+    package_directory: &FilePath,
+    ast_module: &ast::Module,
+    module: &hir::ir::Module,
+    main_function_name: Option<&str>,
+) -> Result<Vec<AnalysisWarning>, Box<dyn Error>> {
+    let package_configuration = infrastructure
+        .package_configuration_reader
+        .read(package_directory)?;
+    let configuration = package_configuration.warnings();
+
+    let mut warnings = if configuration.unused_imports {
+        ast_hir::check_imports(ast_module)?
+    } else {
+        vec![]
+    };
+
+    warnings.extend(
+        hir::analysis::collect_warnings(module, configuration)
+            .into_iter()
+            .filter(|warning| {
+                !matches!(
+                    warning,
+                    AnalysisWarning::UnusedFunction(name, _) if Some(name.as_str()) == main_function_name
+                )
+            }),
+    );
+
+    Ok(warnings)
+}
+
 pub fn compile_prelude(
     infrastructure: &Infrastructure,
     source_file: &FilePath,
@@ -178,7 +261,7 @@ pub fn compile_prelude(
     compile_configuration: &CompileConfiguration,
 ) -> Result<(), Box<dyn Error>> {
     let (module, module_interface) = hir_mir::compile_prelude(&ast_hir::compile_prelude(
-        &parse::parse(
+        &module_parser::parse(
             &infrastructure.file_system.read_to_string(source_file)?,
             &infrastructure.file_path_displayer.display(source_file),
         )?,
@@ -23,6 +23,7 @@ fn qualify_list_type_configuration(
         concatenate_function_name: prelude_prefix.to_owned()
             + &configuration.concatenate_function_name,
         equal_function_name: prelude_prefix.to_owned() + &configuration.equal_function_name,
+        less_than_function_name: prelude_prefix.to_owned() + &configuration.less_than_function_name,
         prepend_function_name: prelude_prefix.to_owned() + &configuration.prepend_function_name,
         deconstruct_function_name: prelude_prefix.to_owned()
             + &configuration.deconstruct_function_name,
@@ -41,6 +42,7 @@ fn qualify_string_type_configuration(
 ) -> hir_mir::StringTypeConfiguration {
     hir_mir::StringTypeConfiguration {
         equal_function_name: prelude_prefix.to_owned() + &configuration.equal_function_name,
+        less_than_function_name: prelude_prefix.to_owned() + &configuration.less_than_function_name,
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{
-    common::{dependency_serializer, file_path_resolver, module_id_calculator},
+    common::{dependency_serializer, file_path_resolver, module_id_calculator, module_parser},
     error::ApplicationError,
     infra::{FilePath, Infrastructure, OBJECT_DIRECTORY},
 };
@@ -20,7 +20,7 @@ pub fn resolve(
         .package_configuration_reader
         .read(package_directory)?;
 
-    let interface_files = parse::parse(
+    let interface_files = module_parser::parse(
         &infrastructure.file_system.read_to_string(source_file)?,
         &infrastructure.file_path_displayer.display(source_file),
     )?
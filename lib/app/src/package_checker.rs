@@ -0,0 +1,121 @@
+use crate::{
+    application_configuration::ApplicationConfiguration,
+    common::module_parser,
+    error::ApplicationError,
+    infra::{FilePath, Infrastructure},
+    module_analyzer,
+    module_compiler::{
+        self, prelude_type_configuration_qualifier, CompileConfiguration, PRELUDE_PREFIX,
+    },
+    module_finder,
+};
+use fnv::{FnvHashMap, FnvHashSet};
+use hir::analysis::AnalysisWarning;
+use std::error::Error;
+
+// Check modules in a package without code generation. Interfaces of
+// dependency modules are shared among modules and read from interface files
+// if they are up to date. Errors are reported for all modules.
+pub fn check(
+    infrastructure: &Infrastructure,
+    main_package_directory: &FilePath,
+    output_directory: &FilePath,
+    prelude_package_url: &url::Url,
+    compile_configuration: Option<&CompileConfiguration>,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Vec<AnalysisWarning>, Box<dyn Error>> {
+    let prelude_interfaces = module_analyzer::compile_prelude_interfaces(
+        infrastructure,
+        output_directory,
+        prelude_package_url,
+    )?;
+    let mut interfaces = Default::default();
+    let mut source_files = module_finder::find(infrastructure, main_package_directory)?;
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    let mut messages = FnvHashSet::default();
+
+    source_files.sort();
+
+    for source_file in &source_files {
+        match check_module(
+            infrastructure,
+            main_package_directory,
+            output_directory,
+            source_file,
+            &prelude_interfaces,
+            &mut interfaces,
+            compile_configuration,
+            application_configuration,
+        ) {
+            Ok(module_warnings) => warnings.extend(module_warnings),
+            // Errors in dependency modules are reported once.
+            Err(error) => {
+                if messages.insert(error.to_string()) {
+                    errors.push(error);
+                }
+            }
+        }
+    }
+
+    match errors.len() {
+        0 => Ok(warnings),
+        1 => Err(errors.remove(0)),
+        _ => Err(ApplicationError::Multiple(errors).into()),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn check_module(
+    infrastructure: &Infrastructure,
+    main_package_directory: &FilePath,
+    output_directory: &FilePath,
+    source_file: &FilePath,
+    prelude_interfaces: &[interface::Module],
+    interfaces: &mut FnvHashMap<FilePath, interface::Module>,
+    compile_configuration: Option<&CompileConfiguration>,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<Vec<AnalysisWarning>, Box<dyn Error>> {
+    let ast_module = module_parser::parse(
+        &infrastructure.file_system.read_to_string(source_file)?,
+        &infrastructure.file_path_displayer.display(source_file),
+    )?;
+    let (module, main_module_configuration) = module_analyzer::compile_module(
+        infrastructure,
+        main_package_directory,
+        output_directory,
+        source_file,
+        &ast_module,
+        prelude_interfaces,
+        interfaces,
+        application_configuration,
+    )?;
+
+    // Compilation into MIR includes its type check.
+    if let Some(compile_configuration) = compile_configuration {
+        let hir_configuration = prelude_type_configuration_qualifier::qualify(
+            &compile_configuration.hir,
+            PRELUDE_PREFIX,
+        );
+
+        if let Some(configuration) = &main_module_configuration {
+            hir_mir::compile_main(&module, &hir_configuration, configuration)?;
+        } else {
+            hir_mir::compile(&module, &hir_configuration)?;
+        }
+    } else if let Some(configuration) = &main_module_configuration {
+        hir_mir::analyze_main(&module, configuration)?;
+    } else {
+        hir_mir::analyze(&module)?;
+    }
+
+    module_compiler::collect_warnings(
+        infrastructure,
+        main_package_directory,
+        &ast_module,
+        &module,
+        main_module_configuration
+            .as_ref()
+            .map(|configuration| configuration.source_main_function_name.as_str()),
+    )
+}
@@ -1,3 +1,4 @@
+use hir::analysis::WarningConfiguration;
 use std::{
     collections::BTreeMap,
     fmt::{self, Display, Formatter},
@@ -28,13 +29,19 @@ impl Display for PackageType {
 pub struct PackageConfiguration {
     type_: PackageType,
     dependencies: BTreeMap<String, url::Url>,
+    warnings: WarningConfiguration,
 }
 
 impl PackageConfiguration {
-    pub fn new(type_: PackageType, dependencies: BTreeMap<String, url::Url>) -> Self {
+    pub fn new(
+        type_: PackageType,
+        dependencies: BTreeMap<String, url::Url>,
+        warnings: WarningConfiguration,
+    ) -> Self {
         Self {
             type_,
             dependencies,
+            warnings,
         }
     }
 
@@ -45,4 +52,8 @@ impl PackageConfiguration {
     pub fn dependencies(&self) -> &BTreeMap<String, url::Url> {
         &self.dependencies
     }
+
+    pub fn warnings(&self) -> &WarningConfiguration {
+        &self.warnings
+    }
 }
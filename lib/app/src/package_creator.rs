@@ -51,7 +51,7 @@ fn create(
     package_directory: &FilePath,
 ) -> Result<(), Box<dyn Error>> {
     infrastructure.package_configuration_writer.write(
-        &PackageConfiguration::new(package_type, dependencies.clone()),
+        &PackageConfiguration::new(package_type, dependencies.clone(), Default::default()),
         package_directory,
     )?;
 
@@ -1,9 +1,9 @@
 use crate::{
-    common::file_path_resolver,
+    common::{file_path_resolver, module_parser},
     infra::{FilePath, Infrastructure},
     module_finder,
 };
-use parse::{parse, parse_comments};
+use parse::parse_comments;
 use std::error::Error;
 
 pub struct DocumentationPackage {
@@ -44,7 +44,10 @@ pub fn generate(
                         let source = infrastructure.file_system.read_to_string(path)?;
                         let path = infrastructure.file_path_displayer.display(path);
 
-                        (parse(&source, &path)?, parse_comments(&source, &path)?)
+                        (
+                            module_parser::parse(&source, &path)?,
+                            parse_comments(&source, &path)?,
+                        )
                     },
                 ))
             })
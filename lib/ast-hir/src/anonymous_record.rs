@@ -0,0 +1,344 @@
+use ast::{types::Type, *};
+
+// Anonymous records in generic type aliases are collected with type parameters
+// of the aliases which their fields refer to.
+pub fn collect(module: &Module) -> Vec<(&types::AnonymousRecord, Vec<String>)> {
+    let mut records = vec![];
+    let mut generic_records = vec![];
+
+    for definition in module.type_definitions() {
+        match definition {
+            TypeDefinition::InterfaceDefinition(definition) => {
+                for method in definition.methods() {
+                    collect_from_function_type(method.type_(), &mut records);
+                }
+            }
+            TypeDefinition::RecordDefinition(definition) => {
+                for field in definition.fields() {
+                    collect_from_type(field.type_(), &mut records);
+
+                    if let Some(default) = field.default() {
+                        collect_from_expression(default, &mut records);
+                    }
+                }
+            }
+            TypeDefinition::TypeAlias(alias) => {
+                if alias.type_parameters().is_empty() {
+                    collect_from_type(alias.type_(), &mut records);
+                } else {
+                    let mut records = vec![];
+
+                    collect_from_type(alias.type_(), &mut records);
+
+                    generic_records.extend(records.into_iter().map(|record| {
+                        (
+                            record,
+                            collect_type_parameters(record, alias.type_parameters()),
+                        )
+                    }));
+                }
+            }
+        }
+    }
+
+    for import in module.foreign_imports() {
+        collect_from_type(import.type_(), &mut records);
+    }
+
+    for implementation in module.implementations() {
+        collect_from_type(implementation.type_(), &mut records);
+
+        for definition in implementation.function_definitions() {
+            collect_from_lambda(definition.lambda(), &mut records);
+        }
+    }
+
+    for definition in module.function_definitions() {
+        collect_from_lambda(definition.lambda(), &mut records);
+    }
+
+    generic_records
+        .into_iter()
+        .chain(records.into_iter().map(|record| (record, vec![])))
+        .collect()
+}
+
+fn collect_type_parameters(record: &types::AnonymousRecord, parameters: &[String]) -> Vec<String> {
+    let mut names = vec![];
+
+    for field in record.fields() {
+        collect_type_parameters_from_type(field.type_(), &mut names);
+    }
+
+    parameters
+        .iter()
+        .filter(|parameter| names.contains(&parameter.as_str()))
+        .cloned()
+        .collect()
+}
+
+fn collect_type_parameters_from_type<'a>(type_: &'a Type, names: &mut Vec<&'a str>) {
+    match type_ {
+        Type::AnonymousRecord(record) => {
+            for field in record.fields() {
+                collect_type_parameters_from_type(field.type_(), names);
+            }
+        }
+        Type::Function(function) => {
+            for type_ in function.arguments() {
+                collect_type_parameters_from_type(type_, names);
+            }
+
+            collect_type_parameters_from_type(function.result(), names);
+        }
+        Type::List(list) => collect_type_parameters_from_type(list.element(), names),
+        Type::Map(map) => {
+            collect_type_parameters_from_type(map.key(), names);
+            collect_type_parameters_from_type(map.value(), names);
+        }
+        Type::Union(union) => {
+            collect_type_parameters_from_type(union.lhs(), names);
+            collect_type_parameters_from_type(union.rhs(), names);
+        }
+        Type::Reference(reference) => {
+            if reference.arguments().is_empty() {
+                names.push(reference.name());
+            }
+
+            for type_ in reference.arguments() {
+                collect_type_parameters_from_type(type_, names);
+            }
+        }
+        Type::Record(_) => {}
+    }
+}
+
+fn collect_from_lambda<'a>(lambda: &'a Lambda, records: &mut Vec<&'a types::AnonymousRecord>) {
+    for argument in lambda.arguments() {
+        collect_from_type(argument.type_(), records);
+    }
+
+    collect_from_type(lambda.result_type(), records);
+    collect_from_block(lambda.body(), records);
+}
+
+fn collect_from_block<'a>(block: &'a Block, records: &mut Vec<&'a types::AnonymousRecord>) {
+    for statement in block.statements() {
+        collect_from_expression(statement.expression(), records);
+    }
+
+    collect_from_expression(block.expression(), records);
+}
+
+fn collect_from_expression<'a>(
+    expression: &'a Expression,
+    records: &mut Vec<&'a types::AnonymousRecord>,
+) {
+    match expression {
+        Expression::BinaryOperation(operation) => {
+            collect_from_expression(operation.lhs(), records);
+            collect_from_expression(operation.rhs(), records);
+        }
+        Expression::Call(call) => {
+            collect_from_expression(call.function(), records);
+
+            for argument in call.arguments() {
+                collect_from_expression(argument, records);
+            }
+        }
+        Expression::If(if_) => {
+            for branch in if_.branches() {
+                collect_from_expression(branch.condition(), records);
+                collect_from_block(branch.block(), records);
+            }
+
+            collect_from_block(if_.else_(), records);
+        }
+        Expression::IfList(if_) => {
+            collect_from_expression(if_.list(), records);
+            collect_from_block(if_.then(), records);
+            collect_from_block(if_.else_(), records);
+        }
+        Expression::IfMap(if_) => {
+            collect_from_expression(if_.map(), records);
+            collect_from_expression(if_.key(), records);
+            collect_from_block(if_.then(), records);
+            collect_from_block(if_.else_(), records);
+        }
+        Expression::IfType(if_) => {
+            collect_from_expression(if_.argument(), records);
+
+            for branch in if_.branches() {
+                collect_from_type(branch.type_(), records);
+                collect_from_block(branch.block(), records);
+            }
+
+            if let Some(block) = if_.else_() {
+                collect_from_block(block, records);
+            }
+        }
+        Expression::Lambda(lambda) => collect_from_lambda(lambda, records),
+        Expression::List(list) => {
+            collect_from_type(list.type_(), records);
+
+            for element in list.elements() {
+                match element {
+                    ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                        collect_from_expression(expression, records)
+                    }
+                }
+            }
+        }
+        Expression::ListComprehension(comprehension) => {
+            collect_from_type(comprehension.type_(), records);
+            collect_from_expression(comprehension.element(), records);
+            collect_from_list_comprehension_branches(comprehension.branches(), records);
+        }
+        Expression::Match(match_) => {
+            collect_from_expression(match_.argument(), records);
+
+            for branch in match_.branches() {
+                collect_from_match_pattern(branch.pattern(), records);
+
+                if let Some(guard) = branch.guard() {
+                    collect_from_expression(guard, records);
+                }
+
+                collect_from_block(branch.block(), records);
+            }
+        }
+        Expression::Map(map) => {
+            collect_from_type(map.key_type(), records);
+            collect_from_type(map.value_type(), records);
+
+            for element in map.elements() {
+                match element {
+                    MapElement::Insertion(entry) => {
+                        collect_from_expression(entry.key(), records);
+                        collect_from_expression(entry.value(), records);
+                    }
+                    MapElement::Map(expression) | MapElement::Removal(expression) => {
+                        collect_from_expression(expression, records)
+                    }
+                }
+            }
+        }
+        Expression::MapComprehension(comprehension) => {
+            collect_from_type(comprehension.key_type(), records);
+            collect_from_type(comprehension.value_type(), records);
+            collect_from_expression(comprehension.key(), records);
+            collect_from_expression(comprehension.value(), records);
+            collect_from_list_comprehension_branches(comprehension.branches(), records);
+        }
+        Expression::Record(record) => {
+            collect_from_type(record.type_(), records);
+
+            if let Some(record) = record.record() {
+                collect_from_expression(record, records);
+            }
+
+            for field in record.fields() {
+                collect_from_expression(field.expression(), records);
+            }
+        }
+        Expression::RecordDeconstruction(deconstruction) => {
+            collect_from_expression(deconstruction.expression(), records)
+        }
+        Expression::StringInterpolation(interpolation) => {
+            for part in interpolation.parts() {
+                match part {
+                    StringInterpolationPart::Expression(expression) => {
+                        collect_from_expression(expression, records)
+                    }
+                    StringInterpolationPart::String(_) => {}
+                }
+            }
+        }
+        Expression::UnaryOperation(operation) => {
+            collect_from_expression(operation.expression(), records)
+        }
+        Expression::Integer(_)
+        | Expression::Number(_)
+        | Expression::String(_)
+        | Expression::Variable(_) => {}
+    }
+}
+
+fn collect_from_list_comprehension_branches<'a>(
+    branches: &'a [ListComprehensionBranch],
+    records: &mut Vec<&'a types::AnonymousRecord>,
+) {
+    for branch in branches {
+        for iteratee in branch.iteratees() {
+            collect_from_expression(iteratee, records);
+        }
+
+        if let Some(condition) = branch.condition() {
+            collect_from_expression(condition, records);
+        }
+    }
+}
+
+fn collect_from_match_pattern<'a>(
+    pattern: &'a MatchPattern,
+    records: &mut Vec<&'a types::AnonymousRecord>,
+) {
+    match pattern {
+        MatchPattern::List(list) => {
+            collect_from_match_pattern(list.first(), records);
+            collect_from_match_pattern(list.rest(), records);
+        }
+        MatchPattern::Record(record) => {
+            for field in record.fields() {
+                collect_from_match_pattern(field.pattern(), records);
+            }
+        }
+        MatchPattern::Type(pattern) => {
+            collect_from_match_pattern(pattern.pattern(), records);
+            collect_from_type(pattern.type_(), records);
+        }
+        MatchPattern::EmptyList(_)
+        | MatchPattern::Number(_)
+        | MatchPattern::String(_)
+        | MatchPattern::Variable(_) => {}
+    }
+}
+
+fn collect_from_type<'a>(type_: &'a Type, records: &mut Vec<&'a types::AnonymousRecord>) {
+    match type_ {
+        Type::AnonymousRecord(record) => {
+            for field in record.fields() {
+                collect_from_type(field.type_(), records);
+            }
+
+            records.push(record);
+        }
+        Type::Function(function) => collect_from_function_type(function, records),
+        Type::List(list) => collect_from_type(list.element(), records),
+        Type::Map(map) => {
+            collect_from_type(map.key(), records);
+            collect_from_type(map.value(), records);
+        }
+        Type::Union(union) => {
+            collect_from_type(union.lhs(), records);
+            collect_from_type(union.rhs(), records);
+        }
+        Type::Reference(reference) => {
+            for type_ in reference.arguments() {
+                collect_from_type(type_, records);
+            }
+        }
+        Type::Record(_) => {}
+    }
+}
+
+fn collect_from_function_type<'a>(
+    function: &'a types::Function,
+    records: &mut Vec<&'a types::AnonymousRecord>,
+) {
+    for type_ in function.arguments() {
+        collect_from_type(type_, records);
+    }
+
+    collect_from_type(function.result(), records);
+}
@@ -7,8 +7,10 @@ use std::{
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CompileError {
+    ComprehensionIterateesNotMatched(Position),
     ModuleNotFound(ast::ModulePath),
     NameNotFound(String, Position),
+    NonExhaustiveMatch(Position),
     ParseFloat {
         error: ParseFloatError,
         position: Position,
@@ -18,17 +20,43 @@ pub enum CompileError {
         position: Position,
     },
     TooFewBranchesInIf(Position),
+    UnreachableMatchBranch(Position),
+}
+
+impl CompileError {
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::ModuleNotFound(_) => None,
+            Self::ComprehensionIterateesNotMatched(position)
+            | Self::NameNotFound(_, position)
+            | Self::NonExhaustiveMatch(position)
+            | Self::ParseFloat { position, .. }
+            | Self::ParseInteger { position, .. }
+            | Self::TooFewBranchesInIf(position)
+            | Self::UnreachableMatchBranch(position) => Some(position),
+        }
+    }
 }
 
 impl Display for CompileError {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
+            Self::ComprehensionIterateesNotMatched(position) => {
+                write!(
+                    formatter,
+                    "numbers of names and iteratees not matched in comprehension\n{}",
+                    position
+                )
+            }
             Self::ModuleNotFound(path) => {
                 write!(formatter, "module \"{}\" not found", path)
             }
             Self::NameNotFound(name, position) => {
                 write!(formatter, "name \"{}\" not found\n{}", name, position)
             }
+            Self::NonExhaustiveMatch(position) => {
+                write!(formatter, "non-exhaustive match\n{}", position)
+            }
             Self::ParseFloat { error, position } => {
                 write!(formatter, "{}\n{}", error, position)
             }
@@ -42,6 +70,9 @@ impl Display for CompileError {
                     position
                 )
             }
+            Self::UnreachableMatchBranch(position) => {
+                write!(formatter, "unreachable match branch\n{}", position)
+            }
         }
     }
 }
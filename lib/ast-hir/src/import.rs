@@ -13,20 +13,23 @@ pub fn compile(
     imported_modules: &[ImportedModule],
     prelude_module_interfaces: &[interface::Module],
 ) -> ir::Module {
-    let module = compile_imports(
-        module,
-        &imported_modules
-            .iter()
-            .map(|module| module.interface())
-            .chain(prelude_module_interfaces)
-            .collect::<Vec<_>>(),
-    );
+    let module = compile_imports(module, imported_modules, prelude_module_interfaces);
 
     let module = rename_types(&module, imported_modules);
     rename_variables(&module, imported_modules)
 }
 
-fn compile_imports(module: &ir::Module, module_interfaces: &[&interface::Module]) -> ir::Module {
+fn compile_imports(
+    module: &ir::Module,
+    imported_modules: &[ImportedModule],
+    prelude_module_interfaces: &[interface::Module],
+) -> ir::Module {
+    let module_interfaces = imported_modules
+        .iter()
+        .map(|module| module.interface())
+        .chain(prelude_module_interfaces)
+        .collect::<Vec<_>>();
+
     ir::Module::new(
         module_interfaces
             .iter()
@@ -44,6 +47,8 @@ fn compile_imports(module: &ir::Module, module_interfaces: &[&interface::Module]
                             true,
                             definition.position().clone(),
                         )
+                        .set_newtype(definition.is_newtype())
+                        .set_type_parameters(definition.type_parameters().to_vec())
                     })
             })
             .sorted_by_key(|definition| (definition.name().to_string(), !definition.is_public()))
@@ -62,6 +67,7 @@ fn compile_imports(module: &ir::Module, module_interfaces: &[&interface::Module]
                         true,
                         alias.position().clone(),
                     )
+                    .set_type_parameters(alias.type_parameters().to_vec())
                 })
             })
             .sorted_by_key(|alias| (alias.name().to_string(), !alias.is_public()))
@@ -69,12 +75,30 @@ fn compile_imports(module: &ir::Module, module_interfaces: &[&interface::Module]
             .chain(module.type_aliases().iter().cloned())
             .collect(),
         module.foreign_declarations().to_vec(),
-        module_interfaces
+        imported_modules
             .iter()
-            .flat_map(|interface| interface.function_declarations())
-            .map(|declaration| {
+            .flat_map(|module| {
+                module
+                    .interface()
+                    .function_declarations()
+                    .iter()
+                    .map(|declaration| {
+                        (
+                            declaration,
+                            qualify_name(module, declaration.original_name()),
+                        )
+                    })
+            })
+            .chain(
+                prelude_module_interfaces
+                    .iter()
+                    .flat_map(|interface| interface.function_declarations())
+                    .map(|declaration| (declaration, declaration.name().into())),
+            )
+            .map(|(declaration, original_name)| {
                 ir::FunctionDeclaration::new(
                     declaration.name(),
+                    original_name,
                     declaration.type_().clone(),
                     declaration.position().clone(),
                 )
@@ -82,6 +106,25 @@ fn compile_imports(module: &ir::Module, module_interfaces: &[&interface::Module]
             .unique_by(|declaration| declaration.name().to_string())
             .chain(module.function_declarations().iter().cloned())
             .collect(),
+        module_interfaces
+            .iter()
+            .flat_map(|module_interface| {
+                module_interface
+                    .implementations()
+                    .iter()
+                    .map(|implementation| {
+                        ir::Implementation::new(
+                            implementation.name(),
+                            implementation.interface().clone(),
+                            implementation.type_().clone(),
+                            true,
+                            implementation.position().clone(),
+                        )
+                    })
+            })
+            .unique_by(|implementation| implementation.name().to_string())
+            .chain(module.implementations().iter().cloned())
+            .collect(),
         module.function_definitions().to_vec(),
         module.position().clone(),
     )
@@ -99,14 +142,7 @@ fn rename_variables(module: &ir::Module, imported_modules: &[ImportedModule]) ->
                     .iter()
                     .map(|declaration| {
                         (
-                            if module
-                                .unqualified_names()
-                                .contains_key(declaration.original_name())
-                            {
-                                declaration.original_name().into()
-                            } else {
-                                name::qualify(module.prefix(), declaration.original_name())
-                            },
+                            qualify_name(module, declaration.original_name()),
                             declaration.name().into(),
                         )
                     })
@@ -134,16 +170,7 @@ fn rename_types(module: &ir::Module, imported_modules: &[ImportedModule]) -> ir:
                         .filter(|alias| alias.is_public())
                         .map(|alias| (alias.original_name(), alias.name())),
                 )
-                .map(|(original_name, name)| {
-                    (
-                        if module.unqualified_names().contains_key(original_name) {
-                            original_name.into()
-                        } else {
-                            name::qualify(module.prefix(), original_name)
-                        },
-                        name.into(),
-                    )
-                })
+                .map(|(original_name, name)| (qualify_name(module, original_name), name.into()))
                 .collect::<Vec<_>>()
         })
         .collect::<FnvHashMap<String, String>>();
@@ -156,6 +183,7 @@ fn rename_types(module: &ir::Module, imported_modules: &[ImportedModule]) -> ir:
                 .unwrap_or_else(|| record.name().into()),
             record.position().clone(),
         )
+        .set_arguments(record.arguments().to_vec())
         .into(),
         Type::Reference(reference) => types::Reference::new(
             names
@@ -164,11 +192,20 @@ fn rename_types(module: &ir::Module, imported_modules: &[ImportedModule]) -> ir:
                 .unwrap_or_else(|| reference.name().into()),
             reference.position().clone(),
         )
+        .set_arguments(reference.arguments().to_vec())
         .into(),
         _ => type_.clone(),
     })
 }
 
+fn qualify_name(module: &ImportedModule, original_name: &str) -> String {
+    if module.unqualified_names().contains_key(original_name) {
+        original_name.into()
+    } else {
+        name::qualify(module.prefix(), original_name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,7 +258,8 @@ mod tests {
                                 Position::fake()
                             ),
                             Position::fake()
-                        )]
+                        )],
+                        vec![]
                     ),
                     "Bar",
                     Default::default(),
@@ -230,6 +268,7 @@ mod tests {
             ir::Module::empty()
                 .set_function_declarations(vec![ir::FunctionDeclaration::new(
                     "RealBar",
+                    "Bar'Bar",
                     types::Function::new(
                         vec![],
                         types::None::new(Position::fake()),
@@ -286,6 +325,7 @@ mod tests {
                             Position::fake()
                         )],
                         vec![],
+                        vec![],
                         vec![]
                     ),
                     "Bar",
@@ -362,6 +402,7 @@ mod tests {
                             true,
                             Position::fake(),
                         )],
+                        vec![],
                         vec![]
                     ),
                     "Bar",
@@ -440,6 +481,7 @@ mod tests {
                         )],
                         vec![],
                         vec![],
+                        vec![],
                     ),
                     "Bar",
                     Default::default()
@@ -500,6 +542,7 @@ mod tests {
                             false,
                             Position::fake(),
                         )],
+                        vec![],
                         vec![]
                     ),
                     "Bar",
@@ -531,12 +574,22 @@ mod tests {
                 &ir::Module::empty(),
                 &vec![
                     ImportedModule::new(
-                        interface::Module::new(vec![create_type_definition(false)], vec![], vec![]),
+                        interface::Module::new(
+                            vec![create_type_definition(false)],
+                            vec![],
+                            vec![],
+                            vec![]
+                        ),
                         "Foo",
                         Default::default()
                     ),
                     ImportedModule::new(
-                        interface::Module::new(vec![create_type_definition(true)], vec![], vec![]),
+                        interface::Module::new(
+                            vec![create_type_definition(true)],
+                            vec![],
+                            vec![],
+                            vec![]
+                        ),
                         "Bar",
                         Default::default()
                     )
@@ -569,12 +622,22 @@ mod tests {
                 &ir::Module::empty(),
                 &vec![
                     ImportedModule::new(
-                        interface::Module::new(vec![], vec![create_type_alias(false)], vec![]),
+                        interface::Module::new(
+                            vec![],
+                            vec![create_type_alias(false)],
+                            vec![],
+                            vec![]
+                        ),
                         "Foo",
                         Default::default()
                     ),
                     ImportedModule::new(
-                        interface::Module::new(vec![], vec![create_type_alias(true)], vec![]),
+                        interface::Module::new(
+                            vec![],
+                            vec![create_type_alias(true)],
+                            vec![],
+                            vec![]
+                        ),
                         "Bar",
                         Default::default()
                     )
@@ -622,7 +685,8 @@ mod tests {
                                     Position::fake()
                                 ),
                                 Position::fake()
-                            )]
+                            )],
+                            vec![]
                         ),
                         "Bar",
                         [("Bar".into(), Position::fake())].into_iter().collect()
@@ -631,6 +695,7 @@ mod tests {
                 ir::Module::empty()
                     .set_function_declarations(vec![ir::FunctionDeclaration::new(
                         "RealBar",
+                        "Bar",
                         types::Function::new(
                             vec![],
                             types::None::new(Position::fake()),
@@ -687,6 +752,7 @@ mod tests {
                                 Position::fake()
                             )],
                             vec![],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -763,6 +829,7 @@ mod tests {
                                 true,
                                 Position::fake(),
                             )],
+                            vec![],
                             vec![]
                         ),
                         "Bar",
@@ -0,0 +1,151 @@
+use crate::{module_prefix, name};
+use fnv::FnvHashSet;
+use hir::{
+    analysis::{expression_visitor, type_visitor, AnalysisWarning},
+    ir,
+    types::Type,
+};
+
+// Names in a module need to be unresolved to check usage of imports.
+pub fn check(module: &ast::Module, hir_module: &ir::Module) -> Vec<AnalysisWarning> {
+    let names = collect_names(hir_module);
+    let mut warnings = vec![];
+
+    for import in module.imports() {
+        let prefix = name::qualify(&module_prefix::compile(import), "");
+        let unused_names = import
+            .unqualified_names()
+            .iter()
+            .filter(|name| !names.contains(name.name()))
+            .collect::<Vec<_>>();
+
+        if names.iter().any(|name| name.starts_with(&prefix))
+            || unused_names.len() < import.unqualified_names().len()
+        {
+            warnings.extend(unused_names.into_iter().map(|name| {
+                AnalysisWarning::UnusedUnqualifiedName(name.name().into(), name.position().clone())
+            }));
+        } else {
+            warnings.push(AnalysisWarning::UnusedImport(
+                import.module_path().to_string(),
+                import.position().clone(),
+            ));
+        }
+    }
+
+    warnings
+}
+
+fn collect_names(module: &ir::Module) -> FnvHashSet<&str> {
+    let mut names = FnvHashSet::default();
+
+    expression_visitor::visit(module, |expression| {
+        if let ir::Expression::Variable(variable) = expression {
+            names.insert(variable.name());
+        }
+    });
+
+    type_visitor::visit(module, |type_| match type_ {
+        Type::Record(record) => {
+            names.insert(record.name());
+        }
+        Type::Reference(reference) => {
+            names.insert(reference.name());
+        }
+        _ => {}
+    });
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module;
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn check_module(module: &ast::Module) -> Vec<AnalysisWarning> {
+        check(module, &module::compile(module).unwrap())
+    }
+
+    fn create_module(
+        unqualified_names: Vec<ast::UnqualifiedName>,
+        body: impl Into<ast::Expression>,
+    ) -> ast::Module {
+        ast::Module::new(
+            vec![ast::Import::new(
+                ast::InternalModulePath::new(vec!["Foo".into()]),
+                None,
+                unqualified_names,
+                Position::fake(),
+            )],
+            vec![],
+            vec![],
+            vec![],
+            vec![ast::FunctionDefinition::new(
+                "f",
+                vec![],
+                ast::Lambda::new(
+                    vec![],
+                    ast::types::Reference::new("none", Position::fake()),
+                    ast::Block::new(vec![], body, Position::fake()),
+                    Position::fake(),
+                ),
+                None,
+                Position::fake(),
+            )],
+            Position::fake(),
+        )
+    }
+
+    #[test]
+    fn check_used_import() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                ast::Variable::new("Foo'Bar", Position::fake())
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_unused_import() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                ast::Variable::new("none", Position::fake())
+            )),
+            vec![AnalysisWarning::UnusedImport(
+                "'Foo".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn check_used_unqualified_name() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![ast::UnqualifiedName::new("Bar", Position::fake())],
+                ast::Variable::new("Bar", Position::fake())
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_unused_unqualified_name() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![ast::UnqualifiedName::new("Bar", Position::fake())],
+                ast::Variable::new("Foo'Baz", Position::fake())
+            )),
+            vec![AnalysisWarning::UnusedUnqualifiedName(
+                "Bar".into(),
+                Position::fake()
+            )]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::error::CompileError;
+use ast::*;
+use hir::types;
+use std::num::ParseIntError;
+
+pub fn compile(integer: &Integer) -> Result<(types::IntegerKind, u64), CompileError> {
+    let (string, radix) = match integer.value() {
+        NumberRepresentation::Binary(string) => (string, 2),
+        NumberRepresentation::Hexadecimal(string) => (string, 16),
+        NumberRepresentation::FloatingPoint(string) => (string, 10),
+    };
+
+    Ok(match integer.kind() {
+        IntegerKind::Byte => (
+            types::IntegerKind::Byte,
+            convert_error(u8::from_str_radix(string, radix), integer)?.into(),
+        ),
+        IntegerKind::I64 => (
+            types::IntegerKind::I64,
+            convert_error(i64::from_str_radix(string, radix), integer)? as u64,
+        ),
+        IntegerKind::U64 => (
+            types::IntegerKind::U64,
+            convert_error(u64::from_str_radix(string, radix), integer)?,
+        ),
+    })
+}
+
+fn convert_error<T>(
+    result: Result<T, ParseIntError>,
+    integer: &Integer,
+) -> Result<T, CompileError> {
+    result.map_err(|error| CompileError::ParseInteger {
+        error,
+        position: integer.position().clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::{test::PositionFake, Position};
+
+    fn compile_integer(
+        value: NumberRepresentation,
+        kind: IntegerKind,
+    ) -> Result<(types::IntegerKind, u64), CompileError> {
+        compile(&Integer::new(value, kind, Position::fake()))
+    }
+
+    #[test]
+    fn compile_decimal() {
+        for (source, kind, value) in [
+            ("0", IntegerKind::Byte, (types::IntegerKind::Byte, 0)),
+            ("255", IntegerKind::Byte, (types::IntegerKind::Byte, 255)),
+            ("-1", IntegerKind::I64, (types::IntegerKind::I64, u64::MAX)),
+            (
+                "9007199254740993",
+                IntegerKind::I64,
+                (types::IntegerKind::I64, 9007199254740993),
+            ),
+            (
+                "18446744073709551615",
+                IntegerKind::U64,
+                (types::IntegerKind::U64, u64::MAX),
+            ),
+        ] {
+            assert_eq!(
+                compile_integer(NumberRepresentation::FloatingPoint(source.into()), kind),
+                Ok(value)
+            );
+        }
+    }
+
+    #[test]
+    fn compile_binary() {
+        assert_eq!(
+            compile_integer(
+                NumberRepresentation::Binary("101".into()),
+                IntegerKind::Byte
+            ),
+            Ok((types::IntegerKind::Byte, 5))
+        );
+    }
+
+    #[test]
+    fn compile_hexadecimal() {
+        assert_eq!(
+            compile_integer(
+                NumberRepresentation::Hexadecimal("ffffffffffffffff".into()),
+                IntegerKind::U64
+            ),
+            Ok((types::IntegerKind::U64, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn fail_to_compile_out_of_range_integers() {
+        for (source, kind) in [
+            ("256", IntegerKind::Byte),
+            ("-1", IntegerKind::Byte),
+            ("9223372036854775808", IntegerKind::I64),
+            ("-1", IntegerKind::U64),
+            ("18446744073709551616", IntegerKind::U64),
+        ] {
+            assert!(matches!(
+                compile_integer(NumberRepresentation::FloatingPoint(source.into()), kind),
+                Err(CompileError::ParseInteger { .. })
+            ));
+        }
+    }
+}
@@ -1,6 +1,10 @@
+mod anonymous_record;
 mod error;
 mod import;
+mod import_checker;
 mod imported_module;
+mod integer;
+mod match_;
 mod module;
 mod module_prefix;
 mod name;
@@ -8,12 +12,13 @@ mod number;
 mod string;
 mod type_;
 
-use error::CompileError;
+pub use error::CompileError;
 use fnv::FnvHashMap;
 use hir::{
     analysis::{
-        built_in_type_transformer, built_in_variable_transformer, function_definition_qualifier,
-        type_qualifier,
+        anonymous_record_qualifier, built_in_type_transformer, built_in_variable_transformer,
+        function_definition_qualifier, generic_type_alias_expander, type_qualifier,
+        AnalysisWarning,
     },
     ir,
 };
@@ -54,6 +59,10 @@ pub fn compile(
     transform_module(&module, prefix)
 }
 
+pub fn check_imports(module: &ast::Module) -> Result<Vec<AnalysisWarning>, CompileError> {
+    Ok(import_checker::check(module, &module::compile(module)?))
+}
+
 pub fn compile_prelude(module: &ast::Module, prefix: &str) -> Result<ir::Module, CompileError> {
     transform_module(&module::compile(module)?, prefix)
 }
@@ -63,6 +72,8 @@ fn transform_module(module: &ir::Module, prefix: &str) -> Result<ir::Module, Com
     let module = type_qualifier::qualify(&module, prefix);
     let module = built_in_type_transformer::transform(&module);
     let module = built_in_variable_transformer::transform(&module);
+    let module = generic_type_alias_expander::expand(&module);
+    let module = anonymous_record_qualifier::qualify(&module);
 
     Ok(module)
 }
@@ -77,7 +88,7 @@ mod tests {
     fn compile_empty_module() {
         assert_eq!(
             compile(
-                &ast::Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                &ast::Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                 "",
                 &Default::default(),
                 &[],
@@ -102,6 +113,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 ),
                 "",
@@ -0,0 +1,714 @@
+use crate::{
+    error::CompileError,
+    module::{compile_block, compile_expression},
+    number, string, type_,
+};
+use hir::{ir, types::Type};
+use position::Position;
+
+const MATCH_NAME: &str = "$match";
+
+enum Step {
+    Bind(String, String, Position),
+    Equal(String, Box<ir::Expression>),
+    EmptyList(String, String, String),
+    Field(String, String, String),
+    List(String, String, String, String),
+    Type(String, String, Type),
+}
+
+impl Step {
+    fn is_refutable(&self) -> bool {
+        match self {
+            Self::Bind(..) | Self::Field(..) => false,
+            Self::Equal(..) | Self::EmptyList(..) | Self::List(..) | Self::Type(..) => true,
+        }
+    }
+}
+
+pub fn compile(match_: &ast::Match) -> Result<ir::Expression, CompileError> {
+    let branches = match_.branches();
+    let branches = if let Some(index) = branches
+        .iter()
+        .position(|branch| branch.guard().is_none() && is_irrefutable(branch.pattern()))
+    {
+        if let Some(branch) = branches.get(index + 1) {
+            return Err(CompileError::UnreachableMatchBranch(
+                branch.position().clone(),
+            ));
+        }
+
+        &branches[..index + 1]
+    } else {
+        branches
+    };
+    let mut index = 0;
+    let mut groups = vec![];
+
+    while index < branches.len() {
+        let length = if is_type_branch(&branches[index]) {
+            branches[index..]
+                .iter()
+                .take_while(|branch| is_type_branch(branch))
+                .count()
+        } else if is_list_group(&branches[index..]) {
+            2
+        } else {
+            1
+        };
+
+        groups.push(&branches[index..index + length]);
+        index += length;
+    }
+
+    let mut names = 0;
+    let mut expression = None;
+
+    for group in groups.into_iter().rev() {
+        expression = Some(compile_group(
+            group,
+            expression,
+            match_.position(),
+            &mut names,
+        )?);
+    }
+
+    Ok(ir::Let::new(
+        Some(MATCH_NAME.into()),
+        None,
+        compile_expression(match_.argument())?,
+        expression.ok_or_else(|| CompileError::NonExhaustiveMatch(match_.position().clone()))?,
+        match_.position().clone(),
+    )
+    .into())
+}
+
+fn is_type_branch(branch: &ast::MatchBranch) -> bool {
+    match branch.pattern() {
+        ast::MatchPattern::Type(pattern) => {
+            branch.guard().is_none() && is_irrefutable(pattern.pattern())
+        }
+        _ => false,
+    }
+}
+
+fn is_list_group(branches: &[ast::MatchBranch]) -> bool {
+    match branches {
+        [one, other, ..] if one.guard().is_none() && other.guard().is_none() => matches!(
+            (one.pattern(), other.pattern()),
+            (ast::MatchPattern::EmptyList(_), ast::MatchPattern::List(_))
+                | (ast::MatchPattern::List(_), ast::MatchPattern::EmptyList(_))
+        ),
+        _ => false,
+    }
+}
+
+fn is_irrefutable(pattern: &ast::MatchPattern) -> bool {
+    match pattern {
+        ast::MatchPattern::Record(record) => record
+            .fields()
+            .iter()
+            .all(|field| is_irrefutable(field.pattern())),
+        ast::MatchPattern::Variable(_) => true,
+        ast::MatchPattern::EmptyList(_)
+        | ast::MatchPattern::List(_)
+        | ast::MatchPattern::Number(_)
+        | ast::MatchPattern::String(_)
+        | ast::MatchPattern::Type(_) => false,
+    }
+}
+
+fn compile_group(
+    branches: &[ast::MatchBranch],
+    failure: Option<ir::Expression>,
+    position: &Position,
+    names: &mut usize,
+) -> Result<ir::Expression, CompileError> {
+    if is_type_branch(&branches[0]) {
+        let name = generate_name(names);
+
+        return Ok(ir::IfType::new(
+            &name,
+            ir::Variable::new(MATCH_NAME, position.clone()),
+            branches
+                .iter()
+                .map(|branch| {
+                    let ast::MatchPattern::Type(pattern) = branch.pattern() else {
+                        unreachable!()
+                    };
+                    let mut steps = vec![];
+
+                    flatten_pattern(pattern.pattern(), &name, &mut steps, names)?;
+
+                    Ok(ir::IfTypeBranch::new(
+                        type_::compile(pattern.type_()),
+                        compile_steps(&steps, compile_block(branch.block())?, None, position)?,
+                    ))
+                })
+                .collect::<Result<_, CompileError>>()?,
+            failure.map(|expression| ir::ElseBranch::new(None, expression, position.clone())),
+            position.clone(),
+        )
+        .into());
+    } else if is_list_group(branches) {
+        let (empty, list) = if let ast::MatchPattern::EmptyList(_) = branches[0].pattern() {
+            (&branches[0], &branches[1])
+        } else {
+            (&branches[1], &branches[0])
+        };
+        let mut steps = vec![];
+
+        flatten_pattern(list.pattern(), MATCH_NAME, &mut steps, names)?;
+
+        let Step::List(_, first_name, rest_name, head_name) = &steps[0] else {
+            unreachable!()
+        };
+        let (failure, definition) = define_failure(failure, &steps[1..], None, position, names);
+
+        return Ok(define_fallback(
+            definition,
+            ir::IfList::new(
+                None,
+                ir::Variable::new(MATCH_NAME, position.clone()),
+                first_name,
+                rest_name,
+                force_head(
+                    first_name,
+                    head_name,
+                    compile_steps(&steps[1..], compile_block(list.block())?, failure, position)?,
+                    position,
+                ),
+                compile_block(empty.block())?,
+                position.clone(),
+            ),
+            position,
+        ));
+    }
+
+    let branch = &branches[0];
+    let mut steps = vec![];
+
+    flatten_pattern(branch.pattern(), MATCH_NAME, &mut steps, names)?;
+
+    let (failure, definition) = define_failure(failure, &steps, branch.guard(), position, names);
+    let block = compile_block(branch.block())?;
+
+    let expression = if let Some(guard) = branch.guard() {
+        ir::If::new(
+            compile_expression(guard)?,
+            block,
+            failure
+                .clone()
+                .ok_or_else(|| CompileError::NonExhaustiveMatch(position.clone()))?,
+            branch.position().clone(),
+        )
+        .into()
+    } else {
+        block
+    };
+
+    Ok(define_fallback(
+        definition,
+        compile_steps(&steps, expression, failure, position)?,
+        position,
+    ))
+}
+
+fn define_failure(
+    failure: Option<ir::Expression>,
+    steps: &[Step],
+    guard: Option<&ast::Expression>,
+    position: &Position,
+    names: &mut usize,
+) -> (Option<ir::Expression>, Option<(String, ir::Expression)>) {
+    let count = steps.iter().filter(|step| step.is_refutable()).count() + guard.iter().count();
+
+    match failure {
+        Some(failure) if count > 1 => {
+            let name = generate_name(names);
+
+            (
+                Some(
+                    ir::Call::new(
+                        None,
+                        ir::Variable::new(&name, position.clone()),
+                        vec![],
+                        position.clone(),
+                    )
+                    .into(),
+                ),
+                Some((name, failure)),
+            )
+        }
+        failure => (failure, None),
+    }
+}
+
+fn define_fallback(
+    definition: Option<(String, ir::Expression)>,
+    expression: impl Into<ir::Expression>,
+    position: &Position,
+) -> ir::Expression {
+    if let Some((name, failure)) = definition {
+        ir::Let::new(
+            Some(name),
+            None,
+            ir::Thunk::new(None, failure, position.clone()),
+            expression,
+            position.clone(),
+        )
+        .into()
+    } else {
+        expression.into()
+    }
+}
+
+fn force_head(
+    first_name: &str,
+    head_name: &str,
+    expression: ir::Expression,
+    position: &Position,
+) -> ir::Expression {
+    ir::Let::new(
+        Some(head_name.into()),
+        None,
+        ir::Call::new(
+            None,
+            ir::Variable::new(first_name, position.clone()),
+            vec![],
+            position.clone(),
+        ),
+        expression,
+        position.clone(),
+    )
+    .into()
+}
+
+fn flatten_pattern(
+    pattern: &ast::MatchPattern,
+    name: &str,
+    steps: &mut Vec<Step>,
+    names: &mut usize,
+) -> Result<(), CompileError> {
+    match pattern {
+        ast::MatchPattern::EmptyList(_) => steps.push(Step::EmptyList(
+            name.into(),
+            generate_name(names),
+            generate_name(names),
+        )),
+        ast::MatchPattern::List(list) => {
+            let first_name = generate_name(names);
+            let rest_name = generate_name(names);
+            let head_name = generate_name(names);
+
+            steps.push(Step::List(
+                name.into(),
+                first_name,
+                rest_name.clone(),
+                head_name.clone(),
+            ));
+            flatten_pattern(list.first(), &head_name, steps, names)?;
+            flatten_pattern(list.rest(), &rest_name, steps, names)?;
+        }
+        ast::MatchPattern::Number(number) => steps.push(Step::Equal(
+            name.into(),
+            Box::new(ir::Number::new(number::compile(number)?, number.position().clone()).into()),
+        )),
+        ast::MatchPattern::Record(record) => {
+            for field in record.fields() {
+                let field_name = generate_name(names);
+
+                steps.push(Step::Field(
+                    name.into(),
+                    field_name.clone(),
+                    field.name().into(),
+                ));
+                flatten_pattern(field.pattern(), &field_name, steps, names)?;
+            }
+        }
+        ast::MatchPattern::String(string) => steps.push(Step::Equal(
+            name.into(),
+            Box::new(
+                ir::ByteString::new(string::compile(string.value()), string.position().clone())
+                    .into(),
+            ),
+        )),
+        ast::MatchPattern::Type(pattern) => {
+            let type_name = generate_name(names);
+
+            steps.push(Step::Type(
+                name.into(),
+                type_name.clone(),
+                type_::compile(pattern.type_()),
+            ));
+            flatten_pattern(pattern.pattern(), &type_name, steps, names)?;
+        }
+        ast::MatchPattern::Variable(variable) => steps.push(Step::Bind(
+            variable.name().into(),
+            name.into(),
+            variable.position().clone(),
+        )),
+    }
+
+    Ok(())
+}
+
+fn compile_steps(
+    steps: &[Step],
+    expression: ir::Expression,
+    failure: Option<ir::Expression>,
+    position: &Position,
+) -> Result<ir::Expression, CompileError> {
+    let variable = |name: &str| ir::Variable::new(name, position.clone());
+    let fail = || {
+        failure
+            .clone()
+            .ok_or_else(|| CompileError::NonExhaustiveMatch(position.clone()))
+    };
+
+    // Variables are bound after all checks so that they do not scope over the
+    // other branches.
+    let steps = steps
+        .iter()
+        .filter(|step| !matches!(step, Step::Bind(..)))
+        .chain(steps.iter().filter(|step| matches!(step, Step::Bind(..))))
+        .collect::<Vec<_>>();
+
+    steps.iter().rev().try_fold(expression, |expression, step| {
+        Ok(match step {
+            Step::Bind(name, value_name, variable_position) => ir::Let::new(
+                Some(name.clone()),
+                None,
+                variable(value_name),
+                expression,
+                variable_position.clone(),
+            )
+            .into(),
+            Step::Equal(name, value) => ir::If::new(
+                ir::EqualityOperation::new(
+                    None,
+                    ir::EqualityOperator::Equal,
+                    variable(name),
+                    value.as_ref().clone(),
+                    position.clone(),
+                ),
+                expression,
+                fail()?,
+                position.clone(),
+            )
+            .into(),
+            Step::EmptyList(name, first_name, rest_name) => ir::IfList::new(
+                None,
+                variable(name),
+                first_name,
+                rest_name,
+                fail()?,
+                expression,
+                position.clone(),
+            )
+            .into(),
+            Step::Field(record_name, name, field_name) => ir::Let::new(
+                Some(name.clone()),
+                None,
+                ir::RecordDeconstruction::new(
+                    None,
+                    variable(record_name),
+                    field_name,
+                    position.clone(),
+                ),
+                expression,
+                position.clone(),
+            )
+            .into(),
+            Step::List(name, first_name, rest_name, head_name) => ir::IfList::new(
+                None,
+                variable(name),
+                first_name,
+                rest_name,
+                force_head(first_name, head_name, expression, position),
+                fail()?,
+                position.clone(),
+            )
+            .into(),
+            Step::Type(argument_name, name, type_) => ir::IfType::new(
+                name,
+                variable(argument_name),
+                vec![ir::IfTypeBranch::new(type_.clone(), expression)],
+                fail()
+                    .ok()
+                    .map(|expression| ir::ElseBranch::new(None, expression, position.clone())),
+                position.clone(),
+            )
+            .into(),
+        })
+    })
+}
+
+fn generate_name(names: &mut usize) -> String {
+    *names += 1;
+
+    format!("{}{}", MATCH_NAME, names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hir::types;
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn compile_branches(branches: Vec<ast::MatchBranch>) -> Result<ir::Expression, CompileError> {
+        compile(&ast::Match::new(
+            ast::Variable::new("x", Position::fake()),
+            branches,
+            Position::fake(),
+        ))
+    }
+
+    fn create_branch(pattern: impl Into<ast::MatchPattern>) -> ast::MatchBranch {
+        ast::MatchBranch::new(
+            pattern,
+            None,
+            ast::Block::new(
+                vec![],
+                ast::Variable::new("y", Position::fake()),
+                Position::fake(),
+            ),
+            Position::fake(),
+        )
+    }
+
+    fn create_let(name: &str, expression: impl Into<ir::Expression>) -> ir::Expression {
+        ir::Let::new(
+            Some(name.into()),
+            None,
+            ir::Variable::new("x", Position::fake()),
+            expression,
+            Position::fake(),
+        )
+        .into()
+    }
+
+    #[test]
+    fn compile_variable_pattern() {
+        assert_eq!(
+            compile_branches(vec![create_branch(ast::Variable::new(
+                "y",
+                Position::fake()
+            ))]),
+            Ok(create_let(
+                MATCH_NAME,
+                ir::Let::new(
+                    Some("y".into()),
+                    None,
+                    ir::Variable::new(MATCH_NAME, Position::fake()),
+                    ir::Variable::new("y", Position::fake()),
+                    Position::fake(),
+                ),
+            ))
+        );
+    }
+
+    #[test]
+    fn compile_type_patterns() {
+        assert_eq!(
+            compile_branches(vec![
+                create_branch(ast::TypePattern::new(
+                    ast::Variable::new("y", Position::fake()),
+                    ast::types::Reference::new("number", Position::fake()),
+                    Position::fake(),
+                )),
+                create_branch(ast::TypePattern::new(
+                    ast::Variable::new("y", Position::fake()),
+                    ast::types::Reference::new("none", Position::fake()),
+                    Position::fake(),
+                )),
+            ]),
+            Ok(create_let(
+                MATCH_NAME,
+                ir::IfType::new(
+                    "$match1",
+                    ir::Variable::new(MATCH_NAME, Position::fake()),
+                    ["number", "none"]
+                        .into_iter()
+                        .map(|name| ir::IfTypeBranch::new(
+                            types::Reference::new(name, Position::fake()),
+                            ir::Let::new(
+                                Some("y".into()),
+                                None,
+                                ir::Variable::new("$match1", Position::fake()),
+                                ir::Variable::new("y", Position::fake()),
+                                Position::fake(),
+                            ),
+                        ))
+                        .collect(),
+                    None,
+                    Position::fake(),
+                ),
+            ))
+        );
+    }
+
+    #[test]
+    fn compile_number_pattern() {
+        assert_eq!(
+            compile_branches(vec![
+                create_branch(ast::Number::new(
+                    ast::NumberRepresentation::FloatingPoint("42".into()),
+                    Position::fake(),
+                )),
+                create_branch(ast::Variable::new("_", Position::fake())),
+            ]),
+            Ok(create_let(
+                MATCH_NAME,
+                ir::If::new(
+                    ir::EqualityOperation::new(
+                        None,
+                        ir::EqualityOperator::Equal,
+                        ir::Variable::new(MATCH_NAME, Position::fake()),
+                        ir::Number::new(42.0, Position::fake()),
+                        Position::fake(),
+                    ),
+                    ir::Variable::new("y", Position::fake()),
+                    ir::Let::new(
+                        Some("_".into()),
+                        None,
+                        ir::Variable::new(MATCH_NAME, Position::fake()),
+                        ir::Variable::new("y", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+            ))
+        );
+    }
+
+    #[test]
+    fn compile_record_pattern_binding_variable_after_checks() {
+        assert_eq!(
+            compile_branches(vec![
+                create_branch(ast::RecordPattern::new(
+                    vec![
+                        ast::RecordFieldPattern::new(
+                            "a",
+                            ast::Variable::new("y", Position::fake()),
+                            Position::fake(),
+                        ),
+                        ast::RecordFieldPattern::new(
+                            "b",
+                            ast::Number::new(
+                                ast::NumberRepresentation::FloatingPoint("42".into()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                    ],
+                    Position::fake(),
+                )),
+                create_branch(ast::Variable::new("_", Position::fake())),
+            ]),
+            Ok(create_let(
+                MATCH_NAME,
+                ir::Let::new(
+                    Some("$match1".into()),
+                    None,
+                    ir::RecordDeconstruction::new(
+                        None,
+                        ir::Variable::new(MATCH_NAME, Position::fake()),
+                        "a",
+                        Position::fake(),
+                    ),
+                    ir::Let::new(
+                        Some("$match2".into()),
+                        None,
+                        ir::RecordDeconstruction::new(
+                            None,
+                            ir::Variable::new(MATCH_NAME, Position::fake()),
+                            "b",
+                            Position::fake(),
+                        ),
+                        ir::If::new(
+                            ir::EqualityOperation::new(
+                                None,
+                                ir::EqualityOperator::Equal,
+                                ir::Variable::new("$match2", Position::fake()),
+                                ir::Number::new(42.0, Position::fake()),
+                                Position::fake(),
+                            ),
+                            ir::Let::new(
+                                Some("y".into()),
+                                None,
+                                ir::Variable::new("$match1", Position::fake()),
+                                ir::Variable::new("y", Position::fake()),
+                                Position::fake(),
+                            ),
+                            ir::Let::new(
+                                Some("_".into()),
+                                None,
+                                ir::Variable::new(MATCH_NAME, Position::fake()),
+                                ir::Variable::new("y", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_compile_non_exhaustive_number_pattern() {
+        assert_eq!(
+            compile_branches(vec![create_branch(ast::Number::new(
+                ast::NumberRepresentation::FloatingPoint("42".into()),
+                Position::fake(),
+            ))]),
+            Err(CompileError::NonExhaustiveMatch(Position::fake()))
+        );
+    }
+
+    #[test]
+    fn fail_to_compile_guard_without_fallback() {
+        assert_eq!(
+            compile_branches(vec![ast::MatchBranch::new(
+                ast::Variable::new("y", Position::fake()),
+                Some(ast::Variable::new("y", Position::fake()).into()),
+                ast::Block::new(
+                    vec![],
+                    ast::Variable::new("y", Position::fake()),
+                    Position::fake(),
+                ),
+                Position::fake(),
+            )]),
+            Err(CompileError::NonExhaustiveMatch(Position::fake()))
+        );
+    }
+
+    #[test]
+    fn fail_to_compile_pattern_after_variable_pattern() {
+        assert_eq!(
+            compile_branches(vec![
+                create_branch(ast::Variable::new("y", Position::fake())),
+                create_branch(ast::Number::new(
+                    ast::NumberRepresentation::FloatingPoint("1".into()),
+                    Position::fake(),
+                )),
+            ]),
+            Err(CompileError::UnreachableMatchBranch(Position::fake()))
+        );
+    }
+
+    #[test]
+    fn fail_to_compile_variable_pattern_after_wildcard_pattern() {
+        assert_eq!(
+            compile_branches(vec![
+                create_branch(ast::Variable::new("_", Position::fake())),
+                create_branch(ast::Variable::new("y", Position::fake())),
+            ]),
+            Err(CompileError::UnreachableMatchBranch(Position::fake()))
+        );
+    }
+}
@@ -1,45 +1,120 @@
 use super::error::CompileError;
-use crate::{number, string, type_};
-use hir::{ir, types};
+use crate::{anonymous_record, integer, match_, number, string, type_};
+use hir::{
+    analysis::{interface_resolver, record_field_resolver, type_transformer},
+    ir,
+    types::{self, Type},
+};
+use itertools::Itertools;
 use position::Position;
 
+const RECORD_NAME: &str = "$record";
+const IMPLEMENTATION_PREFIX: &str = "$implementation:";
+const ARGUMENT_PREFIX: &str = "$argument:";
+
 pub fn compile(module: &ast::Module) -> Result<ir::Module, CompileError> {
     Ok(ir::Module::new(
         module
             .type_definitions()
             .iter()
             .filter_map(|definition| match definition {
-                ast::TypeDefinition::RecordDefinition(definition) => Some(ir::TypeDefinition::new(
-                    definition.name(),
-                    definition.name(),
-                    definition
-                        .fields()
-                        .iter()
-                        .map(|field| {
-                            types::RecordField::new(field.name(), type_::compile(field.type_()))
-                        })
-                        .collect(),
-                    ast::analysis::is_record_open(definition),
-                    ast::analysis::is_name_public(definition.name()),
-                    false,
-                    definition.position().clone(),
-                )),
+                ast::TypeDefinition::InterfaceDefinition(definition) => {
+                    Some(ir::TypeDefinition::new(
+                        definition.name(),
+                        definition.name(),
+                        definition
+                            .methods()
+                            .iter()
+                            .map(|method| {
+                                types::RecordField::new(
+                                    method.name(),
+                                    compile_interface_method_type(definition, method),
+                                )
+                            })
+                            .collect(),
+                        false,
+                        ast::analysis::is_name_public(definition.name()),
+                        false,
+                        definition.position().clone(),
+                    ))
+                }
+                ast::TypeDefinition::RecordDefinition(definition) => Some(
+                    ir::TypeDefinition::new(
+                        definition.name(),
+                        definition.name(),
+                        definition
+                            .fields()
+                            .iter()
+                            .map(|field| {
+                                types::RecordField::new(
+                                    field.name(),
+                                    compile_type_parameters(
+                                        &type_::compile(field.type_()),
+                                        definition.type_parameters(),
+                                    ),
+                                )
+                                .set_default(field.default().is_some())
+                            })
+                            .collect(),
+                        ast::analysis::is_record_open(definition),
+                        ast::analysis::is_name_public(definition.name()),
+                        false,
+                        definition.position().clone(),
+                    )
+                    .set_type_parameters(definition.type_parameters().to_vec())
+                    .set_newtype(definition.is_newtype()),
+                ),
                 ast::TypeDefinition::TypeAlias(_) => None,
             })
+            .chain(
+                anonymous_record::collect(module)
+                    .into_iter()
+                    .map(|(record, parameters)| {
+                        let name = type_::compile_anonymous_record_name(record);
+                        let fields = type_::compile_anonymous_record_fields(record)
+                            .into_iter()
+                            .map(|field| {
+                                types::RecordField::new(
+                                    field.name(),
+                                    compile_type_parameters(field.type_(), &parameters),
+                                )
+                            })
+                            .collect();
+
+                        ir::TypeDefinition::new(
+                            &name,
+                            &name,
+                            fields,
+                            true,
+                            true,
+                            false,
+                            record.position().clone(),
+                        )
+                        .set_type_parameters(parameters)
+                    })
+                    .unique_by(|definition| definition.name().to_string()),
+            )
             .collect(),
         module
             .type_definitions()
             .iter()
             .filter_map(|definition| match definition {
-                ast::TypeDefinition::RecordDefinition(_) => None,
-                ast::TypeDefinition::TypeAlias(alias) => Some(ir::TypeAlias::new(
-                    alias.name(),
-                    alias.name(),
-                    type_::compile(alias.type_()),
-                    ast::analysis::is_name_public(alias.name()),
-                    false,
-                    alias.position().clone(),
-                )),
+                ast::TypeDefinition::InterfaceDefinition(_)
+                | ast::TypeDefinition::RecordDefinition(_) => None,
+                ast::TypeDefinition::TypeAlias(alias) => Some(
+                    ir::TypeAlias::new(
+                        alias.name(),
+                        alias.name(),
+                        compile_type_parameters(
+                            &type_::compile(alias.type_()),
+                            alias.type_parameters(),
+                        ),
+                        ast::analysis::is_name_public(alias.name()),
+                        false,
+                        alias.position().clone(),
+                    )
+                    .set_type_parameters(alias.type_parameters().to_vec()),
+                ),
             })
             .collect(),
         module
@@ -56,19 +131,216 @@ pub fn compile(module: &ast::Module) -> Result<ir::Module, CompileError> {
             })
             .collect(),
         vec![],
+        module
+            .implementations()
+            .iter()
+            .enumerate()
+            .map(|(index, implementation)| {
+                ir::Implementation::new(
+                    compile_implementation_name(index),
+                    types::Reference::new(
+                        implementation.interface(),
+                        implementation.position().clone(),
+                    ),
+                    type_::compile(implementation.type_()),
+                    false,
+                    implementation.position().clone(),
+                )
+            })
+            .collect(),
         module
             .function_definitions()
             .iter()
             .map(compile_function_definition)
+            .chain(
+                module
+                    .type_definitions()
+                    .iter()
+                    .filter_map(|definition| match definition {
+                        ast::TypeDefinition::RecordDefinition(definition) => Some(definition),
+                        ast::TypeDefinition::InterfaceDefinition(_)
+                        | ast::TypeDefinition::TypeAlias(_) => None,
+                    })
+                    .flat_map(compile_default_function_definitions),
+            )
+            .chain(
+                module
+                    .type_definitions()
+                    .iter()
+                    .filter_map(|definition| match definition {
+                        ast::TypeDefinition::InterfaceDefinition(definition) => Some(definition),
+                        ast::TypeDefinition::RecordDefinition(_)
+                        | ast::TypeDefinition::TypeAlias(_) => None,
+                    })
+                    .flat_map(|definition| {
+                        definition
+                            .methods()
+                            .iter()
+                            .map(|method| Ok(compile_interface_method(definition, method)))
+                    }),
+            )
+            .chain(module.implementations().iter().enumerate().flat_map(
+                |(index, implementation)| {
+                    implementation
+                        .function_definitions()
+                        .iter()
+                        .map(move |definition| {
+                            compile_implementation_function_definition(index, definition)
+                        })
+                },
+            ))
             .collect::<Result<_, _>>()?,
         module.position().clone(),
     ))
 }
 
-fn compile_function_definition(
+fn compile_default_function_definitions(
+    definition: &ast::RecordDefinition,
+) -> impl Iterator<Item = Result<ir::FunctionDefinition, CompileError>> + '_ {
+    definition.fields().iter().filter_map(|field| {
+        field.default().map(|default| {
+            let name = record_field_resolver::resolve_default_function_name(
+                definition.name(),
+                field.name(),
+            );
+
+            let function_definition = ir::FunctionDefinition::new(
+                &name,
+                &name,
+                ir::Lambda::new(
+                    vec![],
+                    type_::compile(field.type_()),
+                    compile_expression(default)?,
+                    field.position().clone(),
+                ),
+                None,
+                ast::analysis::is_name_public(definition.name()),
+                field.position().clone(),
+            );
+
+            Ok(if definition.type_parameters().is_empty() {
+                function_definition
+            } else {
+                type_transformer::transform_function_definition(&function_definition, |type_| {
+                    compile_type_parameter(type_, definition.type_parameters())
+                })
+            })
+        })
+    })
+}
+
+fn compile_type_parameters(type_: &Type, parameters: &[String]) -> Type {
+    if parameters.is_empty() {
+        return type_.clone();
+    }
+
+    type_transformer::transform_type(type_, &|type_| compile_type_parameter(type_, parameters))
+}
+
+fn compile_type_parameter(type_: &Type, parameters: &[String]) -> Type {
+    match type_ {
+        Type::Reference(reference)
+            if reference.arguments().is_empty()
+                && parameters.iter().any(|name| name == reference.name()) =>
+        {
+            types::Variable::new(reference.name(), reference.position().clone()).into()
+        }
+        _ => type_.clone(),
+    }
+}
+
+fn compile_interface_method_type(
+    definition: &ast::InterfaceDefinition,
+    method: &ast::InterfaceMethod,
+) -> types::Function {
+    type_transformer::transform_type(&type_::compile(&method.type_().clone().into()), &|type_| {
+        match type_ {
+            Type::Reference(reference) if reference.name() == definition.type_parameter() => {
+                types::Variable::new(reference.name(), reference.position().clone())
+                    .set_constraint(Some(
+                        types::Reference::new(definition.name(), definition.position().clone())
+                            .into(),
+                    ))
+                    .into()
+            }
+            _ => type_.clone(),
+        }
+    })
+    .into_function()
+    .unwrap()
+}
+
+// Interface methods are compiled into generic functions whose bodies are
+// replaced with calls of methods in dictionaries later.
+fn compile_interface_method(
+    definition: &ast::InterfaceDefinition,
+    method: &ast::InterfaceMethod,
+) -> ir::FunctionDefinition {
+    let type_ = compile_interface_method_type(definition, method);
+    let position = method.position();
+
+    ir::FunctionDefinition::new(
+        method.name(),
+        method.name(),
+        ir::Lambda::new(
+            type_
+                .arguments()
+                .iter()
+                .enumerate()
+                .map(|(index, type_)| {
+                    ir::Argument::new(compile_argument_name(index), type_.clone())
+                })
+                .collect(),
+            type_.result().clone(),
+            ir::Call::new(
+                None,
+                ir::Variable::new(method.name(), position.clone()),
+                (0..type_.arguments().len())
+                    .map(|index| {
+                        ir::Variable::new(compile_argument_name(index), position.clone()).into()
+                    })
+                    .collect(),
+                position.clone(),
+            ),
+            position.clone(),
+        ),
+        None,
+        ast::analysis::is_name_public(method.name()),
+        position.clone(),
+    )
+}
+
+fn compile_implementation_function_definition(
+    index: usize,
     definition: &ast::FunctionDefinition,
 ) -> Result<ir::FunctionDefinition, CompileError> {
+    let name = interface_resolver::resolve_implementation_function_name(
+        &compile_implementation_name(index),
+        definition.name(),
+    );
+
     Ok(ir::FunctionDefinition::new(
+        &name,
+        &name,
+        compile_lambda(definition.lambda())?,
+        None,
+        true,
+        definition.position().clone(),
+    ))
+}
+
+fn compile_implementation_name(index: usize) -> String {
+    format!("{}{}", IMPLEMENTATION_PREFIX, index)
+}
+
+fn compile_argument_name(index: usize) -> String {
+    format!("{}{}", ARGUMENT_PREFIX, index)
+}
+
+fn compile_function_definition(
+    definition: &ast::FunctionDefinition,
+) -> Result<ir::FunctionDefinition, CompileError> {
+    let function_definition = ir::FunctionDefinition::new(
         definition.name(),
         definition.name(),
         compile_lambda(definition.lambda())?,
@@ -79,7 +351,32 @@ fn compile_function_definition(
         }),
         ast::analysis::is_name_public(definition.name()),
         definition.position().clone(),
-    ))
+    );
+
+    Ok(if definition.type_parameters().is_empty() {
+        function_definition
+    } else {
+        type_transformer::transform_function_definition(&function_definition, |type_| match type_ {
+            Type::Reference(reference) => {
+                if let Some(parameter) = definition
+                    .type_parameters()
+                    .iter()
+                    .find(|parameter| parameter.name() == reference.name())
+                {
+                    types::Variable::new(reference.name(), reference.position().clone())
+                        .set_constraint(
+                            parameter
+                                .constraint()
+                                .map(|constraint| type_::compile(&constraint.clone().into())),
+                        )
+                        .into()
+                } else {
+                    type_.clone()
+                }
+            }
+            _ => type_.clone(),
+        })
+    })
 }
 
 fn compile_calling_convention(calling_convention: ast::CallingConvention) -> ir::CallingConvention {
@@ -102,24 +399,56 @@ fn compile_lambda(lambda: &ast::Lambda) -> Result<ir::Lambda, CompileError> {
     ))
 }
 
-fn compile_block(block: &ast::Block) -> Result<ir::Expression, CompileError> {
+pub fn compile_block(block: &ast::Block) -> Result<ir::Expression, CompileError> {
     let mut expression = compile_expression(block.expression())?;
 
     for statement in block.statements().iter().rev() {
-        expression = ir::Let::new(
-            statement.name().map(String::from),
-            None,
-            compile_expression(statement.expression())?,
-            expression,
-            statement.position().clone(),
-        )
+        let position = statement.position();
+
+        expression = match statement.pattern() {
+            Some(ast::Pattern::Record(names)) => ir::Let::new(
+                Some(RECORD_NAME.into()),
+                None,
+                compile_expression(statement.expression())?,
+                names.iter().rev().fold(expression, |expression, name| {
+                    ir::Let::new(
+                        Some(name.into()),
+                        None,
+                        ir::RecordDeconstruction::new(
+                            None,
+                            ir::Variable::new(RECORD_NAME, position.clone()),
+                            name,
+                            position.clone(),
+                        ),
+                        expression,
+                        position.clone(),
+                    )
+                    .into()
+                }),
+                position.clone(),
+            ),
+            Some(ast::Pattern::Variable(name)) => ir::Let::new(
+                Some(name.into()),
+                None,
+                compile_expression(statement.expression())?,
+                expression,
+                position.clone(),
+            ),
+            None => ir::Let::new(
+                None,
+                None,
+                compile_expression(statement.expression())?,
+                expression,
+                position.clone(),
+            ),
+        }
         .into();
     }
 
     Ok(expression)
 }
 
-fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, CompileError> {
+pub fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, CompileError> {
     Ok(match expression {
         ast::Expression::BinaryOperation(operation) => {
             let lhs = compile_expression(operation.lhs())?;
@@ -127,11 +456,16 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
             let position = operation.position().clone();
 
             match operation.operator() {
-                ast::BinaryOperator::Add => {
-                    ir::ArithmeticOperation::new(ir::ArithmeticOperator::Add, lhs, rhs, position)
-                        .into()
-                }
+                ast::BinaryOperator::Add => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::Add,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
                 ast::BinaryOperator::Subtract => ir::ArithmeticOperation::new(
+                    None,
                     ir::ArithmeticOperator::Subtract,
                     lhs,
                     rhs,
@@ -139,16 +473,69 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
                 )
                 .into(),
                 ast::BinaryOperator::Multiply => ir::ArithmeticOperation::new(
+                    None,
                     ir::ArithmeticOperator::Multiply,
                     lhs,
                     rhs,
                     position,
                 )
                 .into(),
-                ast::BinaryOperator::Divide => {
-                    ir::ArithmeticOperation::new(ir::ArithmeticOperator::Divide, lhs, rhs, position)
-                        .into()
-                }
+                ast::BinaryOperator::Divide => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::Divide,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::Remainder => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::Remainder,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::BitwiseAnd => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::BitwiseAnd,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::BitwiseOr => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::BitwiseOr,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::BitwiseXor => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::BitwiseXor,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::LeftShift => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::LeftShift,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::RightShift => ir::ArithmeticOperation::new(
+                    None,
+                    ir::ArithmeticOperator::RightShift,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
 
                 ast::BinaryOperator::And => {
                     ir::BooleanOperation::new(ir::BooleanOperator::And, lhs, rhs, position).into()
@@ -175,23 +562,47 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
                 .into(),
 
                 ast::BinaryOperator::LessThan => {
-                    ir::OrderOperation::new(ir::OrderOperator::LessThan, lhs, rhs, position).into()
-                }
-                ast::BinaryOperator::LessThanOrEqual => {
-                    ir::OrderOperation::new(ir::OrderOperator::LessThanOrEqual, lhs, rhs, position)
-                        .into()
-                }
-                ast::BinaryOperator::GreaterThan => {
-                    ir::OrderOperation::new(ir::OrderOperator::GreaterThan, lhs, rhs, position)
+                    ir::OrderOperation::new(None, ir::OrderOperator::LessThan, lhs, rhs, position)
                         .into()
                 }
+                ast::BinaryOperator::LessThanOrEqual => ir::OrderOperation::new(
+                    None,
+                    ir::OrderOperator::LessThanOrEqual,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
+                ast::BinaryOperator::GreaterThan => ir::OrderOperation::new(
+                    None,
+                    ir::OrderOperator::GreaterThan,
+                    lhs,
+                    rhs,
+                    position,
+                )
+                .into(),
                 ast::BinaryOperator::GreaterThanOrEqual => ir::OrderOperation::new(
+                    None,
                     ir::OrderOperator::GreaterThanOrEqual,
                     lhs,
                     rhs,
                     position,
                 )
                 .into(),
+
+                ast::BinaryOperator::Pipe => match (operation.rhs(), rhs) {
+                    (ast::Expression::Call(_), ir::Expression::Call(call)) => ir::Call::new(
+                        None,
+                        call.function().clone(),
+                        [lhs]
+                            .into_iter()
+                            .chain(call.arguments().iter().cloned())
+                            .collect(),
+                        position,
+                    ),
+                    (_, rhs) => ir::Call::new(None, rhs, vec![lhs], position),
+                }
+                .into(),
             }
         }
         ast::Expression::Call(call) => ir::Call::new(
@@ -257,7 +668,13 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
             if_.position().clone(),
         )
         .into(),
+        ast::Expression::Integer(integer) => {
+            let (kind, value) = integer::compile(integer)?;
+
+            ir::Integer::new(kind, value, integer.position().clone()).into()
+        }
         ast::Expression::Lambda(lambda) => compile_lambda(lambda)?.into(),
+        ast::Expression::Match(match_) => match_::compile(match_)?,
         ast::Expression::List(list) => ir::List::new(
             type_::compile(list.type_()),
             list.elements()
@@ -277,11 +694,13 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
         )
         .into(),
         ast::Expression::ListComprehension(comprehension) => ir::ListComprehension::new(
-            None,
             type_::compile(comprehension.type_()),
             compile_expression(comprehension.element())?,
-            comprehension.element_name(),
-            compile_expression(comprehension.list())?,
+            comprehension
+                .branches()
+                .iter()
+                .map(compile_list_comprehension_branch)
+                .collect::<Result<_, _>>()?,
             comprehension.position().clone(),
         )
         .into(),
@@ -311,24 +730,24 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
             map.position().clone(),
         )
         .into(),
-        ast::Expression::MapIterationComprehension(comprehension) => {
-            ir::MapIterationComprehension::new(
-                None,
-                None,
-                type_::compile(comprehension.element_type()),
-                compile_expression(comprehension.element())?,
-                comprehension.key_name(),
-                comprehension.value_name(),
-                compile_expression(comprehension.map())?,
-                comprehension.position().clone(),
-            )
-            .into()
-        }
+        ast::Expression::MapComprehension(comprehension) => ir::MapComprehension::new(
+            type_::compile(comprehension.key_type()),
+            type_::compile(comprehension.value_type()),
+            compile_expression(comprehension.key())?,
+            compile_expression(comprehension.value())?,
+            comprehension
+                .branches()
+                .iter()
+                .map(compile_list_comprehension_branch)
+                .collect::<Result<_, _>>()?,
+            comprehension.position().clone(),
+        )
+        .into(),
         ast::Expression::Number(number) => {
             ir::Number::new(number::compile(number)?, number.position().clone()).into()
         }
         ast::Expression::Record(record) => {
-            let type_ = types::Reference::new(record.type_name(), record.position().clone());
+            let type_ = type_::compile(record.type_());
             let fields = record
                 .fields()
                 .iter()
@@ -356,6 +775,31 @@ fn compile_expression(expression: &ast::Expression) -> Result<ir::Expression, Co
         ast::Expression::String(string) => {
             ir::ByteString::new(string::compile(string.value()), string.position().clone()).into()
         }
+        ast::Expression::StringInterpolation(interpolation) => ir::Call::new(
+            None,
+            ir::BuiltInFunction::new(
+                ir::BuiltInFunctionName::ConcatenateStrings,
+                interpolation.position().clone(),
+            ),
+            interpolation
+                .parts()
+                .iter()
+                .map(|part| {
+                    Ok(match part {
+                        ast::StringInterpolationPart::Expression(expression) => {
+                            compile_expression(expression)?
+                        }
+                        ast::StringInterpolationPart::String(string) => ir::ByteString::new(
+                            string::compile(string),
+                            interpolation.position().clone(),
+                        )
+                        .into(),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            interpolation.position().clone(),
+        )
+        .into(),
         ast::Expression::UnaryOperation(operation) => {
             let operand = compile_expression(operation.expression())?;
 
@@ -396,12 +840,43 @@ fn compile_if(
     })
 }
 
+fn compile_list_comprehension_branch(
+    branch: &ast::ListComprehensionBranch,
+) -> Result<ir::ListComprehensionBranch, CompileError> {
+    match (branch.names(), branch.iteratees()) {
+        ([_, _], [_]) => {}
+        (names, iteratees) if names.len() == iteratees.len() => {}
+        _ => {
+            return Err(CompileError::ComprehensionIterateesNotMatched(
+                branch.position().clone(),
+            ))
+        }
+    }
+
+    Ok(ir::ListComprehensionBranch::new(
+        branch.names().to_vec(),
+        branch
+            .iteratees()
+            .iter()
+            .map(|iteratee| {
+                Ok(ir::ListComprehensionIteratee::new(
+                    None,
+                    compile_expression(iteratee)?,
+                ))
+            })
+            .collect::<Result<_, _>>()?,
+        branch.condition().map(compile_expression).transpose()?,
+        branch.position().clone(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use hir::test::ModuleFake;
     use position::{test::PositionFake, Position};
     use pretty_assertions::assert_eq;
+    use std::sync::Arc;
 
     #[test]
     fn compile_empty_module() {
@@ -411,6 +886,7 @@ mod tests {
                 vec![],
                 vec![],
                 vec![],
+                vec![],
                 Position::fake()
             )),
             Ok(ir::Module::empty())
@@ -432,8 +908,10 @@ mod tests {
                     )
                     .into()
                 ],
+                vec![],
                 vec![ast::FunctionDefinition::new(
                     "Foo3",
+                    vec![],
                     ast::Lambda::new(
                         vec![],
                         ast::types::Reference::new("none", Position::fake()),
@@ -482,4 +960,509 @@ mod tests {
                 )]))
         );
     }
+
+    #[test]
+    fn compile_newtype_definition() {
+        assert_eq!(
+            compile(&ast::Module::new(
+                vec![],
+                vec![],
+                vec![ast::RecordDefinition::new(
+                    "Foo",
+                    vec![ast::RecordFieldDefinition::new(
+                        "bar",
+                        ast::types::Reference::new("any", Position::fake()),
+                        None,
+                        Position::fake(),
+                    )],
+                    Position::fake(),
+                )
+                .set_newtype(true)
+                .into()],
+                vec![],
+                vec![],
+                Position::fake(),
+            )),
+            Ok(
+                ir::Module::empty().set_type_definitions(vec![ir::TypeDefinition::new(
+                    "Foo",
+                    "Foo",
+                    vec![types::RecordField::new(
+                        "bar",
+                        types::Reference::new("any", Position::fake())
+                    )],
+                    false,
+                    true,
+                    false,
+                    Position::fake()
+                )
+                .set_newtype(true)])
+            )
+        );
+    }
+
+    #[test]
+    fn compile_generic_record_definition() {
+        assert_eq!(
+            compile(&ast::Module::new(
+                vec![],
+                vec![],
+                vec![ast::RecordDefinition::new(
+                    "Foo",
+                    vec![ast::RecordFieldDefinition::new(
+                        "bar",
+                        ast::types::List::new(
+                            ast::types::Reference::new("a", Position::fake()),
+                            Position::fake()
+                        ),
+                        None,
+                        Position::fake(),
+                    )],
+                    Position::fake(),
+                )
+                .set_type_parameters(vec!["a".into()])
+                .into()],
+                vec![],
+                vec![],
+                Position::fake(),
+            )),
+            Ok(
+                ir::Module::empty().set_type_definitions(vec![ir::TypeDefinition::new(
+                    "Foo",
+                    "Foo",
+                    vec![types::RecordField::new(
+                        "bar",
+                        types::List::new(
+                            types::Variable::new("a", Position::fake()),
+                            Position::fake()
+                        )
+                    )],
+                    false,
+                    true,
+                    false,
+                    Position::fake()
+                )
+                .set_type_parameters(vec!["a".into()])])
+            )
+        );
+    }
+
+    #[test]
+    fn compile_anonymous_record_type_in_generic_type_alias() {
+        assert_eq!(
+            compile(&ast::Module::new(
+                vec![],
+                vec![],
+                vec![ast::TypeAlias::new(
+                    "Foo",
+                    ast::types::AnonymousRecord::new(
+                        vec![ast::types::RecordField::new(
+                            "x",
+                            ast::types::Reference::new("a", Position::fake()),
+                        )],
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                )
+                .set_type_parameters(vec!["a".into(), "b".into()])
+                .into()],
+                vec![],
+                vec![],
+                Position::fake(),
+            )),
+            Ok(ir::Module::empty()
+                .set_type_definitions(vec![ir::TypeDefinition::new(
+                    "{x a}",
+                    "{x a}",
+                    vec![types::RecordField::new(
+                        "x",
+                        types::Variable::new("a", Position::fake())
+                    )],
+                    true,
+                    true,
+                    false,
+                    Position::fake()
+                )
+                .set_type_parameters(vec!["a".into()])])
+                .set_type_aliases(vec![ir::TypeAlias::new(
+                    "Foo",
+                    "Foo",
+                    types::Record::new("{x a}", Position::fake()),
+                    true,
+                    false,
+                    Position::fake()
+                )
+                .set_type_parameters(vec!["a".into(), "b".into()])]))
+        );
+    }
+
+    #[test]
+    fn compile_record_field_default() {
+        assert_eq!(
+            compile(&ast::Module::new(
+                vec![],
+                vec![],
+                vec![ast::RecordDefinition::new(
+                    "Foo",
+                    vec![ast::RecordFieldDefinition::new(
+                        "Bar",
+                        ast::types::Reference::new("none", Position::fake()),
+                        Some(ast::Variable::new("none", Position::fake()).into()),
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )
+                .into()],
+                vec![],
+                vec![],
+                Position::fake(),
+            )),
+            Ok(ir::Module::empty()
+                .set_type_definitions(vec![ir::TypeDefinition::new(
+                    "Foo",
+                    "Foo",
+                    vec![types::RecordField::new(
+                        "Bar",
+                        types::Reference::new("none", Position::fake())
+                    )
+                    .set_default(true)],
+                    true,
+                    true,
+                    false,
+                    Position::fake()
+                )])
+                .set_function_definitions(vec![ir::FunctionDefinition::new(
+                    "Foo:default:Bar",
+                    "Foo:default:Bar",
+                    ir::Lambda::new(
+                        vec![],
+                        types::Reference::new("none", Position::fake()),
+                        ir::Variable::new("none", Position::fake()),
+                        Position::fake(),
+                    ),
+                    None,
+                    true,
+                    Position::fake()
+                )]))
+        );
+    }
+
+    #[test]
+    fn compile_type_parameters() {
+        assert_eq!(
+            compile(&ast::Module::new(
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![ast::FunctionDefinition::new(
+                    "f",
+                    vec![ast::TypeParameter::new("a", None)],
+                    ast::Lambda::new(
+                        vec![ast::Argument::new(
+                            "x",
+                            ast::types::List::new(
+                                ast::types::Reference::new("a", Position::fake()),
+                                Position::fake()
+                            )
+                        )],
+                        ast::types::Reference::new("b", Position::fake()),
+                        ast::Block::new(
+                            vec![],
+                            ast::Variable::new("x", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    None,
+                    Position::fake(),
+                )],
+                Position::fake(),
+            )),
+            Ok(
+                ir::Module::empty().set_function_definitions(vec![ir::FunctionDefinition::new(
+                    "f",
+                    "f",
+                    ir::Lambda::new(
+                        vec![ir::Argument::new(
+                            "x",
+                            types::List::new(
+                                types::Variable::new("a", Position::fake()),
+                                Position::fake()
+                            )
+                        )],
+                        types::Reference::new("b", Position::fake()),
+                        ir::Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    None,
+                    false,
+                    Position::fake()
+                )])
+            )
+        );
+    }
+
+    #[test]
+    fn compile_anonymous_record_type() {
+        let record_type = ast::types::AnonymousRecord::new(
+            vec![ast::types::RecordField::new(
+                "x",
+                ast::types::Reference::new("number", Position::fake()),
+            )],
+            Position::fake(),
+        );
+
+        assert_eq!(
+            compile(&ast::Module::new(
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![ast::FunctionDefinition::new(
+                    "f",
+                    vec![],
+                    ast::Lambda::new(
+                        vec![ast::Argument::new("x", record_type.clone())],
+                        record_type,
+                        ast::Block::new(
+                            vec![],
+                            ast::Variable::new("x", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    None,
+                    Position::fake(),
+                )],
+                Position::fake(),
+            )),
+            Ok(ir::Module::empty()
+                .set_type_definitions(vec![ir::TypeDefinition::new(
+                    "{x number}",
+                    "{x number}",
+                    vec![types::RecordField::new(
+                        "x",
+                        types::Reference::new("number", Position::fake())
+                    )],
+                    true,
+                    true,
+                    false,
+                    Position::fake()
+                )])
+                .set_function_definitions(vec![ir::FunctionDefinition::new(
+                    "f",
+                    "f",
+                    ir::Lambda::new(
+                        vec![ir::Argument::new(
+                            "x",
+                            types::Record::new("{x number}", Position::fake())
+                        )],
+                        types::Record::new("{x number}", Position::fake()),
+                        ir::Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    None,
+                    false,
+                    Position::fake()
+                )]))
+        );
+    }
+
+    #[test]
+    fn compile_record_pattern() {
+        assert_eq!(
+            compile_block(&ast::Block::new(
+                vec![ast::Statement::new(
+                    Some(ast::Pattern::Record(vec!["x".into(), "y".into()])),
+                    ast::Variable::new("r", Position::fake()),
+                    Position::fake(),
+                )],
+                ast::Variable::new("x", Position::fake()),
+                Position::fake()
+            )),
+            Ok(ir::Let::new(
+                Some(RECORD_NAME.into()),
+                None,
+                ir::Variable::new("r", Position::fake()),
+                ir::Let::new(
+                    Some("x".into()),
+                    None,
+                    ir::RecordDeconstruction::new(
+                        None,
+                        ir::Variable::new(RECORD_NAME, Position::fake()),
+                        "x",
+                        Position::fake(),
+                    ),
+                    ir::Let::new(
+                        Some("y".into()),
+                        None,
+                        ir::RecordDeconstruction::new(
+                            None,
+                            ir::Variable::new(RECORD_NAME, Position::fake()),
+                            "y",
+                            Position::fake(),
+                        ),
+                        ir::Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_string_interpolation() {
+        assert_eq!(
+            compile_expression(
+                &ast::StringInterpolation::new(
+                    vec![
+                        ast::StringInterpolationPart::String("foo\\n".into()),
+                        ast::StringInterpolationPart::Expression(Arc::new(
+                            ast::Variable::new("x", Position::fake()).into()
+                        )),
+                    ],
+                    Position::fake()
+                )
+                .into()
+            ),
+            Ok(ir::Call::new(
+                None,
+                ir::BuiltInFunction::new(
+                    ir::BuiltInFunctionName::ConcatenateStrings,
+                    Position::fake()
+                ),
+                vec![
+                    ir::ByteString::new("foo\n", Position::fake()).into(),
+                    ir::Variable::new("x", Position::fake()).into(),
+                ],
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_pipe_into_variable() {
+        assert_eq!(
+            compile_expression(
+                &ast::BinaryOperation::new(
+                    ast::BinaryOperator::Pipe,
+                    ast::Variable::new("x", Position::fake()),
+                    ast::Variable::new("f", Position::fake()),
+                    Position::fake()
+                )
+                .into()
+            ),
+            Ok(ir::Call::new(
+                None,
+                ir::Variable::new("f", Position::fake()),
+                vec![ir::Variable::new("x", Position::fake()).into()],
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_pipe_into_call() {
+        assert_eq!(
+            compile_expression(
+                &ast::BinaryOperation::new(
+                    ast::BinaryOperator::Pipe,
+                    ast::Variable::new("x", Position::fake()),
+                    ast::Call::new(
+                        ast::Variable::new("f", Position::fake()),
+                        vec![ast::Variable::new("y", Position::fake()).into()],
+                        Position::fake()
+                    ),
+                    Position::fake()
+                )
+                .into()
+            ),
+            Ok(ir::Call::new(
+                None,
+                ir::Variable::new("f", Position::fake()),
+                vec![
+                    ir::Variable::new("x", Position::fake()).into(),
+                    ir::Variable::new("y", Position::fake()).into()
+                ],
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn compile_zipped_list_comprehension() {
+        assert_eq!(
+            compile_expression(
+                &ast::ListComprehension::new(
+                    ast::types::Reference::new("none", Position::fake()),
+                    ast::Variable::new("none", Position::fake()),
+                    vec![ast::ListComprehensionBranch::new(
+                        vec!["x".into(), "y".into()],
+                        vec![
+                            ast::Variable::new("xs", Position::fake()).into(),
+                            ast::Variable::new("ys", Position::fake()).into()
+                        ],
+                        None,
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )
+                .into()
+            ),
+            Ok(ir::ListComprehension::new(
+                types::Reference::new("none", Position::fake()),
+                ir::Variable::new("none", Position::fake()),
+                vec![ir::ListComprehensionBranch::new(
+                    vec!["x".into(), "y".into()],
+                    vec![
+                        ir::ListComprehensionIteratee::new(
+                            None,
+                            ir::Variable::new("xs", Position::fake())
+                        ),
+                        ir::ListComprehensionIteratee::new(
+                            None,
+                            ir::Variable::new("ys", Position::fake())
+                        )
+                    ],
+                    None,
+                    Position::fake()
+                )],
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn fail_to_compile_list_comprehension_with_unmatched_iteratees() {
+        assert_eq!(
+            compile_expression(
+                &ast::ListComprehension::new(
+                    ast::types::Reference::new("none", Position::fake()),
+                    ast::Variable::new("none", Position::fake()),
+                    vec![ast::ListComprehensionBranch::new(
+                        vec!["x".into()],
+                        vec![
+                            ast::Variable::new("xs", Position::fake()).into(),
+                            ast::Variable::new("ys", Position::fake()).into()
+                        ],
+                        None,
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )
+                .into()
+            ),
+            Err(CompileError::ComprehensionIterateesNotMatched(
+                Position::fake()
+            ))
+        );
+    }
 }
@@ -11,6 +11,7 @@ pub fn compile(string: &str) -> Vec<u8> {
             string
                 .replace("\\\\", "\\")
                 .replace("\\\"", "\"")
+                .replace("\\$", "$")
                 .replace("\\n", "\n")
                 .replace("\\r", "\r")
                 .replace("\\t", "\t")
@@ -36,6 +37,11 @@ mod tests {
         assert_eq!(compile("\\\""), Vec::from("\""));
     }
 
+    #[test]
+    fn compile_dollar_sign() {
+        assert_eq!(compile("\\$"), Vec::from("$"));
+    }
+
     #[test]
     fn compile_newline() {
         assert_eq!(compile("\\n"), Vec::from("\n"));
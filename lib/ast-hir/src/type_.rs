@@ -1,7 +1,15 @@
-use hir::types::{self, Type};
+use hir::{
+    analysis::type_formatter,
+    types::{self, Type},
+};
 
 pub fn compile(type_: &ast::types::Type) -> Type {
     match type_ {
+        ast::types::Type::AnonymousRecord(record) => types::Record::new(
+            compile_anonymous_record_name(record),
+            record.position().clone(),
+        )
+        .into(),
         ast::types::Type::Function(function) => types::Function::new(
             function.arguments().iter().map(compile).collect(),
             compile(function.result()),
@@ -21,7 +29,9 @@ pub fn compile(type_: &ast::types::Type) -> Type {
             types::Record::new(record.name(), record.position().clone()).into()
         }
         ast::types::Type::Reference(reference) => {
-            types::Reference::new(reference.name(), reference.position().clone()).into()
+            types::Reference::new(reference.name(), reference.position().clone())
+                .set_arguments(reference.arguments().iter().map(compile).collect())
+                .into()
         }
         ast::types::Type::Union(union) => types::Union::new(
             compile(union.lhs()),
@@ -31,3 +41,19 @@ pub fn compile(type_: &ast::types::Type) -> Type {
         .into(),
     }
 }
+
+// Anonymous record types are named after their structures again later in
+// analysis.
+pub fn compile_anonymous_record_name(record: &ast::types::AnonymousRecord) -> String {
+    type_formatter::format_anonymous_record(&compile_anonymous_record_fields(record))
+}
+
+pub fn compile_anonymous_record_fields(
+    record: &ast::types::AnonymousRecord,
+) -> Vec<types::RecordField> {
+    record
+        .fields()
+        .iter()
+        .map(|field| types::RecordField::new(field.name(), compile(field.type_())))
+        .collect()
+}
@@ -25,6 +25,7 @@ pub fn is_module_path_public(path: &ModulePath) -> bool {
 
 pub fn operator_priority(operator: BinaryOperator) -> usize {
     match operator {
+        BinaryOperator::Pipe => 0,
         BinaryOperator::Or => 1,
         BinaryOperator::And => 2,
         BinaryOperator::Equal
@@ -33,8 +34,11 @@ pub fn operator_priority(operator: BinaryOperator) -> usize {
         | BinaryOperator::LessThanOrEqual
         | BinaryOperator::GreaterThan
         | BinaryOperator::GreaterThanOrEqual => 3,
-        BinaryOperator::Add | BinaryOperator::Subtract => 4,
-        BinaryOperator::Multiply | BinaryOperator::Divide => 5,
+        BinaryOperator::BitwiseOr | BinaryOperator::BitwiseXor => 4,
+        BinaryOperator::BitwiseAnd => 5,
+        BinaryOperator::LeftShift | BinaryOperator::RightShift => 6,
+        BinaryOperator::Add | BinaryOperator::Subtract => 7,
+        BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Remainder => 8,
     }
 }
 
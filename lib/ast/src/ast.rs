@@ -15,28 +15,46 @@ mod if_list;
 mod if_map;
 mod if_type;
 mod if_type_branch;
+mod implementation;
 mod import;
+mod integer;
+mod integer_kind;
+mod interface_definition;
+mod interface_method;
 mod internal_module_path;
 mod lambda;
 mod list;
 mod list_comprehension;
+mod list_comprehension_branch;
 mod list_element;
+mod list_pattern;
 mod map;
+mod map_comprehension;
 mod map_element;
 mod map_entry;
-mod map_iteration_comprehension;
+mod match_;
+mod match_branch;
+mod match_pattern;
 mod module;
 mod module_path;
 mod number;
 mod number_representation;
+mod pattern;
 mod record;
 mod record_deconstruction;
 mod record_definition;
 mod record_field;
+mod record_field_definition;
+mod record_field_pattern;
+mod record_pattern;
 mod statement;
 mod string;
+mod string_interpolation;
+mod string_interpolation_part;
 mod type_alias;
 mod type_definition;
+mod type_parameter;
+mod type_pattern;
 mod unary_operation;
 mod unary_operator;
 mod unqualified_name;
@@ -59,28 +77,46 @@ pub use if_list::*;
 pub use if_map::*;
 pub use if_type::*;
 pub use if_type_branch::*;
+pub use implementation::*;
 pub use import::*;
+pub use integer::*;
+pub use integer_kind::*;
+pub use interface_definition::*;
+pub use interface_method::*;
 pub use internal_module_path::*;
 pub use lambda::*;
 pub use list::*;
 pub use list_comprehension::*;
+pub use list_comprehension_branch::*;
 pub use list_element::*;
+pub use list_pattern::*;
 pub use map::*;
+pub use map_comprehension::*;
 pub use map_element::*;
 pub use map_entry::*;
-pub use map_iteration_comprehension::*;
+pub use match_::*;
+pub use match_branch::*;
+pub use match_pattern::*;
 pub use module::*;
 pub use module_path::*;
 pub use number::*;
 pub use number_representation::*;
+pub use pattern::*;
 pub use record::*;
 pub use record_deconstruction::*;
 pub use record_definition::*;
 pub use record_field::*;
+pub use record_field_definition::*;
+pub use record_field_pattern::*;
+pub use record_pattern::*;
 pub use statement::*;
 pub use string::*;
+pub use string_interpolation::*;
+pub use string_interpolation_part::*;
 pub use type_alias::*;
 pub use type_definition::*;
+pub use type_parameter::*;
+pub use type_pattern::*;
 pub use unary_operation::*;
 pub use unary_operator::*;
 pub use unqualified_name::*;
@@ -1,6 +1,7 @@
 use super::{
-    BinaryOperation, ByteString, Call, If, IfList, IfMap, IfType, Lambda, List, ListComprehension,
-    Map, MapIterationComprehension, Number, Record, RecordDeconstruction, UnaryOperation, Variable,
+    BinaryOperation, ByteString, Call, If, IfList, IfMap, IfType, Integer, Lambda, List,
+    ListComprehension, Map, MapComprehension, Match, Number, Record, RecordDeconstruction,
+    StringInterpolation, UnaryOperation, Variable,
 };
 use position::Position;
 
@@ -12,15 +13,18 @@ pub enum Expression {
     IfList(IfList),
     IfMap(IfMap),
     IfType(IfType),
+    Integer(Integer),
     Lambda(Lambda),
     List(List),
     ListComprehension(ListComprehension),
     Map(Map),
-    MapIterationComprehension(MapIterationComprehension),
+    MapComprehension(MapComprehension),
+    Match(Match),
     Number(Number),
     Record(Record),
     RecordDeconstruction(RecordDeconstruction),
     String(ByteString),
+    StringInterpolation(StringInterpolation),
     UnaryOperation(UnaryOperation),
     Variable(Variable),
 }
@@ -34,15 +38,18 @@ impl Expression {
             Self::IfList(if_) => if_.position(),
             Self::IfMap(if_) => if_.position(),
             Self::IfType(if_) => if_.position(),
+            Self::Integer(integer) => integer.position(),
             Self::Lambda(lambda) => lambda.position(),
             Self::List(list) => list.position(),
             Self::ListComprehension(comprehension) => comprehension.position(),
             Self::Map(map) => map.position(),
-            Self::MapIterationComprehension(comprehension) => comprehension.position(),
+            Self::MapComprehension(comprehension) => comprehension.position(),
+            Self::Match(match_) => match_.position(),
             Self::Number(number) => number.position(),
             Self::Record(record) => record.position(),
             Self::RecordDeconstruction(operation) => operation.position(),
             Self::String(string) => string.position(),
+            Self::StringInterpolation(interpolation) => interpolation.position(),
             Self::UnaryOperation(operation) => operation.position(),
             Self::Variable(variable) => variable.position(),
         }
@@ -91,6 +98,12 @@ impl From<IfMap> for Expression {
     }
 }
 
+impl From<Integer> for Expression {
+    fn from(integer: Integer) -> Self {
+        Self::Integer(integer)
+    }
+}
+
 impl From<Lambda> for Expression {
     fn from(lambda: Lambda) -> Self {
         Self::Lambda(lambda)
@@ -115,9 +128,15 @@ impl From<Map> for Expression {
     }
 }
 
-impl From<MapIterationComprehension> for Expression {
-    fn from(comprehension: MapIterationComprehension) -> Self {
-        Self::MapIterationComprehension(comprehension)
+impl From<MapComprehension> for Expression {
+    fn from(comprehension: MapComprehension) -> Self {
+        Self::MapComprehension(comprehension)
+    }
+}
+
+impl From<Match> for Expression {
+    fn from(match_: Match) -> Self {
+        Self::Match(match_)
     }
 }
 
@@ -139,6 +158,12 @@ impl From<RecordDeconstruction> for Expression {
     }
 }
 
+impl From<StringInterpolation> for Expression {
+    fn from(interpolation: StringInterpolation) -> Self {
+        Self::StringInterpolation(interpolation)
+    }
+}
+
 impl From<UnaryOperation> for Expression {
     fn from(operation: UnaryOperation) -> Self {
         Self::UnaryOperation(operation)
@@ -1,9 +1,10 @@
-use super::{foreign_export::ForeignExport, lambda::Lambda};
+use super::{foreign_export::ForeignExport, lambda::Lambda, TypeParameter};
 use position::Position;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct FunctionDefinition {
     name: String,
+    type_parameters: Vec<TypeParameter>,
     lambda: Lambda,
     foreign_export: Option<ForeignExport>,
     position: Position,
@@ -12,12 +13,14 @@ pub struct FunctionDefinition {
 impl FunctionDefinition {
     pub fn new(
         name: impl Into<String>,
+        type_parameters: Vec<TypeParameter>,
         lambda: Lambda,
         foreign_export: Option<ForeignExport>,
         position: Position,
     ) -> Self {
         Self {
             name: name.into(),
+            type_parameters,
             lambda,
             foreign_export,
             position,
@@ -28,6 +31,10 @@ impl FunctionDefinition {
         &self.name
     }
 
+    pub fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+
     pub fn lambda(&self) -> &Lambda {
         &self.lambda
     }
@@ -0,0 +1,43 @@
+use super::FunctionDefinition;
+use crate::types::Type;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Implementation {
+    interface: String,
+    type_: Type,
+    function_definitions: Vec<FunctionDefinition>,
+    position: Position,
+}
+
+impl Implementation {
+    pub fn new(
+        interface: impl Into<String>,
+        type_: impl Into<Type>,
+        function_definitions: Vec<FunctionDefinition>,
+        position: Position,
+    ) -> Self {
+        Self {
+            interface: interface.into(),
+            type_: type_.into(),
+            function_definitions,
+            position,
+        }
+    }
+
+    pub fn interface(&self) -> &str {
+        &self.interface
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn function_definitions(&self) -> &[FunctionDefinition] {
+        &self.function_definitions
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
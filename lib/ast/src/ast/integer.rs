@@ -0,0 +1,31 @@
+use crate::{IntegerKind, NumberRepresentation};
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Integer {
+    value: NumberRepresentation,
+    kind: IntegerKind,
+    position: Position,
+}
+
+impl Integer {
+    pub fn new(value: NumberRepresentation, kind: IntegerKind, position: Position) -> Self {
+        Self {
+            value,
+            kind,
+            position,
+        }
+    }
+
+    pub fn value(&self) -> &NumberRepresentation {
+        &self.value
+    }
+
+    pub fn kind(&self) -> IntegerKind {
+        self.kind
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
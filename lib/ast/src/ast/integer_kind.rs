@@ -0,0 +1,16 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IntegerKind {
+    Byte,
+    I64,
+    U64,
+}
+
+impl IntegerKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Byte => "byte",
+            Self::I64 => "i64",
+            Self::U64 => "u64",
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::InterfaceMethod;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterfaceDefinition {
+    name: String,
+    type_parameter: String,
+    methods: Vec<InterfaceMethod>,
+    position: Position,
+}
+
+impl InterfaceDefinition {
+    pub fn new(
+        name: impl Into<String>,
+        type_parameter: impl Into<String>,
+        methods: Vec<InterfaceMethod>,
+        position: Position,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            type_parameter: type_parameter.into(),
+            methods,
+            position,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_parameter(&self) -> &str {
+        &self.type_parameter
+    }
+
+    pub fn methods(&self) -> &[InterfaceMethod] {
+        &self.methods
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
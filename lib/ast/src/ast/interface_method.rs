@@ -0,0 +1,31 @@
+use crate::types;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InterfaceMethod {
+    name: String,
+    type_: types::Function,
+    position: Position,
+}
+
+impl InterfaceMethod {
+    pub fn new(name: impl Into<String>, type_: types::Function, position: Position) -> Self {
+        Self {
+            name: name.into(),
+            type_,
+            position,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> &types::Function {
+        &self.type_
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
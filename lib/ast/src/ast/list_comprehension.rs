@@ -1,4 +1,4 @@
-use super::expression::Expression;
+use super::{expression::Expression, ListComprehensionBranch};
 use crate::types::Type;
 use position::Position;
 use std::sync::Arc;
@@ -7,8 +7,7 @@ use std::sync::Arc;
 pub struct ListComprehension {
     type_: Type,
     element: Arc<Expression>,
-    element_name: String,
-    list: Arc<Expression>,
+    branches: Vec<ListComprehensionBranch>,
     position: Position,
 }
 
@@ -16,15 +15,13 @@ impl ListComprehension {
     pub fn new(
         type_: impl Into<Type>,
         element: impl Into<Expression>,
-        element_name: impl Into<String>,
-        list: impl Into<Expression>,
+        branches: Vec<ListComprehensionBranch>,
         position: Position,
     ) -> Self {
         Self {
             type_: type_.into(),
             element: element.into().into(),
-            element_name: element_name.into(),
-            list: list.into().into(),
+            branches,
             position,
         }
     }
@@ -37,12 +34,8 @@ impl ListComprehension {
         &self.element
     }
 
-    pub fn element_name(&self) -> &str {
-        &self.element_name
-    }
-
-    pub fn list(&self) -> &Expression {
-        &self.list
+    pub fn branches(&self) -> &[ListComprehensionBranch] {
+        &self.branches
     }
 
     pub fn position(&self) -> &Position {
@@ -0,0 +1,36 @@
+use super::MatchPattern;
+use position::Position;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListPattern {
+    first: Arc<MatchPattern>,
+    rest: Arc<MatchPattern>,
+    position: Position,
+}
+
+impl ListPattern {
+    pub fn new(
+        first: impl Into<MatchPattern>,
+        rest: impl Into<MatchPattern>,
+        position: Position,
+    ) -> Self {
+        Self {
+            first: first.into().into(),
+            rest: rest.into().into(),
+            position,
+        }
+    }
+
+    pub fn first(&self) -> &MatchPattern {
+        &self.first
+    }
+
+    pub fn rest(&self) -> &MatchPattern {
+        &self.rest
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -0,0 +1,58 @@
+use super::{expression::Expression, ListComprehensionBranch};
+use crate::types::Type;
+use position::Position;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapComprehension {
+    key_type: Type,
+    value_type: Type,
+    key: Arc<Expression>,
+    value: Arc<Expression>,
+    branches: Vec<ListComprehensionBranch>,
+    position: Position,
+}
+
+impl MapComprehension {
+    pub fn new(
+        key_type: impl Into<Type>,
+        value_type: impl Into<Type>,
+        key: impl Into<Expression>,
+        value: impl Into<Expression>,
+        branches: Vec<ListComprehensionBranch>,
+        position: Position,
+    ) -> Self {
+        Self {
+            key_type: key_type.into(),
+            value_type: value_type.into(),
+            key: key.into().into(),
+            value: value.into().into(),
+            branches,
+            position,
+        }
+    }
+
+    pub fn key_type(&self) -> &Type {
+        &self.key_type
+    }
+
+    pub fn value_type(&self) -> &Type {
+        &self.value_type
+    }
+
+    pub fn key(&self) -> &Expression {
+        &self.key
+    }
+
+    pub fn value(&self) -> &Expression {
+        &self.value
+    }
+
+    pub fn branches(&self) -> &[ListComprehensionBranch] {
+        &self.branches
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -0,0 +1,36 @@
+use super::{expression::Expression, MatchBranch};
+use position::Position;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Match {
+    argument: Arc<Expression>,
+    branches: Vec<MatchBranch>,
+    position: Position,
+}
+
+impl Match {
+    pub fn new(
+        argument: impl Into<Expression>,
+        branches: Vec<MatchBranch>,
+        position: Position,
+    ) -> Self {
+        Self {
+            argument: argument.into().into(),
+            branches,
+            position,
+        }
+    }
+
+    pub fn argument(&self) -> &Expression {
+        &self.argument
+    }
+
+    pub fn branches(&self) -> &[MatchBranch] {
+        &self.branches
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -0,0 +1,42 @@
+use super::{expression::Expression, Block, MatchPattern};
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchBranch {
+    pattern: MatchPattern,
+    guard: Option<Expression>,
+    block: Block,
+    position: Position,
+}
+
+impl MatchBranch {
+    pub fn new(
+        pattern: impl Into<MatchPattern>,
+        guard: Option<Expression>,
+        block: Block,
+        position: Position,
+    ) -> Self {
+        Self {
+            pattern: pattern.into(),
+            guard,
+            block,
+            position,
+        }
+    }
+
+    pub fn pattern(&self) -> &MatchPattern {
+        &self.pattern
+    }
+
+    pub fn guard(&self) -> Option<&Expression> {
+        self.guard.as_ref()
+    }
+
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
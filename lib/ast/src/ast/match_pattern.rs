@@ -0,0 +1,63 @@
+use super::{ByteString, ListPattern, Number, RecordPattern, TypePattern, Variable};
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatchPattern {
+    EmptyList(Position),
+    List(ListPattern),
+    Number(Number),
+    Record(RecordPattern),
+    String(ByteString),
+    Type(TypePattern),
+    Variable(Variable),
+}
+
+impl MatchPattern {
+    pub fn position(&self) -> &Position {
+        match self {
+            Self::EmptyList(position) => position,
+            Self::List(pattern) => pattern.position(),
+            Self::Number(number) => number.position(),
+            Self::Record(pattern) => pattern.position(),
+            Self::String(string) => string.position(),
+            Self::Type(pattern) => pattern.position(),
+            Self::Variable(variable) => variable.position(),
+        }
+    }
+}
+
+impl From<ListPattern> for MatchPattern {
+    fn from(pattern: ListPattern) -> Self {
+        Self::List(pattern)
+    }
+}
+
+impl From<Number> for MatchPattern {
+    fn from(number: Number) -> Self {
+        Self::Number(number)
+    }
+}
+
+impl From<RecordPattern> for MatchPattern {
+    fn from(pattern: RecordPattern) -> Self {
+        Self::Record(pattern)
+    }
+}
+
+impl From<ByteString> for MatchPattern {
+    fn from(string: ByteString) -> Self {
+        Self::String(string)
+    }
+}
+
+impl From<TypePattern> for MatchPattern {
+    fn from(pattern: TypePattern) -> Self {
+        Self::Type(pattern)
+    }
+}
+
+impl From<Variable> for MatchPattern {
+    fn from(variable: Variable) -> Self {
+        Self::Variable(variable)
+    }
+}
@@ -1,5 +1,6 @@
 use super::{
-    function_definition::FunctionDefinition, type_definition::TypeDefinition, ForeignImport, Import,
+    function_definition::FunctionDefinition, type_definition::TypeDefinition, ForeignImport,
+    Implementation, Import,
 };
 use position::Position;
 
@@ -8,6 +9,7 @@ pub struct Module {
     imports: Vec<Import>,
     foreign_imports: Vec<ForeignImport>,
     type_definitions: Vec<TypeDefinition>,
+    implementations: Vec<Implementation>,
     function_definitions: Vec<FunctionDefinition>,
     position: Position,
 }
@@ -17,6 +19,7 @@ impl Module {
         imports: Vec<Import>,
         foreign_imports: Vec<ForeignImport>,
         type_definitions: Vec<TypeDefinition>,
+        implementations: Vec<Implementation>,
         function_definitions: Vec<FunctionDefinition>,
         position: Position,
     ) -> Self {
@@ -24,6 +27,7 @@ impl Module {
             imports,
             foreign_imports,
             type_definitions,
+            implementations,
             function_definitions,
             position,
         }
@@ -41,6 +45,10 @@ impl Module {
         &self.type_definitions
     }
 
+    pub fn implementations(&self) -> &[Implementation] {
+        &self.implementations
+    }
+
     pub fn function_definitions(&self) -> &[FunctionDefinition] {
         &self.function_definitions
     }
@@ -0,0 +1,11 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern {
+    Record(Vec<String>),
+    Variable(String),
+}
+
+impl From<&str> for Pattern {
+    fn from(name: &str) -> Self {
+        Self::Variable(name.into())
+    }
+}
@@ -1,10 +1,11 @@
 use super::{expression::Expression, RecordField};
+use crate::types::Type;
 use position::Position;
 use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Record {
-    type_name: String,
+    type_: Type,
     record: Arc<Option<Expression>>,
     fields: Vec<RecordField>,
     position: Position,
@@ -12,21 +13,21 @@ pub struct Record {
 
 impl Record {
     pub fn new(
-        type_name: impl Into<String>,
+        type_: impl Into<Type>,
         record: Option<Expression>,
         fields: Vec<RecordField>,
         position: Position,
     ) -> Self {
         Self {
-            type_name: type_name.into(),
+            type_: type_.into(),
             record: Arc::new(record),
             fields,
             position,
         }
     }
 
-    pub fn type_name(&self) -> &str {
-        &self.type_name
+    pub fn type_(&self) -> &Type {
+        &self.type_
     }
 
     pub fn record(&self) -> Option<&Expression> {
@@ -1,22 +1,26 @@
-use crate::types;
+use super::RecordFieldDefinition;
 use position::Position;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct RecordDefinition {
     name: String,
-    fields: Vec<types::RecordField>,
+    type_parameters: Vec<String>,
+    fields: Vec<RecordFieldDefinition>,
+    newtype: bool,
     position: Position,
 }
 
 impl RecordDefinition {
     pub fn new(
         name: impl Into<String>,
-        fields: Vec<types::RecordField>,
+        fields: Vec<RecordFieldDefinition>,
         position: Position,
     ) -> Self {
         Self {
             name: name.into(),
+            type_parameters: vec![],
             fields,
+            newtype: false,
             position,
         }
     }
@@ -25,11 +29,29 @@ impl RecordDefinition {
         &self.name
     }
 
-    pub fn fields(&self) -> &[types::RecordField] {
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
+    pub fn fields(&self) -> &[RecordFieldDefinition] {
         &self.fields
     }
 
+    pub fn is_newtype(&self) -> bool {
+        self.newtype
+    }
+
     pub fn position(&self) -> &Position {
         &self.position
     }
+
+    pub fn set_newtype(mut self, newtype: bool) -> Self {
+        self.newtype = newtype;
+        self
+    }
+
+    pub fn set_type_parameters(mut self, parameters: Vec<String>) -> Self {
+        self.type_parameters = parameters;
+        self
+    }
 }
@@ -0,0 +1,43 @@
+use super::expression::Expression;
+use crate::types::Type;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordFieldDefinition {
+    name: String,
+    type_: Type,
+    default: Option<Expression>,
+    position: Position,
+}
+
+impl RecordFieldDefinition {
+    pub fn new(
+        name: impl Into<String>,
+        type_: impl Into<Type>,
+        default: Option<Expression>,
+        position: Position,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            type_: type_.into(),
+            default,
+            position,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn default(&self) -> Option<&Expression> {
+        self.default.as_ref()
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -0,0 +1,35 @@
+use super::MatchPattern;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordFieldPattern {
+    name: String,
+    pattern: MatchPattern,
+    position: Position,
+}
+
+impl RecordFieldPattern {
+    pub fn new(
+        name: impl Into<String>,
+        pattern: impl Into<MatchPattern>,
+        position: Position,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            pattern: pattern.into(),
+            position,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn pattern(&self) -> &MatchPattern {
+        &self.pattern
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -0,0 +1,22 @@
+use super::RecordFieldPattern;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordPattern {
+    fields: Vec<RecordFieldPattern>,
+    position: Position,
+}
+
+impl RecordPattern {
+    pub fn new(fields: Vec<RecordFieldPattern>, position: Position) -> Self {
+        Self { fields, position }
+    }
+
+    pub fn fields(&self) -> &[RecordFieldPattern] {
+        &self.fields
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
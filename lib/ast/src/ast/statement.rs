@@ -1,28 +1,28 @@
-use super::expression::Expression;
+use super::{expression::Expression, Pattern};
 use position::Position;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Statement {
-    name: Option<String>,
+    pattern: Option<Pattern>,
     expression: Expression,
     position: Position,
 }
 
 impl Statement {
     pub fn new(
-        name: Option<String>,
+        pattern: Option<Pattern>,
         expression: impl Into<Expression>,
         position: Position,
     ) -> Self {
         Self {
-            name,
+            pattern,
             expression: expression.into(),
             position,
         }
     }
 
-    pub fn name(&self) -> Option<&str> {
-        self.name.as_deref()
+    pub fn pattern(&self) -> Option<&Pattern> {
+        self.pattern.as_ref()
     }
 
     pub fn expression(&self) -> &Expression {
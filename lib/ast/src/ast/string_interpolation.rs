@@ -0,0 +1,22 @@
+use super::string_interpolation_part::StringInterpolationPart;
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StringInterpolation {
+    parts: Vec<StringInterpolationPart>,
+    position: Position,
+}
+
+impl StringInterpolation {
+    pub fn new(parts: Vec<StringInterpolationPart>, position: Position) -> Self {
+        Self { parts, position }
+    }
+
+    pub fn parts(&self) -> &[StringInterpolationPart] {
+        &self.parts
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -0,0 +1,8 @@
+use super::expression::Expression;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StringInterpolationPart {
+    Expression(Arc<Expression>),
+    String(String),
+}
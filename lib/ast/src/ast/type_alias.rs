@@ -4,6 +4,7 @@ use position::Position;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TypeAlias {
     name: String,
+    type_parameters: Vec<String>,
     type_: Type,
     position: Position,
 }
@@ -12,6 +13,7 @@ impl TypeAlias {
     pub fn new(name: impl Into<String>, type_: impl Into<Type>, position: Position) -> Self {
         Self {
             name: name.into(),
+            type_parameters: vec![],
             type_: type_.into(),
             position,
         }
@@ -21,6 +23,10 @@ impl TypeAlias {
         &self.name
     }
 
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
     pub fn type_(&self) -> &Type {
         &self.type_
     }
@@ -28,4 +34,9 @@ impl TypeAlias {
     pub fn position(&self) -> &Position {
         &self.position
     }
+
+    pub fn set_type_parameters(mut self, parameters: Vec<String>) -> Self {
+        self.type_parameters = parameters;
+        self
+    }
 }
@@ -1,8 +1,9 @@
-use super::{RecordDefinition, TypeAlias};
+use super::{InterfaceDefinition, RecordDefinition, TypeAlias};
 use position::Position;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum TypeDefinition {
+    InterfaceDefinition(InterfaceDefinition),
     RecordDefinition(RecordDefinition),
     TypeAlias(TypeAlias),
 }
@@ -10,6 +11,7 @@ pub enum TypeDefinition {
 impl TypeDefinition {
     pub fn name(&self) -> &str {
         match self {
+            Self::InterfaceDefinition(definition) => definition.name(),
             Self::RecordDefinition(definition) => definition.name(),
             Self::TypeAlias(alias) => alias.name(),
         }
@@ -17,12 +19,19 @@ impl TypeDefinition {
 
     pub fn position(&self) -> &Position {
         match self {
+            Self::InterfaceDefinition(definition) => definition.position(),
             Self::RecordDefinition(definition) => definition.position(),
             Self::TypeAlias(alias) => alias.position(),
         }
     }
 }
 
+impl From<InterfaceDefinition> for TypeDefinition {
+    fn from(definition: InterfaceDefinition) -> Self {
+        Self::InterfaceDefinition(definition)
+    }
+}
+
 impl From<RecordDefinition> for TypeDefinition {
     fn from(definition: RecordDefinition) -> Self {
         Self::RecordDefinition(definition)
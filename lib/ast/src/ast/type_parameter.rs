@@ -0,0 +1,24 @@
+use crate::types;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeParameter {
+    name: String,
+    constraint: Option<types::Reference>,
+}
+
+impl TypeParameter {
+    pub fn new(name: impl Into<String>, constraint: Option<types::Reference>) -> Self {
+        Self {
+            name: name.into(),
+            constraint,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn constraint(&self) -> Option<&types::Reference> {
+        self.constraint.as_ref()
+    }
+}
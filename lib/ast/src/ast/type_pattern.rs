@@ -0,0 +1,37 @@
+use super::MatchPattern;
+use crate::types::Type;
+use position::Position;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypePattern {
+    pattern: Arc<MatchPattern>,
+    type_: Type,
+    position: Position,
+}
+
+impl TypePattern {
+    pub fn new(
+        pattern: impl Into<MatchPattern>,
+        type_: impl Into<Type>,
+        position: Position,
+    ) -> Self {
+        Self {
+            pattern: pattern.into().into(),
+            type_: type_.into(),
+            position,
+        }
+    }
+
+    pub fn pattern(&self) -> &MatchPattern {
+        &self.pattern
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
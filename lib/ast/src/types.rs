@@ -1,3 +1,4 @@
+mod anonymous_record;
 mod function;
 mod list;
 mod map;
@@ -7,6 +8,7 @@ mod reference;
 mod type_;
 mod union;
 
+pub use anonymous_record::*;
 pub use function::*;
 pub use list::*;
 pub use map::*;
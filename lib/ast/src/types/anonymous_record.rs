@@ -0,0 +1,22 @@
+use super::RecordField;
+use position::Position;
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AnonymousRecord {
+    fields: Vec<RecordField>,
+    position: Position,
+}
+
+impl AnonymousRecord {
+    pub fn new(fields: Vec<RecordField>, position: Position) -> Self {
+        Self { fields, position }
+    }
+
+    pub fn fields(&self) -> &[RecordField] {
+        &self.fields
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
@@ -1,8 +1,10 @@
+use super::Type;
 use position::Position;
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Reference {
     name: String,
+    arguments: Vec<Type>,
     position: Position,
 }
 
@@ -10,6 +12,7 @@ impl Reference {
     pub fn new(name: impl Into<String>, position: Position) -> Self {
         Self {
             name: name.into(),
+            arguments: vec![],
             position,
         }
     }
@@ -18,7 +21,16 @@ impl Reference {
         &self.name
     }
 
+    pub fn arguments(&self) -> &[Type] {
+        &self.arguments
+    }
+
     pub fn position(&self) -> &Position {
         &self.position
     }
+
+    pub fn set_arguments(mut self, arguments: Vec<Type>) -> Self {
+        self.arguments = arguments;
+        self
+    }
 }
@@ -1,10 +1,12 @@
 use super::{
-    function::Function, list::List, map::Map, record::Record, reference::Reference, union::Union,
+    anonymous_record::AnonymousRecord, function::Function, list::List, map::Map, record::Record,
+    reference::Reference, union::Union,
 };
 use position::Position;
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Type {
+    AnonymousRecord(AnonymousRecord),
     Function(Function),
     List(List),
     Map(Map),
@@ -16,6 +18,7 @@ pub enum Type {
 impl Type {
     pub fn position(&self) -> &Position {
         match self {
+            Self::AnonymousRecord(record) => record.position(),
             Self::Function(function) => function.position(),
             Self::List(list) => list.position(),
             Self::Map(map) => map.position(),
@@ -26,6 +29,12 @@ impl Type {
     }
 }
 
+impl From<AnonymousRecord> for Type {
+    fn from(record: AnonymousRecord) -> Self {
+        Self::AnonymousRecord(record)
+    }
+}
+
 impl From<Function> for Type {
     fn from(function: Function) -> Self {
         Self::Function(function)
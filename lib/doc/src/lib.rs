@@ -128,6 +128,12 @@ fn get_first_child_position(module: &Module) -> Option<&Position> {
                 .iter()
                 .map(|definition| definition.position()),
         )
+        .chain(
+            module
+                .implementations()
+                .iter()
+                .map(|implementation| implementation.position()),
+        )
         .chain(
             module
                 .function_definitions()
@@ -215,7 +221,25 @@ fn compile_function_definition(context: &Context, definition: &FunctionDefinitio
             .into_iter()
             .chain([code_block(
                 &context.configuration.language,
-                format_function_signature(definition.lambda()),
+                if definition.type_parameters().is_empty() {
+                    format_function_signature(definition.lambda())
+                } else {
+                    format!(
+                        "<{}> {}",
+                        definition
+                            .type_parameters()
+                            .iter()
+                            .map(
+                                |parameter| if let Some(constraint) = parameter.constraint() {
+                                    format!("{}: {}", parameter.name(), constraint.name())
+                                } else {
+                                    parameter.name().into()
+                                }
+                            )
+                            .join(", "),
+                        format_function_signature(definition.lambda())
+                    )
+                },
             )]),
         [],
     )
@@ -371,7 +395,7 @@ mod tests {
                     &[(
                         ExternalModulePath::new("Foo", vec!["Bar".into()]).into(),
                         (
-                            Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                            Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                             Default::default()
                         )
                     )]
@@ -422,7 +446,7 @@ mod tests {
                     &[(
                         ExternalModulePath::new("Foo", vec!["bar".into()]).into(),
                         (
-                            Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                            Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                             Default::default()
                         )
                     )]
@@ -464,14 +488,28 @@ mod tests {
                         (
                             ExternalModulePath::new("Foo", vec!["Bar".into()]).into(),
                             (
-                                Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                                Module::new(
+                                    vec![],
+                                    vec![],
+                                    vec![],
+                                    vec![],
+                                    vec![],
+                                    Position::fake()
+                                ),
                                 Default::default()
                             )
                         ),
                         (
                             ExternalModulePath::new("Foo", vec!["Baz".into()]).into(),
                             (
-                                Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                                Module::new(
+                                    vec![],
+                                    vec![],
+                                    vec![],
+                                    vec![],
+                                    vec![],
+                                    Position::fake()
+                                ),
                                 Default::default()
                             )
                         )
@@ -560,7 +598,7 @@ mod tests {
             assert_eq!(
                 generate(
                     &ExternalModulePath::new("Foo", vec!["Bar".into()]).into(),
-                    &Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                    &Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                     &[]
                 ),
                 indoc!(
@@ -584,7 +622,7 @@ mod tests {
             assert_eq!(
                 generate(
                     &ExternalModulePath::new("Foo", vec!["Bar".into()]).into(),
-                    &Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                    &Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                     &[Comment::new("foo", line_position(1))]
                 ),
                 indoc!(
@@ -610,7 +648,7 @@ mod tests {
             assert_eq!(
                 generate(
                     &ExternalModulePath::new("Foo", vec!["Bar".into()]).into(),
-                    &Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                    &Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                     &[
                         Comment::new("foo", line_position(1)),
                         Comment::new("bar", line_position(3))
@@ -644,6 +682,7 @@ mod tests {
                         vec![],
                         vec![RecordDefinition::new("Foo", vec![], line_position(2)).into()],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[Comment::new("foo", line_position(1))]
@@ -680,6 +719,7 @@ mod tests {
                         vec![],
                         vec![RecordDefinition::new("foo", vec![], Position::fake()).into()],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[]
@@ -709,8 +749,10 @@ mod tests {
                         vec![],
                         vec![],
                         vec![],
+                        vec![],
                         vec![FunctionDefinition::new(
                             "foo",
+                            vec![],
                             Lambda::new(
                                 vec![],
                                 types::Reference::new("none", Position::fake()),
@@ -754,6 +796,7 @@ mod tests {
                         vec![],
                         vec![RecordDefinition::new("Foo", vec![], Position::fake()).into()],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[],
@@ -784,8 +827,10 @@ mod tests {
                         vec![],
                         vec![],
                         vec![],
+                        vec![],
                         vec![FunctionDefinition::new(
                             "Foo",
+                            vec![],
                             Lambda::new(
                                 vec![],
                                 types::Reference::new("none", Position::fake()),
@@ -823,7 +868,6 @@ mod tests {
 
     mod type_definition {
         use super::*;
-        use ast::types::RecordField;
 
         fn generate(definition: &TypeDefinition, comments: &[Comment]) -> String {
             markdown::generate(&compile_type_definition(
@@ -857,9 +901,11 @@ mod tests {
                 generate(
                     &RecordDefinition::new(
                         "Foo",
-                        vec![RecordField::new(
+                        vec![RecordFieldDefinition::new(
                             "Bar",
-                            types::Reference::new("none", Position::fake())
+                            types::Reference::new("none", Position::fake()),
+                            None,
+                            Position::fake()
                         )],
                         Position::fake()
                     )
@@ -886,9 +932,11 @@ mod tests {
                 generate(
                     &RecordDefinition::new(
                         "Foo",
-                        vec![RecordField::new(
+                        vec![RecordFieldDefinition::new(
                             "bar",
-                            types::Reference::new("none", Position::fake())
+                            types::Reference::new("none", Position::fake()),
+                            None,
+                            Position::fake()
                         )],
                         Position::fake()
                     )
@@ -950,6 +998,7 @@ mod tests {
                 generate(
                     &FunctionDefinition::new(
                         "Foo",
+                        vec![],
                         Lambda::new(
                             vec![],
                             types::Reference::new("none", Position::fake()),
@@ -1,4 +1,6 @@
-use crate::utilities::{generate_type_size_test, parse_crate_path, parse_string_attribute};
+use crate::utilities::{
+    generate_type_size_test, parse_crate_path, parse_flag_attribute, parse_string_attribute,
+};
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::quote;
@@ -16,7 +18,16 @@ pub fn generate(
         type_.ident.span(),
     );
     let type_name = &type_.ident;
-    let type_size_test = generate_type_size_test(type_name);
+    // Newtypes are unboxed and as large as their only fields.
+    let type_size_test = if parse_flag_attribute(attributes, "newtype") {
+        if type_.fields.len() != 1 {
+            return Err("newtype must have exactly one field".into());
+        }
+
+        quote! {}
+    } else {
+        generate_type_size_test(type_name)
+    };
 
     Ok(quote! {
         #type_
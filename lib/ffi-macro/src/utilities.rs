@@ -32,6 +32,13 @@ pub fn parse_string_attribute<T: Parse>(
         .transpose()?)
 }
 
+pub fn parse_flag_attribute(attributes: &AttributeArgs, key: &str) -> bool {
+    attributes.iter().any(|attribute| match attribute {
+        NestedMeta::Meta(Meta::Path(path)) => path.is_ident(key),
+        _ => false,
+    })
+}
+
 pub fn generate_type_size_test(type_name: &Ident) -> proc_macro2::TokenStream {
     quote! {
         #[test]
@@ -16,3 +16,7 @@ struct PointerSized {
 struct Foo {
     x: usize,
 }
+
+#[into_any(crate = "pen_ffi", fn = "bar_to_any", newtype)]
+#[repr(C)]
+struct Bar(pen_ffi::Any);
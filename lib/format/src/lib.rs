@@ -30,6 +30,13 @@ pub fn format(module: &Module, comments: &[Comment]) -> String {
                     .map(|definition| compile_type_definition(context, definition))
                     .intersperse(line()),
             ),
+            sequence(
+                module
+                    .implementations()
+                    .iter()
+                    .map(|implementation| compile_implementation(context, implementation))
+                    .intersperse(line()),
+            ),
             sequence(
                 module
                     .function_definitions()
@@ -156,6 +163,9 @@ fn compile_foreign_import(context: &mut Context, import: &ForeignImport) -> Docu
 
 fn compile_type_definition(context: &mut Context, definition: &TypeDefinition) -> Document {
     match definition {
+        TypeDefinition::InterfaceDefinition(definition) => {
+            compile_interface_definition(context, definition)
+        }
         TypeDefinition::RecordDefinition(definition) => {
             compile_record_definition(context, definition)
         }
@@ -166,8 +176,14 @@ fn compile_type_definition(context: &mut Context, definition: &TypeDefinition) -
 fn compile_record_definition(context: &mut Context, definition: &RecordDefinition) -> Document {
     sequence([
         compile_block_comment(context, definition.position()),
-        "type ".into(),
+        if definition.is_newtype() {
+            "newtype "
+        } else {
+            "type "
+        }
+        .into(),
         definition.name().into(),
+        compile_type_definition_parameters(definition.type_parameters()),
         " {".into(),
         if definition.fields().is_empty() {
             empty()
@@ -179,6 +195,42 @@ fn compile_record_definition(context: &mut Context, definition: &RecordDefinitio
                         field.name().into(),
                         " ".into(),
                         compile_type(field.type_()),
+                        if let Some(default) = field.default() {
+                            sequence([" = ".into(), compile_expression(context, default)])
+                        } else {
+                            empty()
+                        },
+                    ])
+                }))),
+                line(),
+            ])
+        },
+        "}".into(),
+        line(),
+    ])
+}
+
+fn compile_interface_definition(
+    context: &mut Context,
+    definition: &InterfaceDefinition,
+) -> Document {
+    sequence([
+        compile_block_comment(context, definition.position()),
+        "interface ".into(),
+        definition.name().into(),
+        "<".into(),
+        definition.type_parameter().into(),
+        "> {".into(),
+        if definition.methods().is_empty() {
+            empty()
+        } else {
+            sequence([
+                indent(sequence(definition.methods().iter().map(|method| {
+                    sequence([
+                        line(),
+                        method.name().into(),
+                        " ".into(),
+                        compile_type(&method.type_().clone().into()),
                     ])
                 }))),
                 line(),
@@ -189,6 +241,36 @@ fn compile_record_definition(context: &mut Context, definition: &RecordDefinitio
     ])
 }
 
+fn compile_implementation(context: &mut Context, implementation: &Implementation) -> Document {
+    sequence([
+        compile_block_comment(context, implementation.position()),
+        "implement ".into(),
+        implementation.interface().into(),
+        "<".into(),
+        compile_type(implementation.type_()),
+        "> {".into(),
+        if implementation.function_definitions().is_empty() {
+            empty()
+        } else {
+            sequence([
+                indent(sequence(implementation.function_definitions().iter().map(
+                    |definition| {
+                        sequence([
+                            line(),
+                            definition.name().into(),
+                            " = ".into(),
+                            compile_lambda(context, definition.lambda()),
+                        ])
+                    },
+                ))),
+                line(),
+            ])
+        },
+        "}".into(),
+        line(),
+    ])
+}
+
 fn compile_type_alias(context: &mut Context, alias: &TypeAlias) -> Document {
     let type_ = compile_type(alias.type_());
 
@@ -196,6 +278,7 @@ fn compile_type_alias(context: &mut Context, alias: &TypeAlias) -> Document {
         compile_block_comment(context, alias.position()),
         "type ".into(),
         alias.name().into(),
+        compile_type_definition_parameters(alias.type_parameters()),
         " =".into(),
         if is_broken(&type_) {
             indent(sequence([line(), type_]))
@@ -206,6 +289,23 @@ fn compile_type_alias(context: &mut Context, alias: &TypeAlias) -> Document {
     ])
 }
 
+fn compile_type_definition_parameters(parameters: &[String]) -> Document {
+    if parameters.is_empty() {
+        empty()
+    } else {
+        sequence([
+            "<".into(),
+            sequence(
+                parameters
+                    .iter()
+                    .map(|parameter| Document::from(parameter.as_str()))
+                    .intersperse(", ".into()),
+            ),
+            ">".into(),
+        ])
+    }
+}
+
 fn compile_function_definition(context: &mut Context, definition: &FunctionDefinition) -> Document {
     sequence([
         compile_block_comment(context, definition.position()),
@@ -221,6 +321,27 @@ fn compile_function_definition(context: &mut Context, definition: &FunctionDefin
             empty()
         },
         definition.name().into(),
+        if definition.type_parameters().is_empty() {
+            empty()
+        } else {
+            sequence([
+                "<".into(),
+                sequence(
+                    definition
+                        .type_parameters()
+                        .iter()
+                        .map(|parameter| {
+                            if let Some(constraint) = parameter.constraint() {
+                                sequence([parameter.name(), ": ", constraint.name()])
+                            } else {
+                                parameter.name().into()
+                            }
+                        })
+                        .intersperse(", ".into()),
+                ),
+                ">".into(),
+            ])
+        },
         " = ".into(),
         compile_lambda(context, definition.lambda()),
         line(),
@@ -229,6 +350,19 @@ fn compile_function_definition(context: &mut Context, definition: &FunctionDefin
 
 fn compile_type(type_: &Type) -> Document {
     match type_ {
+        Type::AnonymousRecord(record) => sequence([
+            "{".into(),
+            sequence(
+                record
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        sequence([field.name().into(), " ".into(), compile_type(field.type_())])
+                    })
+                    .intersperse(", ".into()),
+            ),
+            "}".into(),
+        ]),
         Type::Function(function) => sequence([
             "\\(".into(),
             sequence(
@@ -250,7 +384,24 @@ fn compile_type(type_: &Type) -> Document {
             "}".into(),
         ]),
         Type::Record(record) => record.name().into(),
-        Type::Reference(reference) => reference.name().into(),
+        Type::Reference(reference) => {
+            if reference.arguments().is_empty() {
+                reference.name().into()
+            } else {
+                sequence([
+                    reference.name().into(),
+                    "<".into(),
+                    sequence(
+                        reference
+                            .arguments()
+                            .iter()
+                            .map(compile_type)
+                            .intersperse(", ".into()),
+                    ),
+                    ">".into(),
+                ])
+            }
+        }
         Type::Union(_) => {
             let types = collect_union_types(type_);
 
@@ -396,10 +547,14 @@ fn compile_block(context: &mut Context, block: &Block) -> Document {
 
 fn compile_statement(context: &mut Context, statement: &Statement) -> Document {
     sequence([
-        if let Some(name) = statement.name() {
-            sequence([name, " = "])
-        } else {
-            empty()
+        match statement.pattern() {
+            Some(Pattern::Record(names)) => sequence([
+                "{".into(),
+                sequence(names.iter().map(String::as_str).intersperse(", ")),
+                "} = ".into(),
+            ]),
+            Some(Pattern::Variable(name)) => sequence([name, " = "]),
+            None => empty(),
         },
         compile_expression(context, statement.expression()),
         compile_suffix_comment(context, statement.position()),
@@ -462,6 +617,7 @@ fn compile_expression(context: &mut Context, expression: &Expression) -> Documen
             compile_block(context, if_.else_()),
         ]),
         Expression::IfType(if_) => compile_if_type(context, if_),
+        Expression::Integer(integer) => compile_integer(integer),
         Expression::Lambda(lambda) => compile_lambda(context, lambda),
         Expression::List(list) => compile_list(context, list),
         Expression::ListComprehension(comprehension) => {
@@ -470,15 +626,13 @@ fn compile_expression(context: &mut Context, expression: &Expression) -> Documen
                 compile_line_comment(context, comprehension.element().position(), |context| {
                     compile_expression(context, comprehension.element())
                 }),
-                line(),
-                compile_line_comment(context, comprehension.element().position(), |context| {
-                    sequence([
-                        "for ".into(),
-                        comprehension.element_name().into(),
-                        " in ".into(),
-                        compile_expression(context, comprehension.list()),
-                    ])
-                }),
+                sequence(
+                    comprehension
+                        .branches()
+                        .iter()
+                        .map(|branch| compile_list_comprehension_branch(context, branch))
+                        .collect::<Vec<_>>(),
+                ),
             ]);
 
             sequence([
@@ -496,45 +650,11 @@ fn compile_expression(context: &mut Context, expression: &Expression) -> Documen
             ])
         }
         Expression::Map(map) => compile_map(context, map),
-        Expression::MapIterationComprehension(comprehension) => {
-            let elements = sequence([
-                line(),
-                compile_line_comment(context, comprehension.element().position(), |context| {
-                    compile_expression(context, comprehension.element())
-                }),
-                line(),
-                compile_line_comment(context, comprehension.element().position(), |context| {
-                    sequence([
-                        "for ".into(),
-                        comprehension.key_name().into(),
-                        ", ".into(),
-                        comprehension.value_name().into(),
-                        " in ".into(),
-                        compile_expression(context, comprehension.map()),
-                    ])
-                }),
-            ]);
-
-            sequence([
-                "[".into(),
-                compile_type(comprehension.element_type()),
-                if comprehension.position().line_number()
-                    == comprehension.element().position().line_number()
-                    && !is_broken(&elements)
-                {
-                    flatten(elements)
-                } else {
-                    break_(sequence([indent(elements), line()]))
-                },
-                "]".into(),
-            ])
-        }
-        Expression::Number(number) => match number.value() {
-            NumberRepresentation::Binary(string) => "0b".to_owned() + string,
-            NumberRepresentation::Hexadecimal(string) => "0x".to_owned() + &string.to_uppercase(),
-            NumberRepresentation::FloatingPoint(string) => string.clone(),
+        Expression::MapComprehension(comprehension) => {
+            compile_map_comprehension(context, comprehension)
         }
-        .into(),
+        Expression::Match(match_) => compile_match(context, match_),
+        Expression::Number(number) => compile_number(number),
         Expression::Record(record) => {
             let separator = sequence([",".into(), line()]);
             let elements = sequence(
@@ -559,7 +679,7 @@ fn compile_expression(context: &mut Context, expression: &Expression) -> Documen
             );
 
             sequence([
-                record.type_name().into(),
+                compile_type(record.type_()),
                 "{".into(),
                 if record.record().is_none() && record.fields().is_empty()
                     || Some(record.position().line_number())
@@ -583,7 +703,20 @@ fn compile_expression(context: &mut Context, expression: &Expression) -> Documen
             ".".into(),
             deconstruction.name().into(),
         ]),
-        Expression::String(string) => sequence(["\"", string.value(), "\""]),
+        Expression::String(string) => compile_string(string),
+        Expression::StringInterpolation(interpolation) => sequence(
+            ["\"".into()]
+                .into_iter()
+                .chain(interpolation.parts().iter().map(|part| match part {
+                    StringInterpolationPart::Expression(expression) => sequence([
+                        "${".into(),
+                        flatten(compile_expression(context, expression)),
+                        "}".into(),
+                    ]),
+                    StringInterpolationPart::String(string) => string.as_str().into(),
+                }))
+                .chain(["\"".into()]),
+        ),
         Expression::UnaryOperation(operation) => {
             let operand = compile_expression(context, operation.expression());
             let operand = if matches!(operation.expression(), Expression::BinaryOperation(_)) {
@@ -601,6 +734,26 @@ fn compile_expression(context: &mut Context, expression: &Expression) -> Documen
     }
 }
 
+fn compile_number(number: &Number) -> Document {
+    compile_number_representation(number.value()).into()
+}
+
+fn compile_integer(integer: &Integer) -> Document {
+    (compile_number_representation(integer.value()) + integer.kind().name()).into()
+}
+
+fn compile_number_representation(representation: &NumberRepresentation) -> String {
+    match representation {
+        NumberRepresentation::Binary(string) => "0b".to_owned() + string,
+        NumberRepresentation::Hexadecimal(string) => "0x".to_owned() + &string.to_uppercase(),
+        NumberRepresentation::FloatingPoint(string) => string.clone(),
+    }
+}
+
+fn compile_string(string: &ByteString) -> Document {
+    sequence(["\"", string.value(), "\""])
+}
+
 fn compile_if(context: &mut Context, if_: &If) -> Document {
     let document = sequence([
         sequence(if_.branches().iter().map(|branch| {
@@ -663,6 +816,76 @@ fn compile_if_type(context: &mut Context, if_: &IfType) -> Document {
     )
 }
 
+fn compile_match(context: &mut Context, match_: &Match) -> Document {
+    break_(sequence([
+        "match ".into(),
+        compile_expression(context, match_.argument()),
+        " {".into(),
+        indent(sequence(match_.branches().iter().map(|branch| {
+            sequence([
+                line(),
+                compile_block_comment(context, branch.position()),
+                compile_match_pattern(branch.pattern()),
+                if let Some(guard) = branch.guard() {
+                    sequence([" if ".into(), compile_expression(context, guard)])
+                } else {
+                    empty()
+                },
+                " ".into(),
+                flatten_if(
+                    branch.block().statements().is_empty()
+                        && branch.position().line_number()
+                            == branch.block().expression().position().line_number(),
+                    compile_block(context, branch.block()),
+                ),
+            ])
+        }))),
+        line(),
+        "}".into(),
+    ]))
+}
+
+fn compile_match_pattern(pattern: &MatchPattern) -> Document {
+    match pattern {
+        MatchPattern::EmptyList(_) => "[]".into(),
+        MatchPattern::List(pattern) => sequence([
+            "[".into(),
+            compile_match_pattern(pattern.first()),
+            ", ...".into(),
+            compile_match_pattern(pattern.rest()),
+            "]".into(),
+        ]),
+        MatchPattern::Number(number) => compile_number(number),
+        MatchPattern::Record(pattern) => sequence([
+            "{".into(),
+            sequence(
+                pattern
+                    .fields()
+                    .iter()
+                    .map(|field| match field.pattern() {
+                        MatchPattern::Variable(variable) if variable.name() == field.name() => {
+                            field.name().into()
+                        }
+                        pattern => sequence([
+                            field.name().into(),
+                            ": ".into(),
+                            compile_match_pattern(pattern),
+                        ]),
+                    })
+                    .intersperse(", ".into()),
+            ),
+            "}".into(),
+        ]),
+        MatchPattern::String(string) => compile_string(string),
+        MatchPattern::Type(pattern) => sequence([
+            compile_match_pattern(pattern.pattern()),
+            " as ".into(),
+            compile_type(pattern.type_()),
+        ]),
+        MatchPattern::Variable(variable) => variable.name().into(),
+    }
+}
+
 fn compile_list(context: &mut Context, list: &List) -> Document {
     let separator = Document::from(",");
     let elements = sequence(
@@ -800,10 +1023,85 @@ fn compile_binary_operator(operator: BinaryOperator) -> Document {
         BinaryOperator::Subtract => "-",
         BinaryOperator::Multiply => "*",
         BinaryOperator::Divide => "/",
+        BinaryOperator::Remainder => "%",
+        BinaryOperator::BitwiseAnd => "&&&",
+        BinaryOperator::BitwiseOr => "|||",
+        BinaryOperator::BitwiseXor => "^^^",
+        BinaryOperator::LeftShift => "<<<",
+        BinaryOperator::RightShift => ">>>",
+        BinaryOperator::Pipe => "|>",
     }
     .into()
 }
 
+fn compile_map_comprehension(context: &mut Context, comprehension: &MapComprehension) -> Document {
+    let elements = sequence([
+        line(),
+        compile_line_comment(context, comprehension.key().position(), |context| {
+            sequence([
+                compile_expression(context, comprehension.key()),
+                ": ".into(),
+                compile_expression(context, comprehension.value()),
+            ])
+        }),
+        sequence(
+            comprehension
+                .branches()
+                .iter()
+                .map(|branch| compile_list_comprehension_branch(context, branch))
+                .collect::<Vec<_>>(),
+        ),
+    ]);
+
+    sequence([
+        "{".into(),
+        compile_type(comprehension.key_type()),
+        ": ".into(),
+        compile_type(comprehension.value_type()),
+        if comprehension.position().line_number() == comprehension.key().position().line_number()
+            && !is_broken(&elements)
+        {
+            flatten(elements)
+        } else {
+            break_(sequence([indent(elements), line()]))
+        },
+        "}".into(),
+    ])
+}
+
+fn compile_list_comprehension_branch(
+    context: &mut Context,
+    branch: &ListComprehensionBranch,
+) -> Document {
+    sequence([
+        line(),
+        compile_line_comment(context, branch.position(), |context| {
+            sequence([
+                "for ".into(),
+                sequence(branch.names().iter().map(String::as_str).intersperse(", ")),
+                " in ".into(),
+                sequence(
+                    branch
+                        .iteratees()
+                        .iter()
+                        .map(|iteratee| compile_expression(context, iteratee))
+                        .intersperse(", ".into()),
+                ),
+            ])
+        }),
+        if let Some(condition) = branch.condition() {
+            sequence([
+                line(),
+                compile_line_comment(context, condition.position(), |context| {
+                    sequence(["if ".into(), compile_expression(context, condition)])
+                }),
+            ])
+        } else {
+            empty()
+        },
+    ])
+}
+
 fn compile_line_comment(
     context: &mut Context,
     position: &Position,
@@ -883,6 +1181,7 @@ mod tests {
     use super::*;
     use indoc::indoc;
     use position::{test::PositionFake, Position};
+    use std::sync::Arc;
 
     fn line_position(line: usize) -> Position {
         Position::new("", line, 1, "")
@@ -900,6 +1199,7 @@ mod tests {
                 vec![],
                 vec![],
                 vec![],
+                vec![],
                 Position::fake()
             )),
             ""
@@ -922,6 +1222,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 "import 'Foo'Bar\n"
@@ -941,6 +1242,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 "import Package'Foo'Bar\n"
@@ -960,6 +1262,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 "import 'Foo'Bar as Baz\n"
@@ -982,6 +1285,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 "import 'Foo'Bar { Baz, Blah }\n"
@@ -1004,6 +1308,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 "import 'Foo { A, B }\n"
@@ -1031,6 +1336,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 indoc!(
@@ -1063,6 +1369,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 indoc!(
@@ -1095,6 +1402,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 indoc!(
@@ -1125,6 +1433,7 @@ mod tests {
                 )],
                 vec![],
                 vec![],
+                vec![],
                 Position::fake()
             )),
             "import foreign foo \\() none\n"
@@ -1148,6 +1457,7 @@ mod tests {
                 )],
                 vec![],
                 vec![],
+                vec![],
                 Position::fake()
             )),
             "import foreign \"c\" foo \\() none\n"
@@ -1162,6 +1472,7 @@ mod tests {
                 vec![],
                 vec![RecordDefinition::new("foo", vec![], Position::fake()).into()],
                 vec![],
+                vec![],
                 Position::fake()
             )),
             "type foo {}\n"
@@ -1176,14 +1487,17 @@ mod tests {
                 vec![],
                 vec![RecordDefinition::new(
                     "foo",
-                    vec![types::RecordField::new(
+                    vec![RecordFieldDefinition::new(
                         "foo",
-                        types::Reference::new("none", Position::fake())
+                        types::Reference::new("none", Position::fake()),
+                        None,
+                        Position::fake()
                     )],
                     Position::fake()
                 )
                 .into()],
                 vec![],
+                vec![],
                 Position::fake()
             )),
             indoc!(
@@ -1197,63 +1511,271 @@ mod tests {
     }
 
     #[test]
-    fn format_record_definition_with_two_fields() {
+    fn format_generic_record_definition() {
         assert_eq!(
             format_module(&Module::new(
                 vec![],
                 vec![],
                 vec![RecordDefinition::new(
                     "foo",
-                    vec![
-                        types::RecordField::new(
-                            "foo",
-                            types::Reference::new("none", Position::fake())
-                        ),
-                        types::RecordField::new(
-                            "bar",
-                            types::Reference::new("none", Position::fake())
-                        )
-                    ],
+                    vec![RecordFieldDefinition::new(
+                        "foo",
+                        types::Reference::new("bar", Position::fake()).set_arguments(vec![
+                            types::Reference::new("a", Position::fake()).into(),
+                            types::Reference::new("b", Position::fake()).into()
+                        ]),
+                        None,
+                        Position::fake()
+                    )],
                     Position::fake()
                 )
+                .set_type_parameters(vec!["a".into(), "b".into()])
                 .into()],
                 vec![],
+                vec![],
                 Position::fake()
             )),
             indoc!(
                 "
-                type foo {
-                  foo none
-                  bar none
+                type foo<a, b> {
+                  foo bar<a, b>
                 }
                 "
             )
         );
     }
 
-    mod type_alias {
-        use super::*;
-
-        #[test]
-        fn format_type_alias() {
-            assert_eq!(
-                format_module(&Module::new(
-                    vec![],
-                    vec![],
-                    vec![TypeAlias::new(
+    #[test]
+    fn format_newtype_definition() {
+        assert_eq!(
+            format_module(&Module::new(
+                vec![],
+                vec![],
+                vec![RecordDefinition::new(
+                    "foo",
+                    vec![RecordFieldDefinition::new(
                         "foo",
-                        types::Reference::new("none", Position::fake()),
+                        types::Reference::new("any", Position::fake()),
+                        None,
                         Position::fake()
-                    )
-                    .into()],
-                    vec![],
+                    )],
                     Position::fake()
-                )),
-                "type foo = none\n"
-            );
-        }
-
-        #[test]
+                )
+                .set_newtype(true)
+                .into()],
+                vec![],
+                vec![],
+                Position::fake()
+            )),
+            indoc!(
+                "
+                newtype foo {
+                  foo any
+                }
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn format_record_definition_with_two_fields() {
+        assert_eq!(
+            format_module(&Module::new(
+                vec![],
+                vec![],
+                vec![RecordDefinition::new(
+                    "foo",
+                    vec![
+                        RecordFieldDefinition::new(
+                            "foo",
+                            types::Reference::new("none", Position::fake()),
+                            None,
+                            Position::fake()
+                        ),
+                        RecordFieldDefinition::new(
+                            "bar",
+                            types::Reference::new("none", Position::fake()),
+                            None,
+                            Position::fake()
+                        )
+                    ],
+                    Position::fake()
+                )
+                .into()],
+                vec![],
+                vec![],
+                Position::fake()
+            )),
+            indoc!(
+                "
+                type foo {
+                  foo none
+                  bar none
+                }
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn format_record_definition_with_default_field() {
+        assert_eq!(
+            format_module(&Module::new(
+                vec![],
+                vec![],
+                vec![RecordDefinition::new(
+                    "foo",
+                    vec![RecordFieldDefinition::new(
+                        "foo",
+                        types::Reference::new("none", Position::fake()),
+                        Some(Variable::new("none", Position::fake()).into()),
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )
+                .into()],
+                vec![],
+                vec![],
+                Position::fake()
+            )),
+            indoc!(
+                "
+                type foo {
+                  foo none = none
+                }
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn format_interface_definition() {
+        assert_eq!(
+            format_module(&Module::new(
+                vec![],
+                vec![],
+                vec![InterfaceDefinition::new(
+                    "Show",
+                    "a",
+                    vec![InterfaceMethod::new(
+                        "ToString",
+                        types::Function::new(
+                            vec![types::Reference::new("a", Position::fake()).into()],
+                            types::Reference::new("string", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )
+                .into()],
+                vec![],
+                vec![],
+                Position::fake()
+            )),
+            indoc!(
+                "
+                interface Show<a> {
+                  ToString \\(a) string
+                }
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn format_implementation() {
+        assert_eq!(
+            format_module(&Module::new(
+                vec![],
+                vec![],
+                vec![],
+                vec![Implementation::new(
+                    "Show",
+                    types::Reference::new("number", Position::fake()),
+                    vec![FunctionDefinition::new(
+                        "ToString",
+                        vec![],
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                types::Reference::new("number", Position::fake())
+                            )],
+                            types::Reference::new("string", Position::fake()),
+                            Block::new(
+                                vec![],
+                                ByteString::new("", Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        ),
+                        None,
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )],
+                vec![],
+                Position::fake()
+            )),
+            indoc!(
+                "
+                implement Show<number> {
+                  ToString = \\(x number) string { \"\" }
+                }
+                "
+            )
+        );
+    }
+
+    mod type_alias {
+        use super::*;
+
+        #[test]
+        fn format_type_alias() {
+            assert_eq!(
+                format_module(&Module::new(
+                    vec![],
+                    vec![],
+                    vec![TypeAlias::new(
+                        "foo",
+                        types::Reference::new("none", Position::fake()),
+                        Position::fake()
+                    )
+                    .into()],
+                    vec![],
+                    vec![],
+                    Position::fake()
+                )),
+                "type foo = none\n"
+            );
+        }
+
+        #[test]
+        fn format_generic_type_alias() {
+            assert_eq!(
+                format_module(&Module::new(
+                    vec![],
+                    vec![],
+                    vec![TypeAlias::new(
+                        "foo",
+                        types::Union::new(
+                            types::Reference::new("a", Position::fake()),
+                            types::Reference::new("none", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    )
+                    .set_type_parameters(vec!["a".into()])
+                    .into()],
+                    vec![],
+                    vec![],
+                    Position::fake()
+                )),
+                "type foo<a> = a | none\n"
+            );
+        }
+
+        #[test]
         fn format_multiple_type_aliases() {
             assert_eq!(
                 format_module(&Module::new(
@@ -1274,6 +1796,7 @@ mod tests {
                         .into()
                     ],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 indoc!(
@@ -1303,6 +1826,7 @@ mod tests {
                     )
                     .into()],
                     vec![],
+                    vec![],
                     Position::fake()
                 )),
                 "type foo =\n  number |\n  none\n"
@@ -1369,6 +1893,29 @@ mod tests {
                 "number |\nstring |\nnone"
             );
         }
+
+        #[test]
+        fn format_anonymous_record_type() {
+            assert_eq!(
+                format_type(
+                    &types::AnonymousRecord::new(
+                        vec![
+                            types::RecordField::new(
+                                "x",
+                                types::Reference::new("number", Position::fake())
+                            ),
+                            types::RecordField::new(
+                                "y",
+                                types::Reference::new("none", Position::fake())
+                            )
+                        ],
+                        Position::fake()
+                    )
+                    .into()
+                ),
+                "{x number, y none}"
+            );
+        }
     }
 
     mod definition {
@@ -1381,8 +1928,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "foo",
+                        vec![],
                         Lambda::new(
                             vec![],
                             types::Reference::new("none", Position::fake()),
@@ -1406,6 +1955,7 @@ mod tests {
         fn format_multiple() {
             let definition = FunctionDefinition::new(
                 "foo",
+                vec![],
                 Lambda::new(
                     vec![],
                     types::Reference::new("none", Position::fake()),
@@ -1425,6 +1975,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![definition.clone(), definition],
                     Position::fake()
                 )),
@@ -1438,6 +1989,39 @@ mod tests {
             );
         }
 
+        #[test]
+        fn format_type_parameters() {
+            assert_eq!(
+                format_module(&Module::new(
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![FunctionDefinition::new(
+                        "foo",
+                        vec![TypeParameter::new("a", None), TypeParameter::new("b", None)],
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                types::Reference::new("a", Position::fake())
+                            )],
+                            types::Reference::new("a", Position::fake()),
+                            Block::new(
+                                vec![],
+                                Variable::new("x", Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        None,
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )),
+                "foo<a, b> = \\(x a) a { x }\n"
+            );
+        }
+
         #[test]
         fn format_with_argument() {
             assert_eq!(
@@ -1445,8 +2029,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "foo",
+                        vec![],
                         Lambda::new(
                             vec![Argument::new(
                                 "x",
@@ -1476,8 +2062,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "foo",
+                        vec![],
                         Lambda::new(
                             vec![],
                             types::Reference::new("none", Position::fake()),
@@ -1515,8 +2103,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "foo",
+                        vec![],
                         Lambda::new(
                             vec![],
                             types::Function::new(
@@ -1556,8 +2146,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "foo",
+                        vec![],
                         Lambda::new(
                             vec![],
                             types::Reference::new("none", Position::fake()),
@@ -1584,8 +2176,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "foo",
+                        vec![],
                         Lambda::new(
                             vec![],
                             types::Reference::new("none", Position::fake()),
@@ -1689,6 +2283,33 @@ mod tests {
             );
         }
 
+        #[test]
+        fn format_statement_with_record_pattern() {
+            assert_eq!(
+                format(&Block::new(
+                    vec![Statement::new(
+                        Some(Pattern::Record(vec!["x".into(), "y".into()])),
+                        Call::new(
+                            Variable::new("f", Position::fake()),
+                            vec![],
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    )],
+                    Variable::new("none", Position::fake()),
+                    Position::fake()
+                )),
+                indoc!(
+                    "
+                    {
+                      {x, y} = f()
+                      none
+                    }
+                    "
+                )
+            );
+        }
+
         #[test]
         fn format_statement_with_no_blank_line() {
             assert_eq!(
@@ -1779,8 +2400,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "foo",
+                        vec![],
                         Lambda::new(
                             vec![],
                             types::Reference::new("none", Position::fake()),
@@ -2131,78 +2754,228 @@ mod tests {
                             .into()],
                             Position::fake()
                         )
-                        .into(),
-                        &[Comment::new("foo", line_position(2))]
+                        .into(),
+                        &[Comment::new("foo", line_position(2))]
+                    ),
+                    indoc!(
+                        "
+                        foo(
+                          1, #foo
+                        )
+                        "
+                    )
+                    .trim()
+                );
+            }
+        }
+
+        mod if_ {
+            use super::*;
+
+            #[test]
+            fn format_single_line() {
+                assert_eq!(
+                    format(
+                        &If::new(
+                            vec![IfBranch::new(
+                                Variable::new("true", Position::fake()),
+                                Block::new(
+                                    vec![],
+                                    Variable::new("none", Position::fake()),
+                                    Position::fake()
+                                )
+                            )],
+                            Block::new(
+                                vec![],
+                                Variable::new("none", Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    "if true { none } else { none }"
+                );
+            }
+
+            #[test]
+            fn format_multi_line_with_multi_line_input() {
+                assert_eq!(
+                    format(
+                        &If::new(
+                            vec![IfBranch::new(
+                                Variable::new("true", Position::fake()),
+                                Block::new(
+                                    vec![],
+                                    Variable::new("none", line_position(2)),
+                                    Position::fake()
+                                )
+                            )],
+                            Block::new(
+                                vec![],
+                                Variable::new("none", Position::fake()),
+                                Position::fake()
+                            ),
+                            line_position(1)
+                        )
+                        .into()
+                    ),
+                    indoc!(
+                        "
+                        if true {
+                          none
+                        } else {
+                          none
+                        }
+                        "
+                    )
+                    .trim()
+                );
+            }
+
+            #[test]
+            fn format_multi_line_with_multiple_branches() {
+                assert_eq!(
+                    format(
+                        &If::new(
+                            vec![
+                                IfBranch::new(
+                                    Variable::new("true", Position::fake()),
+                                    Block::new(
+                                        vec![],
+                                        Variable::new("none", Position::fake()),
+                                        Position::fake()
+                                    )
+                                ),
+                                IfBranch::new(
+                                    Variable::new("false", Position::fake()),
+                                    Block::new(
+                                        vec![],
+                                        Variable::new("none", Position::fake()),
+                                        Position::fake()
+                                    )
+                                )
+                            ],
+                            Block::new(
+                                vec![],
+                                Variable::new("none", Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    indoc!(
+                        "
+                        if true {
+                          none
+                        } else if false {
+                          none
+                        } else {
+                          none
+                        }
+                        "
+                    )
+                    .trim()
+                );
+            }
+        }
+
+        mod match_ {
+            use super::*;
+
+            fn none_block(position: Position) -> Block {
+                Block::new(vec![], Variable::new("none", position), Position::fake())
+            }
+
+            #[test]
+            fn format_match() {
+                assert_eq!(
+                    format(
+                        &Match::new(
+                            Variable::new("x", Position::fake()),
+                            vec![
+                                MatchBranch::new(
+                                    Number::new(
+                                        NumberRepresentation::FloatingPoint("0".into()),
+                                        Position::fake()
+                                    ),
+                                    None,
+                                    none_block(Position::fake()),
+                                    Position::fake()
+                                ),
+                                MatchBranch::new(
+                                    Variable::new("_", Position::fake()),
+                                    None,
+                                    none_block(Position::fake()),
+                                    Position::fake()
+                                )
+                            ],
+                            Position::fake()
+                        )
+                        .into()
                     ),
                     indoc!(
                         "
-                        foo(
-                          1, #foo
-                        )
+                        match x {
+                          0 { none }
+                          _ { none }
+                        }
                         "
                     )
                     .trim()
                 );
             }
-        }
-
-        mod if_ {
-            use super::*;
 
             #[test]
-            fn format_single_line() {
+            fn format_multi_line_branch() {
                 assert_eq!(
                     format(
-                        &If::new(
-                            vec![IfBranch::new(
-                                Variable::new("true", Position::fake()),
-                                Block::new(
-                                    vec![],
-                                    Variable::new("none", Position::fake()),
-                                    Position::fake()
-                                )
+                        &Match::new(
+                            Variable::new("x", Position::fake()),
+                            vec![MatchBranch::new(
+                                Variable::new("y", Position::fake()),
+                                None,
+                                none_block(line_position(2)),
+                                line_position(1)
                             )],
-                            Block::new(
-                                vec![],
-                                Variable::new("none", Position::fake()),
-                                Position::fake()
-                            ),
                             Position::fake()
                         )
                         .into()
                     ),
-                    "if true { none } else { none }"
+                    indoc!(
+                        "
+                        match x {
+                          y {
+                            none
+                          }
+                        }
+                        "
+                    )
+                    .trim()
                 );
             }
 
             #[test]
-            fn format_multi_line_with_multi_line_input() {
+            fn format_guard() {
                 assert_eq!(
                     format(
-                        &If::new(
-                            vec![IfBranch::new(
-                                Variable::new("true", Position::fake()),
-                                Block::new(
-                                    vec![],
-                                    Variable::new("none", line_position(2)),
-                                    Position::fake()
-                                )
-                            )],
-                            Block::new(
-                                vec![],
-                                Variable::new("none", Position::fake()),
+                        &Match::new(
+                            Variable::new("x", Position::fake()),
+                            vec![MatchBranch::new(
+                                Variable::new("y", Position::fake()),
+                                Some(Variable::new("z", Position::fake()).into()),
+                                none_block(Position::fake()),
                                 Position::fake()
-                            ),
-                            line_position(1)
+                            )],
+                            Position::fake()
                         )
                         .into()
                     ),
                     indoc!(
                         "
-                        if true {
-                          none
-                        } else {
-                          none
+                        match x {
+                          y if z { none }
                         }
                         "
                     )
@@ -2211,45 +2984,59 @@ mod tests {
             }
 
             #[test]
-            fn format_multi_line_with_multiple_branches() {
+            fn format_patterns() {
                 assert_eq!(
                     format(
-                        &If::new(
+                        &Match::new(
+                            Variable::new("x", Position::fake()),
                             vec![
-                                IfBranch::new(
-                                    Variable::new("true", Position::fake()),
-                                    Block::new(
-                                        vec![],
-                                        Variable::new("none", Position::fake()),
-                                        Position::fake()
-                                    )
+                                MatchBranch::new(
+                                    MatchPattern::EmptyList(Position::fake()),
+                                    None,
+                                    none_block(Position::fake()),
+                                    Position::fake()
                                 ),
-                                IfBranch::new(
-                                    Variable::new("false", Position::fake()),
-                                    Block::new(
-                                        vec![],
-                                        Variable::new("none", Position::fake()),
+                                MatchBranch::new(
+                                    ListPattern::new(
+                                        RecordPattern::new(
+                                            vec![
+                                                RecordFieldPattern::new(
+                                                    "a",
+                                                    Variable::new("a", Position::fake()),
+                                                    Position::fake()
+                                                ),
+                                                RecordFieldPattern::new(
+                                                    "b",
+                                                    ByteString::new("b", Position::fake()),
+                                                    Position::fake()
+                                                )
+                                            ],
+                                            Position::fake()
+                                        ),
+                                        TypePattern::new(
+                                            Variable::new("ys", Position::fake()),
+                                            types::List::new(
+                                                types::Reference::new("foo", Position::fake()),
+                                                Position::fake()
+                                            ),
+                                            Position::fake()
+                                        ),
                                         Position::fake()
-                                    )
+                                    ),
+                                    None,
+                                    none_block(Position::fake()),
+                                    Position::fake()
                                 )
                             ],
-                            Block::new(
-                                vec![],
-                                Variable::new("none", Position::fake()),
-                                Position::fake()
-                            ),
                             Position::fake()
                         )
                         .into()
                     ),
                     indoc!(
                         "
-                        if true {
-                          none
-                        } else if false {
-                          none
-                        } else {
-                          none
+                        match x {
+                          [] { none }
+                          [{a, b: \"b\"}, ...ys as [foo]] { none }
                         }
                         "
                     )
@@ -2714,6 +3501,38 @@ mod tests {
                     "0xFA"
                 );
             }
+
+            #[test]
+            fn format_integer() {
+                for (integer, source) in [
+                    (
+                        Integer::new(
+                            NumberRepresentation::FloatingPoint("-42".into()),
+                            IntegerKind::I64,
+                            Position::fake(),
+                        ),
+                        "-42i64",
+                    ),
+                    (
+                        Integer::new(
+                            NumberRepresentation::Binary("01".into()),
+                            IntegerKind::Byte,
+                            Position::fake(),
+                        ),
+                        "0b01byte",
+                    ),
+                    (
+                        Integer::new(
+                            NumberRepresentation::Hexadecimal("fa".into()),
+                            IntegerKind::U64,
+                            Position::fake(),
+                        ),
+                        "0xFAu64",
+                    ),
+                ] {
+                    assert_eq!(format(&integer.into()), source);
+                }
+            }
         }
 
         #[test]
@@ -2724,6 +3543,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn format_string_interpolation() {
+            assert_eq!(
+                format(
+                    &StringInterpolation::new(
+                        vec![
+                            StringInterpolationPart::String("foo ".into()),
+                            StringInterpolationPart::Expression(Arc::new(
+                                Call::new(
+                                    Variable::new("f", Position::fake()),
+                                    vec![Variable::new("x", Position::fake()).into()],
+                                    Position::fake()
+                                )
+                                .into()
+                            )),
+                            StringInterpolationPart::String(" bar".into()),
+                        ],
+                        Position::fake()
+                    )
+                    .into()
+                ),
+                "\"foo ${f(x)} bar\""
+            );
+        }
+
         mod binary_operation {
             use super::*;
 
@@ -2836,6 +3680,34 @@ mod tests {
                     "1 * (2 + 3)"
                 );
             }
+
+            #[test]
+            fn format_pipe_operations() {
+                assert_eq!(
+                    format(
+                        &BinaryOperation::new(
+                            BinaryOperator::Pipe,
+                            BinaryOperation::new(
+                                BinaryOperator::Pipe,
+                                Variable::new("x", line_position(1)),
+                                Variable::new("f", line_position(2)),
+                                Position::fake()
+                            ),
+                            Variable::new("g", line_position(3)),
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    indoc!(
+                        "
+                        x
+                          |> f
+                          |> g
+                        "
+                    )
+                    .trim()
+                );
+            }
         }
 
         mod unary_operation {
@@ -3029,8 +3901,12 @@ mod tests {
                         &ListComprehension::new(
                             types::Reference::new("none", Position::fake()),
                             Variable::new("none", Position::fake()),
-                            "x",
-                            Variable::new("xs", Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![Variable::new("xs", Position::fake()).into()],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake()
                         )
                         .into()
@@ -3046,8 +3922,73 @@ mod tests {
                         &ListComprehension::new(
                             types::Reference::new("none", Position::fake()),
                             Variable::new("none", line_position(2)),
-                            "x",
-                            Variable::new("xs", Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![Variable::new("xs", Position::fake()).into()],
+                                None,
+                                Position::fake(),
+                            )],
+                            line_position(1)
+                        )
+                        .into()
+                    ),
+                    indoc!(
+                        "
+                        [none
+                          none
+                          for x in xs
+                        ]
+                        "
+                    )
+                    .trim()
+                );
+            }
+
+            #[test]
+            fn format_comprehension_with_multiple_branches() {
+                assert_eq!(
+                    format(
+                        &ListComprehension::new(
+                            types::Reference::new("none", Position::fake()),
+                            Variable::new("none", Position::fake()),
+                            vec![
+                                ListComprehensionBranch::new(
+                                    vec!["x".into(), "y".into()],
+                                    vec![
+                                        Variable::new("xs", Position::fake()).into(),
+                                        Variable::new("ys", Position::fake()).into()
+                                    ],
+                                    Some(Variable::new("x", Position::fake()).into()),
+                                    Position::fake(),
+                                ),
+                                ListComprehensionBranch::new(
+                                    vec!["z".into()],
+                                    vec![Variable::new("zs", Position::fake()).into()],
+                                    None,
+                                    Position::fake(),
+                                )
+                            ],
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    "[none none for x, y in xs, ys if x for z in zs]"
+                );
+            }
+
+            #[test]
+            fn format_multi_line_comprehension_with_condition() {
+                assert_eq!(
+                    format(
+                        &ListComprehension::new(
+                            types::Reference::new("none", Position::fake()),
+                            Variable::new("none", line_position(2)),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![Variable::new("xs", Position::fake()).into()],
+                                Some(Variable::new("x", line_position(4)).into()),
+                                line_position(3),
+                            )],
                             line_position(1)
                         )
                         .into()
@@ -3057,6 +3998,7 @@ mod tests {
                         [none
                           none
                           for x in xs
+                          if x
                         ]
                         "
                     )
@@ -3068,6 +4010,61 @@ mod tests {
         mod map {
             use super::*;
 
+            #[test]
+            fn format_map_comprehension() {
+                assert_eq!(
+                    format(
+                        &MapComprehension::new(
+                            types::Reference::new("none", Position::fake()),
+                            types::Reference::new("none", Position::fake()),
+                            Variable::new("k", Position::fake()),
+                            Variable::new("v", Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![Variable::new("m", Position::fake()).into()],
+                                None,
+                                Position::fake(),
+                            )],
+                            Position::fake()
+                        )
+                        .into()
+                    ),
+                    "{none: none k: v for k, v in m}"
+                );
+            }
+
+            #[test]
+            fn format_multi_line_map_comprehension() {
+                assert_eq!(
+                    format(
+                        &MapComprehension::new(
+                            types::Reference::new("none", Position::fake()),
+                            types::Reference::new("none", Position::fake()),
+                            Variable::new("x", line_position(2)),
+                            Variable::new("x", line_position(2)),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![Variable::new("xs", Position::fake()).into()],
+                                Some(Variable::new("x", line_position(4)).into()),
+                                line_position(3),
+                            )],
+                            line_position(1)
+                        )
+                        .into()
+                    ),
+                    indoc!(
+                        "
+                        {none: none
+                          x: x
+                          for x in xs
+                          if x
+                        }
+                        "
+                    )
+                    .trim()
+                );
+            }
+
             #[test]
             fn format_empty() {
                 assert_eq!(
@@ -3257,12 +4254,15 @@ mod tests {
             fn format_comprehension() {
                 assert_eq!(
                     format(
-                        &MapIterationComprehension::new(
+                        &ListComprehension::new(
                             types::Reference::new("none", Position::fake()),
                             Variable::new("none", Position::fake()),
-                            "k",
-                            "v",
-                            Variable::new("xs", Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![Variable::new("xs", Position::fake()).into()],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake()
                         )
                         .into()
@@ -3275,12 +4275,15 @@ mod tests {
             fn format_multi_line_comprehension() {
                 assert_eq!(
                     format(
-                        &MapIterationComprehension::new(
+                        &ListComprehension::new(
                             types::Reference::new("none", Position::fake()),
                             Variable::new("none", line_position(2)),
-                            "k",
-                            "v",
-                            Variable::new("xs", Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![Variable::new("xs", Position::fake()).into()],
+                                None,
+                                Position::fake(),
+                            )],
                             line_position(1)
                         )
                         .into()
@@ -3304,7 +4307,15 @@ mod tests {
             #[test]
             fn format_empty() {
                 assert_eq!(
-                    format(&Record::new("foo", None, vec![], Position::fake()).into()),
+                    format(
+                        &Record::new(
+                            types::Reference::new("foo", Position::fake()),
+                            None,
+                            vec![],
+                            Position::fake()
+                        )
+                        .into()
+                    ),
                     "foo{}"
                 );
             }
@@ -3314,7 +4325,7 @@ mod tests {
                 assert_eq!(
                     format(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             None,
                             vec![RecordField::new(
                                 "x",
@@ -3334,7 +4345,7 @@ mod tests {
                 assert_eq!(
                     format(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             None,
                             vec![
                                 RecordField::new(
@@ -3367,7 +4378,7 @@ mod tests {
                 assert_eq!(
                     format(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             Some(Variable::new("r", Position::fake()).into()),
                             vec![RecordField::new(
                                 "x",
@@ -3387,7 +4398,7 @@ mod tests {
                 assert_eq!(
                     format(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             Some(Variable::new("r", Position::fake()).into()),
                             vec![RecordField::new(
                                 "x",
@@ -3407,7 +4418,7 @@ mod tests {
                 assert_eq!(
                     format(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             None,
                             vec![RecordField::new(
                                 "x",
@@ -3434,7 +4445,7 @@ mod tests {
                 assert_eq!(
                     format(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             None,
                             vec![
                                 RecordField::new(
@@ -3469,7 +4480,7 @@ mod tests {
                 assert_eq!(
                     format_with_comments(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             None,
                             vec![RecordField::new(
                                 "x",
@@ -3498,7 +4509,7 @@ mod tests {
                 assert_eq!(
                     format_with_comments(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             None,
                             vec![RecordField::new(
                                 "x",
@@ -3526,7 +4537,7 @@ mod tests {
                 assert_eq!(
                     format_with_comments(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             Some(Variable::new("x", line_position(3)).into()),
                             vec![],
                             line_position(1)
@@ -3551,7 +4562,7 @@ mod tests {
                 assert_eq!(
                     format_with_comments(
                         &Record::new(
-                            "foo",
+                            types::Reference::new("foo", Position::fake()),
                             Some(Variable::new("x", line_position(2)).into()),
                             vec![],
                             line_position(1)
@@ -3579,7 +4590,7 @@ mod tests {
         fn format_comment() {
             assert_eq!(
                 format(
-                    &Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                    &Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                     &[Comment::new("foo", Position::fake())]
                 ),
                 "#foo\n"
@@ -3590,7 +4601,7 @@ mod tests {
         fn keep_spaces_between_comments() {
             assert_eq!(
                 format(
-                    &Module::new(vec![], vec![], vec![], vec![], Position::fake()),
+                    &Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
                     &[
                         Comment::new("foo", line_position(1)),
                         Comment::new("bar", line_position(3)),
@@ -3620,6 +4631,7 @@ mod tests {
                         vec![],
                         vec![],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[Comment::new("foo", line_position(2))]
@@ -3648,6 +4660,7 @@ mod tests {
                         vec![],
                         vec![],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[Comment::new("foo", line_position(1))]
@@ -3676,6 +4689,7 @@ mod tests {
                         vec![],
                         vec![],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[Comment::new("foo", line_position(1))]
@@ -3707,6 +4721,7 @@ mod tests {
                         )],
                         vec![],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[Comment::new("foo", line_position(1))]
@@ -3729,6 +4744,7 @@ mod tests {
                         vec![],
                         vec![RecordDefinition::new("foo", vec![], line_position(2)).into()],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[Comment::new("foo", line_position(1))]
@@ -3756,6 +4772,7 @@ mod tests {
                         )
                         .into()],
                         vec![],
+                        vec![],
                         Position::fake()
                     ),
                     &[Comment::new("foo", line_position(1))]
@@ -3777,8 +4794,10 @@ mod tests {
                         vec![],
                         vec![],
                         vec![],
+                        vec![],
                         vec![FunctionDefinition::new(
                             "foo",
+                            vec![],
                             Lambda::new(
                                 vec![],
                                 types::Reference::new("none", Position::fake()),
@@ -1,10 +1,10 @@
 use crate::{
     context::CompileContext,
-    downcast, expression,
+    downcast, expression, integer,
     runtime_function_declaration::{
         LOCAL_DEBUG_FUNCTION_NAME, LOCAL_RACE_FUNCTION_NAME, LOCAL_SPAWN_FUNCTION_NAME,
     },
-    type_, CompileError,
+    string_builder, type_, CompileError,
 };
 use hir::{
     analysis::{type_canonicalizer, AnalysisError},
@@ -38,6 +38,24 @@ pub fn compile(
     };
 
     Ok(match function.name() {
+        BuiltInFunctionName::Byte
+        | BuiltInFunctionName::I64
+        | BuiltInFunctionName::Number
+        | BuiltInFunctionName::U64 => {
+            let compile_kind = |type_| -> Result<_, CompileError> {
+                Ok(integer::compile_kind(&type_canonicalizer::canonicalize(
+                    type_,
+                    context.types(),
+                )?))
+            };
+
+            integer::compile_conversion(
+                compile_kind(&function_type.arguments()[0])?,
+                compile_kind(function_type.result())?,
+                arguments[0].clone(),
+            )
+        }
+        BuiltInFunctionName::ConcatenateStrings => string_builder::compile_concatenation(arguments),
         BuiltInFunctionName::Debug => {
             compile_call(mir::ir::Variable::new(LOCAL_DEBUG_FUNCTION_NAME), arguments)?.into()
         }
@@ -60,16 +78,25 @@ pub fn compile(
                 vec![expression::compile(
                     context,
                     &ListComprehension::new(
-                        Some(list_type.clone().into()),
                         any_list_type,
                         Call::new(
-                            Some(types::Function::new(vec![], list_type, position.clone()).into()),
+                            Some(
+                                types::Function::new(vec![], list_type.clone(), position.clone())
+                                    .into(),
+                            ),
                             Variable::new(ELEMENT_NAME, position.clone()),
                             vec![],
                             position.clone(),
                         ),
-                        ELEMENT_NAME,
-                        call.arguments()[0].clone(),
+                        vec![ListComprehensionBranch::new(
+                            vec![ELEMENT_NAME.into()],
+                            vec![ListComprehensionIteratee::new(
+                                Some(types::List::new(list_type, position.clone()).into()),
+                                call.arguments()[0].clone(),
+                            )],
+                            None,
+                            position.clone(),
+                        )],
                         position.clone(),
                     )
                     .into(),
@@ -217,6 +244,179 @@ mod tests {
         );
     }
 
+    mod convert_integers {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn create_call(
+            name: BuiltInFunctionName,
+            argument: Integer,
+            result_type: impl Into<Type>,
+        ) -> Call {
+            Call::new(
+                Some(
+                    types::Function::new(
+                        vec![types::Integer::new(argument.kind(), Position::fake()).into()],
+                        result_type,
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                BuiltInFunction::new(name, Position::fake()),
+                vec![argument.into()],
+                Position::fake(),
+            )
+        }
+
+        #[test]
+        fn convert_i64_constant_to_u64() {
+            assert_eq!(
+                compile_call(&create_call(
+                    BuiltInFunctionName::U64,
+                    Integer::new(types::IntegerKind::I64, -1i64 as u64, Position::fake()),
+                    types::Integer::new(types::IntegerKind::U64, Position::fake()),
+                )),
+                Ok(u64::MAX.into())
+            );
+        }
+
+        #[test]
+        fn convert_u64_constant_to_byte() {
+            assert_eq!(
+                compile_call(&create_call(
+                    BuiltInFunctionName::Byte,
+                    Integer::new(types::IntegerKind::U64, 0x1ff, Position::fake()),
+                    types::Integer::new(types::IntegerKind::Byte, Position::fake()),
+                )),
+                Ok(0xffu8.into())
+            );
+        }
+
+        #[test]
+        fn convert_byte_constant_to_i64() {
+            assert_eq!(
+                compile_call(&create_call(
+                    BuiltInFunctionName::I64,
+                    Integer::new(types::IntegerKind::Byte, 0xff, Position::fake()),
+                    types::Integer::new(types::IntegerKind::I64, Position::fake()),
+                )),
+                Ok(0xffu64.into())
+            );
+        }
+
+        #[test]
+        fn convert_i64_constant_to_number() {
+            assert_eq!(
+                compile_call(&create_call(
+                    BuiltInFunctionName::Number,
+                    Integer::new(types::IntegerKind::I64, -42i64 as u64, Position::fake()),
+                    types::Number::new(Position::fake()),
+                )),
+                Ok(mir::ir::Expression::Number(-42.0))
+            );
+        }
+    }
+
+    mod concatenate_strings {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn create_call(arguments: Vec<Expression>) -> Call {
+            Call::new(
+                Some(
+                    types::Function::new(
+                        vec![types::ByteString::new(Position::fake()).into(); arguments.len()],
+                        types::ByteString::new(Position::fake()),
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                BuiltInFunction::new(BuiltInFunctionName::ConcatenateStrings, Position::fake()),
+                arguments,
+                Position::fake(),
+            )
+        }
+
+        fn create_string(value: &str) -> Expression {
+            ByteString::new(value, Position::fake()).into()
+        }
+
+        #[test]
+        fn compile_no_argument() {
+            assert_eq!(
+                compile_call(&create_call(vec![])),
+                Ok(mir::ir::ByteString::new(vec![]).into())
+            );
+        }
+
+        #[test]
+        fn compile_one_argument() {
+            assert_eq!(
+                compile_call(&create_call(vec![create_string("foo")])),
+                Ok(mir::ir::ByteString::new("foo").into())
+            );
+        }
+
+        #[test]
+        fn compile_three_arguments() {
+            let builder_type = mir::types::Type::from(mir::types::Record::new("__string_builder"));
+            let append_function_type = mir::types::Function::new(
+                vec![builder_type.clone(), mir::types::Type::ByteString],
+                builder_type.clone(),
+            );
+            let append_function = mir::ir::Variable::new("__string_builder_append");
+
+            assert_eq!(
+                compile_call(&create_call(vec![
+                    create_string("foo"),
+                    create_string("bar"),
+                    create_string("baz"),
+                ])),
+                Ok(mir::ir::Call::new(
+                    mir::types::Function::new(
+                        vec![builder_type.clone()],
+                        mir::types::Type::ByteString
+                    ),
+                    mir::ir::Variable::new("__string_builder_build"),
+                    vec![mir::ir::Call::new(
+                        append_function_type.clone(),
+                        append_function.clone(),
+                        vec![
+                            mir::ir::Call::new(
+                                append_function_type.clone(),
+                                append_function.clone(),
+                                vec![
+                                    mir::ir::Call::new(
+                                        append_function_type,
+                                        append_function,
+                                        vec![
+                                            mir::ir::Call::new(
+                                                mir::types::Function::new(
+                                                    vec![mir::types::Type::Number],
+                                                    builder_type,
+                                                ),
+                                                mir::ir::Variable::new("__string_builder_create"),
+                                                vec![mir::ir::Expression::Number(3.0)],
+                                            )
+                                            .into(),
+                                            mir::ir::ByteString::new("foo").into(),
+                                        ],
+                                    )
+                                    .into(),
+                                    mir::ir::ByteString::new("bar").into(),
+                                ],
+                            )
+                            .into(),
+                            mir::ir::ByteString::new("baz").into(),
+                        ],
+                    )
+                    .into()],
+                )
+                .into())
+            );
+        }
+    }
+
     mod spawn {
         use super::*;
         use pretty_assertions::assert_eq;
@@ -1,7 +1,7 @@
 use crate::{CompileConfiguration, CompileError};
 use fnv::FnvHashMap;
 use hir::{
-    analysis::{type_collector, AnalysisContext},
+    analysis::{type_collector, type_extractor, type_instantiator, AnalysisContext},
     ir::Module,
     types::{self, Type},
 };
@@ -10,6 +10,7 @@ use hir::{
 pub struct CompileContext {
     analysis_context: AnalysisContext,
     configuration: Option<CompileConfiguration>,
+    generic_functions: FnvHashMap<String, types::Function>,
 }
 
 impl CompileContext {
@@ -20,6 +21,19 @@ impl CompileContext {
                 type_collector::collect_records(module),
             ),
             configuration,
+            generic_functions: module
+                .function_declarations()
+                .iter()
+                .map(|declaration| (declaration.name(), declaration.type_().clone()))
+                .chain(module.function_definitions().iter().map(|definition| {
+                    (
+                        definition.name(),
+                        type_extractor::extract_from_lambda(definition.lambda()),
+                    )
+                }))
+                .filter(|(_, type_)| type_instantiator::is_generic(&type_.clone().into()))
+                .map(|(name, type_)| (name.into(), type_))
+                .collect(),
         }
     }
 
@@ -33,6 +47,7 @@ impl CompileContext {
         Self {
             analysis_context: AnalysisContext::new(types, records),
             configuration: COMPILE_CONFIGURATION.clone().into(),
+            generic_functions: Default::default(),
         }
     }
 
@@ -48,6 +63,10 @@ impl CompileContext {
         &self.analysis_context
     }
 
+    pub fn generic_functions(&self) -> &FnvHashMap<String, types::Function> {
+        &self.generic_functions
+    }
+
     pub fn configuration(&self) -> Result<&CompileConfiguration, CompileError> {
         self.configuration
             .as_ref()
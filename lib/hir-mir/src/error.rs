@@ -16,6 +16,19 @@ pub enum CompileError {
     VariantTypeInFfi(Position),
 }
 
+impl CompileError {
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::Analysis(error) => error.position(),
+            Self::CompileConfigurationNotProvided | Self::MirTypeCheck(_) => None,
+            Self::InvalidRecordEqualOperation(position)
+            | Self::MainFunctionNotFound(position)
+            | Self::NewContextFunctionNotFound(position)
+            | Self::VariantTypeInFfi(position) => Some(position),
+        }
+    }
+}
+
 impl Display for CompileError {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
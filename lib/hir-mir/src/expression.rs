@@ -1,22 +1,25 @@
 use super::{
     built_in_call,
     context::CompileContext,
-    downcast,
+    downcast, integer,
     transformation::{
-        boolean_operation, equal_operation, if_list, if_map, list_literal, map_literal,
-        not_equal_operation,
+        boolean_operation, equal_operation, generic_call, generic_record, if_list, if_map,
+        list_literal, map_literal, not_equal_operation, order_operation,
     },
     type_, CompileError,
 };
 use fnv::FnvHashMap;
 use hir::{
     analysis::{
-        record_field_resolver, type_canonicalizer, type_equality_checker,
+        name_suggester, record_field_resolver, type_canonicalizer, type_equality_checker,
         union_type_member_calculator, AnalysisError,
     },
     ir::*,
     types::{self, Type},
 };
+use position::Position;
+
+const LOOP_FUNCTION_NAME: &str = "$loop";
 
 pub fn compile(
     context: &CompileContext,
@@ -32,6 +35,8 @@ pub fn compile(
         Expression::Call(call) => {
             if let Expression::BuiltInFunction(function) = call.function() {
                 built_in_call::compile(context, call, function)?
+            } else if let Some(expression) = generic_call::transform(context, call)? {
+                compile(&expression)?
             } else {
                 let type_ = call
                     .function_type()
@@ -102,6 +107,7 @@ pub fn compile(
             },
         )
         .into(),
+        Expression::Integer(integer) => integer::compile_constant(integer.kind(), integer.value()),
         Expression::Lambda(lambda) => compile_lambda(context, lambda)?,
         Expression::Let(let_) => mir::ir::Let::new(
             let_.name().unwrap_or_default(),
@@ -119,13 +125,18 @@ pub fn compile(
             compile_list_comprehension(context, comprehension)?
         }
         Expression::Map(map) => compile(&map_literal::transform(context, map)?)?,
-        Expression::MapIterationComprehension(comprehension) => {
-            compile_map_iteration_comprehension(context, comprehension)?
+        Expression::MapComprehension(comprehension) => {
+            compile_map_comprehension(context, comprehension)?
         }
         Expression::None(_) => mir::ir::Expression::None,
         Expression::Number(number) => mir::ir::Expression::Number(number.value()),
         Expression::Operation(operation) => compile_operation(context, operation)?,
         Expression::RecordConstruction(construction) => {
+            if let Some(expression) = generic_record::transform_construction(context, construction)?
+            {
+                return compile(&expression);
+            }
+
             let field_types = record_field_resolver::resolve(
                 construction.type_(),
                 context.types(),
@@ -135,7 +146,7 @@ pub fn compile(
                 .into_record()
                 .unwrap();
 
-            compile_record_fields(context, construction.fields(), field_types, &|fields| {
+            compile_record_fields(context, construction.fields(), &field_types, &|fields| {
                 mir::ir::Record::new(
                     record_type.clone(),
                     field_types
@@ -147,6 +158,12 @@ pub fn compile(
             })?
         }
         Expression::RecordDeconstruction(deconstruction) => {
+            if let Some(expression) =
+                generic_record::transform_deconstruction(context, deconstruction)?
+            {
+                return compile(&expression);
+            }
+
             let type_ = deconstruction.type_().unwrap();
 
             mir::ir::RecordField::new(
@@ -159,30 +176,36 @@ pub fn compile(
             )
             .into()
         }
-        Expression::RecordUpdate(update) => mir::ir::RecordUpdate::new(
-            type_::compile(context, update.type_())?
-                .into_record()
-                .unwrap(),
-            compile(update.record())?,
-            update
-                .fields()
-                .iter()
-                .map(|field| -> Result<_, CompileError> {
-                    Ok(mir::ir::RecordUpdateField::new(
-                        record_field_resolver::resolve(
-                            update.type_(),
-                            context.types(),
-                            context.records(),
-                        )?
-                        .iter()
-                        .position(|field_type| field_type.name() == field.name())
-                        .unwrap(),
-                        compile(field.expression())?,
-                    ))
-                })
-                .collect::<Result<_, _>>()?,
-        )
-        .into(),
+        Expression::RecordUpdate(update) => {
+            if let Some(expression) = generic_record::transform_update(context, update)? {
+                return compile(&expression);
+            }
+
+            mir::ir::RecordUpdate::new(
+                type_::compile(context, update.type_())?
+                    .into_record()
+                    .unwrap(),
+                compile(update.record())?,
+                update
+                    .fields()
+                    .iter()
+                    .map(|field| -> Result<_, CompileError> {
+                        Ok(mir::ir::RecordUpdateField::new(
+                            record_field_resolver::resolve(
+                                update.type_(),
+                                context.types(),
+                                context.records(),
+                            )?
+                            .iter()
+                            .position(|field_type| field_type.name() == field.name())
+                            .unwrap(),
+                            compile(field.expression())?,
+                        ))
+                    })
+                    .collect::<Result<_, _>>()?,
+            )
+            .into()
+        }
         Expression::String(string) => mir::ir::ByteString::new(string.value()).into(),
         Expression::Thunk(thunk) => {
             const THUNK_NAME: &str = "$thunk";
@@ -210,6 +233,7 @@ pub fn compile(
             match &from {
                 Type::Boolean(_)
                 | Type::Error(_)
+                | Type::Integer(_)
                 | Type::None(_)
                 | Type::Number(_)
                 | Type::Record(_)
@@ -254,7 +278,7 @@ pub fn compile(
                     }
                 }
                 Type::Any(_) | Type::Union(_) => argument,
-                Type::Reference(_) => unreachable!(),
+                Type::Reference(_) | Type::Variable(_) => unreachable!(),
             }
         }
         Expression::Variable(variable) => mir::ir::Variable::new(variable.name()).into(),
@@ -352,100 +376,71 @@ fn compile_list_comprehension(
     context: &CompileContext,
     comprehension: &ListComprehension,
 ) -> Result<mir::ir::Expression, CompileError> {
-    let compile = |expression| compile(context, expression);
-
     const CLOSURE_NAME: &str = "$loop";
-    const LIST_NAME: &str = "$list";
 
     let position = comprehension.position();
-    let input_element_type = comprehension
-        .input_type()
-        .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
-    let output_element_type = comprehension.output_type();
-    let list_type = type_::compile_list(context)?;
-
-    Ok(mir::ir::Call::new(
-        mir::types::Function::new(
-            vec![mir::types::Function::new(vec![], list_type.clone()).into()],
-            list_type.clone(),
-        ),
-        mir::ir::Variable::new(&context.configuration()?.list_type.lazy_function_name),
-        vec![mir::ir::LetRecursive::new(
-            mir::ir::FunctionDefinition::new(
-                CLOSURE_NAME,
-                vec![],
-                list_type.clone(),
-                mir::ir::LetRecursive::new(
-                    mir::ir::FunctionDefinition::new(
-                        CLOSURE_NAME,
-                        vec![mir::ir::Argument::new(LIST_NAME, list_type.clone())],
-                        list_type.clone(),
-                        compile(
-                            &IfList::new(
-                                Some(input_element_type.clone()),
-                                Variable::new(LIST_NAME, position.clone()),
-                                comprehension.element_name(),
-                                LIST_NAME,
-                                List::new(
-                                    output_element_type.clone(),
-                                    vec![
-                                        ListElement::Single(comprehension.element().clone()),
-                                        ListElement::Multiple(
-                                            Call::new(
-                                                Some(
-                                                    types::Function::new(
-                                                        vec![types::List::new(
-                                                            input_element_type.clone(),
-                                                            position.clone(),
-                                                        )
-                                                        .into()],
-                                                        types::List::new(
-                                                            output_element_type.clone(),
-                                                            position.clone(),
-                                                        ),
-                                                        position.clone(),
-                                                    )
-                                                    .into(),
-                                                ),
-                                                Variable::new(CLOSURE_NAME, position.clone()),
-                                                vec![Variable::new(LIST_NAME, position.clone())
-                                                    .into()],
-                                                position.clone(),
-                                            )
-                                            .into(),
-                                        ),
-                                    ],
-                                    position.clone(),
-                                ),
-                                List::new(output_element_type.clone(), vec![], position.clone()),
+    let element_type = comprehension.type_();
+    let (branch, branches) = match comprehension.branches() {
+        [] => {
+            return compile(
+                context,
+                &List::new(
+                    element_type.clone(),
+                    vec![ListElement::Single(comprehension.element().clone())],
+                    position.clone(),
+                )
+                .into(),
+            )
+        }
+        [branch, branches @ ..] => (branch, branches),
+    };
+    let list_type = Type::from(types::List::new(element_type.clone(), position.clone()));
+    let definition = compile_comprehension_function_definition(
+        context,
+        branch,
+        &[],
+        &list_type,
+        &List::new(element_type.clone(), vec![], position.clone()).into(),
+        |argument_types, arguments| {
+            let recursive_call = Expression::from(Call::new(
+                Some(
+                    types::Function::new(argument_types, list_type.clone(), position.clone())
+                        .into(),
+                ),
+                Variable::new(LOOP_FUNCTION_NAME, position.clone()),
+                arguments,
+                position.clone(),
+            ));
+            let list = Expression::from(List::new(
+                element_type.clone(),
+                vec![
+                    if branches.is_empty() {
+                        ListElement::Single(comprehension.element().clone())
+                    } else {
+                        ListElement::Multiple(
+                            ListComprehension::new(
+                                element_type.clone(),
+                                comprehension.element().clone(),
+                                branches.to_vec(),
                                 position.clone(),
                             )
                             .into(),
-                        )?,
-                    ),
-                    mir::ir::Call::new(
-                        mir::types::Function::new(vec![list_type.clone().into()], list_type),
-                        mir::ir::Variable::new(CLOSURE_NAME),
-                        vec![compile(comprehension.list())?],
-                    ),
-                ),
-            ),
-            mir::ir::Variable::new(CLOSURE_NAME),
-        )
-        .into()],
-    )
-    .into())
-}
-
-fn compile_map_iteration_comprehension(
-    context: &CompileContext,
-    comprehension: &MapIterationComprehension,
-) -> Result<mir::ir::Expression, CompileError> {
-    const CLOSURE_NAME: &str = "$loop";
+                        )
+                    },
+                    ListElement::Multiple(recursive_call.clone()),
+                ],
+                position.clone(),
+            ));
 
+            if let Some(condition) = branch.condition() {
+                If::new(condition.clone(), list, recursive_call, position.clone()).into()
+            } else {
+                list
+            }
+        },
+    )?;
     let list_type = type_::compile_list(context)?;
-    let map_type = type_::compile_map(context)?;
-    let definition = compile_map_iteration_function_definition(context, comprehension)?;
+    let (argument_types, arguments) = compile_comprehension_iteratees(context, branch)?;
 
     Ok(mir::ir::Call::new(
         mir::types::Function::new(
@@ -461,23 +456,9 @@ fn compile_map_iteration_comprehension(
                 mir::ir::LetRecursive::new(
                     definition.clone(),
                     mir::ir::Call::new(
-                        mir::types::Function::new(vec![mir::types::Type::Variant], list_type),
+                        mir::types::Function::new(argument_types, list_type),
                         mir::ir::Variable::new(definition.name()),
-                        vec![mir::ir::Call::new(
-                            mir::types::Function::new(
-                                vec![map_type.into()],
-                                mir::types::Type::Variant,
-                            ),
-                            mir::ir::Variable::new(
-                                &context
-                                    .configuration()?
-                                    .map_type
-                                    .iteration
-                                    .iterate_function_name,
-                            ),
-                            vec![compile(context, comprehension.map())?],
-                        )
-                        .into()],
+                        arguments,
                     ),
                 ),
             ),
@@ -488,140 +469,368 @@ fn compile_map_iteration_comprehension(
     .into())
 }
 
-fn compile_map_iteration_function_definition(
+// Map comprehensions are evaluated strictly by threading an accumulated map
+// through a loop function.
+fn compile_map_comprehension(
     context: &CompileContext,
-    comprehension: &MapIterationComprehension,
-) -> Result<mir::ir::FunctionDefinition, CompileError> {
-    const CLOSURE_NAME: &str = "$loop";
-    const ITERATOR_NAME: &str = "$iterator";
+    comprehension: &MapComprehension,
+) -> Result<mir::ir::Expression, CompileError> {
+    const MAP_NAME: &str = "$map";
 
-    let iteration_configuration = &context.configuration()?.map_type.iteration;
     let position = comprehension.position();
-    let any_type = Type::from(types::Any::new(position.clone()));
-    let key_type = comprehension
-        .key_type()
-        .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
-    let value_type = comprehension
-        .value_type()
-        .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
-    let element_type = comprehension.element_type();
-    let iterator_type = Type::from(types::Reference::new(
-        &iteration_configuration.iterator_type_name,
+    let key_type = comprehension.key_type();
+    let value_type = comprehension.value_type();
+    let (branch, branches) = match comprehension.branches() {
+        [] => {
+            return compile(
+                context,
+                &Map::new(
+                    key_type.clone(),
+                    value_type.clone(),
+                    vec![MapElement::Insertion(MapEntry::new(
+                        comprehension.key().clone(),
+                        comprehension.value().clone(),
+                        position.clone(),
+                    ))],
+                    position.clone(),
+                )
+                .into(),
+            )
+        }
+        [branch, branches @ ..] => (branch, branches),
+    };
+    let map_type = Type::from(types::Map::new(
+        key_type.clone(),
+        value_type.clone(),
         position.clone(),
     ));
-    let iterator_or_none_type = types::Union::new(
-        iterator_type.clone(),
-        types::None::new(position.clone()),
-        position.clone(),
-    );
-    let iterator_variable = Variable::new(ITERATOR_NAME, position.clone());
-    let compile_key_value_function_call = |name, type_| {
-        downcast::compile(
-            context,
-            &any_type,
-            type_,
-            &Call::new(
+    let map_variable = Expression::from(Variable::new(MAP_NAME, position.clone()));
+    let definition = compile_comprehension_function_definition(
+        context,
+        branch,
+        &[Argument::new(MAP_NAME, map_type.clone())],
+        &map_type,
+        &map_variable,
+        |argument_types, arguments| {
+            let map = Expression::from(Map::new(
+                key_type.clone(),
+                value_type.clone(),
+                vec![
+                    MapElement::Map(map_variable.clone()),
+                    if branches.is_empty() {
+                        MapElement::Insertion(MapEntry::new(
+                            comprehension.key().clone(),
+                            comprehension.value().clone(),
+                            position.clone(),
+                        ))
+                    } else {
+                        MapElement::Map(
+                            MapComprehension::new(
+                                key_type.clone(),
+                                value_type.clone(),
+                                comprehension.key().clone(),
+                                comprehension.value().clone(),
+                                branches.to_vec(),
+                                position.clone(),
+                            )
+                            .into(),
+                        )
+                    },
+                ],
+                position.clone(),
+            ));
+
+            Call::new(
                 Some(
                     types::Function::new(
-                        vec![iterator_type.clone()],
-                        any_type.clone(),
+                        [map_type.clone()]
+                            .into_iter()
+                            .chain(argument_types)
+                            .collect(),
+                        map_type.clone(),
                         position.clone(),
                     )
                     .into(),
                 ),
-                Variable::new(name, position.clone()),
-                vec![iterator_variable.clone().into()],
+                Variable::new(LOOP_FUNCTION_NAME, position.clone()),
+                [if let Some(condition) = branch.condition() {
+                    If::new(
+                        condition.clone(),
+                        map,
+                        map_variable.clone(),
+                        position.clone(),
+                    )
+                    .into()
+                } else {
+                    map
+                }]
+                .into_iter()
+                .chain(arguments)
+                .collect(),
                 position.clone(),
             )
-            .into(),
-        )
+            .into()
+        },
+    )?;
+    let map_type = type_::compile_map(context)?;
+    let (argument_types, arguments) = compile_comprehension_iteratees(context, branch)?;
+
+    Ok(mir::ir::LetRecursive::new(
+        definition.clone(),
+        mir::ir::Call::new(
+            mir::types::Function::new(
+                [map_type.clone().into()]
+                    .into_iter()
+                    .chain(argument_types)
+                    .collect(),
+                map_type,
+            ),
+            mir::ir::Variable::new(definition.name()),
+            [compile(
+                context,
+                &Map::new(
+                    key_type.clone(),
+                    value_type.clone(),
+                    vec![],
+                    position.clone(),
+                )
+                .into(),
+            )?]
+            .into_iter()
+            .chain(arguments)
+            .collect(),
+        ),
+    )
+    .into())
+}
+
+fn compile_comprehension_iteratees(
+    context: &CompileContext,
+    branch: &ListComprehensionBranch,
+) -> Result<(Vec<mir::types::Type>, Vec<mir::ir::Expression>), CompileError> {
+    let list_type = type_::compile_list(context)?;
+    let map_type = type_::compile_map(context)?;
+    let mut argument_types = vec![];
+    let mut arguments = vec![];
+
+    for iteratee in branch.iteratees() {
+        let expression = compile(context, iteratee.expression())?;
+
+        if let Some(Type::Map(_)) = iteratee.type_() {
+            argument_types.push(mir::types::Type::Variant);
+            arguments.push(
+                mir::ir::Call::new(
+                    mir::types::Function::new(
+                        vec![map_type.clone().into()],
+                        mir::types::Type::Variant,
+                    ),
+                    mir::ir::Variable::new(
+                        &context
+                            .configuration()?
+                            .map_type
+                            .iteration
+                            .iterate_function_name,
+                    ),
+                    vec![expression],
+                )
+                .into(),
+            );
+        } else {
+            argument_types.push(list_type.clone().into());
+            arguments.push(expression);
+        }
+    }
+
+    Ok((argument_types, arguments))
+}
+
+// A loop function iterates over collections of the first branch of a
+// comprehension. The rest of iteratees are passed to a body compiler.
+fn compile_comprehension_function_definition(
+    context: &CompileContext,
+    branch: &ListComprehensionBranch,
+    arguments: &[Argument],
+    result_type: &Type,
+    else_: &Expression,
+    compile_body: impl Fn(Vec<Type>, Vec<Expression>) -> Expression,
+) -> Result<mir::ir::FunctionDefinition, CompileError> {
+    const ITERATOR_NAME: &str = "$iterator";
+
+    let position = branch.position();
+    let iteratee_types = branch
+        .iteratees()
+        .iter()
+        .map(|iteratee| {
+            iteratee
+                .type_()
+                .cloned()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let compile_arguments = |iteratee_arguments: Vec<(String, mir::types::Type)>| {
+        arguments
+            .iter()
+            .map(|argument| {
+                Ok(mir::ir::Argument::new(
+                    argument.name(),
+                    type_::compile(context, argument.type_())?,
+                ))
+            })
+            .chain(
+                iteratee_arguments
+                    .into_iter()
+                    .map(|(name, type_)| Ok(mir::ir::Argument::new(name, type_))),
+            )
+            .collect::<Result<Vec<_>, CompileError>>()
     };
 
-    Ok(mir::ir::FunctionDefinition::new(
-        CLOSURE_NAME,
-        vec![mir::ir::Argument::new(
-            ITERATOR_NAME,
-            mir::types::Type::Variant,
-        )],
-        type_::compile_list(context)?,
-        compile(
-            context,
-            &IfType::new(
-                ITERATOR_NAME,
-                iterator_variable.clone(),
-                vec![IfTypeBranch::new(
-                    iterator_type.clone(),
-                    Let::new(
-                        Some(comprehension.key_name().into()),
-                        Some(key_type.clone()),
-                        compile_key_value_function_call(
-                            &iteration_configuration.key_function_name,
-                            key_type,
-                        )?,
-                        Let::new(
-                            Some(comprehension.value_name().into()),
-                            Some(value_type.clone()),
-                            compile_key_value_function_call(
-                                &iteration_configuration.value_function_name,
-                                value_type,
-                            )?,
-                            List::new(
-                                element_type.clone(),
-                                vec![
-                                    ListElement::Single(comprehension.element().clone()),
-                                    ListElement::Multiple(
-                                        Call::new(
+    Ok(match (branch.names(), iteratee_types.as_slice()) {
+        ([key_name, value_name], [Type::Map(map_type)]) => {
+            let iteration_configuration = &context.configuration()?.map_type.iteration;
+            let any_type = Type::from(types::Any::new(position.clone()));
+            let iterator_type = Type::from(types::Reference::new(
+                &iteration_configuration.iterator_type_name,
+                position.clone(),
+            ));
+            let iterator_or_none_type = Type::from(types::Union::new(
+                iterator_type.clone(),
+                types::None::new(position.clone()),
+                position.clone(),
+            ));
+            let iterator_variable = Variable::new(ITERATOR_NAME, position.clone());
+            let compile_key_value_function_call = |name, type_| {
+                downcast::compile(
+                    context,
+                    &any_type,
+                    type_,
+                    &Call::new(
+                        Some(
+                            types::Function::new(
+                                vec![iterator_type.clone()],
+                                any_type.clone(),
+                                position.clone(),
+                            )
+                            .into(),
+                        ),
+                        Variable::new(name, position.clone()),
+                        vec![iterator_variable.clone().into()],
+                        position.clone(),
+                    )
+                    .into(),
+                )
+            };
+
+            mir::ir::FunctionDefinition::new(
+                LOOP_FUNCTION_NAME,
+                compile_arguments(vec![(ITERATOR_NAME.into(), mir::types::Type::Variant)])?,
+                type_::compile(context, result_type)?,
+                compile(
+                    context,
+                    &IfType::new(
+                        ITERATOR_NAME,
+                        iterator_variable.clone(),
+                        vec![IfTypeBranch::new(
+                            iterator_type.clone(),
+                            Let::new(
+                                Some(key_name.into()),
+                                Some(map_type.key().clone()),
+                                compile_key_value_function_call(
+                                    &iteration_configuration.key_function_name,
+                                    map_type.key(),
+                                )?,
+                                Let::new(
+                                    Some(value_name.into()),
+                                    Some(map_type.value().clone()),
+                                    compile_key_value_function_call(
+                                        &iteration_configuration.value_function_name,
+                                        map_type.value(),
+                                    )?,
+                                    compile_body(
+                                        vec![iterator_or_none_type.clone()],
+                                        vec![Call::new(
                                             Some(
                                                 types::Function::new(
-                                                    vec![iterator_or_none_type.clone().into()],
-                                                    types::List::new(
-                                                        element_type.clone(),
-                                                        position.clone(),
-                                                    ),
+                                                    vec![iterator_type.clone()],
+                                                    iterator_or_none_type,
                                                     position.clone(),
                                                 )
                                                 .into(),
                                             ),
-                                            Variable::new(CLOSURE_NAME, position.clone()),
-                                            vec![Call::new(
-                                                Some(
-                                                    types::Function::new(
-                                                        vec![iterator_type.clone()],
-                                                        iterator_or_none_type,
-                                                        position.clone(),
-                                                    )
-                                                    .into(),
-                                                ),
-                                                Variable::new(
-                                                    &iteration_configuration.rest_function_name,
-                                                    position.clone(),
-                                                ),
-                                                vec![iterator_variable.into()],
+                                            Variable::new(
+                                                &iteration_configuration.rest_function_name,
                                                 position.clone(),
-                                            )
-                                            .into()],
+                                            ),
+                                            vec![iterator_variable.clone().into()],
                                             position.clone(),
                                         )
-                                        .into(),
+                                        .into()],
                                     ),
-                                ],
+                                    position.clone(),
+                                ),
                                 position.clone(),
                             ),
+                        )],
+                        Some(ElseBranch::new(
+                            Some(types::None::new(position.clone()).into()),
+                            else_.clone(),
                             position.clone(),
-                        ),
+                        )),
                         position.clone(),
+                    )
+                    .into(),
+                )?,
+            )
+        }
+        (names, types) => {
+            let list_names = (0..types.len())
+                .map(|index| format!("$list{}", index))
+                .collect::<Vec<_>>();
+            let list_types = types
+                .iter()
+                .map(|type_| {
+                    type_
+                        .clone()
+                        .into_list()
+                        .ok_or_else(|| AnalysisError::ListExpected(type_.clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            mir::ir::FunctionDefinition::new(
+                LOOP_FUNCTION_NAME,
+                compile_arguments(
+                    list_names
+                        .iter()
+                        .map(|name| Ok((name.clone(), type_::compile_list(context)?.into())))
+                        .collect::<Result<_, CompileError>>()?,
+                )?,
+                type_::compile(context, result_type)?,
+                compile(
+                    context,
+                    &names.iter().zip(&list_names).zip(&list_types).rev().fold(
+                        compile_body(
+                            types.to_vec(),
+                            list_names
+                                .iter()
+                                .map(|name| Variable::new(name, position.clone()).into())
+                                .collect(),
+                        ),
+                        |expression, ((name, list_name), list_type)| {
+                            IfList::new(
+                                Some(list_type.element().clone()),
+                                Variable::new(list_name, position.clone()),
+                                name,
+                                list_name,
+                                expression,
+                                else_.clone(),
+                                position.clone(),
+                            )
+                            .into()
+                        },
                     ),
-                )],
-                Some(ElseBranch::new(
-                    Some(types::None::new(position.clone()).into()),
-                    List::new(element_type.clone(), vec![], position.clone()),
-                    position.clone(),
-                )),
-                position.clone(),
+                )?,
             )
-            .into(),
-        )?,
-    ))
+        }
+    })
 }
 
 fn compile_operation(
@@ -631,17 +840,50 @@ fn compile_operation(
     let compile = |expression| compile(context, expression);
 
     Ok(match operation {
-        Operation::Arithmetic(operation) => mir::ir::ArithmeticOperation::new(
-            match operation.operator() {
-                ArithmeticOperator::Add => mir::ir::ArithmeticOperator::Add,
-                ArithmeticOperator::Subtract => mir::ir::ArithmeticOperator::Subtract,
-                ArithmeticOperator::Multiply => mir::ir::ArithmeticOperator::Multiply,
-                ArithmeticOperator::Divide => mir::ir::ArithmeticOperator::Divide,
-            },
-            compile(operation.lhs())?,
-            compile(operation.rhs())?,
-        )
-        .into(),
+        Operation::Arithmetic(operation) => {
+            let lhs = compile(operation.lhs())?;
+            let rhs = compile(operation.rhs())?;
+
+            match (
+                operation.operator(),
+                compile_integer_kind(context, operation.type_(), operation.position())?,
+            ) {
+                (ArithmeticOperator::Divide, Some(kind)) => {
+                    integer::compile_division(kind, lhs, rhs)
+                }
+                (ArithmeticOperator::Remainder, Some(kind)) => {
+                    integer::compile_remainder(kind, lhs, rhs)
+                }
+                (ArithmeticOperator::LeftShift, Some(kind)) => {
+                    integer::compile_shift(kind, mir::ir::ArithmeticOperator::LeftShift, lhs, rhs)
+                }
+                (ArithmeticOperator::RightShift, Some(kind)) => integer::compile_shift(
+                    kind,
+                    mir::ir::ArithmeticOperator::LogicalRightShift,
+                    lhs,
+                    rhs,
+                ),
+                (operator, _) => mir::ir::ArithmeticOperation::new(
+                    match operator {
+                        ArithmeticOperator::Add => mir::ir::ArithmeticOperator::Add,
+                        ArithmeticOperator::Subtract => mir::ir::ArithmeticOperator::Subtract,
+                        ArithmeticOperator::Multiply => mir::ir::ArithmeticOperator::Multiply,
+                        ArithmeticOperator::Divide => mir::ir::ArithmeticOperator::Divide,
+                        ArithmeticOperator::Remainder => mir::ir::ArithmeticOperator::Remainder,
+                        ArithmeticOperator::BitwiseAnd => mir::ir::ArithmeticOperator::BitwiseAnd,
+                        ArithmeticOperator::BitwiseOr => mir::ir::ArithmeticOperator::BitwiseOr,
+                        ArithmeticOperator::BitwiseXor => mir::ir::ArithmeticOperator::BitwiseXor,
+                        ArithmeticOperator::LeftShift => mir::ir::ArithmeticOperator::LeftShift,
+                        ArithmeticOperator::RightShift => {
+                            mir::ir::ArithmeticOperator::LogicalRightShift
+                        }
+                    },
+                    lhs,
+                    rhs,
+                )
+                .into(),
+            }
+        }
         Operation::Boolean(operation) => compile(&boolean_operation::transform(operation))?,
         Operation::Equality(operation) => match operation.operator() {
             EqualityOperator::Equal => {
@@ -651,7 +893,7 @@ fn compile_operation(
                     })?,
                     context.types(),
                 )? {
-                    Type::Number(_) => mir::ir::ComparisonOperation::new(
+                    Type::Integer(_) | Type::Number(_) => mir::ir::ComparisonOperation::new(
                         mir::ir::ComparisonOperator::Equal,
                         compile(operation.lhs())?,
                         compile(operation.rhs())?,
@@ -676,19 +918,42 @@ fn compile_operation(
         Operation::Not(operation) => {
             mir::ir::If::new(compile(operation.expression())?, false, true).into()
         }
-        Operation::Order(operation) => mir::ir::ComparisonOperation::new(
-            match operation.operator() {
-                OrderOperator::LessThan => mir::ir::ComparisonOperator::LessThan,
-                OrderOperator::LessThanOrEqual => mir::ir::ComparisonOperator::LessThanOrEqual,
-                OrderOperator::GreaterThan => mir::ir::ComparisonOperator::GreaterThan,
-                OrderOperator::GreaterThanOrEqual => {
-                    mir::ir::ComparisonOperator::GreaterThanOrEqual
-                }
-            },
-            compile(operation.lhs())?,
-            compile(operation.rhs())?,
-        )
-        .into(),
+        Operation::Order(operation) => match type_canonicalizer::canonicalize(
+            operation
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?,
+            context.types(),
+        )? {
+            Type::Integer(_) | Type::Number(_) => {
+                let kind = compile_integer_kind(context, operation.type_(), operation.position())?;
+                let compile_operand = |expression| -> Result<_, CompileError> {
+                    let expression = compile(expression)?;
+
+                    Ok(if let Some(kind) = kind {
+                        integer::compile_order_operand(kind, expression)
+                    } else {
+                        expression
+                    })
+                };
+
+                mir::ir::ComparisonOperation::new(
+                    match operation.operator() {
+                        OrderOperator::LessThan => mir::ir::ComparisonOperator::LessThan,
+                        OrderOperator::LessThanOrEqual => {
+                            mir::ir::ComparisonOperator::LessThanOrEqual
+                        }
+                        OrderOperator::GreaterThan => mir::ir::ComparisonOperator::GreaterThan,
+                        OrderOperator::GreaterThanOrEqual => {
+                            mir::ir::ComparisonOperator::GreaterThanOrEqual
+                        }
+                    },
+                    compile_operand(operation.lhs())?,
+                    compile_operand(operation.rhs())?,
+                )
+                .into()
+            }
+            _ => compile(&order_operation::expression::transform(context, operation)?)?,
+        },
         Operation::Try(operation) => {
             const SUCCESS_NAME: &str = "$success";
             const ERROR_NAME: &str = "$error";
@@ -720,6 +985,17 @@ fn compile_operation(
     })
 }
 
+fn compile_integer_kind(
+    context: &CompileContext,
+    type_: Option<&Type>,
+    position: &Position,
+) -> Result<Option<types::IntegerKind>, CompileError> {
+    Ok(integer::compile_kind(&type_canonicalizer::canonicalize(
+        type_.ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?,
+        context.types(),
+    )?))
+}
+
 fn compile_record_fields(
     context: &CompileContext,
     fields: &[RecordField],
@@ -740,7 +1016,12 @@ fn compile_record_fields(
                     field_types
                         .iter()
                         .find(|field_type| field_type.name() == field.name())
-                        .ok_or_else(|| AnalysisError::RecordFieldUnknown(field.position().clone()))?
+                        .ok_or_else(|| {
+                            AnalysisError::RecordFieldUnknown(
+                                field.position().clone(),
+                                name_suggester::suggest_record_fields(field.name(), field_types),
+                            )
+                        })?
                         .type_(),
                 )?,
                 compile(context, field.expression())?,
@@ -765,7 +1046,7 @@ fn compile_record_fields(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use position::{test::PositionFake, Position};
+    use position::test::PositionFake;
 
     fn compile_expression(expression: &Expression) -> Result<mir::ir::Expression, CompileError> {
         compile(
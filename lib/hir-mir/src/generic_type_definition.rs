@@ -1,7 +1,14 @@
-use super::{context::CompileContext, type_, CompileError};
+use super::{
+    context::CompileContext,
+    transformation::{generic_call, generic_record},
+    type_, CompileError,
+};
 use fnv::{FnvHashMap, FnvHashSet};
 use hir::{
-    analysis::{expression_visitor, union_type_member_calculator, AnalysisError},
+    analysis::{
+        expression_visitor, record_field_resolver, type_canonicalizer,
+        union_type_member_calculator, AnalysisError,
+    },
     ir::*,
     types,
     types::Type,
@@ -12,6 +19,9 @@ pub fn compile(
     module: &Module,
 ) -> Result<Vec<mir::ir::TypeDefinition>, CompileError> {
     Ok(collect_types(module, context.types())?
+        .into_iter()
+        .chain(collect_generic_types(context, module)?)
+        .collect::<FnvHashSet<_>>()
         .into_iter()
         .map(|type_| compile_type_definition(context, &type_))
         .collect::<Result<Vec<_>, _>>()?
@@ -49,10 +59,11 @@ fn compile_type_definition(
         | Type::Boolean(_)
         | Type::Error(_)
         | Type::String(_)
+        | Type::Integer(_)
         | Type::None(_)
         | Type::Number(_)
         | Type::Record(_) => None,
-        Type::Reference(_) | Type::Union(_) => unreachable!(),
+        Type::Reference(_) | Type::Union(_) | Type::Variable(_) => unreachable!(),
     })
 }
 
@@ -98,13 +109,34 @@ fn collect_types(
             lower_types.insert(list.type_().clone());
         }
         Expression::ListComprehension(comprehension) => {
-            lower_types.insert(comprehension.input_type().unwrap().clone());
-            lower_types.insert(comprehension.output_type().clone());
+            lower_types.insert(comprehension.type_().clone());
+            lower_types.extend(
+                comprehension
+                    .branches()
+                    .iter()
+                    .flat_map(|branch| branch.iteratees())
+                    .flat_map(|iteratee| iteratee.type_())
+                    .filter_map(|type_| type_.clone().into_list())
+                    .map(|list_type| list_type.element().clone()),
+            );
         }
         Expression::Map(map) => {
             lower_types.insert(map.key_type().clone());
             lower_types.insert(map.value_type().clone());
         }
+        Expression::MapComprehension(comprehension) => {
+            lower_types.insert(comprehension.key_type().clone());
+            lower_types.insert(comprehension.value_type().clone());
+            lower_types.extend(
+                comprehension
+                    .branches()
+                    .iter()
+                    .flat_map(|branch| branch.iteratees())
+                    .flat_map(|iteratee| iteratee.type_())
+                    .filter_map(|type_| type_.clone().into_list())
+                    .map(|list_type| list_type.element().clone()),
+            );
+        }
         Expression::TypeCoercion(coercion) => {
             lower_types.insert(coercion.from().clone());
         }
@@ -129,6 +161,86 @@ fn collect_types(
         .collect())
 }
 
+// Values of type variables are converted into any types at calls of generic
+// functions and construction of generic records after this collection. So we
+// collect all types nested in their instance and erased types.
+fn collect_generic_types(
+    context: &CompileContext,
+    module: &Module,
+) -> Result<FnvHashSet<Type>, CompileError> {
+    let mut types = vec![];
+
+    expression_visitor::visit(module, |expression| {
+        if let Expression::Call(call) = expression {
+            if let Expression::Variable(variable) = call.function() {
+                if let Some(type_) = context.generic_functions().get(variable.name()) {
+                    types.push(generic_call::erase(&type_.clone().into()));
+                    types.extend(call.function_type().cloned());
+                }
+            }
+        }
+    });
+
+    for record in generic_record::collect_instances(context, module)? {
+        let generic_type = type_canonicalizer::canonicalize_record(
+            &generic_call::erase(&record.clone().into()),
+            context.types(),
+        )?
+        .ok_or_else(|| AnalysisError::RecordExpected(record.clone().into()))?;
+
+        for field in
+            record_field_resolver::resolve_record(&record, context.types(), context.records())?
+                .into_iter()
+                .chain(record_field_resolver::resolve_record(
+                    &generic_type,
+                    context.types(),
+                    context.records(),
+                )?)
+        {
+            types.push(field.type_().clone());
+        }
+    }
+
+    let mut nested_types = FnvHashSet::default();
+
+    for type_ in &types {
+        collect_nested_types(context, type_, &mut nested_types)?;
+    }
+
+    Ok(nested_types)
+}
+
+fn collect_nested_types(
+    context: &CompileContext,
+    type_: &Type,
+    types: &mut FnvHashSet<Type>,
+) -> Result<(), CompileError> {
+    for type_ in union_type_member_calculator::calculate(type_, context.types())? {
+        match &type_ {
+            Type::Function(function) => {
+                for type_ in function.arguments().iter().chain([function.result()]) {
+                    collect_nested_types(context, type_, types)?;
+                }
+            }
+            Type::List(list) => collect_nested_types(context, list.element(), types)?,
+            Type::Map(map) => {
+                collect_nested_types(context, map.key(), types)?;
+                collect_nested_types(context, map.value(), types)?;
+            }
+            Type::Record(record) => {
+                for type_ in record.arguments() {
+                    collect_nested_types(context, type_, types)?;
+                }
+            }
+            _ => {}
+        }
+
+        types.insert(type_);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -482,11 +594,20 @@ mod tests {
                         vec![],
                         types::None::new(Position::fake()),
                         ListComprehension::new(
-                            Some(union_type.clone().into()),
                             types::None::new(Position::fake()),
                             None::new(Position::fake()),
-                            "_",
-                            List::new(union_type, vec![], Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["_".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(
+                                        types::List::new(union_type.clone(), Position::fake())
+                                            .into()
+                                    ),
+                                    List::new(union_type, vec![], Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -523,11 +644,21 @@ mod tests {
                         vec![],
                         types::None::new(Position::fake()),
                         ListComprehension::new(
-                            Some(types::None::new(Position::fake()).into()),
                             union_type,
                             None::new(Position::fake()),
-                            "_",
-                            List::new(types::None::new(Position::fake()), vec![], Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["_".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(list_type.clone().into()),
+                                    List::new(
+                                        types::None::new(Position::fake()),
+                                        vec![],
+                                        Position::fake()
+                                    ),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
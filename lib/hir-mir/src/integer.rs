@@ -0,0 +1,246 @@
+use hir::types::{IntegerKind, Type};
+
+const FOREIGN_FUNCTION_PREFIX: &str = "_pen_";
+const LOCAL_FUNCTION_PREFIX: &str = "__";
+
+// `None` represents a number type.
+const NUMERIC_KINDS: [Option<IntegerKind>; 4] = [
+    None,
+    Some(IntegerKind::Byte),
+    Some(IntegerKind::I64),
+    Some(IntegerKind::U64),
+];
+const INTEGER_KINDS: [IntegerKind; 3] = [IntegerKind::Byte, IntegerKind::I64, IntegerKind::U64];
+
+// A type needs to be canonicalized before.
+pub fn compile_kind(type_: &Type) -> Option<IntegerKind> {
+    match type_ {
+        Type::Integer(integer) => Some(integer.kind()),
+        _ => None,
+    }
+}
+
+pub fn compile_type(kind: IntegerKind) -> mir::types::Type {
+    compile_numeric_type(Some(kind))
+}
+
+// Integers are converted into others by wrapping around. Numbers are converted
+// into integers by rounding them toward zero and saturating them at bounds of
+// integers while NaN is converted into zero.
+pub fn compile_conversion(
+    from: Option<IntegerKind>,
+    to: Option<IntegerKind>,
+    argument: mir::ir::Expression,
+) -> mir::ir::Expression {
+    match (from, to, &argument) {
+        _ if from == to => argument,
+        (None, Some(IntegerKind::Byte), mir::ir::Expression::Number(number)) => {
+            (*number as u8).into()
+        }
+        (None, Some(IntegerKind::I64), mir::ir::Expression::Number(number)) => {
+            (*number as i64 as u64).into()
+        }
+        (None, Some(IntegerKind::U64), mir::ir::Expression::Number(number)) => {
+            (*number as u64).into()
+        }
+        (Some(from), _, mir::ir::Expression::Integer8(value)) => {
+            compile_constant_conversion(from, to, (*value).into())
+        }
+        (Some(from), _, mir::ir::Expression::Integer64(value)) => {
+            compile_constant_conversion(from, to, *value)
+        }
+        _ => mir::ir::Call::new(
+            compile_conversion_function_type(from, to),
+            mir::ir::Variable::new(
+                LOCAL_FUNCTION_PREFIX.to_owned() + &compile_conversion_function_name(from, to),
+            ),
+            vec![argument],
+        )
+        .into(),
+    }
+}
+
+pub fn compile_constant(kind: IntegerKind, value: u64) -> mir::ir::Expression {
+    match kind {
+        IntegerKind::Byte => (value as u8).into(),
+        IntegerKind::I64 | IntegerKind::U64 => value.into(),
+    }
+}
+
+// Integer division by zero results in zero.
+pub fn compile_division(
+    kind: IntegerKind,
+    lhs: mir::ir::Expression,
+    rhs: mir::ir::Expression,
+) -> mir::ir::Expression {
+    mir::ir::Call::new(
+        compile_division_function_type(kind),
+        mir::ir::Variable::new(
+            LOCAL_FUNCTION_PREFIX.to_owned() + &compile_division_function_name(kind),
+        ),
+        vec![lhs, rhs],
+    )
+    .into()
+}
+
+// Integer remainder by zero results in zero as well as division.
+pub fn compile_remainder(
+    kind: IntegerKind,
+    lhs: mir::ir::Expression,
+    rhs: mir::ir::Expression,
+) -> mir::ir::Expression {
+    mir::ir::Call::new(
+        compile_division_function_type(kind),
+        mir::ir::Variable::new(
+            LOCAL_FUNCTION_PREFIX.to_owned() + &compile_remainder_function_name(kind),
+        ),
+        vec![lhs, rhs],
+    )
+    .into()
+}
+
+// Shift amounts wrap around at bit widths of integers. Only signed integers
+// are shifted arithmetically to the right.
+pub fn compile_shift(
+    kind: IntegerKind,
+    operator: mir::ir::ArithmeticOperator,
+    lhs: mir::ir::Expression,
+    rhs: mir::ir::Expression,
+) -> mir::ir::Expression {
+    mir::ir::ArithmeticOperation::new(
+        match (operator, kind) {
+            (mir::ir::ArithmeticOperator::LogicalRightShift, IntegerKind::I64) => {
+                mir::ir::ArithmeticOperator::ArithmeticRightShift
+            }
+            (operator, _) => operator,
+        },
+        lhs,
+        mir::ir::ArithmeticOperation::new(
+            mir::ir::ArithmeticOperator::BitwiseAnd,
+            rhs,
+            match kind {
+                IntegerKind::Byte => mir::ir::Expression::from(7u8),
+                IntegerKind::I64 | IntegerKind::U64 => 63u64.into(),
+            },
+        ),
+    )
+    .into()
+}
+
+// Integers are compared as signed ones in MIR. So we flip the most significant
+// bits of unsigned integers to preserve their order.
+pub fn compile_order_operand(
+    kind: IntegerKind,
+    expression: mir::ir::Expression,
+) -> mir::ir::Expression {
+    match kind {
+        IntegerKind::Byte => mir::ir::ArithmeticOperation::new(
+            mir::ir::ArithmeticOperator::Add,
+            expression,
+            1u8 << 7,
+        )
+        .into(),
+        IntegerKind::I64 => expression,
+        IntegerKind::U64 => mir::ir::ArithmeticOperation::new(
+            mir::ir::ArithmeticOperator::Add,
+            expression,
+            1u64 << 63,
+        )
+        .into(),
+    }
+}
+
+pub fn compile_foreign_declarations() -> Vec<mir::ir::ForeignDeclaration> {
+    NUMERIC_KINDS
+        .into_iter()
+        .flat_map(|from| {
+            NUMERIC_KINDS
+                .into_iter()
+                .filter(move |&to| to != from)
+                .map(move |to| {
+                    compile_foreign_declaration(
+                        compile_conversion_function_name(from, to),
+                        compile_conversion_function_type(from, to),
+                    )
+                })
+        })
+        .chain(INTEGER_KINDS.into_iter().flat_map(|kind| {
+            [
+                compile_division_function_name(kind),
+                compile_remainder_function_name(kind),
+            ]
+            .into_iter()
+            .map(move |name| {
+                compile_foreign_declaration(name, compile_division_function_type(kind))
+            })
+        }))
+        .collect()
+}
+
+fn compile_constant_conversion(
+    from: IntegerKind,
+    to: Option<IntegerKind>,
+    value: u64,
+) -> mir::ir::Expression {
+    match to {
+        None => mir::ir::Expression::Number(match from {
+            IntegerKind::Byte | IntegerKind::U64 => value as f64,
+            IntegerKind::I64 => value as i64 as f64,
+        }),
+        Some(to) => compile_constant(to, value),
+    }
+}
+
+fn compile_foreign_declaration(
+    name: String,
+    type_: mir::types::Function,
+) -> mir::ir::ForeignDeclaration {
+    mir::ir::ForeignDeclaration::new(
+        LOCAL_FUNCTION_PREFIX.to_owned() + &name,
+        FOREIGN_FUNCTION_PREFIX.to_owned() + &name,
+        type_,
+        mir::ir::CallingConvention::Target,
+    )
+}
+
+fn compile_conversion_function_name(from: Option<IntegerKind>, to: Option<IntegerKind>) -> String {
+    format!(
+        "convert_{}_to_{}",
+        compile_numeric_name(from),
+        compile_numeric_name(to)
+    )
+}
+
+fn compile_division_function_name(kind: IntegerKind) -> String {
+    format!("divide_{}", kind.name())
+}
+
+fn compile_remainder_function_name(kind: IntegerKind) -> String {
+    format!("remainder_{}", kind.name())
+}
+
+fn compile_conversion_function_type(
+    from: Option<IntegerKind>,
+    to: Option<IntegerKind>,
+) -> mir::types::Function {
+    mir::types::Function::new(vec![compile_numeric_type(from)], compile_numeric_type(to))
+}
+
+fn compile_division_function_type(kind: IntegerKind) -> mir::types::Function {
+    mir::types::Function::new(
+        vec![compile_type(kind), compile_type(kind)],
+        compile_type(kind),
+    )
+}
+
+fn compile_numeric_name(kind: Option<IntegerKind>) -> &'static str {
+    kind.map(|kind| kind.name()).unwrap_or("number")
+}
+
+fn compile_numeric_type(kind: Option<IntegerKind>) -> mir::types::Type {
+    match kind {
+        None => mir::types::Type::Number,
+        Some(IntegerKind::Byte) => mir::types::Type::Integer8,
+        Some(IntegerKind::I64 | IntegerKind::U64) => mir::types::Type::Integer64,
+    }
+}
@@ -6,6 +6,7 @@ mod error;
 mod error_type_configuration;
 mod expression;
 mod generic_type_definition;
+mod integer;
 mod list_type_configuration;
 mod main_function;
 mod main_module_configuration;
@@ -13,6 +14,7 @@ mod map_type_configuration;
 mod module;
 mod module_interface;
 mod runtime_function_declaration;
+mod string_builder;
 mod string_type_configuration;
 mod test_function;
 mod test_module_configuration;
@@ -32,7 +34,8 @@ pub use map_type_configuration::{
 pub use string_type_configuration::StringTypeConfiguration;
 pub use test_module_configuration::TestModuleConfiguration;
 use transformation::{
-    equal_operation, hash_calculation, map_context, record_equal_function, record_hash_function,
+    dictionary_passing, equal_operation, hash_calculation, map_context, order_operation,
+    record_equal_function, record_hash_function, record_order_function, type_erasure,
 };
 
 pub fn compile_main(
@@ -70,6 +73,24 @@ pub fn compile_test(
     Ok((module, test_information))
 }
 
+pub fn analyze(module: &Module) -> Result<Module, CompileError> {
+    Ok(hir::analysis::analyze(
+        CompileContext::new(module, None).analysis(),
+        module,
+    )?)
+}
+
+pub fn analyze_main(
+    module: &Module,
+    main_module_configuration: &MainModuleConfiguration,
+) -> Result<Module, CompileError> {
+    analyze(&main_function::compile(module, main_module_configuration)?)
+}
+
+pub fn compile_interface(module: &Module) -> Result<interface::Module, CompileError> {
+    module_interface::compile(module)
+}
+
 fn compile_module(
     module: &Module,
     configuration: Option<&CompileConfiguration>,
@@ -80,10 +101,15 @@ fn compile_module(
 
     Ok((
         {
+            let module = dictionary_passing::transform(&context, &module)?;
+            let context = CompileContext::new(&module, configuration.cloned());
+            let module = type_erasure::transform(&module);
             let module = record_equal_function::transform(&context, &module)?;
             let module = record_hash_function::transform(&context, &module)?;
+            let module = record_order_function::transform(&context, &module)?;
             let module = map_context::module::transform(&context, &module)?;
             let module = equal_operation::module::transform(&context, &module)?;
+            let module = order_operation::module::transform(&context, &module)?;
             let module = hash_calculation::module::transform(&context, &module)?;
             let module = module::compile(&context, &module)?;
 
@@ -111,6 +137,7 @@ mod tests {
 
     static COMBINE_HASH_FUNCTION_DECLARATION: Lazy<FunctionDeclaration> = Lazy::new(|| {
         FunctionDeclaration::new(
+            &HASH_CONFIGURATION.combine_function_name,
             &HASH_CONFIGURATION.combine_function_name,
             types::Function::new(
                 vec![
@@ -192,6 +219,157 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn compile_integer() -> Result<(), CompileError> {
+        let (module, _) = compile_module(&Module::empty().set_function_definitions(vec![
+            FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![],
+                    types::Integer::new(types::IntegerKind::I64, Position::fake()),
+                    Integer::new(types::IntegerKind::I64, 9007199254740993, Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            ),
+        ]))?;
+
+        assert_eq!(
+            module.function_definitions()[0].definition().body(),
+            &mir::ir::Expression::Integer64(9007199254740993)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_integer_conversion() -> Result<(), CompileError> {
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![Argument::new("x", types::Number::new(Position::fake()))],
+                    types::Integer::new(types::IntegerKind::I64, Position::fake()),
+                    Call::new(
+                        None,
+                        BuiltInFunction::new(BuiltInFunctionName::I64, Position::fake()),
+                        vec![Variable::new("x", Position::fake()).into()],
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_integer_division() -> Result<(), CompileError> {
+        let integer_type = types::Integer::new(types::IntegerKind::Byte, Position::fake());
+
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![Argument::new("x", integer_type.clone())],
+                    integer_type,
+                    ArithmeticOperation::new(
+                        None,
+                        ArithmeticOperator::Divide,
+                        Variable::new("x", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_integer_remainder() -> Result<(), CompileError> {
+        let integer_type = types::Integer::new(types::IntegerKind::U64, Position::fake());
+
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![Argument::new("x", integer_type.clone())],
+                    integer_type,
+                    ArithmeticOperation::new(
+                        None,
+                        ArithmeticOperator::Remainder,
+                        Variable::new("x", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_integer_right_shift() -> Result<(), CompileError> {
+        let integer_type = types::Integer::new(types::IntegerKind::I64, Position::fake());
+
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![Argument::new("x", integer_type.clone())],
+                    integer_type,
+                    ArithmeticOperation::new(
+                        None,
+                        ArithmeticOperator::RightShift,
+                        Variable::new("x", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_integer_order_operation() -> Result<(), CompileError> {
+        compile_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![Argument::new(
+                        "x",
+                        types::Integer::new(types::IntegerKind::U64, Position::fake()),
+                    )],
+                    types::Boolean::new(Position::fake()),
+                    OrderOperation::new(
+                        None,
+                        OrderOperator::LessThan,
+                        Variable::new("x", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn compile_string() -> Result<(), CompileError> {
         compile_module(
@@ -285,6 +463,434 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn compile_anonymous_record_equality() {
+        let record_type = types::Record::new("{x none}", Position::fake());
+
+        compile_module(
+            &Module::empty()
+                .set_type_definitions(vec![TypeDefinition::new(
+                    "{x none}",
+                    "{x none}",
+                    vec![types::RecordField::new(
+                        "x",
+                        types::None::new(Position::fake()),
+                    )],
+                    true,
+                    true,
+                    false,
+                    Position::fake(),
+                )])
+                .set_function_declarations(vec![COMBINE_HASH_FUNCTION_DECLARATION.clone()])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new("r", record_type.clone())],
+                        types::Boolean::new(Position::fake()),
+                        EqualityOperation::new(
+                            None,
+                            EqualityOperator::Equal,
+                            Variable::new("r", Position::fake()),
+                            RecordConstruction::new(
+                                record_type,
+                                vec![RecordField::new(
+                                    "x",
+                                    None::new(Position::fake()),
+                                    Position::fake(),
+                                )],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn compile_record_order_operation() {
+        let reference_type = types::Reference::new("foo", Position::fake());
+
+        compile_module(
+            &Module::empty()
+                .set_type_definitions(vec![TypeDefinition::fake(
+                    "foo",
+                    vec![types::RecordField::new(
+                        "x",
+                        types::Number::new(Position::fake()),
+                    )],
+                    false,
+                    false,
+                    false,
+                )])
+                .set_function_declarations(vec![
+                    COMBINE_HASH_FUNCTION_DECLARATION.clone(),
+                    FunctionDeclaration::new(
+                        &HASH_CONFIGURATION.number_hash_function_name,
+                        &HASH_CONFIGURATION.number_hash_function_name,
+                        types::Function::new(
+                            vec![types::Number::new(Position::fake()).into()],
+                            types::Number::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                ])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new("r", reference_type)],
+                        types::Boolean::new(Position::fake()),
+                        OrderOperation::new(
+                            None,
+                            OrderOperator::GreaterThanOrEqual,
+                            Variable::new("r", Position::fake()),
+                            Variable::new("r", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )]),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn compile_generic_function_call() -> Result<(), CompileError> {
+        let variable_type = types::Variable::new("a", Position::fake());
+
+        compile_module(&Module::empty().set_function_definitions(vec![
+            FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![Argument::new("x", variable_type.clone())],
+                    variable_type,
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            ),
+            FunctionDefinition::fake(
+                "g",
+                Lambda::new(
+                    vec![],
+                    types::Number::new(Position::fake()),
+                    Call::new(
+                        None,
+                        Variable::new("f", Position::fake()),
+                        vec![Number::new(42.0, Position::fake()).into()],
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            ),
+        ]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_generic_function_call_with_union_type() -> Result<(), CompileError> {
+        let variable_type = types::Variable::new("a", Position::fake());
+        let union_type = types::Union::new(
+            variable_type.clone(),
+            types::None::new(Position::fake()),
+            Position::fake(),
+        );
+
+        compile_module(&Module::empty().set_function_definitions(vec![
+            FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![Argument::new("x", union_type)],
+                    types::Union::new(
+                        variable_type,
+                        types::None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    IfType::new(
+                        "y",
+                        Variable::new("x", Position::fake()),
+                        vec![IfTypeBranch::new(
+                            types::None::new(Position::fake()),
+                            None::new(Position::fake()),
+                        )],
+                        Some(ElseBranch::new(
+                            None,
+                            Variable::new("y", Position::fake()),
+                            Position::fake(),
+                        )),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            ),
+            FunctionDefinition::fake(
+                "g",
+                Lambda::new(
+                    vec![Argument::new(
+                        "x",
+                        types::Union::new(
+                            types::Number::new(Position::fake()),
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                    )],
+                    types::Union::new(
+                        types::Number::new(Position::fake()),
+                        types::None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    Call::new(
+                        None,
+                        Variable::new("f", Position::fake()),
+                        vec![Variable::new("x", Position::fake()).into()],
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            ),
+        ]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_generic_function_call_with_function_argument() -> Result<(), CompileError> {
+        let argument_type = types::Variable::new("a", Position::fake());
+        let result_type = types::Variable::new("b", Position::fake());
+
+        compile_module(&Module::empty().set_function_definitions(vec![
+            FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![
+                        Argument::new(
+                            "h",
+                            types::Function::new(
+                                vec![argument_type.clone().into()],
+                                result_type.clone(),
+                                Position::fake(),
+                            ),
+                        ),
+                        Argument::new("x", argument_type),
+                    ],
+                    result_type,
+                    Call::new(
+                        None,
+                        Variable::new("h", Position::fake()),
+                        vec![Variable::new("x", Position::fake()).into()],
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            ),
+            FunctionDefinition::fake(
+                "g",
+                Lambda::new(
+                    vec![],
+                    types::ByteString::new(Position::fake()),
+                    Call::new(
+                        None,
+                        Variable::new("f", Position::fake()),
+                        vec![
+                            Lambda::new(
+                                vec![Argument::new("x", types::Number::new(Position::fake()))],
+                                types::ByteString::new(Position::fake()),
+                                ByteString::new("foo", Position::fake()),
+                                Position::fake(),
+                            )
+                            .into(),
+                            Number::new(42.0, Position::fake()).into(),
+                        ],
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            ),
+        ]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_generic_function_value() -> Result<(), CompileError> {
+        let variable_type = types::Variable::new("a", Position::fake());
+
+        compile_module(&Module::empty().set_function_definitions(vec![
+            FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![Argument::new("x", variable_type.clone())],
+                    variable_type,
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            ),
+            FunctionDefinition::fake(
+                "g",
+                Lambda::new(
+                    vec![],
+                    types::Function::new(
+                        vec![types::Number::new(Position::fake()).into()],
+                        types::Number::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    Variable::new("f", Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            ),
+        ]))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_generic_record() -> Result<(), CompileError> {
+        let record_type = types::Record::new("r", Position::fake())
+            .set_arguments(vec![types::None::new(Position::fake()).into()]);
+
+        compile_module(
+            &Module::empty()
+                .set_type_definitions(vec![TypeDefinition::fake(
+                    "r",
+                    vec![types::RecordField::new(
+                        "x",
+                        types::Variable::new("a", Position::fake()),
+                    )],
+                    false,
+                    false,
+                    false,
+                )
+                .set_type_parameters(vec!["a".into()])])
+                .set_function_declarations(vec![COMBINE_HASH_FUNCTION_DECLARATION.clone()])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        RecordDeconstruction::new(
+                            None,
+                            RecordConstruction::new(
+                                record_type,
+                                vec![RecordField::new(
+                                    "x",
+                                    None::new(Position::fake()),
+                                    Position::fake(),
+                                )],
+                                Position::fake(),
+                            ),
+                            "x",
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn compile_constrained_generic_function_call() -> Result<(), CompileError> {
+        let variable_type = types::Variable::new("a", Position::fake())
+            .set_constraint(Some(types::Reference::new("Show", Position::fake()).into()));
+        let method_type = types::Function::new(
+            vec![variable_type.clone().into()],
+            types::ByteString::new(Position::fake()),
+            Position::fake(),
+        );
+
+        compile_module(
+            &Module::empty()
+                .set_type_definitions(vec![TypeDefinition::fake(
+                    "Show",
+                    vec![types::RecordField::new("ToString", method_type.clone())],
+                    false,
+                    false,
+                    false,
+                )])
+                .set_implementations(vec![Implementation::new(
+                    "$implementation:0",
+                    types::Reference::new("Show", Position::fake()),
+                    types::Number::new(Position::fake()),
+                    false,
+                    Position::fake(),
+                )])
+                .set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "ToString",
+                        Lambda::new(
+                            vec![Argument::new("x", variable_type.clone())],
+                            types::ByteString::new(Position::fake()),
+                            Call::new(
+                                None,
+                                Variable::new("ToString", Position::fake()),
+                                vec![Variable::new("x", Position::fake()).into()],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                    FunctionDefinition::fake(
+                        "$implementation:0:ToString",
+                        Lambda::new(
+                            vec![Argument::new("x", types::Number::new(Position::fake()))],
+                            types::ByteString::new(Position::fake()),
+                            ByteString::new("", Position::fake()),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new("x", variable_type)],
+                            types::ByteString::new(Position::fake()),
+                            Call::new(
+                                None,
+                                Variable::new("ToString", Position::fake()),
+                                vec![Variable::new("x", Position::fake()).into()],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                    FunctionDefinition::fake(
+                        "g",
+                        Lambda::new(
+                            vec![],
+                            types::ByteString::new(Position::fake()),
+                            Call::new(
+                                None,
+                                Variable::new("f", Position::fake()),
+                                vec![Number::new(42.0, Position::fake()).into()],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ]),
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn fail_to_compile_duplicate_function_names() {
         let definition = FunctionDefinition::fake(
@@ -7,6 +7,7 @@ pub static LIST_TYPE_CONFIGURATION: Lazy<ListTypeConfiguration> =
         empty_list_function_name: "emptyList".into(),
         concatenate_function_name: "concatenateLists".into(),
         equal_function_name: "equalLists".into(),
+        less_than_function_name: "lessThanLists".into(),
         prepend_function_name: "prependToLists".into(),
         deconstruct_function_name: "deconstruct".into(),
         lazy_function_name: "lazy".into(),
@@ -22,6 +23,7 @@ pub struct ListTypeConfiguration {
     pub empty_list_function_name: String,
     pub concatenate_function_name: String,
     pub equal_function_name: String,
+    pub less_than_function_name: String,
     pub prepend_function_name: String,
     pub deconstruct_function_name: String,
     pub lazy_function_name: String,
@@ -68,6 +68,7 @@ pub fn compile(
         module.type_aliases().to_vec(),
         module.foreign_declarations().to_vec(),
         module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
         module
             .function_definitions()
             .iter()
@@ -1,5 +1,5 @@
 use super::{context::CompileContext, expression, generic_type_definition, type_, CompileError};
-use crate::runtime_function_declaration;
+use crate::{runtime_function_declaration, string_builder};
 use hir::{analysis::AnalysisError, ir::*};
 
 pub fn compile(context: &CompileContext, module: &Module) -> Result<mir::ir::Module, CompileError> {
@@ -11,6 +11,7 @@ pub fn compile(context: &CompileContext, module: &Module) -> Result<mir::ir::Mod
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
             .chain(generic_type_definition::compile(context, module)?)
+            .chain([string_builder::compile_type_definition()])
             .collect(),
         module
             .foreign_declarations()
@@ -29,11 +30,7 @@ pub fn compile(context: &CompileContext, module: &Module) -> Result<mir::ir::Mod
             })
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
-            .chain(if context.configuration().is_ok() {
-                runtime_function_declaration::compile(context)?
-            } else {
-                vec![]
-            })
+            .chain(runtime_function_declaration::compile(context)?)
             .collect(),
         module
             .function_definitions()
@@ -82,7 +79,8 @@ fn compile_type_definition(
                 .iter()
                 .map(|field| type_::compile(context, field.type_()))
                 .collect::<Result<_, _>>()?,
-        ),
+        )
+        .set_unboxed(type_definition.is_newtype()),
     ))
 }
 
@@ -161,6 +159,7 @@ mod tests {
         assert_eq!(
             compile(&context, &module),
             Ok(mir::ir::Module::empty()
+                .set_type_definitions(vec![string_builder::compile_type_definition()])
                 .set_foreign_declarations(runtime_function_declaration::compile(&context).unwrap())
                 .set_foreign_definitions(vec![mir::ir::ForeignDefinition::new(
                     "foo",
@@ -196,6 +195,7 @@ mod tests {
         assert_eq!(
             compile(&context, &module),
             Ok(mir::ir::Module::empty()
+                .set_type_definitions(vec![string_builder::compile_type_definition()])
                 .set_foreign_declarations(runtime_function_declaration::compile(&context).unwrap())
                 .set_foreign_definitions(vec![mir::ir::ForeignDefinition::new(
                     "foo",
@@ -210,4 +210,38 @@ mod tests {
                 )]))
         );
     }
+
+    #[test]
+    fn compile_newtype_definition() {
+        let module = Module::empty().set_type_definitions(vec![TypeDefinition::new(
+            "foo",
+            "foo",
+            vec![types::RecordField::new(
+                "x",
+                types::Number::new(Position::fake()),
+            )],
+            false,
+            false,
+            false,
+            Position::fake(),
+        )
+        .set_newtype(true)]);
+        let context = create_context(&module);
+
+        assert_eq!(
+            compile(&context, &module),
+            Ok(mir::ir::Module::empty()
+                .set_type_definitions(vec![
+                    mir::ir::TypeDefinition::new(
+                        "foo",
+                        mir::types::RecordBody::new(vec![mir::types::Type::Number])
+                            .set_unboxed(true)
+                    ),
+                    string_builder::compile_type_definition(),
+                ])
+                .set_foreign_declarations(
+                    runtime_function_declaration::compile(&context).unwrap()
+                ))
+        );
+    }
 }
@@ -15,6 +15,8 @@ pub fn compile(module: &ir::Module) -> Result<interface::Module, CompileError> {
                     definition.is_public() && !definition.is_external(),
                     definition.position().clone(),
                 )
+                .set_newtype(definition.is_newtype())
+                .set_type_parameters(definition.type_parameters().to_vec())
             })
             .collect(),
         module
@@ -28,6 +30,7 @@ pub fn compile(module: &ir::Module) -> Result<interface::Module, CompileError> {
                     alias.is_public() && !alias.is_external(),
                     alias.position().clone(),
                 )
+                .set_type_parameters(alias.type_parameters().to_vec())
             })
             .collect(),
         module
@@ -46,6 +49,19 @@ pub fn compile(module: &ir::Module) -> Result<interface::Module, CompileError> {
                 }
             })
             .collect(),
+        module
+            .implementations()
+            .iter()
+            .filter(|implementation| !implementation.is_external())
+            .map(|implementation| {
+                interface::Implementation::new(
+                    implementation.name(),
+                    implementation.interface().clone(),
+                    implementation.type_().clone(),
+                    implementation.position().clone(),
+                )
+            })
+            .collect(),
     ))
 }
 
@@ -62,7 +78,7 @@ mod tests {
     fn compile_empty_module() {
         assert_eq!(
             compile(&ir::Module::empty()),
-            Ok(interface::Module::new(vec![], vec![], vec![]))
+            Ok(interface::Module::new(vec![], vec![], vec![], vec![]))
         );
     }
 
@@ -81,7 +97,7 @@ mod tests {
                     false,
                 )
             ])),
-            Ok(interface::Module::new(vec![], vec![], vec![]))
+            Ok(interface::Module::new(vec![], vec![], vec![], vec![]))
         );
     }
 }
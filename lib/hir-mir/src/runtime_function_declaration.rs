@@ -1,4 +1,4 @@
-use crate::{context::CompileContext, type_, CompileError};
+use crate::{context::CompileContext, integer, string_builder, type_, CompileError};
 
 pub const LOCAL_DEBUG_FUNCTION_NAME: &str = "__debug";
 pub const LOCAL_RACE_FUNCTION_NAME: &str = "__race";
@@ -8,6 +8,20 @@ pub const LOCAL_SPAWN_FUNCTION_NAME: &str = "__spawn";
 // because they might be defined in the same file. So we first alias them to use
 // them in code generation.
 pub fn compile(context: &CompileContext) -> Result<Vec<mir::ir::ForeignDeclaration>, CompileError> {
+    Ok(if context.configuration().is_ok() {
+        compile_configured(context)?
+    } else {
+        vec![]
+    }
+    .into_iter()
+    .chain(integer::compile_foreign_declarations())
+    .chain(string_builder::compile_foreign_declarations())
+    .collect())
+}
+
+fn compile_configured(
+    context: &CompileContext,
+) -> Result<Vec<mir::ir::ForeignDeclaration>, CompileError> {
     let configuration = context.configuration()?;
 
     Ok(vec![
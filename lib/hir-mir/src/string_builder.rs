@@ -0,0 +1,93 @@
+const FOREIGN_FUNCTION_PREFIX: &str = "_pen_";
+const LOCAL_FUNCTION_PREFIX: &str = "__";
+const TYPE_NAME: &str = "__string_builder";
+
+const CREATE_FUNCTION_NAME: &str = "string_builder_create";
+const APPEND_FUNCTION_NAME: &str = "string_builder_append";
+const BUILD_FUNCTION_NAME: &str = "string_builder_build";
+
+// Strings are concatenated by a builder so that their bytes are copied into a
+// result string only once.
+pub fn compile_concatenation(strings: Vec<mir::ir::Expression>) -> mir::ir::Expression {
+    if strings.len() <= 1 {
+        return strings
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| mir::ir::ByteString::new(vec![]).into());
+    }
+
+    let builder_type = compile_type();
+    let capacity = strings.len() as f64;
+
+    mir::ir::Call::new(
+        mir::types::Function::new(vec![builder_type.clone()], mir::types::Type::ByteString),
+        compile_function_variable(BUILD_FUNCTION_NAME),
+        vec![strings.into_iter().fold(
+            mir::ir::Call::new(
+                mir::types::Function::new(vec![mir::types::Type::Number], builder_type.clone()),
+                compile_function_variable(CREATE_FUNCTION_NAME),
+                vec![mir::ir::Expression::Number(capacity)],
+            )
+            .into(),
+            |builder, string| {
+                mir::ir::Call::new(
+                    compile_append_function_type(),
+                    compile_function_variable(APPEND_FUNCTION_NAME),
+                    vec![builder, string],
+                )
+                .into()
+            },
+        )],
+    )
+    .into()
+}
+
+pub fn compile_type_definition() -> mir::ir::TypeDefinition {
+    mir::ir::TypeDefinition::new(
+        TYPE_NAME,
+        mir::types::RecordBody::new(vec![mir::types::Type::Variant]),
+    )
+}
+
+pub fn compile_foreign_declarations() -> Vec<mir::ir::ForeignDeclaration> {
+    let builder_type = compile_type();
+
+    [
+        (
+            CREATE_FUNCTION_NAME,
+            mir::types::Function::new(vec![mir::types::Type::Number], builder_type.clone()),
+        ),
+        (APPEND_FUNCTION_NAME, compile_append_function_type()),
+        (
+            BUILD_FUNCTION_NAME,
+            mir::types::Function::new(vec![builder_type], mir::types::Type::ByteString),
+        ),
+    ]
+    .into_iter()
+    .map(|(name, type_)| {
+        mir::ir::ForeignDeclaration::new(
+            LOCAL_FUNCTION_PREFIX.to_owned() + name,
+            FOREIGN_FUNCTION_PREFIX.to_owned() + name,
+            type_,
+            mir::ir::CallingConvention::Target,
+        )
+    })
+    .collect()
+}
+
+fn compile_type() -> mir::types::Type {
+    mir::types::Record::new(TYPE_NAME).into()
+}
+
+fn compile_append_function_type() -> mir::types::Function {
+    let builder_type = compile_type();
+
+    mir::types::Function::new(
+        vec![builder_type.clone(), mir::types::Type::ByteString],
+        builder_type,
+    )
+}
+
+fn compile_function_variable(name: &str) -> mir::ir::Variable {
+    mir::ir::Variable::new(LOCAL_FUNCTION_PREFIX.to_owned() + name)
+}
@@ -5,9 +5,11 @@ use once_cell::sync::Lazy;
 pub static STRING_TYPE_CONFIGURATION: Lazy<StringTypeConfiguration> =
     Lazy::new(|| StringTypeConfiguration {
         equal_function_name: "_equalStrings".into(),
+        less_than_function_name: "_lessThanStrings".into(),
     });
 
 #[derive(Clone, Debug)]
 pub struct StringTypeConfiguration {
     pub equal_function_name: String,
+    pub less_than_function_name: String,
 }
@@ -1,5 +1,5 @@
 use super::{error::CompileError, test_module_configuration::TestModuleConfiguration};
-use hir::{ir::*, types};
+use hir::{analysis::record_field_resolver, ir::*, types};
 use std::{
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
@@ -16,10 +16,11 @@ pub fn compile(
 ) -> Result<(Module, test_info::Module), CompileError> {
     let position = module.position();
 
+    let default_names = record_field_resolver::collect_default_function_names(module);
     let definitions = module
         .function_definitions()
         .iter()
-        .filter(|definition| definition.is_public())
+        .filter(|definition| definition.is_public() && !default_names.contains(definition.name()))
         .collect::<Vec<_>>();
 
     Ok((
@@ -28,6 +29,7 @@ pub fn compile(
             module.type_aliases().to_vec(),
             module.foreign_declarations().to_vec(),
             module.function_declarations().to_vec(),
+            module.implementations().to_vec(),
             module
                 .function_definitions()
                 .iter()
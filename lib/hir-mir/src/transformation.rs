@@ -1,6 +1,9 @@
 pub mod boolean_operation;
 mod collection_type;
+pub mod dictionary_passing;
 pub mod equal_operation;
+pub mod generic_call;
+pub mod generic_record;
 pub mod hash_calculation;
 pub mod if_list;
 pub mod if_map;
@@ -8,6 +11,9 @@ pub mod list_literal;
 pub mod map_context;
 pub mod map_literal;
 pub mod not_equal_operation;
+pub mod order_operation;
 pub mod record_equal_function;
 pub mod record_hash_function;
+pub mod record_order_function;
 mod record_type_information;
+pub mod type_erasure;
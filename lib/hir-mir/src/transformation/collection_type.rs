@@ -2,8 +2,8 @@ use crate::{context::CompileContext, CompileError};
 use fnv::FnvHashSet;
 use hir::{
     analysis::{
-        expression_visitor, type_canonicalizer, type_comparability_checker, type_visitor,
-        AnalysisError,
+        expression_visitor, type_canonicalizer, type_comparability_checker,
+        type_orderability_checker, type_visitor, AnalysisError,
     },
     ir::*,
     types::{self, Type},
@@ -41,6 +41,39 @@ pub fn collect_comparable_parameter_types(
     context: &CompileContext,
     module: &Module,
 ) -> Result<FnvHashSet<Type>, AnalysisError> {
+    filter_parameter_types(context, module, |type_| {
+        type_comparability_checker::check(type_, context.types(), context.records())
+    })
+}
+
+pub fn collect_orderable_parameter_types(
+    context: &CompileContext,
+    module: &Module,
+) -> Result<FnvHashSet<Type>, AnalysisError> {
+    filter_parameter_types(context, module, |type_| {
+        type_orderability_checker::check(type_, context.types(), context.records())
+    })
+}
+
+fn filter_parameter_types(
+    context: &CompileContext,
+    module: &Module,
+    check: impl Fn(&Type) -> Result<bool, AnalysisError>,
+) -> Result<FnvHashSet<Type>, AnalysisError> {
+    Ok(collect_parameter_types(module)
+        .iter()
+        .map(|type_| {
+            let type_ = type_canonicalizer::canonicalize(type_, context.types())?;
+
+            Ok(if check(&type_)? { Some(type_) } else { None })
+        })
+        .collect::<Result<FnvHashSet<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+fn collect_parameter_types(module: &Module) -> FnvHashSet<&Type> {
     let mut types = FnvHashSet::default();
 
     type_visitor::visit(module, |type_| match type_ {
@@ -64,37 +97,32 @@ pub fn collect_comparable_parameter_types(
             types.insert(list.type_());
         }
         Expression::ListComprehension(comprehension) => {
-            types.extend(comprehension.input_type());
-            types.insert(comprehension.output_type());
+            types.insert(comprehension.type_());
+            types.extend(collect_iteratee_element_types(comprehension.branches()));
         }
         Expression::Map(map) => {
             types.extend([map.key_type(), map.value_type()]);
         }
-        Expression::MapIterationComprehension(comprehension) => {
-            types.extend(comprehension.key_type());
-            types.extend(comprehension.value_type());
-            types.insert(comprehension.element_type());
+        Expression::MapComprehension(comprehension) => {
+            types.extend([comprehension.key_type(), comprehension.value_type()]);
+            types.extend(collect_iteratee_element_types(comprehension.branches()));
         }
         _ => {}
     });
 
-    Ok(types
-        .iter()
-        .map(|type_| {
-            let type_ = type_canonicalizer::canonicalize(type_, context.types())?;
+    types
+}
 
-            Ok(
-                if type_comparability_checker::check(&type_, context.types(), context.records())? {
-                    Some(type_)
-                } else {
-                    None
-                },
-            )
+fn collect_iteratee_element_types(branches: &[ListComprehensionBranch]) -> Vec<&Type> {
+    branches
+        .iter()
+        .flat_map(|branch| branch.iteratees())
+        .flat_map(|iteratee| match iteratee.type_() {
+            Some(Type::List(list_type)) => vec![list_type.element()],
+            Some(Type::Map(map_type)) => vec![map_type.key(), map_type.value()],
+            _ => vec![],
         })
-        .collect::<Result<FnvHashSet<_>, _>>()?
-        .into_iter()
-        .flatten()
-        .collect())
+        .collect()
 }
 
 #[cfg(test)]
@@ -0,0 +1,300 @@
+use super::generic_call;
+use crate::{context::CompileContext, error::CompileError};
+use hir::{
+    analysis::{
+        expression_transformer, interface_resolver, type_canonicalizer, type_extractor,
+        type_instantiator, type_transformer, AnalysisError,
+    },
+    ir::*,
+    types::{self, Type},
+};
+use position::Position;
+
+const DICTIONARY_PREFIX: &str = "$dictionary:";
+
+// Functions with type variables constrained by interfaces take dictionaries of
+// interface methods as their first arguments.
+pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, CompileError> {
+    let module = expression_transformer::transform(module, &|expression| match expression {
+        Expression::Call(call) => transform_call(context, module, call),
+        _ => Ok(expression.clone()),
+    })?;
+    let method_definitions = compile_interface_method_definitions(context, &module)?;
+
+    Ok(Module::new(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module
+            .function_declarations()
+            .iter()
+            .map(|declaration| {
+                FunctionDeclaration::new(
+                    declaration.name(),
+                    declaration.original_name(),
+                    transform_function_type(declaration.type_()),
+                    declaration.position().clone(),
+                )
+            })
+            .collect(),
+        module.implementations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| {
+                transform_function_definition(
+                    method_definitions
+                        .iter()
+                        .find(|method| method.name() == definition.name())
+                        .unwrap_or(definition),
+                )
+            })
+            .collect(),
+        module.position().clone(),
+    ))
+}
+
+fn transform_function_definition(definition: &FunctionDefinition) -> FunctionDefinition {
+    let lambda = definition.lambda();
+    let variables = interface_resolver::collect_constrained_variables(
+        &type_extractor::extract_from_lambda(lambda).into(),
+    );
+
+    if variables.is_empty() {
+        return definition.clone();
+    }
+
+    FunctionDefinition::new(
+        definition.name(),
+        definition.original_name(),
+        Lambda::new(
+            variables
+                .iter()
+                .map(|variable| {
+                    Argument::new(
+                        dictionary_name(variable.name()),
+                        constraint(variable).clone(),
+                    )
+                })
+                .chain(lambda.arguments().iter().cloned())
+                .collect(),
+            lambda.result_type().clone(),
+            lambda.body().clone(),
+            lambda.position().clone(),
+        ),
+        definition.foreign_definition_configuration().cloned(),
+        definition.is_public(),
+        definition.position().clone(),
+    )
+}
+
+fn transform_function_type(type_: &types::Function) -> types::Function {
+    types::Function::new(
+        interface_resolver::collect_constrained_variables(&type_.clone().into())
+            .iter()
+            .map(|variable| constraint(variable).clone())
+            .chain(type_.arguments().iter().cloned())
+            .collect(),
+        type_.result().clone(),
+        type_.position().clone(),
+    )
+}
+
+fn transform_call(
+    context: &CompileContext,
+    module: &Module,
+    call: &Call,
+) -> Result<Expression, CompileError> {
+    let generic_type = match call.function() {
+        Expression::Variable(variable) => match context.generic_functions().get(variable.name()) {
+            Some(type_) => type_,
+            None => return Ok(call.clone().into()),
+        },
+        _ => return Ok(call.clone().into()),
+    };
+    let variables = interface_resolver::collect_constrained_variables(&generic_type.clone().into());
+
+    if variables.is_empty() {
+        return Ok(call.clone().into());
+    }
+
+    let instance_type = call
+        .function_type()
+        .ok_or_else(|| AnalysisError::TypeNotInferred(call.position().clone()))?;
+    let instance_type = type_canonicalizer::canonicalize_function(instance_type, context.types())?
+        .ok_or_else(|| AnalysisError::FunctionExpected(instance_type.clone()))?;
+    let bindings =
+        type_instantiator::resolve_variables(generic_type, &instance_type, context.types())?;
+
+    Ok(Call::new(
+        Some(
+            types::Function::new(
+                variables
+                    .iter()
+                    .map(|variable| constraint(variable).clone())
+                    .chain(instance_type.arguments().iter().cloned())
+                    .collect(),
+                instance_type.result().clone(),
+                instance_type.position().clone(),
+            )
+            .into(),
+        ),
+        call.function().clone(),
+        variables
+            .iter()
+            .map(|variable| {
+                compile_dictionary(
+                    context,
+                    module,
+                    constraint(variable),
+                    &bindings
+                        .get(variable.name())
+                        .cloned()
+                        .unwrap_or_else(|| types::Any::new(call.position().clone()).into()),
+                    call.position(),
+                )
+            })
+            .chain(call.arguments().iter().cloned().map(Ok))
+            .collect::<Result<_, _>>()?,
+        call.position().clone(),
+    )
+    .into())
+}
+
+fn compile_dictionary(
+    context: &CompileContext,
+    module: &Module,
+    interface: &Type,
+    type_: &Type,
+    position: &Position,
+) -> Result<Expression, CompileError> {
+    if let Type::Variable(variable) = type_canonicalizer::canonicalize(type_, context.types())? {
+        return Ok(Variable::new(dictionary_name(variable.name()), position.clone()).into());
+    }
+
+    let implementation = interface_resolver::resolve_implementation(
+        context.analysis(),
+        module.implementations(),
+        interface,
+        type_,
+    )?
+    .ok_or_else(|| AnalysisError::ImplementationNotFound(interface.clone(), type_.clone()))?;
+
+    Ok(RecordConstruction::new(
+        interface.clone(),
+        interface_resolver::resolve(context.analysis(), interface)?
+            .ok_or_else(|| AnalysisError::InterfaceExpected(interface.clone()))?
+            .iter()
+            .map(|field| {
+                Ok(RecordField::new(
+                    field.name(),
+                    generic_call::transform_argument(
+                        context,
+                        &Variable::new(
+                            interface_resolver::resolve_implementation_function_name(
+                                implementation.name(),
+                                field.name(),
+                            ),
+                            position.clone(),
+                        )
+                        .into(),
+                        field.type_(),
+                        &type_transformer::transform_type(field.type_(), &|other| match other {
+                            Type::Variable(_) => type_.clone(),
+                            _ => other.clone(),
+                        }),
+                    )?,
+                    position.clone(),
+                ))
+            })
+            .collect::<Result<_, CompileError>>()?,
+        position.clone(),
+    )
+    .into())
+}
+
+// Bodies of interface methods are replaced with calls of methods in
+// dictionaries.
+fn compile_interface_method_definitions(
+    context: &CompileContext,
+    module: &Module,
+) -> Result<Vec<FunctionDefinition>, CompileError> {
+    let mut definitions = vec![];
+
+    for type_definition in module.type_definitions() {
+        if type_definition.is_external() {
+            continue;
+        }
+
+        let interface =
+            types::Record::new(type_definition.name(), type_definition.position().clone());
+        let fields = match interface_resolver::resolve(context.analysis(), &interface.into())? {
+            Some(fields) => fields,
+            None => continue,
+        };
+        let prefix = type_definition
+            .name()
+            .strip_suffix(type_definition.original_name())
+            .unwrap_or_default();
+
+        for field in fields {
+            let name = format!("{}{}", prefix, field.name());
+            let definition = match module
+                .function_definitions()
+                .iter()
+                .find(|definition| definition.name() == name)
+            {
+                Some(definition) => definition,
+                None => continue,
+            };
+            let variable = match interface_resolver::collect_constrained_variables(field.type_())
+                .into_iter()
+                .next()
+            {
+                Some(variable) => variable,
+                None => continue,
+            };
+            let lambda = definition.lambda();
+            let position = lambda.position();
+
+            definitions.push(FunctionDefinition::new(
+                definition.name(),
+                definition.original_name(),
+                Lambda::new(
+                    lambda.arguments().to_vec(),
+                    lambda.result_type().clone(),
+                    Call::new(
+                        Some(field.type_().clone()),
+                        RecordDeconstruction::new(
+                            Some(constraint(&variable).clone()),
+                            Variable::new(dictionary_name(variable.name()), position.clone()),
+                            field.name(),
+                            position.clone(),
+                        ),
+                        lambda
+                            .arguments()
+                            .iter()
+                            .map(|argument| Variable::new(argument.name(), position.clone()).into())
+                            .collect(),
+                        position.clone(),
+                    ),
+                    position.clone(),
+                ),
+                definition.foreign_definition_configuration().cloned(),
+                definition.is_public(),
+                definition.position().clone(),
+            ));
+        }
+    }
+
+    Ok(definitions)
+}
+
+fn constraint(variable: &types::Variable) -> &Type {
+    // Only constrained variables are collected.
+    variable.constraint().unwrap()
+}
+
+fn dictionary_name(name: &str) -> String {
+    format!("{}{}", DICTIONARY_PREFIX, name)
+}
@@ -117,7 +117,7 @@ fn transform_canonical(
             .into()
         }
         Type::None(_) => Boolean::new(true, position.clone()).into(),
-        Type::Number(_) => EqualityOperation::new(
+        Type::Integer(_) | Type::Number(_) => EqualityOperation::new(
             Some(type_.clone()),
             EqualityOperator::Equal,
             lhs.clone(),
@@ -220,7 +220,7 @@ fn transform_canonical(
             rhs,
             position,
         )?,
-        Type::Any(_) | Type::Error(_) | Type::Function(_) => {
+        Type::Any(_) | Type::Error(_) | Type::Function(_) | Type::Variable(_) => {
             return Err(AnalysisError::TypeNotComparable(type_.clone()).into())
         }
     })
@@ -0,0 +1,379 @@
+use crate::{context::CompileContext, downcast, error::CompileError};
+use hir::{
+    analysis::{
+        type_canonicalizer, type_equality_checker, type_instantiator, type_transformer,
+        AnalysisError,
+    },
+    ir::*,
+    types::{self, Type},
+};
+use position::Position;
+
+const FUNCTION_NAME: &str = "$generic_function";
+const ARGUMENT_PREFIX: &str = "$generic_argument_";
+const ELEMENT_NAME: &str = "$generic_element";
+const KEY_NAME: &str = "$generic_key";
+const VALUE_NAME: &str = "$generic_value";
+
+// Generic functions take and return values of their type variables as any
+// types. So we convert arguments and results between instantiated and erased
+// types at their calls.
+pub fn transform(
+    context: &CompileContext,
+    call: &Call,
+) -> Result<Option<Expression>, CompileError> {
+    let generic_type = if let Expression::Variable(variable) = call.function() {
+        if let Some(type_) = context.generic_functions().get(variable.name()) {
+            type_
+        } else {
+            return Ok(None);
+        }
+    } else {
+        return Ok(None);
+    };
+    let erased_type = erase(&generic_type.clone().into());
+    let instance_type = call
+        .function_type()
+        .ok_or_else(|| AnalysisError::TypeNotInferred(call.position().clone()))?;
+
+    if type_equality_checker::check(instance_type, &erased_type, context.types())? {
+        return Ok(None);
+    }
+
+    let instance_type = canonicalize_function(context, instance_type)?;
+
+    Ok(Some(transform_result(
+        context,
+        Call::new(
+            Some(erased_type),
+            call.function().clone(),
+            call.arguments()
+                .iter()
+                .zip(
+                    generic_type
+                        .arguments()
+                        .iter()
+                        .zip(instance_type.arguments()),
+                )
+                .map(|(argument, (generic_type, instance_type))| {
+                    transform_argument(context, argument, generic_type, instance_type)
+                })
+                .collect::<Result<_, _>>()?,
+            call.position().clone(),
+        ),
+        generic_type.result(),
+        instance_type.result(),
+    )?))
+}
+
+// Convert a value of an instance type into one of an erased type.
+pub(crate) fn transform_argument(
+    context: &CompileContext,
+    expression: &Expression,
+    generic_type: &Type,
+    instance_type: &Type,
+) -> Result<Expression, CompileError> {
+    let position = expression.position();
+    let generic_type = &type_canonicalizer::canonicalize(generic_type, context.types())?;
+
+    Ok(match generic_type {
+        _ if !type_instantiator::is_generic(generic_type) => expression.clone(),
+        Type::Function(generic_type) => {
+            let instance_type = canonicalize_function(context, instance_type)?;
+
+            Let::new(
+                Some(FUNCTION_NAME.into()),
+                Some(instance_type.clone().into()),
+                expression.clone(),
+                Lambda::new(
+                    compile_arguments(generic_type.arguments().iter().map(erase)),
+                    erase(generic_type.result()),
+                    transform_argument(
+                        context,
+                        &Call::new(
+                            Some(instance_type.clone().into()),
+                            Variable::new(FUNCTION_NAME, position.clone()),
+                            generic_type
+                                .arguments()
+                                .iter()
+                                .zip(instance_type.arguments())
+                                .enumerate()
+                                .map(|(index, (generic_type, instance_type))| {
+                                    transform_result(
+                                        context,
+                                        Variable::new(argument_name(index), position.clone()),
+                                        generic_type,
+                                        instance_type,
+                                    )
+                                })
+                                .collect::<Result<_, _>>()?,
+                            position.clone(),
+                        )
+                        .into(),
+                        generic_type.result(),
+                        instance_type.result(),
+                    )?,
+                    position.clone(),
+                ),
+                position.clone(),
+            )
+            .into()
+        }
+        // Lists and maps hold their elements as any types at runtime. But we
+        // need to convert elements whose erased types have different runtime
+        // type information from their instance types.
+        Type::List(generic_type) if is_element_converted(generic_type.element()) => {
+            let instance_type = canonicalize_list(context, instance_type)?;
+
+            transform_list(
+                expression,
+                &instance_type,
+                &erase(generic_type.element()),
+                transform_argument(
+                    context,
+                    &compile_element(instance_type.element(), position),
+                    generic_type.element(),
+                    instance_type.element(),
+                )?,
+            )
+        }
+        Type::Map(generic_type) if is_element_converted(generic_type.value()) => {
+            let instance_type = canonicalize_map(context, instance_type)?;
+
+            transform_map(
+                expression,
+                &instance_type,
+                &erase(generic_type.value()),
+                transform_argument(
+                    context,
+                    &Variable::new(VALUE_NAME, position.clone()).into(),
+                    generic_type.value(),
+                    instance_type.value(),
+                )?,
+            )
+        }
+        Type::List(_) | Type::Map(_) | Type::Record(_) => expression.clone(),
+        Type::Union(_) if is_variant(context, instance_type)? => expression.clone(),
+        _ => TypeCoercion::new(
+            instance_type.clone(),
+            erase(generic_type),
+            expression.clone(),
+            position.clone(),
+        )
+        .into(),
+    })
+}
+
+// Convert a value of an erased type into one of an instance type.
+pub(crate) fn transform_result(
+    context: &CompileContext,
+    expression: impl Into<Expression>,
+    generic_type: &Type,
+    instance_type: &Type,
+) -> Result<Expression, CompileError> {
+    let expression = expression.into();
+    let position = expression.position().clone();
+    let generic_type = &type_canonicalizer::canonicalize(generic_type, context.types())?;
+
+    Ok(match generic_type {
+        _ if !type_instantiator::is_generic(generic_type) => expression,
+        Type::Function(generic_type) => {
+            let instance_type = canonicalize_function(context, instance_type)?;
+
+            Let::new(
+                Some(FUNCTION_NAME.into()),
+                Some(erase(&generic_type.clone().into())),
+                expression,
+                Lambda::new(
+                    compile_arguments(instance_type.arguments().iter().cloned()),
+                    instance_type.result().clone(),
+                    transform_result(
+                        context,
+                        Call::new(
+                            Some(erase(&generic_type.clone().into())),
+                            Variable::new(FUNCTION_NAME, position.clone()),
+                            generic_type
+                                .arguments()
+                                .iter()
+                                .zip(instance_type.arguments())
+                                .enumerate()
+                                .map(|(index, (generic_type, instance_type))| {
+                                    transform_argument(
+                                        context,
+                                        &Variable::new(argument_name(index), position.clone())
+                                            .into(),
+                                        generic_type,
+                                        instance_type,
+                                    )
+                                })
+                                .collect::<Result<_, _>>()?,
+                            position.clone(),
+                        ),
+                        generic_type.result(),
+                        instance_type.result(),
+                    )?,
+                    position.clone(),
+                ),
+                position,
+            )
+            .into()
+        }
+        Type::List(generic_type) if is_element_converted(generic_type.element()) => {
+            let instance_type = canonicalize_list(context, instance_type)?;
+            let element_type = erase(generic_type.element());
+
+            transform_list(
+                &expression,
+                &types::List::new(element_type.clone(), position.clone()),
+                instance_type.element(),
+                transform_result(
+                    context,
+                    compile_element(&element_type, &position),
+                    generic_type.element(),
+                    instance_type.element(),
+                )?,
+            )
+        }
+        Type::Map(generic_type) if is_element_converted(generic_type.value()) => {
+            let instance_type = canonicalize_map(context, instance_type)?;
+
+            transform_map(
+                &expression,
+                &types::Map::new(
+                    instance_type.key().clone(),
+                    erase(generic_type.value()),
+                    position.clone(),
+                ),
+                instance_type.value(),
+                transform_result(
+                    context,
+                    Variable::new(VALUE_NAME, position.clone()),
+                    generic_type.value(),
+                    instance_type.value(),
+                )?,
+            )
+        }
+        Type::List(_) | Type::Map(_) | Type::Record(_) => expression,
+        Type::Union(_) if is_variant(context, instance_type)? => expression,
+        _ => downcast::compile(context, &erase(generic_type), instance_type, &expression)?,
+    })
+}
+
+// Values of generic function, list, and map types are converted into `any`
+// types with type information of their erased types.
+fn is_element_converted(type_: &Type) -> bool {
+    type_instantiator::is_generic(type_)
+        && matches!(type_, Type::Function(_) | Type::List(_) | Type::Map(_))
+}
+
+// Values of union types have the same representation regardless of their
+// member types.
+fn is_variant(context: &CompileContext, type_: &Type) -> Result<bool, CompileError> {
+    Ok(type_canonicalizer::canonicalize(type_, context.types())?.is_variant())
+}
+
+fn transform_list(
+    expression: &Expression,
+    type_: &types::List,
+    element_type: &Type,
+    element: Expression,
+) -> Expression {
+    let position = expression.position();
+
+    ListComprehension::new(
+        element_type.clone(),
+        element,
+        vec![ListComprehensionBranch::new(
+            vec![ELEMENT_NAME.into()],
+            vec![ListComprehensionIteratee::new(
+                Some(type_.clone().into()),
+                expression.clone(),
+            )],
+            None,
+            position.clone(),
+        )],
+        position.clone(),
+    )
+    .into()
+}
+
+fn transform_map(
+    expression: &Expression,
+    type_: &types::Map,
+    value_type: &Type,
+    value: Expression,
+) -> Expression {
+    let position = expression.position();
+
+    MapComprehension::new(
+        type_.key().clone(),
+        value_type.clone(),
+        Variable::new(KEY_NAME, position.clone()),
+        value,
+        vec![ListComprehensionBranch::new(
+            vec![KEY_NAME.into(), VALUE_NAME.into()],
+            vec![ListComprehensionIteratee::new(
+                Some(type_.clone().into()),
+                expression.clone(),
+            )],
+            None,
+            position.clone(),
+        )],
+        position.clone(),
+    )
+    .into()
+}
+
+// Elements in list comprehension are lazily evaluated.
+fn compile_element(type_: &Type, position: &Position) -> Expression {
+    Call::new(
+        Some(types::Function::new(vec![], type_.clone(), position.clone()).into()),
+        Variable::new(ELEMENT_NAME, position.clone()),
+        vec![],
+        position.clone(),
+    )
+    .into()
+}
+
+fn compile_arguments(types: impl IntoIterator<Item = Type>) -> Vec<Argument> {
+    types
+        .into_iter()
+        .enumerate()
+        .map(|(index, type_)| Argument::new(argument_name(index), type_))
+        .collect()
+}
+
+fn canonicalize_function(
+    context: &CompileContext,
+    type_: &Type,
+) -> Result<types::Function, CompileError> {
+    Ok(
+        type_canonicalizer::canonicalize_function(type_, context.types())?
+            .ok_or_else(|| AnalysisError::FunctionExpected(type_.clone()))?,
+    )
+}
+
+fn canonicalize_list(context: &CompileContext, type_: &Type) -> Result<types::List, CompileError> {
+    Ok(
+        type_canonicalizer::canonicalize_list(type_, context.types())?
+            .ok_or_else(|| AnalysisError::ListExpected(type_.clone()))?,
+    )
+}
+
+fn canonicalize_map(context: &CompileContext, type_: &Type) -> Result<types::Map, CompileError> {
+    Ok(
+        type_canonicalizer::canonicalize_map(type_, context.types())?
+            .ok_or_else(|| AnalysisError::MapExpected(type_.clone()))?,
+    )
+}
+
+fn argument_name(index: usize) -> String {
+    format!("{}{}", ARGUMENT_PREFIX, index)
+}
+
+pub fn erase(type_: &Type) -> Type {
+    type_transformer::transform_type(type_, &|type_| match type_ {
+        Type::Variable(variable) => types::Any::new(variable.position().clone()).into(),
+        _ => type_.clone(),
+    })
+}
@@ -0,0 +1,217 @@
+use super::generic_call;
+use crate::{context::CompileContext, error::CompileError};
+use fnv::FnvHashMap;
+use hir::{
+    analysis::{
+        record_field_resolver, type_canonicalizer, type_equality_checker, type_id_calculator,
+        type_visitor, AnalysisError,
+    },
+    ir::*,
+    types::{self, Type},
+};
+
+// Generic records hold values of their type variables as any types like
+// generic functions. So we convert their fields between instantiated and
+// erased types at their construction, deconstruction, and update.
+pub fn transform_construction(
+    context: &CompileContext,
+    construction: &RecordConstruction,
+) -> Result<Option<Expression>, CompileError> {
+    let (instance_type, generic_type) = match resolve_types(context, construction.type_())? {
+        Some(types) => types,
+        None => return Ok(None),
+    };
+
+    Ok(Some(
+        RecordConstruction::new(
+            generic_call::erase(&generic_type.clone().into()),
+            transform_fields(
+                context,
+                &instance_type,
+                &generic_type,
+                construction.fields(),
+            )?,
+            construction.position().clone(),
+        )
+        .into(),
+    ))
+}
+
+pub fn transform_deconstruction(
+    context: &CompileContext,
+    deconstruction: &RecordDeconstruction,
+) -> Result<Option<Expression>, CompileError> {
+    let type_ = deconstruction
+        .type_()
+        .ok_or_else(|| AnalysisError::TypeNotInferred(deconstruction.position().clone()))?;
+    let (instance_type, generic_type) = match resolve_types(context, type_)? {
+        Some(types) => types,
+        None => return Ok(None),
+    };
+    let (instance_field, generic_field) = resolve_field(
+        context,
+        &instance_type,
+        &generic_type,
+        deconstruction.field_name(),
+    )?;
+
+    Ok(Some(generic_call::transform_result(
+        context,
+        RecordDeconstruction::new(
+            Some(generic_call::erase(&generic_type.clone().into())),
+            deconstruction.record().clone(),
+            deconstruction.field_name(),
+            deconstruction.position().clone(),
+        ),
+        generic_field.type_(),
+        instance_field.type_(),
+    )?))
+}
+
+pub fn transform_update(
+    context: &CompileContext,
+    update: &RecordUpdate,
+) -> Result<Option<Expression>, CompileError> {
+    let (instance_type, generic_type) = match resolve_types(context, update.type_())? {
+        Some(types) => types,
+        None => return Ok(None),
+    };
+
+    Ok(Some(
+        RecordUpdate::new(
+            generic_call::erase(&generic_type.clone().into()),
+            update.record().clone(),
+            transform_fields(context, &instance_type, &generic_type, update.fields())?,
+            update.position().clone(),
+        )
+        .into(),
+    ))
+}
+
+// Collect instances of generic records used in a module including ones in
+// their fields.
+pub fn collect_instances(
+    context: &CompileContext,
+    module: &Module,
+) -> Result<Vec<types::Record>, CompileError> {
+    let mut records = vec![];
+
+    type_visitor::visit(module, |type_| collect_records(type_, &mut records));
+
+    let mut instances = FnvHashMap::default();
+
+    while let Some(type_) = records.pop() {
+        let record = type_canonicalizer::canonicalize_record(&type_, context.types())?
+            .ok_or(AnalysisError::RecordExpected(type_))?;
+        let id = type_id_calculator::calculate(&record.clone().into(), context.types())?;
+
+        if instances.contains_key(&id) {
+            continue;
+        }
+
+        for field in
+            record_field_resolver::resolve_record(&record, context.types(), context.records())?
+        {
+            type_visitor::visit_type(field.type_(), &mut |type_| {
+                collect_records(type_, &mut records)
+            });
+        }
+
+        instances.insert(id, record);
+    }
+
+    let mut instances = instances.into_iter().collect::<Vec<_>>();
+
+    instances.sort_by(|(one, _), (other, _)| one.cmp(other));
+
+    Ok(instances.into_iter().map(|(_, record)| record).collect())
+}
+
+fn collect_records(type_: &Type, records: &mut Vec<Type>) {
+    match type_ {
+        Type::Record(record) if !record.arguments().is_empty() => records.push(type_.clone()),
+        Type::Reference(reference) if !reference.arguments().is_empty() => {
+            records.push(type_.clone())
+        }
+        _ => {}
+    }
+}
+
+fn transform_fields(
+    context: &CompileContext,
+    instance_type: &types::Record,
+    generic_type: &types::Record,
+    fields: &[RecordField],
+) -> Result<Vec<RecordField>, CompileError> {
+    fields
+        .iter()
+        .map(|field| {
+            let (instance_field, generic_field) =
+                resolve_field(context, instance_type, generic_type, field.name())?;
+
+            Ok(RecordField::new(
+                field.name(),
+                generic_call::transform_argument(
+                    context,
+                    field.expression(),
+                    generic_field.type_(),
+                    instance_field.type_(),
+                )?,
+                field.position().clone(),
+            ))
+        })
+        .collect()
+}
+
+fn resolve_field(
+    context: &CompileContext,
+    instance_type: &types::Record,
+    generic_type: &types::Record,
+    name: &str,
+) -> Result<(types::RecordField, types::RecordField), CompileError> {
+    let find = |record| {
+        record_field_resolver::resolve_record(record, context.types(), context.records())?
+            .into_iter()
+            .find(|field| field.name() == name)
+            .ok_or_else(|| {
+                AnalysisError::RecordFieldUnknown(instance_type.position().clone(), vec![])
+            })
+    };
+
+    Ok((find(instance_type)?, find(generic_type)?))
+}
+
+// Resolve instance and generic types of a record. It returns nothing if a
+// record is not generic or its instance type is already erased.
+fn resolve_types(
+    context: &CompileContext,
+    type_: &Type,
+) -> Result<Option<(types::Record, types::Record)>, CompileError> {
+    let instance_type = type_canonicalizer::canonicalize_record(type_, context.types())?
+        .ok_or_else(|| AnalysisError::RecordExpected(type_.clone()))?;
+
+    if instance_type.arguments().is_empty() {
+        return Ok(None);
+    }
+
+    let generic_type = type_canonicalizer::canonicalize_record(
+        context
+            .types()
+            .get(instance_type.name())
+            .ok_or_else(|| AnalysisError::RecordNotFound(instance_type.clone(), vec![]))?,
+        context.types(),
+    )?
+    .ok_or_else(|| AnalysisError::RecordExpected(type_.clone()))?;
+
+    Ok(
+        if type_equality_checker::check(
+            &instance_type.clone().into(),
+            &generic_call::erase(&generic_type.clone().into()),
+            context.types(),
+        )? {
+            None
+        } else {
+            Some((instance_type, generic_type))
+        },
+    )
+}
@@ -79,6 +79,25 @@ pub fn transform(
             position.clone(),
         )
         .into(),
+        Type::Integer(_) => compile_concrete_hash_function_call(
+            &configuration.map_type.hash.number_hash_function_name,
+            &Call::new(
+                Some(
+                    types::Function::new(
+                        vec![type_.clone()],
+                        types::Number::new(position.clone()),
+                        position.clone(),
+                    )
+                    .into(),
+                ),
+                BuiltInFunction::new(BuiltInFunctionName::Number, position.clone()),
+                vec![value.clone()],
+                position.clone(),
+            )
+            .into(),
+            &types::Number::new(position.clone()).into(),
+            position,
+        ),
         Type::None(_) => Number::new(NONE_HASH, position.clone()).into(),
         Type::Number(_) => compile_concrete_hash_function_call(
             &configuration.map_type.hash.number_hash_function_name,
@@ -136,7 +155,7 @@ pub fn transform(
             &type_resolver::resolve(reference, context.types())?,
             position,
         )?,
-        Type::Any(_) | Type::Error(_) | Type::Function(_) => {
+        Type::Any(_) | Type::Error(_) | Type::Function(_) | Type::Variable(_) => {
             return Err(AnalysisError::TypeNotComparable(type_.clone()).into())
         }
     })
@@ -15,6 +15,7 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
         module.type_aliases().to_vec(),
         module.foreign_declarations().to_vec(),
         module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
         module
             .function_definitions()
             .iter()
@@ -22,6 +22,7 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
         module.type_aliases().to_vec(),
         module.foreign_declarations().to_vec(),
         module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
         module
             .function_definitions()
             .iter()
@@ -163,6 +164,13 @@ fn collect_map_types(
                 map.position().clone(),
             ));
         }
+        Expression::MapComprehension(comprehension) => {
+            map_types.insert(types::Map::new(
+                comprehension.key_type().clone(),
+                comprehension.value_type().clone(),
+                comprehension.position().clone(),
+            ));
+        }
         _ => {}
     });
 
@@ -0,0 +1,4 @@
+pub mod expression;
+pub mod function;
+pub mod module;
+mod operation;
@@ -0,0 +1,30 @@
+use super::operation;
+use crate::{context::CompileContext, error::CompileError};
+use hir::{analysis::AnalysisError, ir::*};
+
+// All order operators are transformed into less-than operations.
+pub fn transform(
+    context: &CompileContext,
+    operation: &OrderOperation,
+) -> Result<Expression, CompileError> {
+    let type_ = operation
+        .type_()
+        .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?;
+    let position = operation.position();
+    let transform = |lhs, rhs| operation::transform(context, type_, lhs, rhs, position);
+
+    Ok(match operation.operator() {
+        OrderOperator::LessThan => transform(operation.lhs(), operation.rhs())?,
+        OrderOperator::LessThanOrEqual => NotOperation::new(
+            transform(operation.rhs(), operation.lhs())?,
+            position.clone(),
+        )
+        .into(),
+        OrderOperator::GreaterThan => transform(operation.rhs(), operation.lhs())?,
+        OrderOperator::GreaterThanOrEqual => NotOperation::new(
+            transform(operation.lhs(), operation.rhs())?,
+            position.clone(),
+        )
+        .into(),
+    })
+}
@@ -0,0 +1,243 @@
+use super::function;
+use crate::{
+    context::CompileContext,
+    error::CompileError,
+    transformation::{collection_type, record_type_information},
+};
+use hir::{
+    analysis::{type_canonicalizer, type_resolver, AnalysisError},
+    ir::*,
+    types::{self, Type},
+};
+use position::Position;
+
+// Transform an operation into one checking if a left-hand side is less than a
+// right-hand side.
+pub fn transform(
+    context: &CompileContext,
+    type_: &Type,
+    lhs: &Expression,
+    rhs: &Expression,
+    position: &Position,
+) -> Result<Expression, CompileError> {
+    transform_canonical(
+        context,
+        &type_canonicalizer::canonicalize(type_, context.types())?,
+        lhs,
+        rhs,
+        position,
+    )
+}
+
+fn transform_canonical(
+    context: &CompileContext,
+    type_: &Type,
+    lhs: &Expression,
+    rhs: &Expression,
+    position: &Position,
+) -> Result<Expression, CompileError> {
+    Ok(match type_ {
+        Type::Integer(_) | Type::Number(_) => OrderOperation::new(
+            Some(type_.clone()),
+            OrderOperator::LessThan,
+            lhs.clone(),
+            rhs.clone(),
+            position.clone(),
+        )
+        .into(),
+        Type::List(list_type) => {
+            let any_list_type = collection_type::transform_list(context, position)?;
+
+            Call::new(
+                Some(
+                    types::Function::new(
+                        vec![
+                            compile_any_function_type(position).into(),
+                            any_list_type.clone(),
+                            any_list_type,
+                        ],
+                        types::Boolean::new(position.clone()),
+                        position.clone(),
+                    )
+                    .into(),
+                ),
+                Variable::new(
+                    &context.configuration()?.list_type.less_than_function_name,
+                    position.clone(),
+                ),
+                vec![
+                    function::transform(context, list_type.element())?,
+                    lhs.clone(),
+                    rhs.clone(),
+                ],
+                position.clone(),
+            )
+            .into()
+        }
+        Type::Record(record_type) => Call::new(
+            Some(
+                types::Function::new(
+                    vec![record_type.clone().into(), record_type.clone().into()],
+                    types::Boolean::new(position.clone()),
+                    position.clone(),
+                )
+                .into(),
+            ),
+            Variable::new(
+                record_type_information::compile_less_than_function_name(record_type),
+                position.clone(),
+            ),
+            vec![lhs.clone(), rhs.clone()],
+            position.clone(),
+        )
+        .into(),
+        Type::String(_) => Call::new(
+            Some(
+                types::Function::new(
+                    vec![type_.clone(), type_.clone()],
+                    types::Boolean::new(position.clone()),
+                    position.clone(),
+                )
+                .into(),
+            ),
+            Variable::new(
+                &context.configuration()?.string_type.less_than_function_name,
+                position.clone(),
+            ),
+            vec![lhs.clone(), rhs.clone()],
+            position.clone(),
+        )
+        .into(),
+        Type::Reference(reference) => transform_canonical(
+            context,
+            &type_resolver::resolve(reference, context.types())?,
+            lhs,
+            rhs,
+            position,
+        )?,
+        Type::Any(_)
+        | Type::Boolean(_)
+        | Type::Error(_)
+        | Type::Function(_)
+        | Type::Map(_)
+        | Type::None(_)
+        | Type::Union(_)
+        | Type::Variable(_) => return Err(AnalysisError::TypeNotOrderable(type_.clone()).into()),
+    })
+}
+
+fn compile_any_function_type(position: &Position) -> types::Function {
+    types::Function::new(
+        vec![
+            types::Any::new(position.clone()).into(),
+            types::Any::new(position.clone()).into(),
+        ],
+        types::Boolean::new(position.clone()),
+        position.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compile_configuration::COMPILE_CONFIGURATION;
+    use position::test::PositionFake;
+    use pretty_assertions::assert_eq;
+
+    fn context() -> CompileContext {
+        CompileContext::dummy(Default::default(), Default::default())
+    }
+
+    #[test]
+    fn transform_string() {
+        let string_type = types::ByteString::new(Position::fake());
+
+        assert_eq!(
+            transform(
+                &context(),
+                &string_type.clone().into(),
+                &Variable::new("x", Position::fake()).into(),
+                &Variable::new("y", Position::fake()).into(),
+                &Position::fake(),
+            ),
+            Ok(Call::new(
+                Some(
+                    types::Function::new(
+                        vec![string_type.clone().into(), string_type.into()],
+                        types::Boolean::new(Position::fake()),
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                Variable::new(
+                    &COMPILE_CONFIGURATION.string_type.less_than_function_name,
+                    Position::fake()
+                ),
+                vec![
+                    Variable::new("x", Position::fake()).into(),
+                    Variable::new("y", Position::fake()).into(),
+                ],
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn transform_list() {
+        let list_type = types::List::new(types::Number::new(Position::fake()), Position::fake());
+        let context = context();
+
+        assert_eq!(
+            transform(
+                &context,
+                &list_type.clone().into(),
+                &Variable::new("x", Position::fake()).into(),
+                &Variable::new("y", Position::fake()).into(),
+                &Position::fake(),
+            ),
+            Ok(Call::new(
+                Some(
+                    types::Function::new(
+                        vec![
+                            compile_any_function_type(&Position::fake()).into(),
+                            collection_type::transform_list(&context, &Position::fake()).unwrap(),
+                            collection_type::transform_list(&context, &Position::fake()).unwrap(),
+                        ],
+                        types::Boolean::new(Position::fake()),
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                Variable::new(
+                    &COMPILE_CONFIGURATION.list_type.less_than_function_name,
+                    Position::fake()
+                ),
+                vec![
+                    function::transform(&context, list_type.element()).unwrap(),
+                    Variable::new("x", Position::fake()).into(),
+                    Variable::new("y", Position::fake()).into(),
+                ],
+                Position::fake(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn fail_to_transform_boolean() {
+        assert_eq!(
+            transform(
+                &context(),
+                &types::Boolean::new(Position::fake()).into(),
+                &Variable::new("x", Position::fake()).into(),
+                &Variable::new("y", Position::fake()).into(),
+                &Position::fake(),
+            ),
+            Err(
+                AnalysisError::TypeNotOrderable(types::Boolean::new(Position::fake()).into())
+                    .into()
+            )
+        );
+    }
+}
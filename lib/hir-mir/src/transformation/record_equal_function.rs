@@ -1,5 +1,10 @@
+use super::generic_record;
 use crate::{context::CompileContext, transformation::record_type_information, CompileError};
-use hir::{analysis::type_comparability_checker, ir::*, types};
+use hir::{
+    analysis::{anonymous_record_qualifier, record_field_resolver, type_comparability_checker},
+    ir::*,
+    types,
+};
 
 const LHS_NAME: &str = "$lhs";
 const RHS_NAME: &str = "$rhs";
@@ -25,6 +30,25 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
         .into_iter()
         .flatten()
         .partition::<Vec<_>, _>(|definition| definition.is_external());
+    let instances = generic_record::collect_instances(context, module)?
+        .into_iter()
+        .map(|record| {
+            Ok(
+                if type_comparability_checker::check(
+                    &record.clone().into(),
+                    context.types(),
+                    context.records(),
+                )? {
+                    Some(record)
+                } else {
+                    None
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, CompileError>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
 
     Ok(Module::new(
         module.type_definitions().to_vec(),
@@ -41,6 +65,7 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
                     .map(compile_function_declaration),
             )
             .collect(),
+        module.implementations().to_vec(),
         module
             .function_definitions()
             .iter()
@@ -51,6 +76,12 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
                     .copied()
                     .map(compile_function_definition),
             )
+            .chain(
+                instances
+                    .iter()
+                    .map(|record| compile_instance_function_definition(context, record))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
             .collect(),
         module.position().clone(),
     ))
@@ -59,9 +90,11 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
 fn compile_function_declaration(type_definition: &TypeDefinition) -> FunctionDeclaration {
     let position = type_definition.position();
     let record_type = types::Record::new(type_definition.name(), position.clone());
+    let name = record_type_information::compile_equal_function_name(&record_type);
 
     FunctionDeclaration::new(
-        record_type_information::compile_equal_function_name(&record_type),
+        &name,
+        &name,
         types::Function::new(
             vec![record_type.clone().into(), record_type.clone().into()],
             types::Boolean::new(position.clone()),
@@ -72,10 +105,33 @@ fn compile_function_declaration(type_definition: &TypeDefinition) -> FunctionDec
 }
 
 fn compile_function_definition(type_definition: &TypeDefinition) -> FunctionDefinition {
-    let position = type_definition.position();
-    let record_type = types::Record::new(type_definition.name(), position.clone());
+    compile_record_function_definition(
+        &types::Record::new(type_definition.name(), type_definition.position().clone()),
+        type_definition.fields(),
+        // Anonymous record types are defined in every module using them.
+        !anonymous_record_qualifier::is_anonymous(type_definition),
+    )
+}
+
+// Instances of generic records are defined in every module using them.
+fn compile_instance_function_definition(
+    context: &CompileContext,
+    record_type: &types::Record,
+) -> Result<FunctionDefinition, CompileError> {
+    Ok(compile_record_function_definition(
+        record_type,
+        &record_field_resolver::resolve_record(record_type, context.types(), context.records())?,
+        false,
+    ))
+}
 
-    let function_name = record_type_information::compile_equal_function_name(&record_type);
+fn compile_record_function_definition(
+    record_type: &types::Record,
+    fields: &[types::RecordField],
+    public: bool,
+) -> FunctionDefinition {
+    let position = record_type.position();
+    let function_name = record_type_information::compile_equal_function_name(record_type);
 
     FunctionDefinition::new(
         &function_name,
@@ -86,7 +142,7 @@ fn compile_function_definition(type_definition: &TypeDefinition) -> FunctionDefi
                 Argument::new(RHS_NAME, record_type.clone()),
             ],
             types::Boolean::new(position.clone()),
-            type_definition.fields().iter().rev().fold(
+            fields.iter().rev().fold(
                 Boolean::new(true, position.clone()).into(),
                 |expression: Expression, field| {
                     If::new(
@@ -117,7 +173,7 @@ fn compile_function_definition(type_definition: &TypeDefinition) -> FunctionDefi
             position.clone(),
         ),
         None,
-        true,
+        public,
         position.clone(),
     )
 }
@@ -239,6 +295,7 @@ mod tests {
             Ok(Module::empty()
                 .set_type_definitions(vec![type_definition])
                 .set_function_declarations(vec![FunctionDeclaration::new(
+                    "foo.$equal",
                     "foo.$equal",
                     types::Function::new(
                         vec![record_type.clone().into(), record_type.into()],
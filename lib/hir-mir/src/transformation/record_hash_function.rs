@@ -1,7 +1,7 @@
-use super::hash_calculation;
+use super::{generic_record, hash_calculation};
 use crate::{context::CompileContext, transformation::record_type_information, CompileError};
 use hir::{
-    analysis::type_comparability_checker,
+    analysis::{anonymous_record_qualifier, record_field_resolver, type_comparability_checker},
     ir::*,
     types::{self, Type},
 };
@@ -40,6 +40,25 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
         .into_iter()
         .flatten()
         .partition::<Vec<_>, _>(|definition| definition.is_external());
+    let instances = generic_record::collect_instances(context, module)?
+        .into_iter()
+        .map(|record| {
+            Ok(
+                if type_comparability_checker::check(
+                    &record.clone().into(),
+                    context.types(),
+                    context.records(),
+                )? {
+                    Some(record)
+                } else {
+                    None
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, CompileError>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
 
     Ok(Module::new(
         module.type_definitions().to_vec(),
@@ -56,6 +75,7 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
                     .map(compile_function_declaration),
             )
             .collect(),
+        module.implementations().to_vec(),
         module
             .function_definitions()
             .iter()
@@ -66,6 +86,12 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
                     .map(|definition| compile_function_definition(context, definition))
                     .collect::<Result<Vec<_>, _>>()?,
             )
+            .chain(
+                instances
+                    .iter()
+                    .map(|record| compile_instance_function_definition(context, record))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
             .collect(),
         module.position().clone(),
     ))
@@ -74,9 +100,11 @@ pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, Co
 fn compile_function_declaration(type_definition: &TypeDefinition) -> FunctionDeclaration {
     let position = type_definition.position();
     let record_type = types::Record::new(type_definition.name(), position.clone());
+    let name = record_type_information::compile_hash_function_name(&record_type);
 
     FunctionDeclaration::new(
-        record_type_information::compile_hash_function_name(&record_type),
+        &name,
+        &name,
         types::Function::new(
             vec![record_type.clone().into()],
             compile_hash_type(position),
@@ -90,9 +118,35 @@ fn compile_function_definition(
     context: &CompileContext,
     type_definition: &TypeDefinition,
 ) -> Result<FunctionDefinition, CompileError> {
-    let position = type_definition.position();
-    let record_type = types::Record::new(type_definition.name(), position.clone());
-    let function_name = record_type_information::compile_hash_function_name(&record_type);
+    compile_record_function_definition(
+        context,
+        &types::Record::new(type_definition.name(), type_definition.position().clone()),
+        type_definition.fields(),
+        !anonymous_record_qualifier::is_anonymous(type_definition),
+    )
+}
+
+// Instances of generic records are defined in every module using them.
+fn compile_instance_function_definition(
+    context: &CompileContext,
+    record_type: &types::Record,
+) -> Result<FunctionDefinition, CompileError> {
+    compile_record_function_definition(
+        context,
+        record_type,
+        &record_field_resolver::resolve_record(record_type, context.types(), context.records())?,
+        false,
+    )
+}
+
+fn compile_record_function_definition(
+    context: &CompileContext,
+    record_type: &types::Record,
+    fields: &[types::RecordField],
+    public: bool,
+) -> Result<FunctionDefinition, CompileError> {
+    let position = record_type.position();
+    let function_name = record_type_information::compile_hash_function_name(record_type);
     let hash_type = compile_hash_type(position);
     let configuration = &context.configuration()?.map_type.hash;
 
@@ -102,8 +156,8 @@ fn compile_function_definition(
         Lambda::new(
             vec![Argument::new(RECORD_NAME, record_type.clone())],
             hash_type.clone(),
-            type_definition.fields().iter().rev().fold(
-                Ok(Expression::from(compile_identity_hash(type_definition))),
+            fields.iter().rev().fold(
+                Ok(Expression::from(compile_identity_hash(record_type))),
                 |expression, field| -> Result<_, CompileError> {
                     Ok(Call::new(
                         Some(
@@ -138,7 +192,7 @@ fn compile_function_definition(
             position.clone(),
         ),
         None,
-        true,
+        public,
         position.clone(),
     ))
 }
@@ -149,14 +203,14 @@ fn compile_hash_type(position: &Position) -> Type {
 
 // TODO Collision of these hashes might lead to infinite loop in built-in map
 // type insertion because they are treated as identities there.
-fn compile_identity_hash(type_definition: &TypeDefinition) -> Number {
+fn compile_identity_hash(record_type: &types::Record) -> Number {
     let mut hasher = DefaultHasher::new();
 
-    type_definition.name().hash(&mut hasher);
+    record_type.name().hash(&mut hasher);
 
     Number::new(
         f64::from_bits(hasher.finish()),
-        type_definition.position().clone(),
+        record_type.position().clone(),
     )
 }
 
@@ -210,7 +264,7 @@ mod tests {
                     "foo.$hash",
                     "foo.$hash",
                     Lambda::new(
-                        vec![Argument::new(RECORD_NAME, record_type),],
+                        vec![Argument::new(RECORD_NAME, record_type.clone()),],
                         HASH_TYPE.clone(),
                         Call::new(
                             Some(COMBINE_HASH_FUNCTION_TYPE.clone()),
@@ -226,7 +280,7 @@ mod tests {
                                         Position::fake()
                                     ),
                                     vec![
-                                        compile_identity_hash(&type_definition).into(),
+                                        compile_identity_hash(&record_type).into(),
                                         Number::new(0.0, Position::fake()).into(),
                                     ],
                                     Position::fake()
@@ -265,6 +319,7 @@ mod tests {
             Ok(Module::empty()
                 .set_type_definitions(vec![type_definition.clone()])
                 .set_function_declarations(vec![FunctionDeclaration::new(
+                    "foo.$hash",
                     "foo.$hash",
                     types::Function::new(
                         vec![types::Record::new(type_definition.name(), Position::fake()).into()],
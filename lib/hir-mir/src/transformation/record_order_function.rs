@@ -0,0 +1,322 @@
+use super::generic_record;
+use crate::{context::CompileContext, transformation::record_type_information, CompileError};
+use hir::{
+    analysis::{anonymous_record_qualifier, record_field_resolver, type_orderability_checker},
+    ir::*,
+    types,
+};
+
+const LHS_NAME: &str = "$lhs";
+const RHS_NAME: &str = "$rhs";
+
+pub fn transform(context: &CompileContext, module: &Module) -> Result<Module, CompileError> {
+    let (external_type_definitions, internal_type_definitions) = module
+        .type_definitions()
+        .iter()
+        .map(|definition| {
+            Ok(
+                if type_orderability_checker::check(
+                    &types::Record::new(definition.name(), definition.position().clone()).into(),
+                    context.types(),
+                    context.records(),
+                )? {
+                    Some(definition)
+                } else {
+                    None
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, CompileError>>()?
+        .into_iter()
+        .flatten()
+        .partition::<Vec<_>, _>(|definition| definition.is_external());
+    let instances = generic_record::collect_instances(context, module)?
+        .into_iter()
+        .map(|record| {
+            Ok(
+                if type_orderability_checker::check(
+                    &record.clone().into(),
+                    context.types(),
+                    context.records(),
+                )? {
+                    Some(record)
+                } else {
+                    None
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, CompileError>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    Ok(Module::new(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module
+            .function_declarations()
+            .iter()
+            .cloned()
+            .chain(
+                external_type_definitions
+                    .iter()
+                    .copied()
+                    .map(compile_function_declaration),
+            )
+            .collect(),
+        module.implementations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .cloned()
+            .chain(
+                internal_type_definitions
+                    .iter()
+                    .copied()
+                    .map(compile_function_definition),
+            )
+            .chain(
+                instances
+                    .iter()
+                    .map(|record| compile_instance_function_definition(context, record))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
+            .collect(),
+        module.position().clone(),
+    ))
+}
+
+fn compile_function_declaration(type_definition: &TypeDefinition) -> FunctionDeclaration {
+    let position = type_definition.position();
+    let record_type = types::Record::new(type_definition.name(), position.clone());
+    let name = record_type_information::compile_less_than_function_name(&record_type);
+
+    FunctionDeclaration::new(
+        &name,
+        &name,
+        types::Function::new(
+            vec![record_type.clone().into(), record_type.clone().into()],
+            types::Boolean::new(position.clone()),
+            position.clone(),
+        ),
+        position.clone(),
+    )
+}
+
+fn compile_function_definition(type_definition: &TypeDefinition) -> FunctionDefinition {
+    compile_record_function_definition(
+        &types::Record::new(type_definition.name(), type_definition.position().clone()),
+        type_definition.fields(),
+        // Anonymous record types are defined in every module using them.
+        !anonymous_record_qualifier::is_anonymous(type_definition),
+    )
+}
+
+// Instances of generic records are defined in every module using them.
+fn compile_instance_function_definition(
+    context: &CompileContext,
+    record_type: &types::Record,
+) -> Result<FunctionDefinition, CompileError> {
+    Ok(compile_record_function_definition(
+        record_type,
+        &record_field_resolver::resolve_record(record_type, context.types(), context.records())?,
+        false,
+    ))
+}
+
+fn compile_record_function_definition(
+    record_type: &types::Record,
+    fields: &[types::RecordField],
+    public: bool,
+) -> FunctionDefinition {
+    let position = record_type.position();
+    let function_name = record_type_information::compile_less_than_function_name(record_type);
+
+    FunctionDefinition::new(
+        &function_name,
+        &function_name,
+        Lambda::new(
+            vec![
+                Argument::new(LHS_NAME, record_type.clone()),
+                Argument::new(RHS_NAME, record_type.clone()),
+            ],
+            types::Boolean::new(position.clone()),
+            fields.iter().rev().fold(
+                Boolean::new(false, position.clone()).into(),
+                |expression: Expression, field| {
+                    let compile_field = |name| -> Expression {
+                        RecordDeconstruction::new(
+                            Some(record_type.clone().into()),
+                            Variable::new(name, position.clone()),
+                            field.name(),
+                            position.clone(),
+                        )
+                        .into()
+                    };
+                    let compile_less_than = |lhs, rhs| {
+                        OrderOperation::new(
+                            Some(field.type_().clone()),
+                            OrderOperator::LessThan,
+                            compile_field(lhs),
+                            compile_field(rhs),
+                            position.clone(),
+                        )
+                    };
+
+                    If::new(
+                        compile_less_than(LHS_NAME, RHS_NAME),
+                        Boolean::new(true, position.clone()),
+                        If::new(
+                            compile_less_than(RHS_NAME, LHS_NAME),
+                            Boolean::new(false, position.clone()),
+                            expression,
+                            position.clone(),
+                        ),
+                        position.clone(),
+                    )
+                    .into()
+                },
+            ),
+            position.clone(),
+        ),
+        None,
+        public,
+        position.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compile_configuration::COMPILE_CONFIGURATION;
+    use hir::test::ModuleFake;
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn transform_module(module: &Module) -> Result<Module, CompileError> {
+        transform(
+            &CompileContext::new(module, COMPILE_CONFIGURATION.clone().into()),
+            module,
+        )
+    }
+
+    #[test]
+    fn compile_less_than_function() {
+        let type_definition = TypeDefinition::new(
+            "foo",
+            "foo",
+            vec![types::RecordField::new(
+                "x",
+                types::Number::new(Position::fake()),
+            )],
+            false,
+            false,
+            false,
+            Position::fake(),
+        );
+        let record_type = types::Record::new(type_definition.name(), Position::fake());
+        let compile_less_than = |lhs, rhs| {
+            OrderOperation::new(
+                Some(types::Number::new(Position::fake()).into()),
+                OrderOperator::LessThan,
+                RecordDeconstruction::new(
+                    Some(record_type.clone().into()),
+                    Variable::new(lhs, Position::fake()),
+                    "x",
+                    Position::fake(),
+                ),
+                RecordDeconstruction::new(
+                    Some(record_type.clone().into()),
+                    Variable::new(rhs, Position::fake()),
+                    "x",
+                    Position::fake(),
+                ),
+                Position::fake(),
+            )
+        };
+
+        assert_eq!(
+            transform_module(&Module::empty().set_type_definitions(vec![type_definition.clone()])),
+            Ok(Module::empty()
+                .set_type_definitions(vec![type_definition])
+                .set_function_definitions(vec![FunctionDefinition::new(
+                    "foo.$less_than",
+                    "foo.$less_than",
+                    Lambda::new(
+                        vec![
+                            Argument::new(LHS_NAME, record_type.clone()),
+                            Argument::new(RHS_NAME, record_type.clone()),
+                        ],
+                        types::Boolean::new(Position::fake()),
+                        If::new(
+                            compile_less_than(LHS_NAME, RHS_NAME),
+                            Boolean::new(true, Position::fake()),
+                            If::new(
+                                compile_less_than(RHS_NAME, LHS_NAME),
+                                Boolean::new(false, Position::fake()),
+                                Boolean::new(false, Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    None,
+                    true,
+                    Position::fake()
+                )]))
+        );
+    }
+
+    #[test]
+    fn compile_less_than_function_declaration_for_external_type_definition() {
+        let record_type = types::Record::new("foo", Position::fake());
+        let type_definition = TypeDefinition::new(
+            "foo",
+            "foo",
+            vec![types::RecordField::new(
+                "x",
+                types::Number::new(Position::fake()),
+            )],
+            false,
+            false,
+            true,
+            Position::fake(),
+        );
+
+        assert_eq!(
+            transform_module(&Module::empty().set_type_definitions(vec![type_definition.clone()])),
+            Ok(Module::empty()
+                .set_type_definitions(vec![type_definition])
+                .set_function_declarations(vec![FunctionDeclaration::new(
+                    "foo.$less_than",
+                    "foo.$less_than",
+                    types::Function::new(
+                        vec![record_type.clone().into(), record_type.into()],
+                        types::Boolean::new(Position::fake()),
+                        Position::fake()
+                    ),
+                    Position::fake()
+                )]))
+        );
+    }
+
+    #[test]
+    fn do_not_compile_less_than_function_for_boolean_field() {
+        let module = Module::empty().set_type_definitions(vec![TypeDefinition::new(
+            "foo",
+            "foo",
+            vec![types::RecordField::new(
+                "x",
+                types::Boolean::new(Position::fake()),
+            )],
+            false,
+            false,
+            false,
+            Position::fake(),
+        )]);
+
+        assert_eq!(transform_module(&module), Ok(module.clone()));
+    }
+}
@@ -1,9 +1,22 @@
-use hir::types;
+use hir::{analysis::type_formatter, types};
 
 pub fn compile_equal_function_name(record_type: &types::Record) -> String {
-    format!("{}.$equal", record_type.name())
+    format!("{}.$equal", compile_name(record_type))
 }
 
 pub fn compile_hash_function_name(record_type: &types::Record) -> String {
-    format!("{}.$hash", record_type.name())
+    format!("{}.$hash", compile_name(record_type))
+}
+
+pub fn compile_less_than_function_name(record_type: &types::Record) -> String {
+    format!("{}.$less_than", compile_name(record_type))
+}
+
+// Instances of generic records have their own functions.
+fn compile_name(record_type: &types::Record) -> String {
+    if record_type.arguments().is_empty() {
+        record_type.name().into()
+    } else {
+        type_formatter::format(&record_type.clone().into())
+    }
 }
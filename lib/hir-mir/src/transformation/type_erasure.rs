@@ -0,0 +1,9 @@
+use super::generic_call;
+use hir::{analysis::type_transformer, ir::*, types::Type};
+
+pub fn transform(module: &Module) -> Module {
+    type_transformer::transform(module, |type_| match type_ {
+        Type::Variable(_) => generic_call::erase(type_),
+        _ => type_.clone(),
+    })
+}
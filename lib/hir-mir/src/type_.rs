@@ -1,4 +1,4 @@
-use super::{context::CompileContext, CompileError};
+use super::{context::CompileContext, integer, CompileError};
 use fnv::FnvHashMap;
 use hir::{
     analysis::{type_canonicalizer, type_id_calculator},
@@ -13,13 +13,14 @@ pub fn compile(context: &CompileContext, type_: &Type) -> Result<mir::types::Typ
                 mir::types::Record::new(&context.configuration()?.error_type.error_type_name).into()
             }
             Type::Function(function) => compile_function(context, &function)?.into(),
+            Type::Integer(integer) => integer::compile_type(integer.kind()),
             Type::List(_) => compile_list(context)?.into(),
             Type::Map(_) => compile_map(context)?.into(),
             Type::None(_) => mir::types::Type::None,
             Type::Number(_) => mir::types::Type::Number,
             Type::Record(record) => mir::types::Record::new(record.name()).into(),
             Type::String(_) => mir::types::Type::ByteString,
-            Type::Any(_) | Type::Union(_) => mir::types::Type::Variant,
+            Type::Any(_) | Type::Union(_) | Type::Variable(_) => mir::types::Type::Variant,
             Type::Reference(_) => unreachable!(),
         },
     )
@@ -37,11 +38,12 @@ pub fn compile_concrete(
         Type::Map(map_type) => compile_concrete_map(map_type, context.types())?.into(),
         Type::Boolean(_)
         | Type::Error(_)
+        | Type::Integer(_)
         | Type::None(_)
         | Type::Number(_)
         | Type::Record(_)
         | Type::String(_) => compile(context, type_)?,
-        Type::Any(_) | Type::Reference(_) | Type::Union(_) => unreachable!(),
+        Type::Any(_) | Type::Reference(_) | Type::Union(_) | Type::Variable(_) => unreachable!(),
     })
 }
 
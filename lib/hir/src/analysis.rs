@@ -1,16 +1,27 @@
+pub mod anonymous_record_qualifier;
 pub mod built_in_type_transformer;
 pub mod built_in_variable_transformer;
+pub mod constraint_validator;
 mod context;
 pub mod duplicate_function_name_validator;
 pub mod duplicate_type_name_validator;
 mod error;
+pub mod error_explanation;
+pub mod expression_transformer;
 pub mod expression_visitor;
 pub mod function_definition_qualifier;
+pub mod generic_type_alias_expander;
+pub mod implementation_validator;
 pub mod impossible_type_validator;
+pub mod interface_resolver;
+pub mod local_variable_checker;
+pub mod method_collector;
 pub mod module_environment_creator;
+pub mod name_suggester;
 pub mod record_field_resolver;
 pub mod record_field_validator;
 pub mod recursive_type_alias_validator;
+pub mod redundant_branch_checker;
 pub mod try_operation_validator;
 pub mod type_canonicalizer;
 pub mod type_checker;
@@ -24,38 +35,382 @@ pub mod type_extractor;
 pub mod type_formatter;
 pub mod type_id_calculator;
 pub mod type_inferrer;
+pub mod type_instantiator;
+pub mod type_orderability_checker;
 pub mod type_qualifier;
 pub mod type_resolver;
 pub mod type_subsumption_checker;
 pub mod type_transformer;
+pub mod type_variable_validator;
 pub mod type_visitor;
 pub mod union_type_creator;
 pub mod union_type_member_calculator;
+pub mod unused_definition_checker;
 pub mod unused_error_validator;
 pub mod variable_renamer;
 pub mod variable_transformer;
+mod warning;
+mod warning_configuration;
 
-use crate::ir::Module;
+use crate::ir::*;
 pub use context::AnalysisContext;
 pub use error::AnalysisError;
+use fnv::FnvHashSet;
+pub use warning::AnalysisWarning;
+pub use warning_configuration::WarningConfiguration;
+
+const MAX_ERROR_COUNT: usize = 32;
 
 // Validate a module and replace subtyping with type coercion there.
 pub fn analyze(context: &AnalysisContext, module: &Module) -> Result<Module, AnalysisError> {
-    duplicate_function_name_validator::validate(module)?;
-    duplicate_type_name_validator::validate(module)?;
-    type_existence_validator::validate(module)?;
+    validate_module(context, module)
+        .map_err(|error| name_suggester::suggest_global_names(module, error))
+}
+
+// Collect warnings of a module before its analysis.
+pub fn collect_warnings(
+    module: &Module,
+    configuration: &WarningConfiguration,
+) -> Vec<AnalysisWarning> {
+    let mut warnings = local_variable_checker::check(module, configuration);
+
+    warnings.extend(unused_definition_checker::check(module, configuration));
+    warnings.extend(redundant_branch_checker::check(module, configuration));
+
+    warnings
+}
+
+fn validate_module(context: &AnalysisContext, module: &Module) -> Result<Module, AnalysisError> {
+    collect_results([
+        duplicate_function_name_validator::validate(module),
+        duplicate_type_name_validator::validate(module),
+        type_existence_validator::validate(module),
+    ])?;
     recursive_type_alias_validator::validate(module)?;
     impossible_type_validator::validate(context, module)?;
+    implementation_validator::validate(context, module)?;
 
-    let module = type_inferrer::infer(context, module)?;
+    let methods =
+        method_collector::collect(context, module, &module_environment_creator::create(module))?;
+    let module = type_inferrer::infer(context, module, &methods)?;
+    let module = type_coercer::instantiate_generic_functions(context, &module);
     type_checker::check_types(context, &module)?;
-
-    try_operation_validator::validate(context, &module)?;
-    record_field_validator::validate(context, &module)?;
-    unused_error_validator::validate(context, &module)?;
+    collect_results([
+        try_operation_validator::validate(context, &module),
+        record_field_validator::validate(context, &module),
+        unused_error_validator::validate(context, &module),
+    ])?;
 
     let module = type_coercer::coerce_types(context, &module)?;
     type_checker::check_types(context, &module)?;
+    type_variable_validator::validate(context, &module)?;
+    constraint_validator::validate(context, &module)?;
 
     Ok(module)
 }
+
+fn collect_results<T>(
+    results: impl IntoIterator<Item = Result<T, AnalysisError>>,
+) -> Result<Vec<T>, AnalysisError> {
+    let mut values = vec![];
+    let mut errors = vec![];
+
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(merge_errors(errors))
+    }
+}
+
+fn merge_errors(errors: Vec<AnalysisError>) -> AnalysisError {
+    let mut messages = FnvHashSet::default();
+    let mut errors = errors
+        .into_iter()
+        .flat_map(|error| match error {
+            AnalysisError::Multiple(errors) => errors,
+            error => vec![error],
+        })
+        .filter(|error| messages.insert(error.to_string()))
+        .take(MAX_ERROR_COUNT)
+        .collect::<Vec<_>>();
+
+    if errors.len() == 1 {
+        errors.remove(0)
+    } else {
+        AnalysisError::Multiple(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::{FunctionDefinitionFake, ModuleFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn analyze_module(module: &Module) -> Result<Module, AnalysisError> {
+        analyze(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_records(module),
+            ),
+            module,
+        )
+    }
+
+    fn fake_definition(name: &str, body: impl Into<Expression>) -> FunctionDefinition {
+        FunctionDefinition::fake(
+            name,
+            Lambda::new(
+                vec![],
+                types::None::new(Position::fake()),
+                body,
+                Position::fake(),
+            ),
+            false,
+        )
+    }
+
+    #[test]
+    fn analyze_function_definitions_referencing_each_other() {
+        assert!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                fake_definition("f", None::new(Position::fake())),
+                fake_definition(
+                    "g",
+                    Call::new(
+                        None,
+                        Variable::new("f", Position::fake()),
+                        vec![],
+                        Position::fake()
+                    )
+                ),
+            ]))
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn report_errors_in_multiple_function_definitions() {
+        assert_eq!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                fake_definition("f", Variable::new("x", Position::fake())),
+                fake_definition("g", None::new(Position::fake())),
+                fake_definition("h", Variable::new("y", Position::fake())),
+            ])),
+            Err(AnalysisError::Multiple(vec![
+                AnalysisError::VariableNotFound(Variable::new("x", Position::fake()), vec![]),
+                AnalysisError::VariableNotFound(Variable::new("y", Position::fake()), vec![]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn report_errors_in_sequential_statements() {
+        let number_type = types::Number::new(Position::fake());
+        let call = |line_number| {
+            Call::new(
+                None,
+                Variable::new("f", Position::fake()),
+                vec![None::new(Position::new("", line_number, 1, "")).into()],
+                Position::fake(),
+            )
+        };
+
+        assert_eq!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", number_type.clone())],
+                        types::None::new(Position::fake()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                fake_definition(
+                    "g",
+                    Let::new(
+                        None,
+                        None,
+                        call(1),
+                        Let::new(
+                            None,
+                            None,
+                            call(2),
+                            None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                ),
+            ])),
+            Err(AnalysisError::Multiple(vec![
+                AnalysisError::TypesNotMatched(
+                    types::None::new(Position::new("", 1, 1, "")).into(),
+                    number_type.clone().into(),
+                ),
+                AnalysisError::TypesNotMatched(
+                    types::None::new(Position::new("", 2, 1, "")).into(),
+                    number_type.into(),
+                ),
+            ]))
+        );
+    }
+
+    #[test]
+    fn deduplicate_errors() {
+        assert_eq!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                fake_definition("f", Variable::new("x", Position::fake())),
+                fake_definition("g", Variable::new("x", Position::fake())),
+            ])),
+            Err(AnalysisError::VariableNotFound(
+                Variable::new("x", Position::fake()),
+                vec![]
+            ))
+        );
+    }
+
+    #[test]
+    fn suggest_global_names() {
+        assert_eq!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::new(
+                    "Foo:foo",
+                    "foo",
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    None,
+                    false,
+                    Position::fake()
+                ),
+                fake_definition("bar", Variable::new("fo", Position::fake())),
+            ])),
+            Err(AnalysisError::VariableNotFound(
+                Variable::new("fo", Position::fake()),
+                vec!["foo".into()]
+            ))
+        );
+    }
+
+    #[test]
+    fn analyze_generic_function_call() {
+        let variable_type = types::Variable::new("a", Position::fake());
+
+        assert!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", variable_type.clone())],
+                        variable_type,
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                fake_definition(
+                    "g",
+                    Call::new(
+                        None,
+                        Variable::new("f", Position::fake()),
+                        vec![None::new(Position::fake()).into()],
+                        Position::fake()
+                    )
+                ),
+            ]))
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn fail_to_analyze_generic_function_call_with_wrong_result_type() {
+        let variable_type = types::Variable::new("a", Position::fake());
+
+        assert!(matches!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", variable_type.clone())],
+                        variable_type,
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+                fake_definition(
+                    "g",
+                    Call::new(
+                        None,
+                        Variable::new("f", Position::fake()),
+                        vec![Number::new(42.0, Position::fake()).into()],
+                        Position::fake()
+                    )
+                ),
+            ])),
+            Err(AnalysisError::TypesNotMatched(..))
+        ));
+    }
+
+    #[test]
+    fn fail_to_analyze_generic_function_using_type_variable_as_concrete_type() {
+        let variable_type = types::Variable::new("a", Position::fake());
+
+        assert!(matches!(
+            analyze_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", variable_type)],
+                        types::Number::new(Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ])),
+            Err(AnalysisError::TypesNotMatched(..))
+        ));
+    }
+
+    #[test]
+    fn limit_number_of_errors() {
+        assert_eq!(
+            analyze_module(
+                &Module::empty().set_function_definitions(
+                    (0..2 * MAX_ERROR_COUNT)
+                        .map(|index| fake_definition(
+                            &format!("f{}", index),
+                            Variable::new(format!("x{}", index), Position::fake())
+                        ))
+                        .collect()
+                )
+            ),
+            Err(AnalysisError::Multiple(
+                (0..MAX_ERROR_COUNT)
+                    .map(|index| AnalysisError::VariableNotFound(
+                        Variable::new(format!("x{}", index), Position::fake()),
+                        vec![]
+                    ))
+                    .collect()
+            ))
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use super::{type_formatter, type_transformer};
+use crate::{
+    ir::*,
+    types::{self, Type},
+};
+use fnv::{FnvHashMap, FnvHashSet};
+
+pub fn is_anonymous(definition: &TypeDefinition) -> bool {
+    definition.original_name().starts_with('{')
+}
+
+// Anonymous record types are named after their structures so that the same
+// types in different modules are identical.
+pub fn qualify(module: &Module) -> Module {
+    let mut definitions = module
+        .type_definitions()
+        .iter()
+        .filter(|definition| is_anonymous(definition))
+        .collect::<Vec<_>>();
+
+    // Names of anonymous record types are always longer than ones of their
+    // nested anonymous record types.
+    definitions.sort_by_key(|definition| definition.original_name().len());
+
+    let mut names = FnvHashMap::<String, String>::default();
+
+    for definition in definitions {
+        let fields = qualify_fields(definition.fields(), &names);
+
+        names.insert(
+            definition.name().into(),
+            type_formatter::format_anonymous_record(&fields),
+        );
+    }
+
+    let module = type_transformer::transform(module, |type_| rename_type(type_, &names));
+    let mut defined_names = FnvHashSet::default();
+
+    Module::new(
+        module
+            .type_definitions()
+            .iter()
+            .filter_map(|definition| {
+                if !is_anonymous(definition) {
+                    return Some(definition.clone());
+                }
+
+                let name = &names[definition.name()];
+
+                defined_names.insert(name.clone()).then(|| {
+                    TypeDefinition::new(
+                        name,
+                        name,
+                        sort_fields(definition.fields()),
+                        true,
+                        true,
+                        false,
+                        definition.position().clone(),
+                    )
+                    .set_type_parameters(definition.type_parameters().to_vec())
+                })
+            })
+            .collect(),
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
+        module.function_definitions().to_vec(),
+        module.position().clone(),
+    )
+}
+
+fn qualify_fields(
+    fields: &[types::RecordField],
+    names: &FnvHashMap<String, String>,
+) -> Vec<types::RecordField> {
+    sort_fields(
+        &fields
+            .iter()
+            .map(|field| {
+                types::RecordField::new(
+                    field.name(),
+                    type_transformer::transform_type(field.type_(), &|type_| {
+                        rename_type(type_, names)
+                    }),
+                )
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn sort_fields(fields: &[types::RecordField]) -> Vec<types::RecordField> {
+    let mut fields = fields.to_vec();
+
+    fields.sort_by(|one, other| one.name().cmp(other.name()));
+
+    fields
+}
+
+fn rename_type(type_: &Type, names: &FnvHashMap<String, String>) -> Type {
+    match type_ {
+        Type::Record(record) => {
+            if let Some(name) = names.get(record.name()) {
+                types::Record::new(name, record.position().clone()).into()
+            } else {
+                type_.clone()
+            }
+        }
+        _ => type_.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{FunctionDefinitionFake, ModuleFake, TypeDefinitionFake};
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn anonymous_definition(
+        name: &str,
+        original_name: &str,
+        fields: Vec<types::RecordField>,
+        external: bool,
+    ) -> TypeDefinition {
+        TypeDefinition::new(
+            name,
+            original_name,
+            fields,
+            true,
+            true,
+            external,
+            Position::fake(),
+        )
+    }
+
+    #[test]
+    fn qualify_empty_module() {
+        assert_eq!(qualify(&Module::empty()), Module::empty());
+    }
+
+    #[test]
+    fn skip_named_record() {
+        let module = Module::empty().set_type_definitions(vec![TypeDefinition::fake(
+            "foo",
+            vec![types::RecordField::new(
+                "x",
+                types::Number::new(Position::fake()),
+            )],
+            false,
+            false,
+            false,
+        )]);
+
+        assert_eq!(qualify(&module), module);
+    }
+
+    #[test]
+    fn qualify_anonymous_record() {
+        let record_type = types::Record::new("foo'{y none, x number}", Position::fake());
+
+        assert_eq!(
+            qualify(
+                &Module::empty()
+                    .set_type_definitions(vec![anonymous_definition(
+                        "foo'{y none, x number}",
+                        "{y none, x number}",
+                        vec![
+                            types::RecordField::new("y", types::None::new(Position::fake())),
+                            types::RecordField::new("x", types::Number::new(Position::fake())),
+                        ],
+                        false,
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new("x", record_type.clone())],
+                            record_type,
+                            Variable::new("x", Position::fake()),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+            ),
+            Module::empty()
+                .set_type_definitions(vec![anonymous_definition(
+                    "{x number, y none}",
+                    "{x number, y none}",
+                    vec![
+                        types::RecordField::new("x", types::Number::new(Position::fake())),
+                        types::RecordField::new("y", types::None::new(Position::fake())),
+                    ],
+                    false,
+                )])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            types::Record::new("{x number, y none}", Position::fake())
+                        )],
+                        types::Record::new("{x number, y none}", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+        );
+    }
+
+    #[test]
+    fn qualify_nested_anonymous_record() {
+        assert_eq!(
+            qualify(&Module::empty().set_type_definitions(vec![
+                anonymous_definition(
+                    "foo'{x {y none}}",
+                    "{x {y none}}",
+                    vec![types::RecordField::new(
+                        "x",
+                        types::Record::new("foo'{y none}", Position::fake())
+                    )],
+                    false,
+                ),
+                anonymous_definition(
+                    "foo'{y none}",
+                    "{y none}",
+                    vec![types::RecordField::new(
+                        "y",
+                        types::None::new(Position::fake())
+                    )],
+                    false,
+                ),
+            ])),
+            Module::empty().set_type_definitions(vec![
+                anonymous_definition(
+                    "{x {y none}}",
+                    "{x {y none}}",
+                    vec![types::RecordField::new(
+                        "x",
+                        types::Record::new("{y none}", Position::fake())
+                    )],
+                    false,
+                ),
+                anonymous_definition(
+                    "{y none}",
+                    "{y none}",
+                    vec![types::RecordField::new(
+                        "y",
+                        types::None::new(Position::fake())
+                    )],
+                    false,
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn merge_imported_anonymous_record() {
+        let fields = vec![types::RecordField::new(
+            "x",
+            types::Number::new(Position::fake()),
+        )];
+
+        assert_eq!(
+            qualify(&Module::empty().set_type_definitions(vec![
+                anonymous_definition("{x number}", "{x number}", fields.clone(), true),
+                anonymous_definition("foo'{x number}", "{x number}", fields.clone(), false),
+            ])),
+            Module::empty().set_type_definitions(vec![anonymous_definition(
+                "{x number}",
+                "{x number}",
+                fields,
+                false
+            )])
+        );
+    }
+}
@@ -30,10 +30,15 @@ pub fn transform(module: &Module) -> Module {
                 match reference.name() {
                     "any" => types::Any::new(position.clone()).into(),
                     "boolean" => types::Boolean::new(position.clone()).into(),
+                    "byte" => {
+                        types::Integer::new(types::IntegerKind::Byte, position.clone()).into()
+                    }
                     "error" => types::Error::new(position.clone()).into(),
+                    "i64" => types::Integer::new(types::IntegerKind::I64, position.clone()).into(),
                     "none" => types::None::new(position.clone()).into(),
                     "number" => types::Number::new(position.clone()).into(),
                     "string" => types::ByteString::new(position.clone()).into(),
+                    "u64" => types::Integer::new(types::IntegerKind::U64, position.clone()).into(),
                     _ => type_.clone(),
                 }
             }
@@ -82,6 +87,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transform_integer_type() {
+        assert_eq!(
+            transform(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            types::Reference::new("i64", Position::fake())
+                        )],
+                        types::None::new(Position::fake()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            ),
+            Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![Argument::new(
+                        "x",
+                        types::Integer::new(types::IntegerKind::I64, Position::fake())
+                    )],
+                    types::None::new(Position::fake()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            )])
+        );
+    }
+
     #[test]
     fn transform_function_argument_type() {
         assert_eq!(
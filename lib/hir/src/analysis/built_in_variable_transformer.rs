@@ -6,15 +6,19 @@ pub fn transform(module: &Module) -> Module {
         let position = variable.position();
 
         match variable.name() {
+            "byte" => BuiltInFunction::new(BuiltInFunctionName::Byte, position.clone()).into(),
             "debug" => BuiltInFunction::new(BuiltInFunctionName::Debug, position.clone()).into(),
             "error" => BuiltInFunction::new(BuiltInFunctionName::Error, position.clone()).into(),
             "false" => Boolean::new(false, position.clone()).into(),
             "go" => BuiltInFunction::new(BuiltInFunctionName::Spawn, position.clone()).into(),
+            "i64" => BuiltInFunction::new(BuiltInFunctionName::I64, position.clone()).into(),
             "none" => None::new(position.clone()).into(),
+            "number" => BuiltInFunction::new(BuiltInFunctionName::Number, position.clone()).into(),
             "race" => BuiltInFunction::new(BuiltInFunctionName::Race, position.clone()).into(),
             "size" => BuiltInFunction::new(BuiltInFunctionName::Size, position.clone()).into(),
             "source" => BuiltInFunction::new(BuiltInFunctionName::Source, position.clone()).into(),
             "true" => Boolean::new(true, position.clone()).into(),
+            "u64" => BuiltInFunction::new(BuiltInFunctionName::U64, position.clone()).into(),
             _ => variable.clone().into(),
         }
     })
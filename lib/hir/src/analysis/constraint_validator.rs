@@ -0,0 +1,243 @@
+use super::{
+    context::AnalysisContext, expression_visitor, interface_resolver, module_environment_creator,
+    type_canonicalizer, type_equality_checker, type_extractor, type_instantiator, AnalysisError,
+};
+use crate::{
+    ir::*,
+    types::{self, Type},
+};
+
+pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
+    for definition in module.function_definitions() {
+        for variable in interface_resolver::collect_constrained_variables(
+            &type_extractor::extract_from_lambda(definition.lambda()).into(),
+        ) {
+            if let Some(constraint) = variable.constraint() {
+                if interface_resolver::resolve(context, constraint)?.is_none() {
+                    return Err(AnalysisError::InterfaceExpected(constraint.clone()));
+                }
+            }
+        }
+    }
+
+    let variables = module_environment_creator::create(module);
+    let mut calls = vec![];
+
+    expression_visitor::visit(module, |expression| {
+        if let Expression::Call(call) = expression {
+            calls.push(call);
+        }
+    });
+
+    for call in calls {
+        let generic_type = match call.function() {
+            Expression::Variable(variable) => match variables.get(variable.name()) {
+                Some(type_) => type_,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let constrained_variables = interface_resolver::collect_constrained_variables(generic_type);
+
+        if constrained_variables.is_empty() {
+            continue;
+        }
+
+        let generic_type =
+            type_canonicalizer::canonicalize_function(generic_type, context.types())?
+                .ok_or_else(|| AnalysisError::FunctionExpected(generic_type.clone()))?;
+        let instance_type = call
+            .function_type()
+            .ok_or_else(|| AnalysisError::TypeNotInferred(call.position().clone()))?;
+        let instance_type =
+            type_canonicalizer::canonicalize_function(instance_type, context.types())?
+                .ok_or_else(|| AnalysisError::FunctionExpected(instance_type.clone()))?;
+        let bindings =
+            type_instantiator::resolve_variables(&generic_type, &instance_type, context.types())?;
+
+        for variable in constrained_variables {
+            if let Some(constraint) = variable.constraint() {
+                let type_ = bindings
+                    .get(variable.name())
+                    .cloned()
+                    .unwrap_or_else(|| types::Any::new(call.position().clone()).into());
+
+                if !is_constraint_satisfied(context, module, constraint, &type_)? {
+                    return Err(AnalysisError::ImplementationNotFound(
+                        constraint.clone(),
+                        type_,
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_constraint_satisfied(
+    context: &AnalysisContext,
+    module: &Module,
+    constraint: &Type,
+    type_: &Type,
+) -> Result<bool, AnalysisError> {
+    Ok(
+        if let Type::Variable(variable) = type_canonicalizer::canonicalize(type_, context.types())?
+        {
+            if let Some(other) = variable.constraint() {
+                type_equality_checker::check(constraint, other, context.types())?
+            } else {
+                false
+            }
+        } else {
+            interface_resolver::resolve_implementation(
+                context,
+                module.implementations(),
+                constraint,
+                type_,
+            )?
+            .is_some()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::type_collector,
+        test::{FunctionDefinitionFake, ModuleFake, TypeDefinitionFake},
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn validate_module(module: &Module) -> Result<(), AnalysisError> {
+        validate(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_records(module),
+            ),
+            module,
+        )
+    }
+
+    fn constrained_variable() -> types::Variable {
+        types::Variable::new("a", Position::fake())
+            .set_constraint(Some(types::Reference::new("Show", Position::fake()).into()))
+    }
+
+    fn interface_definition() -> TypeDefinition {
+        TypeDefinition::fake(
+            "Show",
+            vec![types::RecordField::new(
+                "String",
+                types::Function::new(
+                    vec![constrained_variable().into()],
+                    types::ByteString::new(Position::fake()),
+                    Position::fake(),
+                ),
+            )],
+            false,
+            false,
+            false,
+        )
+    }
+
+    fn generic_function_definition() -> FunctionDefinition {
+        FunctionDefinition::fake(
+            "f",
+            Lambda::new(
+                vec![Argument::new("x", constrained_variable())],
+                types::None::new(Position::fake()),
+                None::new(Position::fake()),
+                Position::fake(),
+            ),
+            false,
+        )
+    }
+
+    fn call_function_definition() -> FunctionDefinition {
+        FunctionDefinition::fake(
+            "g",
+            Lambda::new(
+                vec![],
+                types::None::new(Position::fake()),
+                Call::new(
+                    Some(
+                        types::Function::new(
+                            vec![types::Number::new(Position::fake()).into()],
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        )
+                        .into(),
+                    ),
+                    Variable::new("f", Position::fake()),
+                    vec![Number::new(42.0, Position::fake()).into()],
+                    Position::fake(),
+                ),
+                Position::fake(),
+            ),
+            false,
+        )
+    }
+
+    #[test]
+    fn validate_call_with_implementation() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![interface_definition()])
+                    .set_implementations(vec![Implementation::new(
+                        "$implementation:0",
+                        types::Reference::new("Show", Position::fake()),
+                        types::Number::new(Position::fake()),
+                        false,
+                        Position::fake(),
+                    )])
+                    .set_function_definitions(vec![
+                        generic_function_definition(),
+                        call_function_definition()
+                    ])
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_call_without_implementation() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![interface_definition()])
+                    .set_function_definitions(vec![
+                        generic_function_definition(),
+                        call_function_definition()
+                    ])
+            ),
+            Err(AnalysisError::ImplementationNotFound(
+                types::Reference::new("Show", Position::fake()).into(),
+                types::Number::new(Position::fake()).into(),
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_constraint_of_record() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "Show",
+                        vec![],
+                        false,
+                        false,
+                        false
+                    )])
+                    .set_function_definitions(vec![generic_function_definition()])
+            ),
+            Err(AnalysisError::InterfaceExpected(
+                types::Reference::new("Show", Position::fake()).into()
+            ))
+        );
+    }
+}
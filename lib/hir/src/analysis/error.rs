@@ -1,7 +1,7 @@
 use super::type_formatter;
 use crate::{
     ir::*,
-    types::{self, *},
+    types::{self, Record, Reference, Type},
 };
 use position::Position;
 use std::{
@@ -11,40 +11,172 @@ use std::{
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AnalysisError {
+    AmbiguousGenericRecord(Type),
+    AmbiguousMethodCall(Position),
     AnyTypeBranch(Position),
     BuiltInFunctionNotCalled(Position),
     CollectionExpected(Type),
     DuplicateFunctionNames(Position, Position),
+    DuplicateImplementations(Position, Position),
     DuplicateTypeNames(Position, Position),
     ErrorTypeUndefined,
     FunctionExpected(Type),
+    GenericFunctionNotInstantiated(Position),
+    ImplementationNotFound(Type, Type),
     ImpossibleRecord(Position),
+    IntegerExpected(Type),
+    InterfaceExpected(Type),
+    InterfaceMethodMissing(Position, String),
+    InterfaceMethodUnknown(Position),
     InvalidTryOperation(Position),
     ListExpected(Type),
     MapExpected(Type),
-    MissingElseBlock(Position),
+    MissingElseBlock(Position, Type),
+    Multiple(Vec<AnalysisError>),
     RecordExpected(Type),
     RecordFieldMissing(Position),
     RecordFieldPrivate(Position),
-    RecordFieldUnknown(Position),
-    RecordNotFound(Record),
+    RecordFieldUnknown(Position, Vec<String>),
+    RecordNotFound(Record, Vec<String>),
     RecursiveTypeAlias(Position),
     SpawnedFunctionArguments(Position),
     TryOperationInList(Position),
-    TypeNotFound(Reference),
+    TypeNotFound(Reference, Vec<String>),
     TypeNotInferred(Position),
     TypeNotComparable(Type),
+    TypeNotOrderable(Type),
     TypesNotMatched(Type, Type),
     UnionExpected(Type),
-    UnknownRecordField(Position),
+    UnknownRecordField(Position, Vec<String>),
     UnreachableCode(Position),
+    UnsupportedTypeVariable(Type),
     UnusedErrorValue(Position),
-    VariableNotFound(Variable),
+    VariableNotFound(Variable, Vec<String>),
     VariantExpected(Type),
     WrongArgumentCount(Position),
+    WrongTypeArgumentCount(Position),
 }
 
 impl AnalysisError {
+    pub fn position(&self) -> Option<&Position> {
+        match self {
+            Self::AmbiguousMethodCall(position)
+            | Self::AnyTypeBranch(position)
+            | Self::BuiltInFunctionNotCalled(position)
+            | Self::DuplicateFunctionNames(position, _)
+            | Self::DuplicateImplementations(position, _)
+            | Self::DuplicateTypeNames(position, _)
+            | Self::GenericFunctionNotInstantiated(position)
+            | Self::ImpossibleRecord(position)
+            | Self::InterfaceMethodMissing(position, _)
+            | Self::InterfaceMethodUnknown(position)
+            | Self::InvalidTryOperation(position)
+            | Self::MissingElseBlock(position, _)
+            | Self::RecordFieldMissing(position)
+            | Self::RecordFieldPrivate(position)
+            | Self::RecordFieldUnknown(position, _)
+            | Self::RecursiveTypeAlias(position)
+            | Self::SpawnedFunctionArguments(position)
+            | Self::TryOperationInList(position)
+            | Self::TypeNotInferred(position)
+            | Self::UnknownRecordField(position, _)
+            | Self::UnreachableCode(position)
+            | Self::UnusedErrorValue(position)
+            | Self::WrongArgumentCount(position)
+            | Self::WrongTypeArgumentCount(position) => Some(position),
+            Self::AmbiguousGenericRecord(type_)
+            | Self::CollectionExpected(type_)
+            | Self::FunctionExpected(type_)
+            | Self::IntegerExpected(type_)
+            | Self::InterfaceExpected(type_)
+            | Self::ListExpected(type_)
+            | Self::MapExpected(type_)
+            | Self::RecordExpected(type_)
+            | Self::TypeNotComparable(type_)
+            | Self::TypeNotOrderable(type_)
+            | Self::TypesNotMatched(type_, _)
+            | Self::UnionExpected(type_)
+            | Self::UnsupportedTypeVariable(type_)
+            | Self::VariantExpected(type_) => Some(type_.position()),
+            Self::ImplementationNotFound(_, type_) => Some(type_.position()),
+            Self::RecordNotFound(record, _) => Some(record.position()),
+            Self::TypeNotFound(reference, _) => Some(reference.position()),
+            Self::VariableNotFound(variable, _) => Some(variable.position()),
+            Self::Multiple(errors) => errors.first().and_then(AnalysisError::position),
+            Self::ErrorTypeUndefined => None,
+        }
+    }
+
+    // Codes are stable across versions. Never reuse codes of removed errors.
+    pub fn code(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::AnyTypeBranch(_) => "E0001",
+            Self::BuiltInFunctionNotCalled(_) => "E0002",
+            Self::CollectionExpected(_) => "E0003",
+            Self::DuplicateFunctionNames(..) => "E0004",
+            Self::DuplicateTypeNames(..) => "E0005",
+            Self::ErrorTypeUndefined => "E0006",
+            Self::FunctionExpected(_) => "E0007",
+            Self::ImpossibleRecord(_) => "E0008",
+            Self::InvalidTryOperation(_) => "E0009",
+            Self::ListExpected(_) => "E0010",
+            Self::MapExpected(_) => "E0011",
+            Self::MissingElseBlock(..) => "E0012",
+            Self::RecordExpected(_) => "E0013",
+            Self::RecordFieldMissing(_) => "E0014",
+            Self::RecordFieldPrivate(_) => "E0015",
+            Self::RecordFieldUnknown(..) => "E0016",
+            Self::RecordNotFound(..) => "E0017",
+            Self::RecursiveTypeAlias(_) => "E0018",
+            Self::SpawnedFunctionArguments(_) => "E0019",
+            Self::TryOperationInList(_) => "E0020",
+            Self::TypeNotFound(..) => "E0021",
+            Self::TypeNotInferred(_) => "E0022",
+            Self::TypeNotComparable(_) => "E0023",
+            Self::TypesNotMatched(..) => "E0024",
+            Self::UnionExpected(_) => "E0025",
+            Self::UnknownRecordField(..) => "E0026",
+            Self::UnreachableCode(_) => "E0027",
+            Self::UnusedErrorValue(_) => "E0028",
+            Self::VariableNotFound(..) => "E0029",
+            Self::VariantExpected(_) => "E0030",
+            Self::WrongArgumentCount(_) => "E0031",
+            Self::GenericFunctionNotInstantiated(_) => "E0032",
+            Self::UnsupportedTypeVariable(_) => "E0033",
+            Self::AmbiguousMethodCall(_) => "E0034",
+            Self::DuplicateImplementations(..) => "E0035",
+            Self::ImplementationNotFound(..) => "E0036",
+            Self::InterfaceExpected(_) => "E0037",
+            Self::InterfaceMethodMissing(..) => "E0038",
+            Self::InterfaceMethodUnknown(_) => "E0039",
+            Self::TypeNotOrderable(_) => "E0040",
+            Self::WrongTypeArgumentCount(_) => "E0041",
+            Self::AmbiguousGenericRecord(_) => "E0042",
+            Self::IntegerExpected(_) => "E0043",
+            Self::Multiple(_) => return None,
+        })
+    }
+
+    pub fn suggestion(&self) -> Option<String> {
+        let names = match self {
+            Self::RecordFieldUnknown(_, names)
+            | Self::RecordNotFound(_, names)
+            | Self::TypeNotFound(_, names)
+            | Self::UnknownRecordField(_, names)
+            | Self::VariableNotFound(_, names) => names,
+            _ => return None,
+        }
+        .iter()
+        .map(|name| format!("\"{}\"", name))
+        .collect::<Vec<_>>();
+
+        match names.as_slice() {
+            [] => None,
+            [name] => Some(format!("did you mean {}?", name)),
+            [names @ .., last] => Some(format!("did you mean {} or {}?", names.join(", "), last)),
+        }
+    }
+
     fn format_type(type_: &Type) -> String {
         format!("`{}`", type_formatter::format(type_))
     }
@@ -66,7 +198,25 @@ impl AnalysisError {
 
 impl Display for AnalysisError {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        if let Some(code) = self.code() {
+            write!(formatter, "[{}] ", code)?;
+        }
+
         match self {
+            Self::AmbiguousGenericRecord(type_) => {
+                write!(
+                    formatter,
+                    "instances of generic record cannot be distinguished\n{}",
+                    Self::format_found_type_message(type_)
+                )
+            }
+            Self::AmbiguousMethodCall(position) => {
+                write!(
+                    formatter,
+                    "method call ambiguous between record field and function\n{}",
+                    position
+                )
+            }
             Self::AnyTypeBranch(position) => {
                 write!(
                     formatter,
@@ -94,6 +244,9 @@ impl Display for AnalysisError {
             Self::DuplicateTypeNames(one, other) => {
                 write!(formatter, "duplicate type names\n{}\n{}", one, other)
             }
+            Self::DuplicateImplementations(one, other) => {
+                write!(formatter, "duplicate implementations\n{}\n{}", one, other)
+            }
             Self::ErrorTypeUndefined => {
                 write!(formatter, "error type undefined")
             }
@@ -104,6 +257,22 @@ impl Display for AnalysisError {
                     Self::format_found_type_message(type_)
                 )
             }
+            Self::GenericFunctionNotInstantiated(position) => {
+                write!(
+                    formatter,
+                    "generic function cannot be instantiated\n{}",
+                    position
+                )
+            }
+            Self::ImplementationNotFound(interface, type_) => {
+                write!(
+                    formatter,
+                    "implementation of interface {} not found for type {}\n{}",
+                    Self::format_type(interface),
+                    Self::format_type(type_),
+                    type_.position()
+                )
+            }
             Self::ImpossibleRecord(position) => {
                 write!(
                     formatter,
@@ -111,6 +280,36 @@ impl Display for AnalysisError {
                     position
                 )
             }
+            Self::IntegerExpected(type_) => {
+                write!(
+                    formatter,
+                    "integer type expected\n{}",
+                    position::format_message(
+                        type_.position(),
+                        &format!(
+                            "bitwise operator requires an integer type, found {}",
+                            Self::format_type(type_),
+                        ),
+                    )
+                )
+            }
+            Self::InterfaceExpected(type_) => {
+                write!(
+                    formatter,
+                    "interface expected\n{}",
+                    Self::format_found_type_message(type_)
+                )
+            }
+            Self::InterfaceMethodMissing(position, name) => {
+                write!(
+                    formatter,
+                    "missing interface method \"{}\"\n{}",
+                    name, position
+                )
+            }
+            Self::InterfaceMethodUnknown(position) => {
+                write!(formatter, "unknown interface method\n{}", position)
+            }
             Self::InvalidTryOperation(position) => {
                 write!(
                     formatter,
@@ -132,13 +331,23 @@ impl Display for AnalysisError {
                     Self::format_found_type_message(type_)
                 )
             }
-            Self::MissingElseBlock(position) => {
+            Self::MissingElseBlock(position, type_) => {
                 write!(
                     formatter,
-                    "missing else block in if-type expression\n{}",
+                    "missing else block in if-type expression for uncovered types {}\n{}",
+                    Self::format_type(type_),
                     position
                 )
             }
+            Self::Multiple(errors) => write!(
+                formatter,
+                "{}",
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            ),
             Self::RecordExpected(type_) => {
                 write!(
                     formatter,
@@ -152,10 +361,10 @@ impl Display for AnalysisError {
             Self::RecordFieldPrivate(position) => {
                 write!(formatter, "private record field\n{}", position)
             }
-            Self::RecordFieldUnknown(position) => {
+            Self::RecordFieldUnknown(position, _) => {
                 write!(formatter, "unknown record field\n{}", position)
             }
-            Self::RecordNotFound(record) => write!(
+            Self::RecordNotFound(record, _) => write!(
                 formatter,
                 "record type \"{}\" not found\n{}",
                 record.name(),
@@ -193,7 +402,20 @@ impl Display for AnalysisError {
                     )
                 )
             }
-            Self::TypeNotFound(reference) => write!(
+            Self::TypeNotOrderable(type_) => {
+                write!(
+                    formatter,
+                    "type not orderable\n{}",
+                    position::format_message(
+                        type_.position(),
+                        &format!(
+                            "{} is not a number, string, list, or record of orderable types",
+                            Self::format_type(type_),
+                        ),
+                    )
+                )
+            }
+            Self::TypeNotFound(reference, _) => write!(
                 formatter,
                 "type \"{}\" not found\n{}",
                 reference.name(),
@@ -215,16 +437,23 @@ impl Display for AnalysisError {
                     Self::format_found_type_message(type_)
                 )
             }
-            Self::UnknownRecordField(position) => {
+            Self::UnknownRecordField(position, _) => {
                 write!(formatter, "unknown record field\n{}", position)
             }
             Self::UnreachableCode(position) => {
                 write!(formatter, "unreachable code\n{}", position)
             }
+            Self::UnsupportedTypeVariable(type_) => {
+                write!(
+                    formatter,
+                    "unsupported use of type variable\n{}",
+                    Self::format_found_type_message(type_)
+                )
+            }
             Self::UnusedErrorValue(position) => {
                 write!(formatter, "unused error value\n{}", position)
             }
-            Self::VariableNotFound(variable) => write!(
+            Self::VariableNotFound(variable, _) => write!(
                 formatter,
                 "variable \"{}\" not found\n{}",
                 variable.name(),
@@ -244,8 +473,85 @@ impl Display for AnalysisError {
                     position
                 )
             }
+            Self::WrongTypeArgumentCount(position) => {
+                write!(formatter, "wrong number of type arguments\n{}", position)
+            }
+        }?;
+
+        if let Some(suggestion) = self.suggestion() {
+            write!(formatter, "\n{}", suggestion)?;
         }
+
+        Ok(())
     }
 }
 
 impl Error for AnalysisError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::test::PositionFake;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display_error_code() {
+        assert_eq!(
+            AnalysisError::UnreachableCode(Position::fake())
+                .to_string()
+                .lines()
+                .next(),
+            Some("[E0027] unreachable code")
+        );
+    }
+
+    #[test]
+    fn display_multiple_error_codes() {
+        let message = AnalysisError::Multiple(vec![
+            AnalysisError::UnreachableCode(Position::fake()),
+            AnalysisError::UnusedErrorValue(Position::fake()),
+        ])
+        .to_string();
+
+        assert!(message.starts_with("[E0027] unreachable code"));
+        assert!(message.contains("[E0028] unused error value"));
+    }
+
+    #[test]
+    fn display_integer_expected() {
+        assert!(
+            AnalysisError::IntegerExpected(types::Number::new(Position::fake()).into())
+                .to_string()
+                .contains("bitwise operator requires an integer type, found `number`")
+        );
+    }
+
+    #[test]
+    fn display_suggestion() {
+        assert!(AnalysisError::VariableNotFound(
+            Variable::new("fo", Position::fake()),
+            vec!["foo".into()]
+        )
+        .to_string()
+        .ends_with("\ndid you mean \"foo\"?"));
+    }
+
+    #[test]
+    fn display_suggestions() {
+        assert!(AnalysisError::VariableNotFound(
+            Variable::new("fo", Position::fake()),
+            vec!["foo".into(), "fob".into(), "Foo'fo".into()]
+        )
+        .to_string()
+        .ends_with("\ndid you mean \"foo\", \"fob\" or \"Foo'fo\"?"));
+    }
+
+    #[test]
+    fn display_no_suggestion() {
+        assert!(
+            !AnalysisError::VariableNotFound(Variable::new("fo", Position::fake()), vec![])
+                .to_string()
+                .contains("did you mean")
+        );
+    }
+}
@@ -0,0 +1,79 @@
+pub fn explain(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "E0001" => include_str!("error_explanation/E0001.md"),
+        "E0002" => include_str!("error_explanation/E0002.md"),
+        "E0003" => include_str!("error_explanation/E0003.md"),
+        "E0004" => include_str!("error_explanation/E0004.md"),
+        "E0005" => include_str!("error_explanation/E0005.md"),
+        "E0006" => include_str!("error_explanation/E0006.md"),
+        "E0007" => include_str!("error_explanation/E0007.md"),
+        "E0008" => include_str!("error_explanation/E0008.md"),
+        "E0009" => include_str!("error_explanation/E0009.md"),
+        "E0010" => include_str!("error_explanation/E0010.md"),
+        "E0011" => include_str!("error_explanation/E0011.md"),
+        "E0012" => include_str!("error_explanation/E0012.md"),
+        "E0013" => include_str!("error_explanation/E0013.md"),
+        "E0014" => include_str!("error_explanation/E0014.md"),
+        "E0015" => include_str!("error_explanation/E0015.md"),
+        "E0016" => include_str!("error_explanation/E0016.md"),
+        "E0017" => include_str!("error_explanation/E0017.md"),
+        "E0018" => include_str!("error_explanation/E0018.md"),
+        "E0019" => include_str!("error_explanation/E0019.md"),
+        "E0020" => include_str!("error_explanation/E0020.md"),
+        "E0021" => include_str!("error_explanation/E0021.md"),
+        "E0022" => include_str!("error_explanation/E0022.md"),
+        "E0023" => include_str!("error_explanation/E0023.md"),
+        "E0024" => include_str!("error_explanation/E0024.md"),
+        "E0025" => include_str!("error_explanation/E0025.md"),
+        "E0026" => include_str!("error_explanation/E0026.md"),
+        "E0027" => include_str!("error_explanation/E0027.md"),
+        "E0028" => include_str!("error_explanation/E0028.md"),
+        "E0029" => include_str!("error_explanation/E0029.md"),
+        "E0030" => include_str!("error_explanation/E0030.md"),
+        "E0031" => include_str!("error_explanation/E0031.md"),
+        "E0032" => include_str!("error_explanation/E0032.md"),
+        "E0033" => include_str!("error_explanation/E0033.md"),
+        "E0034" => include_str!("error_explanation/E0034.md"),
+        "E0035" => include_str!("error_explanation/E0035.md"),
+        "E0036" => include_str!("error_explanation/E0036.md"),
+        "E0037" => include_str!("error_explanation/E0037.md"),
+        "E0038" => include_str!("error_explanation/E0038.md"),
+        "E0039" => include_str!("error_explanation/E0039.md"),
+        "E0040" => include_str!("error_explanation/E0040.md"),
+        "E0041" => include_str!("error_explanation/E0041.md"),
+        "E0042" => include_str!("error_explanation/E0042.md"),
+        "E0043" => include_str!("error_explanation/E0043.md"),
+        "W0001" => include_str!("error_explanation/W0001.md"),
+        "W0002" => include_str!("error_explanation/W0002.md"),
+        "W0003" => include_str!("error_explanation/W0003.md"),
+        "W0004" => include_str!("error_explanation/W0004.md"),
+        "W0005" => include_str!("error_explanation/W0005.md"),
+        "W0006" => include_str!("error_explanation/W0006.md"),
+        "W0007" => include_str!("error_explanation/W0007.md"),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain_all_error_codes() {
+        for index in 1..=43 {
+            assert!(explain(&format!("E{:04}", index)).is_some());
+        }
+    }
+
+    #[test]
+    fn explain_all_warning_codes() {
+        for index in 1..=7 {
+            assert!(explain(&format!("W{:04}", index)).is_some());
+        }
+    }
+
+    #[test]
+    fn explain_unknown_error_code() {
+        assert_eq!(explain("E9999"), None);
+    }
+}
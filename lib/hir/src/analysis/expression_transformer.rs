@@ -0,0 +1,386 @@
+use crate::ir::*;
+
+// Expressions are transformed bottom-up.
+pub fn transform<E>(
+    module: &Module,
+    transform: &dyn Fn(&Expression) -> Result<Expression, E>,
+) -> Result<Module, E> {
+    Ok(Module::new(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| transform_function_definition(definition, transform))
+            .collect::<Result<_, _>>()?,
+        module.position().clone(),
+    ))
+}
+
+pub fn transform_function_definition<E>(
+    definition: &FunctionDefinition,
+    transform: &dyn Fn(&Expression) -> Result<Expression, E>,
+) -> Result<FunctionDefinition, E> {
+    Ok(FunctionDefinition::new(
+        definition.name(),
+        definition.original_name(),
+        transform_lambda(definition.lambda(), transform)?,
+        definition.foreign_definition_configuration().cloned(),
+        definition.is_public(),
+        definition.position().clone(),
+    ))
+}
+
+fn transform_lambda<E>(
+    lambda: &Lambda,
+    transform: &dyn Fn(&Expression) -> Result<Expression, E>,
+) -> Result<Lambda, E> {
+    Ok(Lambda::new(
+        lambda.arguments().to_vec(),
+        lambda.result_type().clone(),
+        transform_expression(lambda.body(), transform)?,
+        lambda.position().clone(),
+    ))
+}
+
+fn transform_expression<E>(
+    expression: &Expression,
+    transform: &dyn Fn(&Expression) -> Result<Expression, E>,
+) -> Result<Expression, E> {
+    let transform_expression = |expression| transform_expression(expression, transform);
+
+    transform(&match expression {
+        Expression::Call(call) => Call::new(
+            call.function_type().cloned(),
+            transform_expression(call.function())?,
+            call.arguments()
+                .iter()
+                .map(transform_expression)
+                .collect::<Result<_, _>>()?,
+            call.position().clone(),
+        )
+        .into(),
+        Expression::If(if_) => If::new(
+            transform_expression(if_.condition())?,
+            transform_expression(if_.then())?,
+            transform_expression(if_.else_())?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::IfList(if_) => IfList::new(
+            if_.type_().cloned(),
+            transform_expression(if_.list())?,
+            if_.first_name(),
+            if_.rest_name(),
+            transform_expression(if_.then())?,
+            transform_expression(if_.else_())?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::IfMap(if_) => IfMap::new(
+            if_.key_type().cloned(),
+            if_.value_type().cloned(),
+            if_.name(),
+            transform_expression(if_.map())?,
+            transform_expression(if_.key())?,
+            transform_expression(if_.then())?,
+            transform_expression(if_.else_())?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::IfType(if_) => IfType::new(
+            if_.name(),
+            transform_expression(if_.argument())?,
+            if_.branches()
+                .iter()
+                .map(|branch| {
+                    Ok(IfTypeBranch::new(
+                        branch.type_().clone(),
+                        transform_expression(branch.expression())?,
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+            if_.else_()
+                .map(|branch| {
+                    Ok(ElseBranch::new(
+                        branch.type_().cloned(),
+                        transform_expression(branch.expression())?,
+                        branch.position().clone(),
+                    ))
+                })
+                .transpose()?,
+            if_.position().clone(),
+        )
+        .into(),
+        Expression::Lambda(lambda) => transform_lambda(lambda, transform)?.into(),
+        Expression::Let(let_) => Let::new(
+            let_.name().map(String::from),
+            let_.type_().cloned(),
+            transform_expression(let_.bound_expression())?,
+            transform_expression(let_.expression())?,
+            let_.position().clone(),
+        )
+        .into(),
+        Expression::List(list) => List::new(
+            list.type_().clone(),
+            list.elements()
+                .iter()
+                .map(|element| {
+                    Ok(match element {
+                        ListElement::Multiple(element) => {
+                            ListElement::Multiple(transform_expression(element)?)
+                        }
+                        ListElement::Single(element) => {
+                            ListElement::Single(transform_expression(element)?)
+                        }
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            list.position().clone(),
+        )
+        .into(),
+        Expression::ListComprehension(comprehension) => ListComprehension::new(
+            comprehension.type_().clone(),
+            transform_expression(comprehension.element())?,
+            transform_list_comprehension_branches(comprehension.branches(), transform)?,
+            comprehension.position().clone(),
+        )
+        .into(),
+        Expression::Map(map) => Map::new(
+            map.key_type().clone(),
+            map.value_type().clone(),
+            map.elements()
+                .iter()
+                .map(|element| {
+                    Ok(match element {
+                        MapElement::Insertion(entry) => MapElement::Insertion(MapEntry::new(
+                            transform_expression(entry.key())?,
+                            transform_expression(entry.value())?,
+                            entry.position().clone(),
+                        )),
+                        MapElement::Map(map) => MapElement::Map(transform_expression(map)?),
+                        MapElement::Removal(key) => MapElement::Removal(transform_expression(key)?),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            map.position().clone(),
+        )
+        .into(),
+        Expression::MapComprehension(comprehension) => MapComprehension::new(
+            comprehension.key_type().clone(),
+            comprehension.value_type().clone(),
+            transform_expression(comprehension.key())?,
+            transform_expression(comprehension.value())?,
+            transform_list_comprehension_branches(comprehension.branches(), transform)?,
+            comprehension.position().clone(),
+        )
+        .into(),
+        Expression::Operation(operation) => transform_operation(operation, transform)?.into(),
+        Expression::RecordConstruction(construction) => RecordConstruction::new(
+            construction.type_().clone(),
+            transform_record_fields(construction.fields(), transform)?,
+            construction.position().clone(),
+        )
+        .into(),
+        Expression::RecordDeconstruction(deconstruction) => RecordDeconstruction::new(
+            deconstruction.type_().cloned(),
+            transform_expression(deconstruction.record())?,
+            deconstruction.field_name(),
+            deconstruction.position().clone(),
+        )
+        .into(),
+        Expression::RecordUpdate(update) => RecordUpdate::new(
+            update.type_().clone(),
+            transform_expression(update.record())?,
+            transform_record_fields(update.fields(), transform)?,
+            update.position().clone(),
+        )
+        .into(),
+        Expression::Thunk(thunk) => Thunk::new(
+            thunk.type_().cloned(),
+            transform_expression(thunk.expression())?,
+            thunk.position().clone(),
+        )
+        .into(),
+        Expression::TypeCoercion(coercion) => TypeCoercion::new(
+            coercion.from().clone(),
+            coercion.to().clone(),
+            transform_expression(coercion.argument())?,
+            coercion.position().clone(),
+        )
+        .into(),
+        Expression::Boolean(_)
+        | Expression::BuiltInFunction(_)
+        | Expression::String(_)
+        | Expression::Integer(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::Variable(_) => expression.clone(),
+    })
+}
+
+fn transform_list_comprehension_branches<E>(
+    branches: &[ListComprehensionBranch],
+    transform: &dyn Fn(&Expression) -> Result<Expression, E>,
+) -> Result<Vec<ListComprehensionBranch>, E> {
+    branches
+        .iter()
+        .map(|branch| {
+            Ok(ListComprehensionBranch::new(
+                branch.names().to_vec(),
+                branch
+                    .iteratees()
+                    .iter()
+                    .map(|iteratee| {
+                        Ok(ListComprehensionIteratee::new(
+                            iteratee.type_().cloned(),
+                            transform_expression(iteratee.expression(), transform)?,
+                        ))
+                    })
+                    .collect::<Result<_, _>>()?,
+                branch
+                    .condition()
+                    .map(|condition| transform_expression(condition, transform))
+                    .transpose()?,
+                branch.position().clone(),
+            ))
+        })
+        .collect()
+}
+
+fn transform_record_fields<E>(
+    fields: &[RecordField],
+    transform: &dyn Fn(&Expression) -> Result<Expression, E>,
+) -> Result<Vec<RecordField>, E> {
+    fields
+        .iter()
+        .map(|field| {
+            Ok(RecordField::new(
+                field.name(),
+                transform_expression(field.expression(), transform)?,
+                field.position().clone(),
+            ))
+        })
+        .collect()
+}
+
+fn transform_operation<E>(
+    operation: &Operation,
+    transform: &dyn Fn(&Expression) -> Result<Expression, E>,
+) -> Result<Operation, E> {
+    let transform_expression = |expression| transform_expression(expression, transform);
+
+    Ok(match operation {
+        Operation::Arithmetic(operation) => ArithmeticOperation::new(
+            operation.type_().cloned(),
+            operation.operator(),
+            transform_expression(operation.lhs())?,
+            transform_expression(operation.rhs())?,
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Boolean(operation) => BooleanOperation::new(
+            operation.operator(),
+            transform_expression(operation.lhs())?,
+            transform_expression(operation.rhs())?,
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Equality(operation) => EqualityOperation::new(
+            operation.type_().cloned(),
+            operation.operator(),
+            transform_expression(operation.lhs())?,
+            transform_expression(operation.rhs())?,
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Not(operation) => NotOperation::new(
+            transform_expression(operation.expression())?,
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Order(operation) => OrderOperation::new(
+            operation.type_().cloned(),
+            operation.operator(),
+            transform_expression(operation.lhs())?,
+            transform_expression(operation.rhs())?,
+            operation.position().clone(),
+        )
+        .into(),
+        Operation::Try(operation) => TryOperation::new(
+            operation.type_().cloned(),
+            transform_expression(operation.expression())?,
+            operation.position().clone(),
+        )
+        .into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::{FunctionDefinitionFake, ModuleFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_expressions_bottom_up() {
+        assert_eq!(
+            transform(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        Call::new(
+                            None,
+                            Variable::new("x", Position::fake()),
+                            vec![],
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    ),
+                    false
+                )]),
+                &|expression| -> Result<_, ()> {
+                    Ok(match expression {
+                        Expression::Variable(variable) => {
+                            Variable::new("y", variable.position().clone()).into()
+                        }
+                        Expression::Call(call) => Call::new(
+                            call.function_type().cloned(),
+                            call.function().clone(),
+                            vec![None::new(Position::fake()).into()],
+                            call.position().clone(),
+                        )
+                        .into(),
+                        _ => expression.clone(),
+                    })
+                }
+            ),
+            Ok(
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        Call::new(
+                            None,
+                            Variable::new("y", Position::fake()),
+                            vec![None::new(Position::fake()).into()],
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    ),
+                    false
+                )])
+            )
+        );
+    }
+}
@@ -6,7 +6,7 @@ pub fn visit<'a>(module: &'a Module, mut visit: impl FnMut(&'a Expression)) {
     }
 }
 
-fn visit_definition<'a>(
+pub fn visit_definition<'a>(
     definition: &'a FunctionDefinition,
     visit: &mut impl FnMut(&'a Expression),
 ) {
@@ -75,7 +75,16 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a E
         }
         Expression::ListComprehension(comprehension) => {
             visit_expression(comprehension.element());
-            visit_expression(comprehension.list());
+
+            for branch in comprehension.branches() {
+                for iteratee in branch.iteratees() {
+                    visit_expression(iteratee.expression());
+                }
+
+                if let Some(condition) = branch.condition() {
+                    visit_expression(condition);
+                }
+            }
         }
         Expression::Map(map) => {
             for element in map.elements() {
@@ -89,9 +98,19 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a E
                 }
             }
         }
-        Expression::MapIterationComprehension(comprehension) => {
-            visit_expression(comprehension.element());
-            visit_expression(comprehension.map());
+        Expression::MapComprehension(comprehension) => {
+            visit_expression(comprehension.key());
+            visit_expression(comprehension.value());
+
+            for branch in comprehension.branches() {
+                for iteratee in branch.iteratees() {
+                    visit_expression(iteratee.expression());
+                }
+
+                if let Some(condition) = branch.condition() {
+                    visit_expression(condition);
+                }
+            }
         }
         Expression::Operation(operation) => match operation {
             Operation::Arithmetic(operation) => {
@@ -133,6 +152,7 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a E
         Expression::Thunk(thunk) => visit_expression(thunk.expression()),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
+        | Expression::Integer(_)
         | Expression::None(_)
         | Expression::Number(_)
         | Expression::String(_)
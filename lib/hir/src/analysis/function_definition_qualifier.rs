@@ -38,6 +38,23 @@ pub fn qualify(module: &Module, prefix: &str) -> Module {
                 })
                 .collect(),
             module.function_declarations().to_vec(),
+            module
+                .implementations()
+                .iter()
+                .map(|implementation| {
+                    if implementation.is_external() {
+                        implementation.clone()
+                    } else {
+                        Implementation::new(
+                            prefix.to_owned() + implementation.name(),
+                            implementation.interface().clone(),
+                            implementation.type_().clone(),
+                            implementation.is_external(),
+                            implementation.position().clone(),
+                        )
+                    }
+                })
+                .collect(),
             module
                 .function_definitions()
                 .iter()
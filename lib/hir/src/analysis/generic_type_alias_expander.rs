@@ -0,0 +1,319 @@
+use super::{anonymous_record_qualifier, type_formatter, type_instantiator, type_transformer};
+use crate::{
+    ir::*,
+    types::{self, Type},
+};
+use fnv::FnvHashMap;
+use position::Position;
+use std::cell::RefCell;
+
+// Generic type aliases are expanded with their type arguments as they cannot
+// be resolved by their names only. Anonymous records in them are instantiated
+// into new type definitions.
+pub fn expand(module: &Module) -> Module {
+    let aliases = module
+        .type_aliases()
+        .iter()
+        .filter(|alias| !alias.type_parameters().is_empty())
+        .map(|alias| (alias.name(), alias))
+        .collect::<FnvHashMap<_, _>>();
+
+    if aliases.is_empty() {
+        return module.clone();
+    }
+
+    let context = Context {
+        aliases,
+        records: module
+            .type_definitions()
+            .iter()
+            .filter(|definition| {
+                anonymous_record_qualifier::is_anonymous(definition)
+                    && !definition.type_parameters().is_empty()
+            })
+            .map(|definition| (definition.name(), definition))
+            .collect(),
+        instances: Default::default(),
+    };
+    let module = type_transformer::transform(module, |type_| expand_type(type_, &context, &[]));
+    let mut definitions = module.type_definitions().to_vec();
+
+    for definition in context.instances.into_inner() {
+        if !definitions
+            .iter()
+            .any(|other| other.name() == definition.name())
+        {
+            definitions.push(definition);
+        }
+    }
+
+    Module::new(
+        definitions,
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
+        module.function_definitions().to_vec(),
+        module.position().clone(),
+    )
+}
+
+struct Context<'a> {
+    aliases: FnvHashMap<&'a str, &'a TypeAlias>,
+    records: FnvHashMap<&'a str, &'a TypeDefinition>,
+    instances: RefCell<Vec<TypeDefinition>>,
+}
+
+fn expand_type(type_: &Type, context: &Context, names: &[&str]) -> Type {
+    match type_ {
+        Type::Reference(reference) => match context.aliases.get(reference.name()) {
+            // Recursive aliases are reported later.
+            Some(alias)
+                if alias.type_parameters().len() == reference.arguments().len()
+                    && !names.contains(&alias.name()) =>
+            {
+                let names = names
+                    .iter()
+                    .copied()
+                    .chain([alias.name()])
+                    .collect::<Vec<_>>();
+
+                type_transformer::transform_type(
+                    &instantiate_type(
+                        alias.type_(),
+                        &alias
+                            .type_parameters()
+                            .iter()
+                            .cloned()
+                            .zip(reference.arguments().iter().cloned())
+                            .collect(),
+                        context,
+                    ),
+                    &|type_| expand_type(type_, context, &names),
+                )
+            }
+            _ => type_.clone(),
+        },
+        _ => type_.clone(),
+    }
+}
+
+fn instantiate_type(type_: &Type, variables: &FnvHashMap<String, Type>, context: &Context) -> Type {
+    type_transformer::transform_type(&type_instantiator::substitute(type_, variables), &|type_| {
+        match type_ {
+            Type::Record(record) => match context.records.get(record.name()) {
+                Some(definition) => {
+                    instantiate_record(definition, variables, context, record.position())
+                }
+                None => type_.clone(),
+            },
+            _ => type_.clone(),
+        }
+    })
+}
+
+fn instantiate_record(
+    definition: &TypeDefinition,
+    variables: &FnvHashMap<String, Type>,
+    context: &Context,
+    position: &Position,
+) -> Type {
+    let fields = definition
+        .fields()
+        .iter()
+        .map(|field| {
+            types::RecordField::new(
+                field.name(),
+                instantiate_type(field.type_(), variables, context),
+            )
+        })
+        .collect::<Vec<_>>();
+    let name = type_formatter::format_anonymous_record(&fields);
+
+    context.instances.borrow_mut().push(TypeDefinition::new(
+        &name,
+        &name,
+        fields,
+        definition.is_open(),
+        definition.is_public(),
+        false,
+        definition.position().clone(),
+    ));
+
+    types::Record::new(&name, position.clone()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test::{ModuleFake, TypeAliasFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn create_alias() -> TypeAlias {
+        TypeAlias::fake(
+            "foo",
+            types::Union::new(
+                types::Variable::new("a", Position::fake()),
+                types::None::new(Position::fake()),
+                Position::fake(),
+            ),
+            false,
+            false,
+        )
+        .set_type_parameters(vec!["a".into()])
+    }
+
+    #[test]
+    fn expand_alias() {
+        let alias = create_alias();
+
+        assert_eq!(
+            expand(&Module::empty().set_type_aliases(vec![
+                    alias.clone(),
+                    TypeAlias::fake(
+                        "bar",
+                        types::Reference::new("foo", Position::fake())
+                            .set_arguments(vec![types::Number::new(Position::fake()).into()]),
+                        false,
+                        false,
+                    )
+                ])),
+            Module::empty().set_type_aliases(vec![
+                alias,
+                TypeAlias::fake(
+                    "bar",
+                    types::Union::new(
+                        types::Number::new(Position::fake()),
+                        types::None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                    false,
+                )
+            ])
+        );
+    }
+
+    #[test]
+    fn expand_nested_alias() {
+        let alias = create_alias();
+
+        assert_eq!(
+            expand(&Module::empty().set_type_aliases(vec![
+                alias.clone(),
+                TypeAlias::fake(
+                    "bar",
+                    types::Reference::new("foo", Position::fake()).set_arguments(vec![
+                            types::Reference::new("foo", Position::fake())
+                                .set_arguments(vec![types::Number::new(Position::fake()).into()])
+                                .into()
+                        ]),
+                    false,
+                    false,
+                )
+            ])),
+            Module::empty().set_type_aliases(vec![
+                alias,
+                TypeAlias::fake(
+                    "bar",
+                    types::Union::new(
+                        types::Union::new(
+                            types::Number::new(Position::fake()),
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        types::None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                    false,
+                )
+            ])
+        );
+    }
+
+    #[test]
+    fn expand_alias_of_anonymous_record() {
+        let definition = TypeDefinition::new(
+            "{x a}",
+            "{x a}",
+            vec![types::RecordField::new(
+                "x",
+                types::Variable::new("a", Position::fake()),
+            )],
+            true,
+            true,
+            false,
+            Position::fake(),
+        )
+        .set_type_parameters(vec!["a".into()]);
+        let alias = TypeAlias::fake(
+            "foo",
+            types::Record::new("{x a}", Position::fake()),
+            false,
+            false,
+        )
+        .set_type_parameters(vec!["a".into()]);
+
+        assert_eq!(
+            expand(
+                &Module::empty()
+                    .set_type_definitions(vec![definition.clone()])
+                    .set_type_aliases(vec![
+                        alias.clone(),
+                        TypeAlias::fake(
+                            "bar",
+                            types::Reference::new("foo", Position::fake())
+                                .set_arguments(vec![types::Number::new(Position::fake()).into()]),
+                            false,
+                            false,
+                        )
+                    ])
+            ),
+            Module::empty()
+                .set_type_definitions(vec![
+                    definition,
+                    TypeDefinition::new(
+                        "{x number}",
+                        "{x number}",
+                        vec![types::RecordField::new(
+                            "x",
+                            types::Number::new(Position::fake()),
+                        )],
+                        true,
+                        true,
+                        false,
+                        Position::fake(),
+                    )
+                ])
+                .set_type_aliases(vec![
+                    alias,
+                    TypeAlias::fake(
+                        "bar",
+                        types::Record::new("{x number}", Position::fake()),
+                        false,
+                        false,
+                    )
+                ])
+        );
+    }
+
+    #[test]
+    fn do_not_expand_alias_with_wrong_number_of_arguments() {
+        let module = Module::empty().set_type_aliases(vec![
+            create_alias(),
+            TypeAlias::fake(
+                "bar",
+                types::Reference::new("foo", Position::fake()),
+                false,
+                false,
+            ),
+        ]);
+
+        assert_eq!(expand(&module), module);
+    }
+}
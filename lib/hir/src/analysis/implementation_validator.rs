@@ -0,0 +1,276 @@
+use super::{
+    context::AnalysisContext, interface_resolver, type_equality_checker, type_extractor,
+    type_transformer, AnalysisError,
+};
+use crate::{ir::*, types::Type};
+use fnv::FnvHashMap;
+
+pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
+    let definitions = module
+        .function_definitions()
+        .iter()
+        .map(|definition| (definition.name(), definition))
+        .collect::<FnvHashMap<_, _>>();
+
+    for implementation in module
+        .implementations()
+        .iter()
+        .filter(|implementation| !implementation.is_external())
+    {
+        let fields = interface_resolver::resolve(context, implementation.interface())?
+            .ok_or_else(|| AnalysisError::InterfaceExpected(implementation.interface().clone()))?;
+
+        for field in fields {
+            let definition = definitions
+                .get(
+                    interface_resolver::resolve_implementation_function_name(
+                        implementation.name(),
+                        field.name(),
+                    )
+                    .as_str(),
+                )
+                .ok_or_else(|| {
+                    AnalysisError::InterfaceMethodMissing(
+                        implementation.position().clone(),
+                        field.name().into(),
+                    )
+                })?;
+            let type_ = type_extractor::extract_from_lambda(definition.lambda()).into();
+            let method_type = type_transformer::transform_type(field.type_(), &|type_| {
+                if type_.is_variable() {
+                    implementation.type_().clone()
+                } else {
+                    type_.clone()
+                }
+            });
+
+            if !type_equality_checker::check(&type_, &method_type, context.types())? {
+                return Err(AnalysisError::TypesNotMatched(type_, method_type));
+            }
+        }
+
+        let prefix =
+            interface_resolver::resolve_implementation_function_name(implementation.name(), "");
+
+        for definition in module.function_definitions() {
+            if let Some(name) = definition.name().strip_prefix(&prefix) {
+                if !fields.iter().any(|field| field.name() == name) {
+                    return Err(AnalysisError::InterfaceMethodUnknown(
+                        definition.position().clone(),
+                    ));
+                }
+            }
+        }
+    }
+
+    validate_duplicates(context, module.implementations())
+}
+
+fn validate_duplicates(
+    context: &AnalysisContext,
+    implementations: &[Implementation],
+) -> Result<(), AnalysisError> {
+    let is_equal =
+        |one: &Type, other: &Type| type_equality_checker::check(one, other, context.types());
+
+    for (index, one) in implementations.iter().enumerate() {
+        for other in &implementations[index + 1..] {
+            if is_equal(one.interface(), other.interface())?
+                && is_equal(one.type_(), other.type_())?
+            {
+                return Err(AnalysisError::DuplicateImplementations(
+                    one.position().clone(),
+                    other.position().clone(),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::type_collector,
+        test::{FunctionDefinitionFake, ModuleFake, TypeDefinitionFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn validate_module(module: &Module) -> Result<(), AnalysisError> {
+        validate(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_records(module),
+            ),
+            module,
+        )
+    }
+
+    fn interface_definition() -> TypeDefinition {
+        TypeDefinition::fake(
+            "Show",
+            vec![types::RecordField::new(
+                "String",
+                types::Function::new(
+                    vec![types::Variable::new("a", Position::fake())
+                        .set_constraint(Some(
+                            types::Reference::new("Show", Position::fake()).into(),
+                        ))
+                        .into()],
+                    types::ByteString::new(Position::fake()),
+                    Position::fake(),
+                ),
+            )],
+            false,
+            false,
+            false,
+        )
+    }
+
+    fn implementation() -> Implementation {
+        Implementation::new(
+            "$implementation:0",
+            types::Reference::new("Show", Position::fake()),
+            types::Number::new(Position::fake()),
+            false,
+            Position::fake(),
+        )
+    }
+
+    fn implementation_function_definition(
+        name: &str,
+        argument_type: impl Into<Type>,
+    ) -> FunctionDefinition {
+        FunctionDefinition::fake(
+            interface_resolver::resolve_implementation_function_name("$implementation:0", name),
+            Lambda::new(
+                vec![Argument::new("x", argument_type)],
+                types::ByteString::new(Position::fake()),
+                ByteString::new("", Position::fake()),
+                Position::fake(),
+            ),
+            true,
+        )
+    }
+
+    #[test]
+    fn validate_implementation() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![interface_definition()])
+                    .set_implementations(vec![implementation()])
+                    .set_function_definitions(vec![implementation_function_definition(
+                        "String",
+                        types::Number::new(Position::fake())
+                    )])
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_implementation_of_record() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "Show",
+                        vec![],
+                        false,
+                        false,
+                        false
+                    )])
+                    .set_implementations(vec![implementation()])
+            ),
+            Err(AnalysisError::InterfaceExpected(
+                types::Reference::new("Show", Position::fake()).into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_missing_method() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![interface_definition()])
+                    .set_implementations(vec![implementation()])
+            ),
+            Err(AnalysisError::InterfaceMethodMissing(
+                Position::fake(),
+                "String".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_unknown_method() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![interface_definition()])
+                    .set_implementations(vec![implementation()])
+                    .set_function_definitions(vec![
+                        implementation_function_definition(
+                            "String",
+                            types::Number::new(Position::fake())
+                        ),
+                        implementation_function_definition(
+                            "Debug",
+                            types::Number::new(Position::fake())
+                        )
+                    ])
+            ),
+            Err(AnalysisError::InterfaceMethodUnknown(Position::fake()))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_method_of_wrong_type() {
+        assert!(matches!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![interface_definition()])
+                    .set_implementations(vec![implementation()])
+                    .set_function_definitions(vec![implementation_function_definition(
+                        "String",
+                        types::None::new(Position::fake())
+                    )])
+            ),
+            Err(AnalysisError::TypesNotMatched(_, _))
+        ));
+    }
+
+    #[test]
+    fn fail_to_validate_duplicate_implementations() {
+        assert_eq!(
+            validate_module(
+                &Module::empty()
+                    .set_type_definitions(vec![interface_definition()])
+                    .set_implementations(vec![
+                        implementation(),
+                        Implementation::new(
+                            "$implementation:1",
+                            types::Reference::new("Show", Position::fake()),
+                            types::Number::new(Position::fake()),
+                            true,
+                            Position::fake(),
+                        )
+                    ])
+                    .set_function_definitions(vec![implementation_function_definition(
+                        "String",
+                        types::Number::new(Position::fake())
+                    )])
+            ),
+            Err(AnalysisError::DuplicateImplementations(
+                Position::fake(),
+                Position::fake()
+            ))
+        );
+    }
+}
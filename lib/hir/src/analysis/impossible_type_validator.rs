@@ -49,9 +49,13 @@ fn is_type_recursive(
                         context,
                         name,
                         &cache.clone().into_iter().chain([record.name()]).collect(),
-                        record_field_resolver::resolve_record(record, context.records())?
-                            .iter()
-                            .map(|field| field.type_()),
+                        record_field_resolver::resolve_record(
+                            record,
+                            context.types(),
+                            context.records(),
+                        )?
+                        .iter()
+                        .map(|field| field.type_()),
                     )?)
         }
         Type::Union(union) => [union.lhs(), union.rhs()]
@@ -67,8 +71,10 @@ fn is_type_recursive(
         | Type::List(_)
         | Type::Map(_)
         | Type::None(_)
+        | Type::Integer(_)
         | Type::Number(_)
-        | Type::String(_) => false,
+        | Type::String(_)
+        | Type::Variable(_) => false,
     })
 }
 
@@ -0,0 +1,108 @@
+use super::{context::AnalysisContext, type_canonicalizer, type_equality_checker, AnalysisError};
+use crate::{
+    ir::*,
+    types::{self, Type},
+};
+
+// Interfaces are records of their methods whose types refer to type variables
+// constrained by the interfaces themselves.
+pub fn resolve<'a>(
+    context: &'a AnalysisContext,
+    type_: &Type,
+) -> Result<Option<&'a [types::RecordField]>, AnalysisError> {
+    let record = match type_canonicalizer::canonicalize_record(type_, context.types())? {
+        Some(record) => record,
+        None => return Ok(None),
+    };
+    // Interfaces are never generic records.
+    let fields = context
+        .records()
+        .get(record.name())
+        .ok_or_else(|| AnalysisError::RecordNotFound(record.clone(), vec![]))?;
+
+    for field in fields {
+        for variable in collect_constrained_variables(field.type_()) {
+            if let Some(constraint) = variable.constraint() {
+                if type_canonicalizer::canonicalize_record(constraint, context.types())?
+                    .map(|constraint| constraint.name() == record.name())
+                    .unwrap_or_default()
+                {
+                    return Ok(Some(fields));
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn resolve_implementation<'a>(
+    context: &AnalysisContext,
+    implementations: &'a [Implementation],
+    interface: &Type,
+    type_: &Type,
+) -> Result<Option<&'a Implementation>, AnalysisError> {
+    for implementation in implementations {
+        if type_equality_checker::check(implementation.interface(), interface, context.types())?
+            && type_equality_checker::check(implementation.type_(), type_, context.types())?
+        {
+            return Ok(Some(implementation));
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn resolve_implementation_function_name(
+    implementation_name: &str,
+    method_name: &str,
+) -> String {
+    format!("{}:{}", implementation_name, method_name)
+}
+
+// Variables are collected in a deterministic order so that dictionaries of
+// their implementations can be passed as arguments.
+pub fn collect_constrained_variables(type_: &Type) -> Vec<types::Variable> {
+    let mut variables = vec![];
+
+    collect_variables(type_, &mut variables);
+
+    variables
+}
+
+fn collect_variables(type_: &Type, variables: &mut Vec<types::Variable>) {
+    match type_ {
+        Type::Function(function) => {
+            for type_ in function.arguments().iter().chain([function.result()]) {
+                collect_variables(type_, variables);
+            }
+        }
+        Type::List(list) => collect_variables(list.element(), variables),
+        Type::Map(map) => {
+            collect_variables(map.key(), variables);
+            collect_variables(map.value(), variables);
+        }
+        Type::Union(union) => {
+            collect_variables(union.lhs(), variables);
+            collect_variables(union.rhs(), variables);
+        }
+        Type::Variable(variable) => {
+            if variable.constraint().is_some()
+                && !variables
+                    .iter()
+                    .any(|other| other.name() == variable.name())
+            {
+                variables.push(variable.clone());
+            }
+        }
+        Type::Any(_)
+        | Type::Boolean(_)
+        | Type::Error(_)
+        | Type::None(_)
+        | Type::Integer(_)
+        | Type::Number(_)
+        | Type::Record(_)
+        | Type::Reference(_)
+        | Type::String(_) => {}
+    }
+}
@@ -0,0 +1,473 @@
+use super::{warning::AnalysisWarning, warning_configuration::WarningConfiguration};
+use crate::ir::*;
+use position::Position;
+
+const IGNORED_NAME_PREFIXES: &[&str] = &["_", "$"];
+
+struct Binding<'a> {
+    name: &'a str,
+    position: &'a Position,
+    used: bool,
+}
+
+struct Checker<'a, 'b> {
+    configuration: &'b WarningConfiguration,
+    scope: Vec<Binding<'a>>,
+    warnings: Vec<AnalysisWarning>,
+}
+
+pub fn check(module: &Module, configuration: &WarningConfiguration) -> Vec<AnalysisWarning> {
+    let mut checker = Checker {
+        configuration,
+        scope: vec![],
+        warnings: vec![],
+    };
+
+    for definition in module.function_definitions() {
+        checker.check_lambda(definition.lambda());
+    }
+
+    checker.warnings
+}
+
+impl<'a, 'b> Checker<'a, 'b> {
+    fn check_lambda(&mut self, lambda: &'a Lambda) {
+        let bindings = lambda
+            .arguments()
+            .iter()
+            .map(|argument| (argument.name(), lambda.position()))
+            .collect::<Vec<_>>();
+
+        self.check_scope(&bindings, lambda.body());
+    }
+
+    fn check_expression(&mut self, expression: &'a Expression) {
+        match expression {
+            Expression::Call(call) => {
+                self.check_expression(call.function());
+
+                for argument in call.arguments() {
+                    self.check_expression(argument);
+                }
+            }
+            Expression::If(if_) => {
+                self.check_expression(if_.condition());
+                self.check_expression(if_.then());
+                self.check_expression(if_.else_());
+            }
+            Expression::IfList(if_) => {
+                self.check_expression(if_.list());
+                self.check_scope(
+                    &[
+                        (if_.first_name(), if_.position()),
+                        (if_.rest_name(), if_.position()),
+                    ],
+                    if_.then(),
+                );
+                self.check_expression(if_.else_());
+            }
+            Expression::IfMap(if_) => {
+                self.check_expression(if_.map());
+                self.check_expression(if_.key());
+                self.check_scope(&[(if_.name(), if_.position())], if_.then());
+                self.check_expression(if_.else_());
+            }
+            Expression::IfType(if_) => {
+                self.check_expression(if_.argument());
+
+                for branch in if_.branches() {
+                    self.check_scope(&[(if_.name(), if_.position())], branch.expression());
+                }
+
+                if let Some(branch) = if_.else_() {
+                    self.check_scope(&[(if_.name(), if_.position())], branch.expression());
+                }
+            }
+            Expression::Lambda(lambda) => self.check_lambda(lambda),
+            Expression::Let(let_) => {
+                self.check_expression(let_.bound_expression());
+
+                if let Some(name) = let_.name() {
+                    self.check_shadowing(name, let_.position());
+                    self.scope.push(Binding {
+                        name,
+                        position: let_.position(),
+                        used: false,
+                    });
+                    self.check_expression(let_.expression());
+
+                    if let Some(binding) = self.scope.pop() {
+                        if self.configuration.unused_variables
+                            && !binding.used
+                            && !is_ignored(binding.name)
+                        {
+                            self.warnings.push(AnalysisWarning::UnusedVariable(
+                                binding.name.into(),
+                                binding.position.clone(),
+                            ));
+                        }
+                    }
+                } else {
+                    self.check_expression(let_.expression());
+                }
+            }
+            Expression::List(list) => {
+                for element in list.elements() {
+                    match element {
+                        ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                            self.check_expression(expression)
+                        }
+                    }
+                }
+            }
+            Expression::ListComprehension(comprehension) => {
+                self.check_comprehension(comprehension.branches(), &[comprehension.element()])
+            }
+            Expression::Map(map) => {
+                for element in map.elements() {
+                    match element {
+                        MapElement::Insertion(entry) => {
+                            self.check_expression(entry.key());
+                            self.check_expression(entry.value());
+                        }
+                        MapElement::Map(expression) | MapElement::Removal(expression) => {
+                            self.check_expression(expression)
+                        }
+                    }
+                }
+            }
+            Expression::MapComprehension(comprehension) => self.check_comprehension(
+                comprehension.branches(),
+                &[comprehension.key(), comprehension.value()],
+            ),
+            Expression::Operation(operation) => match operation {
+                Operation::Arithmetic(operation) => {
+                    self.check_expression(operation.lhs());
+                    self.check_expression(operation.rhs());
+                }
+                Operation::Boolean(operation) => {
+                    self.check_expression(operation.lhs());
+                    self.check_expression(operation.rhs());
+                }
+                Operation::Equality(operation) => {
+                    self.check_expression(operation.lhs());
+                    self.check_expression(operation.rhs());
+                }
+                Operation::Not(operation) => self.check_expression(operation.expression()),
+                Operation::Order(operation) => {
+                    self.check_expression(operation.lhs());
+                    self.check_expression(operation.rhs());
+                }
+                Operation::Try(operation) => self.check_expression(operation.expression()),
+            },
+            Expression::RecordConstruction(construction) => {
+                for field in construction.fields() {
+                    self.check_expression(field.expression());
+                }
+            }
+            Expression::RecordDeconstruction(deconstruction) => {
+                self.check_expression(deconstruction.record())
+            }
+            Expression::RecordUpdate(update) => {
+                self.check_expression(update.record());
+
+                for field in update.fields() {
+                    self.check_expression(field.expression());
+                }
+            }
+            Expression::Thunk(thunk) => self.check_expression(thunk.expression()),
+            Expression::TypeCoercion(coercion) => self.check_expression(coercion.argument()),
+            Expression::Variable(variable) => {
+                if let Some(binding) = self
+                    .scope
+                    .iter_mut()
+                    .rev()
+                    .find(|binding| binding.name == variable.name())
+                {
+                    binding.used = true;
+                }
+            }
+            Expression::Boolean(_)
+            | Expression::BuiltInFunction(_)
+            | Expression::Integer(_)
+            | Expression::None(_)
+            | Expression::Number(_)
+            | Expression::String(_) => {}
+        }
+    }
+
+    // Variables bound by anything other than let expressions are never
+    // reported as unused since they are often required syntactically.
+    fn check_scope(&mut self, bindings: &[(&'a str, &'a Position)], expression: &'a Expression) {
+        for &(name, position) in bindings {
+            self.check_shadowing(name, position);
+        }
+
+        let length = self.scope.len();
+
+        self.scope
+            .extend(bindings.iter().map(|&(name, position)| Binding {
+                name,
+                position,
+                used: false,
+            }));
+        self.check_expression(expression);
+        self.scope.truncate(length);
+    }
+
+    fn check_comprehension(
+        &mut self,
+        branches: &'a [ListComprehensionBranch],
+        elements: &[&'a Expression],
+    ) {
+        let (branch, branches) = match branches {
+            [] => {
+                for element in elements {
+                    self.check_expression(element);
+                }
+
+                return;
+            }
+            [branch, branches @ ..] => (branch, branches),
+        };
+
+        for iteratee in branch.iteratees() {
+            self.check_expression(iteratee.expression());
+        }
+
+        for name in branch.names() {
+            self.check_shadowing(name, branch.position());
+        }
+
+        let length = self.scope.len();
+
+        self.scope.extend(branch.names().iter().map(|name| Binding {
+            name,
+            position: branch.position(),
+            used: false,
+        }));
+
+        if let Some(condition) = branch.condition() {
+            self.check_expression(condition);
+        }
+
+        self.check_comprehension(branches, elements);
+        self.scope.truncate(length);
+    }
+
+    fn check_shadowing(&mut self, name: &str, position: &Position) {
+        if self.configuration.shadowed_variables
+            && !is_ignored(name)
+            && self.scope.iter().any(|binding| binding.name == name)
+        {
+            self.warnings.push(AnalysisWarning::ShadowedVariable(
+                name.into(),
+                position.clone(),
+            ));
+        }
+    }
+}
+
+// Variables generated by compilers are prefixed with `$`.
+fn is_ignored(name: &str) -> bool {
+    IGNORED_NAME_PREFIXES
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::ModuleFake, types};
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn check_module(module: &Module) -> Vec<AnalysisWarning> {
+        check(module, &Default::default())
+    }
+
+    fn create_module(arguments: Vec<Argument>, body: impl Into<Expression>) -> Module {
+        Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+            "f",
+            "f",
+            Lambda::new(
+                arguments,
+                types::None::new(Position::fake()),
+                body,
+                Position::fake(),
+            ),
+            None,
+            false,
+            Position::fake(),
+        )])
+    }
+
+    #[test]
+    fn check_used_variable() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                Let::new(
+                    Some("x".into()),
+                    None,
+                    None::new(Position::fake()),
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                ),
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_unused_variable() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                Let::new(
+                    Some("x".into()),
+                    None,
+                    None::new(Position::fake()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                ),
+            )),
+            vec![AnalysisWarning::UnusedVariable(
+                "x".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn check_unused_variable_with_ignored_name() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                Let::new(
+                    Some("_x".into()),
+                    None,
+                    None::new(Position::fake()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                ),
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_variable_shadowed_by_generated_variable() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                Let::new(
+                    Some("$x".into()),
+                    None,
+                    None::new(Position::fake()),
+                    Let::new(
+                        Some("$x".into()),
+                        None,
+                        None::new(Position::fake()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_variable_used_in_closure() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                Let::new(
+                    Some("x".into()),
+                    None,
+                    None::new(Position::fake()),
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+            )),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_variable_shadowed_by_variable() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![],
+                Let::new(
+                    Some("x".into()),
+                    None,
+                    None::new(Position::fake()),
+                    Let::new(
+                        Some("x".into()),
+                        None,
+                        None::new(Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+            )),
+            vec![
+                AnalysisWarning::ShadowedVariable("x".into(), Position::fake()),
+                AnalysisWarning::UnusedVariable("x".into(), Position::fake()),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_argument_shadowed_by_variable() {
+        assert_eq!(
+            check_module(&create_module(
+                vec![Argument::new("x", types::None::new(Position::fake()))],
+                Let::new(
+                    Some("x".into()),
+                    None,
+                    Variable::new("x", Position::fake()),
+                    Variable::new("x", Position::fake()),
+                    Position::fake(),
+                ),
+            )),
+            vec![AnalysisWarning::ShadowedVariable(
+                "x".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn check_variables_with_configuration() {
+        assert_eq!(
+            check(
+                &create_module(
+                    vec![Argument::new("x", types::None::new(Position::fake()))],
+                    Let::new(
+                        Some("x".into()),
+                        None,
+                        None::new(Position::fake()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                ),
+                &WarningConfiguration {
+                    unused_variables: false,
+                    shadowed_variables: false,
+                    ..Default::default()
+                }
+            ),
+            vec![]
+        );
+    }
+}
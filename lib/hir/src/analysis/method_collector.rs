@@ -0,0 +1,132 @@
+use super::{context::AnalysisContext, type_canonicalizer, AnalysisError};
+use crate::{ir::*, types::Type};
+use fnv::FnvHashMap;
+
+// Methods of a record type are functions in the module defining the type
+// whose first arguments are of the type.
+pub fn collect(
+    context: &AnalysisContext,
+    module: &Module,
+    variables: &FnvHashMap<String, Type>,
+) -> Result<FnvHashMap<(String, String), String>, AnalysisError> {
+    let prefixes = module
+        .type_definitions()
+        .iter()
+        .map(|definition| {
+            (
+                definition.name(),
+                definition
+                    .name()
+                    .strip_suffix(definition.original_name())
+                    .unwrap_or_default(),
+            )
+        })
+        .collect::<FnvHashMap<_, _>>();
+    let mut methods = FnvHashMap::default();
+
+    for (name, type_) in variables {
+        let function_type = match type_canonicalizer::canonicalize_function(type_, context.types())?
+        {
+            Some(type_) => type_,
+            None => continue,
+        };
+        let record_type = match function_type.arguments().first() {
+            Some(type_) => match type_canonicalizer::canonicalize_record(type_, context.types())? {
+                Some(type_) => type_,
+                None => continue,
+            },
+            None => continue,
+        };
+
+        if let Some(method_name) = prefixes
+            .get(record_type.name())
+            .and_then(|prefix| name.strip_prefix(prefix))
+        {
+            methods.insert(
+                (record_type.name().into(), method_name.into()),
+                name.clone(),
+            );
+        }
+    }
+
+    Ok(methods)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::{module_environment_creator, type_collector},
+        test::ModuleFake,
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn collect_module(module: &Module) -> FnvHashMap<(String, String), String> {
+        collect(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_records(module),
+            ),
+            module,
+            &module_environment_creator::create(module),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn collect_method() {
+        let record_type = types::Record::new("foo:bar", Position::fake());
+
+        assert_eq!(
+            collect_module(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::new(
+                        "foo:bar",
+                        "bar",
+                        vec![],
+                        false,
+                        false,
+                        false,
+                        Position::fake()
+                    )])
+                    .set_function_declarations(vec![
+                        FunctionDeclaration::new(
+                            "foo:Baz",
+                            "Baz",
+                            types::Function::new(
+                                vec![record_type.clone().into()],
+                                types::None::new(Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        ),
+                        FunctionDeclaration::new(
+                            "qux:Baz",
+                            "Baz",
+                            types::Function::new(
+                                vec![record_type.into()],
+                                types::None::new(Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        ),
+                        FunctionDeclaration::new(
+                            "foo:Qux",
+                            "Qux",
+                            types::Function::new(
+                                vec![types::None::new(Position::fake()).into()],
+                                types::None::new(Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        )
+                    ])
+            ),
+            [(("foo:bar".into(), "Baz".into()), "foo:Baz".into())]
+                .into_iter()
+                .collect()
+        );
+    }
+}
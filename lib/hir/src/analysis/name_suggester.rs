@@ -0,0 +1,194 @@
+use super::AnalysisError;
+use crate::{ir::*, types};
+use fnv::FnvHashMap;
+
+const MAX_SUGGESTION_COUNT: usize = 3;
+const IDENTIFIER_SEPARATOR: char = '\'';
+
+pub fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut suggestions = candidates
+        .into_iter()
+        .filter(|&candidate| !candidate.is_empty() && candidate != name)
+        .filter_map(|candidate| {
+            // Match unqualified names too so that we can suggest qualified
+            // names of functions and types in imported modules.
+            let distance = calculate_distance(name, candidate).min(calculate_distance(
+                name,
+                candidate
+                    .rsplit(IDENTIFIER_SEPARATOR)
+                    .next()
+                    .unwrap_or(candidate),
+            ));
+
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+
+    suggestions.sort();
+    suggestions.dedup_by_key(|(_, candidate)| *candidate);
+
+    suggestions
+        .into_iter()
+        .take(MAX_SUGGESTION_COUNT)
+        .map(|(_, candidate)| candidate.into())
+        .collect()
+}
+
+pub fn suggest_record_fields(name: &str, fields: &[types::RecordField]) -> Vec<String> {
+    suggest(name, fields.iter().map(|field| field.name()))
+}
+
+// Variables not found have suggestions only of names in their scopes where
+// global names are qualified. So we replace them with their original names.
+pub fn suggest_global_names(module: &Module, error: AnalysisError) -> AnalysisError {
+    match error {
+        AnalysisError::Multiple(errors) => AnalysisError::Multiple(
+            errors
+                .into_iter()
+                .map(|error| suggest_global_names(module, error))
+                .collect(),
+        ),
+        AnalysisError::VariableNotFound(variable, suggestions) => {
+            let names = collect_global_names(module);
+            let suggestions = suggest(
+                variable.name(),
+                suggestions
+                    .iter()
+                    .map(|name| names.get(name.as_str()).copied().unwrap_or(name))
+                    .chain(names.values().copied()),
+            );
+
+            AnalysisError::VariableNotFound(variable, suggestions)
+        }
+        error => error,
+    }
+}
+
+fn collect_global_names(module: &Module) -> FnvHashMap<&str, &str> {
+    module
+        .function_declarations()
+        .iter()
+        .map(|declaration| (declaration.name(), declaration.original_name()))
+        .chain(
+            module
+                .function_definitions()
+                .iter()
+                .map(|definition| (definition.name(), definition.original_name())),
+        )
+        .collect()
+}
+
+fn calculate_distance(one: &str, other: &str) -> usize {
+    let other = other.chars().collect::<Vec<_>>();
+    let mut distances = (0..=other.len()).collect::<Vec<_>>();
+
+    for (index, one) in one.chars().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = index + 1;
+
+        for (other_index, other) in other.iter().enumerate() {
+            let distance = (distances[other_index] + 1)
+                .min(distances[other_index + 1] + 1)
+                .min(diagonal + usize::from(one != *other));
+
+            diagonal = distances[other_index + 1];
+            distances[other_index + 1] = distance;
+        }
+    }
+
+    distances[other.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleFake;
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn calculate_distances() {
+        assert_eq!(calculate_distance("", ""), 0);
+        assert_eq!(calculate_distance("foo", "foo"), 0);
+        assert_eq!(calculate_distance("foo", ""), 3);
+        assert_eq!(calculate_distance("", "foo"), 3);
+        assert_eq!(calculate_distance("foo", "fo"), 1);
+        assert_eq!(calculate_distance("foo", "fob"), 1);
+        assert_eq!(calculate_distance("foo", "ofo"), 2);
+        assert_eq!(calculate_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn suggest_nothing() {
+        assert_eq!(suggest("foo", []), Vec::<String>::new());
+        assert_eq!(suggest("foo", ["bar"]), Vec::<String>::new());
+    }
+
+    #[test]
+    fn suggest_name() {
+        assert_eq!(suggest("fo", ["foo", "bar"]), vec!["foo".to_string()]);
+    }
+
+    #[test]
+    fn suggest_closest_names_first() {
+        assert_eq!(
+            suggest("foobar", ["fooba", "foobaz", "fobar", "foo", "fooba"]),
+            vec!["fobar".to_string(), "fooba".into(), "foobaz".into()]
+        );
+    }
+
+    #[test]
+    fn suggest_qualified_name() {
+        assert_eq!(suggest("foo", ["Foo'foo"]), vec!["Foo'foo".to_string()]);
+    }
+
+    #[test]
+    fn suggest_global_names_with_original_names() {
+        assert_eq!(
+            suggest_global_names(
+                &Module::empty()
+                    .set_function_declarations(vec![FunctionDeclaration::new(
+                        "RealFoo",
+                        "Foo'foo",
+                        types::Function::new(
+                            vec![],
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::new(
+                        "RealBar",
+                        "bar",
+                        Lambda::new(
+                            vec![],
+                            types::None::new(Position::fake()),
+                            None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        None,
+                        false,
+                        Position::fake(),
+                    )]),
+                AnalysisError::Multiple(vec![
+                    AnalysisError::VariableNotFound(Variable::new("foo", Position::fake()), vec![]),
+                    AnalysisError::VariableNotFound(
+                        Variable::new("ba", Position::fake()),
+                        vec!["RealBar".into()]
+                    ),
+                ])
+            ),
+            AnalysisError::Multiple(vec![
+                AnalysisError::VariableNotFound(
+                    Variable::new("foo", Position::fake()),
+                    vec!["Foo'foo".into()]
+                ),
+                AnalysisError::VariableNotFound(
+                    Variable::new("ba", Position::fake()),
+                    vec!["bar".into()]
+                ),
+            ])
+        );
+    }
+}
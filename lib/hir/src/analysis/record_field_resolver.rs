@@ -1,24 +1,73 @@
-use super::{type_canonicalizer, AnalysisError};
-use crate::types::*;
-use fnv::FnvHashMap;
+use super::{type_canonicalizer, type_instantiator, AnalysisError};
+use crate::{ir::Module, types::*};
+use fnv::{FnvHashMap, FnvHashSet};
 
-pub fn resolve<'a>(
+pub fn resolve(
     type_: &Type,
     types: &FnvHashMap<String, Type>,
-    records: &'a FnvHashMap<String, Vec<RecordField>>,
-) -> Result<&'a [RecordField], AnalysisError> {
+    records: &FnvHashMap<String, Vec<RecordField>>,
+) -> Result<Vec<RecordField>, AnalysisError> {
     resolve_record(
         &type_canonicalizer::canonicalize_record(type_, types)?
             .ok_or_else(|| AnalysisError::RecordExpected(type_.clone()))?,
+        types,
         records,
     )
 }
 
-pub fn resolve_record<'a>(
+// Type variables of generic records are substituted with their arguments.
+pub fn resolve_record(
     record: &Record,
-    records: &'a FnvHashMap<String, Vec<RecordField>>,
-) -> Result<&'a [RecordField], AnalysisError> {
-    Ok(records
+    types: &FnvHashMap<String, Type>,
+    records: &FnvHashMap<String, Vec<RecordField>>,
+) -> Result<Vec<RecordField>, AnalysisError> {
+    let fields = records
         .get(record.name())
-        .ok_or_else(|| AnalysisError::RecordNotFound(record.clone()))?)
+        .ok_or_else(|| AnalysisError::RecordNotFound(record.clone(), vec![]))?;
+    let parameters = match types.get(record.name()) {
+        Some(Type::Record(definition)) => definition.arguments(),
+        _ => &[],
+    };
+
+    if record.arguments().is_empty() || parameters.is_empty() {
+        return Ok(fields.clone());
+    }
+
+    let variables = parameters
+        .iter()
+        .filter_map(|type_| match type_ {
+            Type::Variable(variable) => Some(variable.name().to_owned()),
+            _ => None,
+        })
+        .zip(record.arguments().iter().cloned())
+        .collect();
+
+    Ok(fields
+        .iter()
+        .map(|field| {
+            RecordField::new(
+                field.name(),
+                type_instantiator::substitute(field.type_(), &variables),
+            )
+            .set_default(field.has_default())
+        })
+        .collect())
+}
+
+pub fn resolve_default_function_name(record_name: &str, field_name: &str) -> String {
+    format!("{}:default:{}", record_name, field_name)
+}
+
+pub fn collect_default_function_names(module: &Module) -> FnvHashSet<String> {
+    module
+        .type_definitions()
+        .iter()
+        .flat_map(|definition| {
+            definition
+                .fields()
+                .iter()
+                .filter(|field| field.has_default())
+                .map(|field| resolve_default_function_name(definition.name(), field.name()))
+        })
+        .collect()
 }
@@ -1,6 +1,6 @@
 use super::AnalysisContext;
 use crate::{
-    analysis::{expression_visitor, type_canonicalizer, AnalysisError},
+    analysis::{collect_results, expression_visitor, type_canonicalizer, AnalysisError},
     ir::*,
 };
 use fnv::FnvHashSet;
@@ -8,49 +8,59 @@ use fnv::FnvHashSet;
 pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
     let open_records = collect_open_records(module.type_definitions());
 
-    for expression in collect_expressions(module) {
-        match expression {
-            Expression::RecordConstruction(construction) => {
-                let record_type =
-                    type_canonicalizer::canonicalize_record(construction.type_(), context.types())?
-                        .ok_or_else(|| {
-                            AnalysisError::RecordExpected(construction.type_().clone())
-                        })?;
+    collect_results(
+        collect_expressions(module)
+            .iter()
+            .map(|expression| validate_expression(context, &open_records, expression)),
+    )?;
 
-                if !open_records.contains(record_type.name()) {
-                    // We never panic on indexing the first field because
-                    // records with no fields are always open.
-                    return Err(AnalysisError::RecordFieldPrivate(
-                        construction.fields()[0].position().clone(),
-                    ));
-                }
+    Ok(())
+}
+
+fn validate_expression(
+    context: &AnalysisContext,
+    open_records: &FnvHashSet<&str>,
+    expression: &Expression,
+) -> Result<(), AnalysisError> {
+    match expression {
+        Expression::RecordConstruction(construction) => {
+            let record_type =
+                type_canonicalizer::canonicalize_record(construction.type_(), context.types())?
+                    .ok_or_else(|| AnalysisError::RecordExpected(construction.type_().clone()))?;
+
+            if !open_records.contains(record_type.name()) {
+                // We never panic on indexing the first field because
+                // records with no fields are always open.
+                return Err(AnalysisError::RecordFieldPrivate(
+                    construction.fields()[0].position().clone(),
+                ));
             }
-            Expression::RecordDeconstruction(deconstruction) => {
-                let type_ = deconstruction.type_().ok_or_else(|| {
-                    AnalysisError::TypeNotInferred(deconstruction.position().clone())
-                })?;
-                let record_type = type_canonicalizer::canonicalize_record(type_, context.types())?
-                    .ok_or_else(|| AnalysisError::RecordExpected(type_.clone()))?;
+        }
+        Expression::RecordDeconstruction(deconstruction) => {
+            let type_ = deconstruction
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(deconstruction.position().clone()))?;
+            let record_type = type_canonicalizer::canonicalize_record(type_, context.types())?
+                .ok_or_else(|| AnalysisError::RecordExpected(type_.clone()))?;
 
-                if !open_records.contains(record_type.name()) {
-                    return Err(AnalysisError::RecordFieldPrivate(
-                        deconstruction.position().clone(),
-                    ));
-                }
+            if !open_records.contains(record_type.name()) {
+                return Err(AnalysisError::RecordFieldPrivate(
+                    deconstruction.position().clone(),
+                ));
             }
-            Expression::RecordUpdate(update) => {
-                let record_type =
-                    type_canonicalizer::canonicalize_record(update.type_(), context.types())?
-                        .ok_or_else(|| AnalysisError::RecordExpected(update.type_().clone()))?;
+        }
+        Expression::RecordUpdate(update) => {
+            let record_type =
+                type_canonicalizer::canonicalize_record(update.type_(), context.types())?
+                    .ok_or_else(|| AnalysisError::RecordExpected(update.type_().clone()))?;
 
-                if !open_records.contains(record_type.name()) {
-                    return Err(AnalysisError::RecordFieldPrivate(
-                        update.fields()[0].position().clone(),
-                    ));
-                }
+            if !open_records.contains(record_type.name()) {
+                return Err(AnalysisError::RecordFieldPrivate(
+                    update.fields()[0].position().clone(),
+                ));
             }
-            _ => {}
         }
+        _ => {}
     }
 
     Ok(())
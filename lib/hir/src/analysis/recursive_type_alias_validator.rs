@@ -42,14 +42,23 @@ fn collect_references(type_: &Type) -> FnvHashSet<&str> {
             .into_iter()
             .chain(collect_references(union.rhs()))
             .collect(),
+        Type::Record(record) => record
+            .arguments()
+            .iter()
+            .flat_map(collect_references)
+            .collect(),
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
-        | Type::Record(_)
         | Type::None(_)
+        | Type::Integer(_)
         | Type::Number(_)
-        | Type::String(_) => Default::default(),
-        Type::Reference(reference) => [reference.name()].into_iter().collect(),
+        | Type::String(_)
+        | Type::Variable(_) => Default::default(),
+        Type::Reference(reference) => [reference.name()]
+            .into_iter()
+            .chain(reference.arguments().iter().flat_map(collect_references))
+            .collect(),
     }
 }
 
@@ -0,0 +1,140 @@
+use super::{
+    expression_visitor, type_collector, type_difference_calculator, union_type_creator,
+    warning::AnalysisWarning, warning_configuration::WarningConfiguration,
+};
+use crate::{ir::*, types::Type};
+use fnv::FnvHashMap;
+
+pub fn check(module: &Module, configuration: &WarningConfiguration) -> Vec<AnalysisWarning> {
+    if !configuration.redundant_branches {
+        return vec![];
+    }
+
+    let types = type_collector::collect(module);
+    let mut warnings = vec![];
+
+    expression_visitor::visit(module, |expression| {
+        if let Expression::IfType(if_) = expression {
+            warnings.extend(check_if_type(if_, &types));
+        }
+    });
+
+    warnings
+}
+
+// Invalid types are ignored here as they are reported by analysis later.
+fn check_if_type(if_: &IfType, types: &FnvHashMap<String, Type>) -> Vec<AnalysisWarning> {
+    let branch_types = if_
+        .branches()
+        .iter()
+        .map(|branch| branch.type_().clone())
+        .collect::<Vec<_>>();
+
+    if_.branches()
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(index, branch)| {
+            union_type_creator::create(&branch_types[..*index], if_.position())
+                .map(|type_| {
+                    matches!(
+                        type_difference_calculator::calculate(branch.type_(), &type_, types),
+                        Ok(None)
+                    )
+                })
+                .unwrap_or_default()
+        })
+        .map(|(_, branch)| AnalysisWarning::RedundantBranch(branch.type_().position().clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::ModuleFake, types};
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn check_branches(branch_types: Vec<Type>) -> Vec<AnalysisWarning> {
+        check(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+                "f",
+                "f",
+                Lambda::new(
+                    vec![Argument::new("x", types::Any::new(Position::fake()))],
+                    types::None::new(Position::fake()),
+                    IfType::new(
+                        "y",
+                        Variable::new("x", Position::fake()),
+                        branch_types
+                            .into_iter()
+                            .map(|type_| IfTypeBranch::new(type_, None::new(Position::fake())))
+                            .collect(),
+                        None,
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                None,
+                false,
+                Position::fake(),
+            )]),
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn check_distinct_branches() {
+        assert_eq!(
+            check_branches(vec![
+                types::Number::new(Position::fake()).into(),
+                types::None::new(Position::fake()).into(),
+            ]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_duplicate_branches() {
+        assert_eq!(
+            check_branches(vec![
+                types::Number::new(Position::fake()).into(),
+                types::Number::new(Position::fake()).into(),
+            ]),
+            vec![AnalysisWarning::RedundantBranch(Position::fake())]
+        );
+    }
+
+    #[test]
+    fn check_branch_covered_by_union_of_branches() {
+        assert_eq!(
+            check_branches(vec![
+                types::Number::new(Position::fake()).into(),
+                types::None::new(Position::fake()).into(),
+                types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                )
+                .into(),
+            ]),
+            vec![AnalysisWarning::RedundantBranch(Position::fake())]
+        );
+    }
+
+    #[test]
+    fn check_branch_partially_covered() {
+        assert_eq!(
+            check_branches(vec![
+                types::Number::new(Position::fake()).into(),
+                types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                )
+                .into(),
+            ]),
+            vec![]
+        );
+    }
+}
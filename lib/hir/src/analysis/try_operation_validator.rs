@@ -1,10 +1,13 @@
-use super::{context::AnalysisContext, error::AnalysisError};
+use super::{collect_results, context::AnalysisContext, error::AnalysisError};
 use crate::{analysis::type_subsumption_checker, ir::*, types, types::Type};
 
 pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
-    for definition in module.function_definitions() {
-        validate_lambda(context, definition.lambda())?;
-    }
+    collect_results(
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| validate_lambda(context, definition.lambda())),
+    )?;
 
     Ok(())
 }
@@ -19,159 +22,164 @@ fn validate_expression(
     result_type: Option<&Type>,
 ) -> Result<(), AnalysisError> {
     let validate = |expression| validate_expression(context, expression, result_type);
+    let validate_all = |expressions| validate_expressions(context, expressions, result_type);
 
     match expression {
-        Expression::Call(call) => {
-            validate(call.function())?;
-
-            for argument in call.arguments() {
-                validate(argument)?;
-            }
-        }
-        Expression::If(if_) => {
-            validate(if_.condition())?;
-            validate(if_.then())?;
-            validate(if_.else_())?;
-        }
-        Expression::IfList(if_) => {
-            validate(if_.list())?;
-            validate(if_.then())?;
-            validate(if_.else_())?;
-        }
-        Expression::IfMap(if_) => {
-            validate(if_.map())?;
-            validate(if_.key())?;
-            validate(if_.then())?;
-            validate(if_.else_())?;
-        }
-        Expression::IfType(if_) => {
-            validate(if_.argument())?;
-
-            for branch in if_.branches() {
-                validate(branch.expression())?;
-            }
-
-            if let Some(branch) = if_.else_() {
-                validate(branch.expression())?;
-            }
-        }
-        Expression::TypeCoercion(coercion) => {
-            validate(coercion.argument())?;
-        }
-        Expression::Lambda(lambda) => {
-            validate_lambda(context, lambda)?;
-        }
-        Expression::Let(let_) => {
-            validate(let_.bound_expression())?;
-            validate(let_.expression())?;
-        }
-        Expression::List(list) => {
-            for element in list.elements() {
-                validate_expression(
-                    context,
-                    match element {
-                        ListElement::Multiple(expression) => expression,
-                        ListElement::Single(expression) => expression,
-                    },
-                    None,
-                )?;
-            }
-        }
-        Expression::ListComprehension(comprehension) => {
-            validate_expression(context, comprehension.element(), None)?;
-            validate_expression(context, comprehension.list(), None)?;
-        }
-        Expression::Map(map) => {
-            for element in map.elements() {
-                match element {
-                    MapElement::Insertion(entry) => {
-                        validate(entry.key())?;
-                        validate(entry.value())?
-                    }
-                    MapElement::Map(expression) => validate(expression)?,
-                    MapElement::Removal(expression) => validate(expression)?,
-                }
-            }
-        }
-        Expression::MapIterationComprehension(comprehension) => {
-            validate_expression(context, comprehension.element(), None)?;
-            validate_expression(context, comprehension.map(), None)?;
-        }
+        Expression::Call(call) => validate_all(
+            [call.function()]
+                .into_iter()
+                .chain(call.arguments())
+                .collect(),
+        ),
+        Expression::If(if_) => validate_all(vec![if_.condition(), if_.then(), if_.else_()]),
+        Expression::IfList(if_) => validate_all(vec![if_.list(), if_.then(), if_.else_()]),
+        Expression::IfMap(if_) => validate_all(vec![if_.map(), if_.key(), if_.then(), if_.else_()]),
+        Expression::IfType(if_) => validate_all(
+            [if_.argument()]
+                .into_iter()
+                .chain(if_.branches().iter().map(|branch| branch.expression()))
+                .chain(if_.else_().map(|branch| branch.expression()))
+                .collect(),
+        ),
+        Expression::TypeCoercion(coercion) => validate(coercion.argument()),
+        Expression::Lambda(lambda) => validate_lambda(context, lambda),
+        Expression::Let(let_) => validate_all(vec![let_.bound_expression(), let_.expression()]),
+        Expression::List(list) => validate_expressions(
+            context,
+            list.elements()
+                .iter()
+                .map(|element| match element {
+                    ListElement::Multiple(expression) => expression,
+                    ListElement::Single(expression) => expression,
+                })
+                .collect(),
+            None,
+        ),
+        Expression::ListComprehension(comprehension) => validate_expressions(
+            context,
+            [comprehension.element()]
+                .into_iter()
+                .chain(collect_branch_expressions(comprehension.branches()))
+                .collect(),
+            None,
+        ),
+        Expression::Map(map) => validate_all(
+            map.elements()
+                .iter()
+                .flat_map(|element| match element {
+                    MapElement::Insertion(entry) => vec![entry.key(), entry.value()],
+                    MapElement::Map(expression) => vec![expression],
+                    MapElement::Removal(expression) => vec![expression],
+                })
+                .collect(),
+        ),
+        Expression::MapComprehension(comprehension) => validate_expressions(
+            context,
+            [comprehension.key(), comprehension.value()]
+                .into_iter()
+                .chain(collect_branch_expressions(comprehension.branches()))
+                .collect(),
+            None,
+        ),
         Expression::Operation(operation) => match operation {
             Operation::Arithmetic(operation) => {
-                validate(operation.lhs())?;
-                validate(operation.rhs())?;
-            }
-            Operation::Boolean(operation) => {
-                validate(operation.lhs())?;
-                validate(operation.rhs())?;
-            }
-            Operation::Equality(operation) => {
-                validate(operation.lhs())?;
-                validate(operation.rhs())?;
-            }
-            Operation::Not(operation) => {
-                validate(operation.expression())?;
-            }
-            Operation::Order(operation) => {
-                validate(operation.lhs())?;
-                validate(operation.rhs())?;
+                validate_all(vec![operation.lhs(), operation.rhs()])
             }
+            Operation::Boolean(operation) => validate_all(vec![operation.lhs(), operation.rhs()]),
+            Operation::Equality(operation) => validate_all(vec![operation.lhs(), operation.rhs()]),
+            Operation::Not(operation) => validate(operation.expression()),
+            Operation::Order(operation) => validate_all(vec![operation.lhs(), operation.rhs()]),
             Operation::Try(operation) => {
-                let position = operation.position();
+                collect_results([
+                    validate_try_operation(context, operation, result_type),
+                    validate(operation.expression()),
+                ])?;
 
-                if let Some(result_type) = result_type {
-                    if !type_subsumption_checker::check(
-                        &types::Error::new(position.clone()).into(),
-                        result_type,
-                        context.types(),
-                    )? {
-                        return Err(AnalysisError::InvalidTryOperation(position.clone()));
-                    }
-                } else {
-                    return Err(AnalysisError::TryOperationInList(position.clone()));
-                }
-
-                validate(operation.expression())?;
+                Ok(())
             }
         },
-        Expression::RecordConstruction(construction) => {
-            for field in construction.fields() {
-                validate(field.expression())?;
-            }
-        }
-        Expression::RecordDeconstruction(deconstruction) => {
-            validate(deconstruction.record())?;
-        }
-        Expression::RecordUpdate(update) => {
-            validate(update.record())?;
-
-            for field in update.fields() {
-                validate(field.expression())?;
-            }
-        }
-        Expression::Thunk(thunk) => {
-            validate_expression(
-                context,
-                thunk.expression(),
-                Some(
-                    thunk
-                        .type_()
-                        .ok_or_else(|| AnalysisError::TypeNotInferred(thunk.position().clone()))?,
-                ),
-            )?;
-        }
+        Expression::RecordConstruction(construction) => validate_all(
+            construction
+                .fields()
+                .iter()
+                .map(|field| field.expression())
+                .collect(),
+        ),
+        Expression::RecordDeconstruction(deconstruction) => validate(deconstruction.record()),
+        Expression::RecordUpdate(update) => validate_all(
+            [update.record()]
+                .into_iter()
+                .chain(update.fields().iter().map(|field| field.expression()))
+                .collect(),
+        ),
+        Expression::Thunk(thunk) => validate_expression(
+            context,
+            thunk.expression(),
+            Some(
+                thunk
+                    .type_()
+                    .ok_or_else(|| AnalysisError::TypeNotInferred(thunk.position().clone()))?,
+            ),
+        ),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
+        | Expression::Integer(_)
         | Expression::None(_)
         | Expression::Number(_)
         | Expression::String(_)
-        | Expression::Variable(_) => {}
+        | Expression::Variable(_) => Ok(()),
+    }
+}
+
+// Validate sibling expressions independently to report all errors in them.
+fn validate_expressions(
+    context: &AnalysisContext,
+    expressions: Vec<&Expression>,
+    result_type: Option<&Type>,
+) -> Result<(), AnalysisError> {
+    collect_results(
+        expressions
+            .into_iter()
+            .map(|expression| validate_expression(context, expression, result_type)),
+    )?;
+
+    Ok(())
+}
+
+fn validate_try_operation(
+    context: &AnalysisContext,
+    operation: &TryOperation,
+    result_type: Option<&Type>,
+) -> Result<(), AnalysisError> {
+    let position = operation.position();
+
+    if let Some(result_type) = result_type {
+        if !type_subsumption_checker::check(
+            &types::Error::new(position.clone()).into(),
+            result_type,
+            context.types(),
+        )? {
+            return Err(AnalysisError::InvalidTryOperation(position.clone()));
+        }
+    } else {
+        return Err(AnalysisError::TryOperationInList(position.clone()));
     }
 
     Ok(())
 }
 
+fn collect_branch_expressions(
+    branches: &[ListComprehensionBranch],
+) -> impl Iterator<Item = &Expression> {
+    branches.iter().flat_map(|branch| {
+        branch
+            .iteratees()
+            .iter()
+            .map(|iteratee| iteratee.expression())
+            .chain(branch.condition())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,15 +375,21 @@ mod tests {
                             Position::fake()
                         ),
                         ListComprehension::new(
-                            None,
                             types::None::new(Position::fake()),
                             TryOperation::new(
                                 None,
                                 Variable::new("x", Position::fake()),
                                 Position::fake(),
                             ),
-                            "x",
-                            Variable::new("xs", Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    None,
+                                    Variable::new("xs", Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake()
                         ),
                         Position::fake(),
@@ -401,15 +415,21 @@ mod tests {
                             Position::fake()
                         ),
                         ListComprehension::new(
-                            None,
                             types::None::new(Position::fake()),
                             None::new(Position::fake()),
-                            "x",
-                            TryOperation::new(
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    None,
+                                    TryOperation::new(
+                                        None,
+                                        Variable::new("xs", Position::fake()),
+                                        Position::fake(),
+                                    ),
+                                )],
                                 None,
-                                Variable::new("xs", Position::fake()),
                                 Position::fake(),
-                            ),
+                            )],
                             Position::fake()
                         ),
                         Position::fake(),
@@ -420,4 +440,48 @@ mod tests {
             Err(AnalysisError::TryOperationInList(Position::fake()))
         );
     }
+
+    #[test]
+    fn report_all_errors_in_list() {
+        let try_operation = |line_number| {
+            TryOperation::new(
+                None,
+                Variable::new("x", Position::fake()),
+                Position::new("", line_number, 1, ""),
+            )
+        };
+
+        assert_eq!(
+            validate_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            types::Union::new(
+                                types::None::new(Position::fake()),
+                                types::Error::new(Position::fake()),
+                                Position::fake(),
+                            ),
+                        )],
+                        types::None::new(Position::fake()),
+                        List::new(
+                            types::None::new(Position::fake()),
+                            vec![
+                                ListElement::Single(try_operation(1).into()),
+                                ListElement::Single(try_operation(2).into()),
+                            ],
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Err(AnalysisError::Multiple(vec![
+                AnalysisError::TryOperationInList(Position::new("", 1, 1, "")),
+                AnalysisError::TryOperationInList(Position::new("", 2, 1, "")),
+            ]))
+        );
+    }
 }
@@ -27,14 +27,25 @@ pub fn canonicalize(type_: &Type, types: &FnvHashMap<String, Type>) -> Result<Ty
             map.position().clone(),
         )
         .into(),
+        Type::Record(record) => record
+            .clone()
+            .set_arguments(
+                record
+                    .arguments()
+                    .iter()
+                    .map(|type_| canonicalize(type_, types))
+                    .collect::<Result<_, _>>()?,
+            )
+            .into(),
         Type::Union(union) => canonicalize_union(union, types)?,
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
-        | Type::Record(_)
         | Type::None(_)
+        | Type::Integer(_)
         | Type::Number(_)
-        | Type::String(_) => type_.clone(),
+        | Type::String(_)
+        | Type::Variable(_) => type_.clone(),
         Type::Reference(reference) => {
             canonicalize(&type_resolver::resolve(reference, types)?, types)?
         }
@@ -104,8 +115,10 @@ fn collect_types(
         | Type::List(_)
         | Type::Map(_)
         | Type::None(_)
+        | Type::Integer(_)
         | Type::Number(_)
-        | Type::String(_) => [canonicalize(type_, types)?].into_iter().collect(),
+        | Type::String(_)
+        | Type::Variable(_) => [canonicalize(type_, types)?].into_iter().collect(),
         Type::Reference(reference) => {
             collect_types(&type_resolver::resolve(reference, types)?, types)?
         }
@@ -1,20 +1,28 @@
-use super::{context::AnalysisContext, module_environment_creator, AnalysisError};
+use super::{
+    collect_results, context::AnalysisContext, merge_errors, module_environment_creator,
+    name_suggester, AnalysisError,
+};
 use crate::{
     analysis::{
-        record_field_resolver, type_canonicalizer, type_equality_checker, type_extractor,
+        record_field_resolver, type_canonicalizer, type_difference_calculator,
+        type_equality_checker, type_extractor, type_instantiator, type_orderability_checker,
         type_subsumption_checker, union_type_creator,
     },
     ir::*,
     types::{self, Type},
 };
 use fnv::{FnvHashMap, FnvHashSet};
+use position::Position;
 
 pub fn check_types(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
     let variables = module_environment_creator::create(module);
 
-    for definition in module.function_definitions() {
-        check_lambda(context, definition.lambda(), &variables)?;
-    }
+    collect_results(
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| check_lambda(context, definition.lambda(), &variables)),
+    )?;
 
     Ok(())
 }
@@ -53,7 +61,7 @@ fn check_expression(
 ) -> Result<Type, AnalysisError> {
     let check_expression =
         |expression, variables: &_| check_expression(context, expression, variables);
-    let check_subsumption = |lower: &_, upper| check_subsumption(lower, upper, context.types());
+    let check_subsumption = |lower: &_, upper: &_| check_subsumption(lower, upper, context.types());
 
     Ok(match expression {
         Expression::Boolean(boolean) => types::Boolean::new(boolean.position().clone()).into(),
@@ -73,12 +81,21 @@ fn check_expression(
                 return Err(AnalysisError::WrongArgumentCount(call.position().clone()));
             }
 
-            for (argument, type_) in call.arguments().iter().zip(function_type.arguments()) {
-                check_subsumption(&check_expression(argument, variables)?, type_)?;
-            }
+            collect_results(call.arguments().iter().zip(function_type.arguments()).map(
+                |(argument, type_)| {
+                    check_subsumption(&check_expression(argument, variables)?, type_)
+                },
+            ))?;
 
             if let Expression::BuiltInFunction(function) = call.function() {
                 check_built_in_call(context, call, function, &function_type)?;
+            } else if let Some(generic_type) = match call.function() {
+                Expression::Variable(variable) => variables
+                    .get(variable.name())
+                    .filter(|type_| type_instantiator::is_generic(type_)),
+                _ => None,
+            } {
+                check_generic_call(context, generic_type, &function_type)?;
             } else {
                 check_subsumption(&check_expression(call.function(), variables)?, type_)?;
             }
@@ -207,7 +224,7 @@ fn check_expression(
                         )])
                         .collect(),
                 )?;
-            } else if !type_equality_checker::check(
+            } else if let Some(type_) = type_difference_calculator::calculate(
                 &argument_type,
                 &union_type_creator::create(
                     &if_.branches()
@@ -219,89 +236,52 @@ fn check_expression(
                 .unwrap(),
                 context.types(),
             )? {
-                return Err(AnalysisError::MissingElseBlock(if_.position().clone()));
+                return Err(AnalysisError::MissingElseBlock(
+                    if_.position().clone(),
+                    type_,
+                ));
             }
 
             type_extractor::extract_from_expression(context, expression, variables)?
         }
-        Expression::Lambda(lambda) => check_lambda(context, lambda, variables)?.into(),
-        Expression::Let(let_) => {
-            check_subsumption(
-                &check_expression(let_.bound_expression(), variables)?,
-                let_.type_().ok_or_else(|| {
-                    AnalysisError::TypeNotInferred(let_.bound_expression().position().clone())
-                })?,
-            )?;
-
-            check_expression(
-                let_.expression(),
-                &variables
-                    .clone()
-                    .into_iter()
-                    .chain(if let Some(name) = let_.name() {
-                        Some((
-                            name.into(),
-                            let_.type_()
-                                .ok_or_else(|| {
-                                    AnalysisError::TypeNotInferred(let_.position().clone())
-                                })?
-                                .clone(),
-                        ))
-                    } else {
-                        None
-                    })
-                    .collect(),
-            )?
+        Expression::Integer(integer) => {
+            types::Integer::new(integer.kind(), integer.position().clone()).into()
         }
+        Expression::Lambda(lambda) => check_lambda(context, lambda, variables)?.into(),
+        Expression::Let(let_) => check_let(context, let_, variables)?,
         Expression::List(list) => {
-            for element in list.elements() {
-                match element {
-                    ListElement::Multiple(expression) => {
-                        let type_ = check_expression(expression, variables)?;
-
-                        check_subsumption(
-                            type_canonicalizer::canonicalize_list(&type_, context.types())?
-                                .ok_or(AnalysisError::ListExpected(type_))?
-                                .element(),
-                            list.type_(),
-                        )?;
-                    }
-                    ListElement::Single(expression) => {
-                        check_subsumption(&check_expression(expression, variables)?, list.type_())?;
-                    }
+            collect_results(list.elements().iter().map(|element| match element {
+                ListElement::Multiple(expression) => {
+                    let type_ = check_expression(expression, variables)?;
+
+                    check_subsumption(
+                        type_canonicalizer::canonicalize_list(&type_, context.types())?
+                            .ok_or(AnalysisError::ListExpected(type_))?
+                            .element(),
+                        list.type_(),
+                    )
                 }
-            }
+                ListElement::Single(expression) => {
+                    check_subsumption(&check_expression(expression, variables)?, list.type_())
+                }
+            }))?;
 
             types::List::new(list.type_().clone(), list.position().clone()).into()
         }
         Expression::ListComprehension(comprehension) => {
-            let position = comprehension.position();
-            let input_type = comprehension
-                .input_type()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+            let variables =
+                check_list_comprehension_branches(context, comprehension.branches(), variables)?;
 
             check_subsumption(
-                &check_expression(
-                    comprehension.element(),
-                    &variables
-                        .clone()
-                        .into_iter()
-                        .chain([(
-                            comprehension.element_name().into(),
-                            types::Function::new(vec![], input_type.clone(), position.clone())
-                                .into(),
-                        )])
-                        .collect(),
-                )?,
-                comprehension.output_type(),
-            )?;
-
-            check_subsumption(
-                &check_expression(comprehension.list(), variables)?,
-                &types::List::new(input_type.clone(), position.clone()).into(),
+                &check_expression(comprehension.element(), &variables)?,
+                comprehension.type_(),
             )?;
 
-            types::List::new(comprehension.output_type().clone(), position.clone()).into()
+            types::List::new(
+                comprehension.type_().clone(),
+                comprehension.position().clone(),
+            )
+            .into()
         }
         Expression::Map(map) => {
             for element in map.elements() {
@@ -341,36 +321,25 @@ fn check_expression(
             )
             .into()
         }
-        Expression::MapIterationComprehension(comprehension) => {
-            let position = comprehension.position();
-            let key_type = comprehension
-                .key_type()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
-            let value_type = comprehension
-                .value_type()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+        Expression::MapComprehension(comprehension) => {
+            let variables =
+                check_list_comprehension_branches(context, comprehension.branches(), variables)?;
 
             check_subsumption(
-                &check_expression(
-                    comprehension.element(),
-                    &variables
-                        .clone()
-                        .into_iter()
-                        .chain([
-                            (comprehension.key_name().into(), key_type.clone()),
-                            (comprehension.value_name().into(), value_type.clone()),
-                        ])
-                        .collect(),
-                )?,
-                comprehension.element_type(),
+                &check_expression(comprehension.key(), &variables)?,
+                comprehension.key_type(),
             )?;
-
             check_subsumption(
-                &check_expression(comprehension.map(), variables)?,
-                &types::Map::new(key_type.clone(), value_type.clone(), position.clone()).into(),
+                &check_expression(comprehension.value(), &variables)?,
+                comprehension.value_type(),
             )?;
 
-            types::List::new(comprehension.element_type().clone(), position.clone()).into()
+            types::Map::new(
+                comprehension.key_type().clone(),
+                comprehension.value_type().clone(),
+                comprehension.position().clone(),
+            )
+            .into()
         }
         Expression::None(none) => types::None::new(none.position().clone()).into(),
         Expression::Number(number) => types::Number::new(number.position().clone()).into(),
@@ -382,16 +351,21 @@ fn check_expression(
                 context.records(),
             )?;
 
-            for field in construction.fields() {
+            collect_results(construction.fields().iter().map(|field| {
                 check_subsumption(
                     &check_expression(field.expression(), variables)?,
                     field_types
                         .iter()
                         .find(|field_type| field_type.name() == field.name())
-                        .ok_or_else(|| AnalysisError::UnknownRecordField(field.position().clone()))?
+                        .ok_or_else(|| {
+                            AnalysisError::UnknownRecordField(
+                                field.position().clone(),
+                                name_suggester::suggest_record_fields(field.name(), &field_types),
+                            )
+                        })?
                         .type_(),
-                )?;
-            }
+                )
+            }))?;
 
             let field_names = construction
                 .fields()
@@ -426,7 +400,13 @@ fn check_expression(
                 .iter()
                 .find(|field_type| field_type.name() == deconstruction.field_name())
                 .ok_or_else(|| {
-                    AnalysisError::UnknownRecordField(deconstruction.position().clone())
+                    AnalysisError::UnknownRecordField(
+                        deconstruction.position().clone(),
+                        name_suggester::suggest_record_fields(
+                            deconstruction.field_name(),
+                            &field_types,
+                        ),
+                    )
                 })?
                 .type_()
                 .clone()
@@ -446,7 +426,12 @@ fn check_expression(
                     field_types
                         .iter()
                         .find(|field_type| field_type.name() == field.name())
-                        .ok_or_else(|| AnalysisError::UnknownRecordField(field.position().clone()))?
+                        .ok_or_else(|| {
+                            AnalysisError::UnknownRecordField(
+                                field.position().clone(),
+                                name_suggester::suggest_record_fields(field.name(), &field_types),
+                            )
+                        })?
                         .type_(),
                 )?;
             }
@@ -479,11 +464,94 @@ fn check_expression(
         }
         Expression::Variable(variable) => variables
             .get(variable.name())
-            .ok_or_else(|| AnalysisError::VariableNotFound(variable.clone()))?
+            .ok_or_else(|| {
+                AnalysisError::VariableNotFound(
+                    variable.clone(),
+                    name_suggester::suggest(variable.name(), variables.keys().map(String::as_str)),
+                )
+            })?
             .clone(),
     })
 }
 
+// Check a bound expression and its following expression independently so
+// that errors in sequential statements are reported at once.
+fn check_let(
+    context: &AnalysisContext,
+    let_: &Let,
+    variables: &FnvHashMap<String, Type>,
+) -> Result<Type, AnalysisError> {
+    let bound_result =
+        check_expression(context, let_.bound_expression(), variables).and_then(|type_| {
+            check_subsumption(
+                &type_,
+                let_.type_().ok_or_else(|| {
+                    AnalysisError::TypeNotInferred(let_.bound_expression().position().clone())
+                })?,
+                context.types(),
+            )
+        });
+    let result = check_expression(
+        context,
+        let_.expression(),
+        &variables
+            .clone()
+            .into_iter()
+            .chain(if let Some(name) = let_.name() {
+                Some((
+                    name.into(),
+                    let_.type_()
+                        .ok_or_else(|| AnalysisError::TypeNotInferred(let_.position().clone()))?
+                        .clone(),
+                ))
+            } else {
+                None
+            })
+            .collect(),
+    );
+
+    match (bound_result, result) {
+        (Ok(()), result) => result,
+        (Err(error), Ok(_)) => Err(error),
+        (Err(error), Err(other)) => Err(merge_errors(vec![error, other])),
+    }
+}
+
+fn check_list_comprehension_branches(
+    context: &AnalysisContext,
+    branches: &[ListComprehensionBranch],
+    variables: &FnvHashMap<String, Type>,
+) -> Result<FnvHashMap<String, Type>, AnalysisError> {
+    let check_expression =
+        |expression, variables: &_| check_expression(context, expression, variables);
+    let check_subsumption = |lower: &_, upper: &_| check_subsumption(lower, upper, context.types());
+    let mut variables = variables.clone();
+
+    for branch in branches {
+        for iteratee in branch.iteratees() {
+            check_subsumption(
+                &check_expression(iteratee.expression(), &variables)?,
+                iteratee
+                    .type_()
+                    .ok_or_else(|| AnalysisError::TypeNotInferred(branch.position().clone()))?,
+            )?;
+        }
+
+        variables.extend(type_extractor::extract_from_list_comprehension_branch(
+            branch,
+        )?);
+
+        if let Some(condition) = branch.condition() {
+            check_subsumption(
+                &check_expression(condition, &variables)?,
+                &types::Boolean::new(branch.position().clone()).into(),
+            )?;
+        }
+    }
+
+    Ok(variables)
+}
+
 fn check_built_in_call(
     context: &AnalysisContext,
     call: &Call,
@@ -493,6 +561,16 @@ fn check_built_in_call(
     let position = call.position();
 
     match function.name() {
+        BuiltInFunctionName::Byte
+        | BuiltInFunctionName::I64
+        | BuiltInFunctionName::Number
+        | BuiltInFunctionName::U64 => {
+            if let [argument_type] = function_type.arguments() {
+                check_numeric_type(context, Some(argument_type), position)?;
+            } else {
+                return Err(AnalysisError::WrongArgumentCount(position.clone()));
+            }
+        }
         BuiltInFunctionName::Race => {
             let argument_type = if let [argument_type] = function_type.arguments() {
                 Ok(argument_type)
@@ -531,7 +609,10 @@ fn check_built_in_call(
                 return Err(AnalysisError::WrongArgumentCount(position.clone()));
             }
         }
-        BuiltInFunctionName::Debug | BuiltInFunctionName::Error | BuiltInFunctionName::Source => {}
+        BuiltInFunctionName::ConcatenateStrings
+        | BuiltInFunctionName::Debug
+        | BuiltInFunctionName::Error
+        | BuiltInFunctionName::Source => {}
     }
 
     Ok(())
@@ -547,12 +628,27 @@ fn check_operation(
 
     Ok(match operation {
         Operation::Arithmetic(operation) => {
-            let number_type = types::Number::new(operation.position().clone()).into();
+            let operand_type = match operation.operator() {
+                ArithmeticOperator::Add
+                | ArithmeticOperator::Subtract
+                | ArithmeticOperator::Multiply
+                | ArithmeticOperator::Divide
+                | ArithmeticOperator::Remainder => {
+                    check_numeric_type(context, operation.type_(), operation.position())?
+                }
+                ArithmeticOperator::BitwiseAnd
+                | ArithmeticOperator::BitwiseOr
+                | ArithmeticOperator::BitwiseXor
+                | ArithmeticOperator::LeftShift
+                | ArithmeticOperator::RightShift => {
+                    check_integer_type(context, operation.type_(), operation.position())?
+                }
+            };
 
-            check_subsumption(&check_expression(operation.lhs())?, &number_type)?;
-            check_subsumption(&check_expression(operation.rhs())?, &number_type)?;
+            check_subsumption(&check_expression(operation.lhs())?, operand_type)?;
+            check_subsumption(&check_expression(operation.rhs())?, operand_type)?;
 
-            number_type
+            operand_type.clone()
         }
         Operation::Boolean(operation) => {
             let boolean_type = types::Boolean::new(operation.position().clone()).into();
@@ -591,10 +687,17 @@ fn check_operation(
             boolean_type
         }
         Operation::Order(operation) => {
-            let number_type = types::Number::new(operation.position().clone()).into();
+            let operand_type = operation
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?;
+
+            if !type_orderability_checker::check(operand_type, context.types(), context.records())?
+            {
+                return Err(AnalysisError::TypeNotOrderable(operand_type.clone()));
+            }
 
-            check_subsumption(&check_expression(operation.lhs())?, &number_type)?;
-            check_subsumption(&check_expression(operation.rhs())?, &number_type)?;
+            check_subsumption(&check_expression(operation.lhs())?, operand_type)?;
+            check_subsumption(&check_expression(operation.rhs())?, operand_type)?;
 
             types::Boolean::new(operation.position().clone()).into()
         }
@@ -619,6 +722,79 @@ fn check_operation(
     })
 }
 
+fn check_numeric_type<'a>(
+    context: &AnalysisContext,
+    type_: Option<&'a Type>,
+    position: &Position,
+) -> Result<&'a Type, AnalysisError> {
+    let type_ = type_.ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+
+    if matches!(
+        type_canonicalizer::canonicalize(type_, context.types())?,
+        Type::Integer(_) | Type::Number(_)
+    ) {
+        Ok(type_)
+    } else {
+        Err(AnalysisError::TypesNotMatched(
+            type_.clone(),
+            types::Number::new(position.clone()).into(),
+        ))
+    }
+}
+
+fn check_integer_type<'a>(
+    context: &AnalysisContext,
+    type_: Option<&'a Type>,
+    position: &Position,
+) -> Result<&'a Type, AnalysisError> {
+    let type_ = type_.ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+
+    if matches!(
+        type_canonicalizer::canonicalize(type_, context.types())?,
+        Type::Integer(_)
+    ) {
+        Ok(type_)
+    } else {
+        Err(AnalysisError::IntegerExpected(type_.clone()))
+    }
+}
+
+// Calls of generic functions have their instantiated types while local
+// functions of type variables are checked by subsumption.
+fn check_generic_call(
+    context: &AnalysisContext,
+    generic_type: &Type,
+    function_type: &types::Function,
+) -> Result<(), AnalysisError> {
+    let generic_function =
+        type_canonicalizer::canonicalize_function(generic_type, context.types())?
+            .ok_or_else(|| AnalysisError::FunctionExpected(generic_type.clone()))?;
+    let instance_type = type_instantiator::instantiate(
+        &generic_function,
+        function_type.arguments(),
+        context.types(),
+    )?;
+    // Functions without arguments, such as default functions of generic record
+    // fields, are instantiated by their result types.
+    let resolved_type = type_instantiator::substitute(
+        &generic_function.clone().into(),
+        &type_instantiator::resolve_variables(&generic_function, function_type, context.types())?,
+    );
+    let function_type = function_type.clone().into();
+
+    if type_subsumption_checker::check(generic_type, &function_type, context.types())?
+        || type_equality_checker::check(&instance_type.into(), &function_type, context.types())?
+        || type_equality_checker::check(&resolved_type, &function_type, context.types())?
+    {
+        Ok(())
+    } else {
+        Err(AnalysisError::TypesNotMatched(
+            generic_type.clone(),
+            function_type,
+        ))
+    }
+}
+
 fn check_subsumption(
     lower: &Type,
     upper: &Type,
@@ -944,6 +1120,7 @@ mod tests {
 
     mod if_type {
         use super::*;
+        use pretty_assertions::assert_eq;
 
         #[test]
         fn check_with_union() {
@@ -983,6 +1160,52 @@ mod tests {
             .unwrap()
         }
 
+        #[test]
+        fn fail_to_check_with_missing_else_block() {
+            let union_type = types::Union::new(
+                types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                types::ByteString::new(Position::fake()),
+                Position::fake(),
+            );
+
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![Argument::new("x", union_type)],
+                            types::None::new(Position::fake()),
+                            IfType::new(
+                                "y",
+                                Variable::new("x", Position::fake()),
+                                vec![IfTypeBranch::new(
+                                    types::Number::new(Position::fake()),
+                                    None::new(Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ])),
+                Err(AnalysisError::MissingElseBlock(
+                    Position::fake(),
+                    types::Union::new(
+                        types::None::new(Position::fake()),
+                        types::ByteString::new(Position::fake()),
+                        Position::fake(),
+                    )
+                    .into()
+                ))
+            );
+        }
+
         #[test]
         fn check_with_any() {
             check_module(
@@ -1376,6 +1599,7 @@ mod tests {
                         vec![],
                         types::Number::new(Position::fake()),
                         ArithmeticOperation::new(
+                            Some(types::Number::new(Position::fake()).into()),
                             ArithmeticOperator::Add,
                             Number::new(0.0, Position::fake()),
                             Number::new(0.0, Position::fake()),
@@ -1389,6 +1613,168 @@ mod tests {
             .unwrap();
         }
 
+        #[test]
+        fn check_integer_arithmetic_operation() {
+            let integer_type = types::Integer::new(types::IntegerKind::I64, Position::fake());
+
+            check_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new("x", integer_type.clone())],
+                        integer_type.clone(),
+                        ArithmeticOperation::new(
+                            Some(integer_type.into()),
+                            ArithmeticOperator::Add,
+                            Variable::new("x", Position::fake()),
+                            Variable::new("x", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+
+        #[test]
+        fn check_integer_arithmetic_operation_with_literal() {
+            let integer_type = types::Integer::new(types::IntegerKind::I64, Position::fake());
+
+            check_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new("x", integer_type.clone())],
+                        integer_type.clone(),
+                        ArithmeticOperation::new(
+                            Some(integer_type.into()),
+                            ArithmeticOperator::Add,
+                            Variable::new("x", Position::fake()),
+                            Integer::new(types::IntegerKind::I64, 1, Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+
+        #[test]
+        fn fail_to_check_arithmetic_operation_with_different_integer_literal() {
+            let integer_type = types::Integer::new(types::IntegerKind::I64, Position::fake());
+
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![Argument::new("x", integer_type.clone())],
+                            integer_type.clone(),
+                            ArithmeticOperation::new(
+                                Some(integer_type.clone().into()),
+                                ArithmeticOperator::Add,
+                                Variable::new("x", Position::fake()),
+                                Integer::new(types::IntegerKind::U64, 1, Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ])),
+                Err(AnalysisError::TypesNotMatched(
+                    types::Integer::new(types::IntegerKind::U64, Position::fake()).into(),
+                    integer_type.into(),
+                ))
+            );
+        }
+
+        #[test]
+        fn fail_to_check_arithmetic_operation_with_different_numeric_types() {
+            let integer_type = types::Integer::new(types::IntegerKind::I64, Position::fake());
+
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![Argument::new("x", integer_type.clone())],
+                            integer_type.clone(),
+                            ArithmeticOperation::new(
+                                Some(integer_type.clone().into()),
+                                ArithmeticOperator::Add,
+                                Variable::new("x", Position::fake()),
+                                Number::new(0.0, Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ])),
+                Err(AnalysisError::TypesNotMatched(
+                    types::Number::new(Position::fake()).into(),
+                    integer_type.into(),
+                ))
+            );
+        }
+
+        #[test]
+        fn check_bitwise_operation() {
+            let integer_type = types::Integer::new(types::IntegerKind::U64, Position::fake());
+
+            check_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![Argument::new("x", integer_type.clone())],
+                        integer_type.clone(),
+                        ArithmeticOperation::new(
+                            Some(integer_type.into()),
+                            ArithmeticOperator::BitwiseXor,
+                            Variable::new("x", Position::fake()),
+                            Variable::new("x", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+
+        #[test]
+        fn fail_to_check_bitwise_operation_with_numbers() {
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            types::Number::new(Position::fake()),
+                            ArithmeticOperation::new(
+                                Some(types::Number::new(Position::fake()).into()),
+                                ArithmeticOperator::LeftShift,
+                                Number::new(1.0, Position::fake()),
+                                Number::new(1.0, Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ])),
+                Err(AnalysisError::IntegerExpected(
+                    types::Number::new(Position::fake()).into()
+                ))
+            );
+        }
+
         #[test]
         fn check_boolean_operation() {
             check_module(&Module::empty().set_function_definitions(vec![
@@ -1552,6 +1938,7 @@ mod tests {
                         vec![],
                         types::Boolean::new(Position::fake()),
                         OrderOperation::new(
+                            Some(types::Number::new(Position::fake()).into()),
                             OrderOperator::LessThan,
                             Number::new(0.0, Position::fake()),
                             Number::new(0.0, Position::fake()),
@@ -1565,6 +1952,56 @@ mod tests {
             .unwrap();
         }
 
+        #[test]
+        fn check_order_operation_of_strings() {
+            check_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        types::Boolean::new(Position::fake()),
+                        OrderOperation::new(
+                            Some(types::ByteString::new(Position::fake()).into()),
+                            OrderOperator::LessThan,
+                            ByteString::new("foo", Position::fake()),
+                            ByteString::new("bar", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+
+        #[test]
+        fn fail_to_check_order_operation_of_booleans() {
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            types::Boolean::new(Position::fake()),
+                            OrderOperation::new(
+                                Some(types::Boolean::new(Position::fake()).into()),
+                                OrderOperator::LessThan,
+                                Boolean::new(false, Position::fake()),
+                                Boolean::new(true, Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ])),
+                Err(AnalysisError::TypeNotOrderable(
+                    types::Boolean::new(Position::fake()).into()
+                ))
+            );
+        }
+
         #[test]
         fn check_try_operation() {
             let union_type = types::Union::new(
@@ -1607,6 +2044,7 @@ mod tests {
                         vec![Argument::new("x", union_type.clone())],
                         union_type,
                         ArithmeticOperation::new(
+                            Some(types::Number::new(Position::fake()).into()),
                             ArithmeticOperator::Add,
                             TryOperation::new(
                                 Some(types::Number::new(Position::fake()).into()),
@@ -1820,7 +2258,7 @@ mod tests {
                             false
                         )])
                 ),
-                Err(AnalysisError::UnknownRecordField(_))
+                Err(AnalysisError::UnknownRecordField(..))
             ));
         }
 
@@ -1929,7 +2367,10 @@ mod tests {
                             false,
                         )])
                 ),
-                Err(AnalysisError::UnknownRecordField(Position::fake()))
+                Err(AnalysisError::UnknownRecordField(
+                    Position::fake(),
+                    vec!["x".into()]
+                ))
             );
         }
 
@@ -2138,15 +2579,15 @@ mod tests {
         #[test]
         fn check_list_comprehension() {
             let element_type = types::None::new(Position::fake());
+            let list_type = types::List::new(element_type.clone(), Position::fake());
 
             check_module(&Module::empty().set_function_definitions(vec![
                     FunctionDefinition::fake(
                         "f",
                         Lambda::new(
                             vec![],
-                            types::List::new(element_type.clone(), Position::fake()),
+                            list_type.clone(),
                             ListComprehension::new(
-                                Some(element_type.clone().into()),
                                 element_type.clone(),
                                 Call::new(
                                     Some(
@@ -2161,8 +2602,15 @@ mod tests {
                                     vec![],
                                     Position::fake(),
                                 ),
-                                "x",
-                                List::new(element_type, vec![], Position::fake()),
+                                vec![ListComprehensionBranch::new(
+                                    vec!["x".into()],
+                                    vec![ListComprehensionIteratee::new(
+                                        Some(list_type.clone().into()),
+                                        List::new(element_type, vec![], Position::fake()),
+                                    )],
+                                    None,
+                                    Position::fake(),
+                                )],
                                 Position::fake(),
                             ),
                             Position::fake(),
@@ -2176,6 +2624,7 @@ mod tests {
         #[test]
         fn fail_to_check_list_in_list_comprehension() {
             let element_type = types::None::new(Position::fake());
+            let list_type = types::List::new(element_type.clone(), Position::fake());
 
             assert_eq!(
                 check_module(&Module::empty().set_function_definitions(
@@ -2183,9 +2632,8 @@ mod tests {
                         "f",
                         Lambda::new(
                             vec![],
-                            types::List::new(element_type.clone(), Position::fake()),
+                            list_type.clone(),
                             ListComprehension::new(
-                                Some(element_type.clone().into()),
                                 element_type.clone(),
                                 Call::new(
                                     Some(
@@ -2200,14 +2648,21 @@ mod tests {
                                     vec![],
                                     Position::fake(),
                                 ),
-                                "x",
-                                List::new(
-                                    element_type,
-                                    vec![ListElement::Single(
-                                        Number::new(42.0, Position::fake()).into(),
+                                vec![ListComprehensionBranch::new(
+                                    vec!["x".into()],
+                                    vec![ListComprehensionIteratee::new(
+                                        Some(list_type.clone().into()),
+                                        List::new(
+                                            element_type,
+                                            vec![ListElement::Single(
+                                                Number::new(42.0, Position::fake()).into(),
+                                            )],
+                                            Position::fake(),
+                                        ),
                                     )],
+                                    None,
                                     Position::fake(),
-                                ),
+                                )],
                                 Position::fake(),
                             ),
                             Position::fake(),
@@ -2221,6 +2676,43 @@ mod tests {
                 ))
             );
         }
+        #[test]
+        fn fail_to_check_condition_in_list_comprehension() {
+            let element_type = types::None::new(Position::fake());
+            let list_type = types::List::new(element_type.clone(), Position::fake());
+
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            list_type.clone(),
+                            ListComprehension::new(
+                                element_type.clone(),
+                                None::new(Position::fake()),
+                                vec![ListComprehensionBranch::new(
+                                    vec!["x".into()],
+                                    vec![ListComprehensionIteratee::new(
+                                        Some(list_type.into()),
+                                        List::new(element_type, vec![], Position::fake()),
+                                    )],
+                                    Some(Number::new(42.0, Position::fake()).into()),
+                                    Position::fake(),
+                                )],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )
+                ])),
+                Err(AnalysisError::TypesNotMatched(
+                    types::Number::new(Position::fake()).into(),
+                    types::Boolean::new(Position::fake()).into(),
+                ))
+            );
+        }
     }
 
     mod map {
@@ -2542,14 +3034,25 @@ mod tests {
                     Lambda::new(
                         vec![],
                         types::List::new(key_type.clone(), Position::fake()),
-                        MapIterationComprehension::new(
-                            Some(key_type.clone().into()),
-                            Some(value_type.clone().into()),
+                        ListComprehension::new(
                             key_type.clone(),
                             Variable::new("k", Position::fake()),
-                            "k",
-                            "v",
-                            Map::new(key_type, value_type, vec![], Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(
+                                        types::Map::new(
+                                            key_type.clone(),
+                                            value_type.clone(),
+                                            Position::fake(),
+                                        )
+                                        .into(),
+                                    ),
+                                    Map::new(key_type, value_type, vec![], Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -2571,14 +3074,25 @@ mod tests {
                     Lambda::new(
                         vec![],
                         types::List::new(value_type.clone(), Position::fake()),
-                        MapIterationComprehension::new(
-                            Some(key_type.clone().into()),
-                            Some(value_type.clone().into()),
+                        ListComprehension::new(
                             value_type.clone(),
                             Variable::new("v", Position::fake()),
-                            "k",
-                            "v",
-                            Map::new(key_type, value_type, vec![], Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(
+                                        types::Map::new(
+                                            key_type.clone(),
+                                            value_type.clone(),
+                                            Position::fake(),
+                                        )
+                                        .into(),
+                                    ),
+                                    Map::new(key_type, value_type, vec![], Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -2600,14 +3114,61 @@ mod tests {
                     Lambda::new(
                         vec![],
                         types::List::new(key_type.clone(), Position::fake()),
-                        MapIterationComprehension::new(
-                            Some(key_type.clone().into()),
-                            Some(value_type.clone().into()),
+                        ListComprehension::new(
                             key_type.clone(),
                             Variable::new("k", Position::fake()),
-                            "k",
-                            "v",
-                            Map::new(key_type, value_type, vec![], Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(
+                                        types::Map::new(
+                                            key_type.clone(),
+                                            value_type.clone(),
+                                            Position::fake(),
+                                        )
+                                        .into(),
+                                    ),
+                                    Map::new(key_type, value_type, vec![], Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                ),
+            ]))
+            .unwrap();
+        }
+
+        #[test]
+        fn check_map_comprehension() {
+            let key_type = types::Number::new(Position::fake());
+            let value_type = types::None::new(Position::fake());
+            let map_type = types::Map::new(key_type.clone(), value_type.clone(), Position::fake());
+
+            check_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        map_type.clone(),
+                        MapComprehension::new(
+                            key_type.clone(),
+                            value_type.clone(),
+                            Variable::new("x", Position::fake()),
+                            None::new(Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into(), "y".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(map_type.into()),
+                                    Map::new(key_type, value_type, vec![], Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -2617,6 +3178,38 @@ mod tests {
             ]))
             .unwrap();
         }
+
+        #[test]
+        fn fail_to_check_value_in_map_comprehension() {
+            let key_type = types::Number::new(Position::fake());
+            let value_type = types::None::new(Position::fake());
+
+            assert_eq!(
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            types::Map::new(key_type.clone(), value_type.clone(), Position::fake()),
+                            MapComprehension::new(
+                                key_type.clone(),
+                                value_type,
+                                Number::new(42.0, Position::fake()),
+                                Number::new(42.0, Position::fake()),
+                                vec![],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ])),
+                Err(AnalysisError::TypesNotMatched(
+                    types::Number::new(Position::fake()).into(),
+                    types::None::new(Position::fake()).into(),
+                ))
+            );
+        }
     }
 
     mod if_list {
@@ -3121,6 +3714,56 @@ mod tests {
     mod built_in_call {
         use super::*;
 
+        mod concatenate_strings {
+            use super::*;
+
+            fn check_concatenation(argument: impl Into<Expression>) -> Result<(), AnalysisError> {
+                check_module(&Module::empty().set_function_definitions(vec![
+                    FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            types::ByteString::new(Position::fake()),
+                            Call::new(
+                                Some(
+                                    types::Function::new(
+                                        vec![types::ByteString::new(Position::fake()).into(); 2],
+                                        types::ByteString::new(Position::fake()),
+                                        Position::fake(),
+                                    )
+                                    .into(),
+                                ),
+                                BuiltInFunction::new(
+                                    BuiltInFunctionName::ConcatenateStrings,
+                                    Position::fake(),
+                                ),
+                                vec![
+                                    ByteString::new("foo", Position::fake()).into(),
+                                    argument.into(),
+                                ],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    ),
+                ]))
+            }
+
+            #[test]
+            fn check_strings() {
+                check_concatenation(ByteString::new("bar", Position::fake())).unwrap();
+            }
+
+            #[test]
+            fn fail_to_check_number() {
+                assert!(matches!(
+                    check_concatenation(Number::new(42.0, Position::fake())),
+                    Err(AnalysisError::TypesNotMatched(_, _))
+                ));
+            }
+        }
+
         mod size {
             use super::*;
 
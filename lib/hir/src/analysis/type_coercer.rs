@@ -1,40 +1,75 @@
 use super::{context::AnalysisContext, AnalysisError};
 use crate::{
     analysis::{
-        module_environment_creator, record_field_resolver, type_canonicalizer,
-        type_equality_checker, type_extractor,
+        module_environment_creator, name_suggester, record_field_resolver, type_canonicalizer,
+        type_equality_checker, type_extractor, type_instantiator,
     },
     ir::*,
     types::{self, Type},
 };
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 
 pub fn coerce_types(context: &AnalysisContext, module: &Module) -> Result<Module, AnalysisError> {
     let variables = module_environment_creator::create(module);
+    let generic_functions = collect_generic_functions(&variables);
 
     Ok(Module::new(
         module.type_definitions().to_vec(),
         module.type_aliases().to_vec(),
         module.foreign_declarations().to_vec(),
         module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
         module
             .function_definitions()
             .iter()
-            .map(|definition| transform_function_definition(context, definition, &variables))
+            .map(|definition| {
+                transform_function_definition(context, &generic_functions, definition, &variables)
+            })
             .collect::<Result<_, _>>()?,
         module.position().clone(),
     ))
 }
 
+// Generic functions used as values are instantiated before type checking as
+// their instance types are determined by their expected types. Definitions
+// failing to be coerced are left as they are for the type checker to report
+// errors.
+pub fn instantiate_generic_functions(context: &AnalysisContext, module: &Module) -> Module {
+    let variables = module_environment_creator::create(module);
+    let generic_functions = collect_generic_functions(&variables);
+
+    if generic_functions.is_empty() {
+        return module.clone();
+    }
+
+    Module::new(
+        module.type_definitions().to_vec(),
+        module.type_aliases().to_vec(),
+        module.foreign_declarations().to_vec(),
+        module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
+        module
+            .function_definitions()
+            .iter()
+            .map(|definition| {
+                transform_function_definition(context, &generic_functions, definition, &variables)
+                    .unwrap_or_else(|_| definition.clone())
+            })
+            .collect(),
+        module.position().clone(),
+    )
+}
+
 fn transform_function_definition(
     context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
     definition: &FunctionDefinition,
     variables: &FnvHashMap<String, Type>,
 ) -> Result<FunctionDefinition, AnalysisError> {
     Ok(FunctionDefinition::new(
         definition.name(),
         definition.original_name(),
-        transform_lambda(definition.lambda(), variables, context)?,
+        transform_lambda(context, generic_functions, definition.lambda(), variables)?,
         definition.foreign_definition_configuration().cloned(),
         definition.is_public(),
         definition.position().clone(),
@@ -42,9 +77,10 @@ fn transform_function_definition(
 }
 
 fn transform_lambda(
+    context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
     lambda: &Lambda,
     variables: &FnvHashMap<String, Type>,
-    context: &AnalysisContext,
 ) -> Result<Lambda, AnalysisError> {
     let variables = variables
         .clone()
@@ -62,7 +98,8 @@ fn transform_lambda(
         lambda.result_type().clone(),
         coerce_expression(
             context,
-            &transform_expression(context, lambda.body(), &variables)?,
+            generic_functions,
+            &transform_expression(context, generic_functions, lambda.body(), &variables)?,
             lambda.result_type(),
             &variables,
         )?,
@@ -72,14 +109,17 @@ fn transform_lambda(
 
 fn transform_expression(
     context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
     expression: &Expression,
     variables: &FnvHashMap<String, Type>,
 ) -> Result<Expression, AnalysisError> {
-    let transform_expression =
-        |expression, variables: &_| transform_expression(context, expression, variables);
+    let transform_expression = |expression, variables: &_| {
+        transform_expression(context, generic_functions, expression, variables)
+    };
     let transform_and_coerce_expression = |expression, type_: &_, variables: &_| {
         coerce_expression(
             context,
+            generic_functions,
             &transform_expression(expression, variables)?,
             type_,
             variables,
@@ -247,7 +287,9 @@ fn transform_expression(
             )
             .into()
         }
-        Expression::Lambda(lambda) => transform_lambda(lambda, variables, context)?.into(),
+        Expression::Lambda(lambda) => {
+            transform_lambda(context, generic_functions, lambda, variables)?.into()
+        }
         Expression::Let(let_) => Let::new(
             let_.name().map(String::from),
             let_.type_().cloned(),
@@ -302,30 +344,22 @@ fn transform_expression(
         )
         .into(),
         Expression::ListComprehension(comprehension) => {
-            let position = comprehension.position();
-            let input_type = comprehension
-                .input_type()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+            let (branches, variables) = transform_list_comprehension_branches(
+                context,
+                generic_functions,
+                comprehension.branches(),
+                variables,
+            )?;
 
             ListComprehension::new(
-                comprehension.input_type().cloned(),
-                comprehension.output_type().clone(),
+                comprehension.type_().clone(),
                 transform_and_coerce_expression(
                     comprehension.element(),
-                    comprehension.output_type(),
-                    &variables
-                        .clone()
-                        .into_iter()
-                        .chain([(
-                            comprehension.element_name().into(),
-                            types::Function::new(vec![], input_type.clone(), position.clone())
-                                .into(),
-                        )])
-                        .collect(),
+                    comprehension.type_(),
+                    &variables,
                 )?,
-                comprehension.element_name(),
-                transform_expression(comprehension.list(), variables)?,
-                position.clone(),
+                branches,
+                comprehension.position().clone(),
             )
             .into()
         }
@@ -368,40 +402,35 @@ fn transform_expression(
             map.position().clone(),
         )
         .into(),
-        Expression::MapIterationComprehension(comprehension) => {
-            let position = comprehension.position();
-            let key_type = comprehension
-                .key_type()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
-            let value_type = comprehension
-                .value_type()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
-
-            MapIterationComprehension::new(
-                comprehension.key_type().cloned(),
-                comprehension.value_type().cloned(),
-                comprehension.element_type().clone(),
+        Expression::MapComprehension(comprehension) => {
+            let (branches, variables) = transform_list_comprehension_branches(
+                context,
+                generic_functions,
+                comprehension.branches(),
+                variables,
+            )?;
+
+            MapComprehension::new(
+                comprehension.key_type().clone(),
+                comprehension.value_type().clone(),
                 transform_and_coerce_expression(
-                    comprehension.element(),
-                    comprehension.element_type(),
-                    &variables
-                        .clone()
-                        .into_iter()
-                        .chain([
-                            (comprehension.key_name().into(), key_type.clone()),
-                            (comprehension.value_name().into(), value_type.clone()),
-                        ])
-                        .collect(),
+                    comprehension.key(),
+                    comprehension.key_type(),
+                    &variables,
                 )?,
-                comprehension.key_name(),
-                comprehension.value_name(),
-                transform_expression(comprehension.map(), variables)?,
-                position.clone(),
+                transform_and_coerce_expression(
+                    comprehension.value(),
+                    comprehension.value_type(),
+                    &variables,
+                )?,
+                branches,
+                comprehension.position().clone(),
             )
             .into()
         }
         Expression::Operation(operation) => match operation {
             Operation::Arithmetic(operation) => ArithmeticOperation::new(
+                operation.type_().cloned(),
                 operation.operator(),
                 transform_expression(operation.lhs(), variables)?,
                 transform_expression(operation.rhs(), variables)?,
@@ -435,6 +464,7 @@ fn transform_expression(
             )
             .into(),
             Operation::Order(operation) => OrderOperation::new(
+                operation.type_().cloned(),
                 operation.operator(),
                 transform_expression(operation.lhs(), variables)?,
                 transform_expression(operation.rhs(), variables)?,
@@ -451,10 +481,11 @@ fn transform_expression(
         Expression::RecordConstruction(construction) => RecordConstruction::new(
             construction.type_().clone(),
             transform_record_fields(
+                context,
+                generic_functions,
                 construction.fields(),
                 construction.type_(),
                 variables,
-                context,
             )?,
             construction.position().clone(),
         )
@@ -469,7 +500,13 @@ fn transform_expression(
         Expression::RecordUpdate(update) => RecordUpdate::new(
             update.type_().clone(),
             transform_expression(update.record(), variables)?,
-            transform_record_fields(update.fields(), update.type_(), variables, context)?,
+            transform_record_fields(
+                context,
+                generic_functions,
+                update.fields(),
+                update.type_(),
+                variables,
+            )?,
             update.position().clone(),
         )
         .into(),
@@ -494,6 +531,7 @@ fn transform_expression(
         .into(),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
+        | Expression::Integer(_)
         | Expression::None(_)
         | Expression::Number(_)
         | Expression::String(_)
@@ -501,11 +539,54 @@ fn transform_expression(
     })
 }
 
+fn transform_list_comprehension_branches(
+    context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
+    branches: &[ListComprehensionBranch],
+    variables: &FnvHashMap<String, Type>,
+) -> Result<(Vec<ListComprehensionBranch>, FnvHashMap<String, Type>), AnalysisError> {
+    let transform_expression = |expression, variables: &_| {
+        transform_expression(context, generic_functions, expression, variables)
+    };
+    let mut variables = variables.clone();
+    let mut transformed_branches = vec![];
+
+    for branch in branches {
+        let iteratees = branch
+            .iteratees()
+            .iter()
+            .map(|iteratee| {
+                Ok(ListComprehensionIteratee::new(
+                    iteratee.type_().cloned(),
+                    transform_expression(iteratee.expression(), &variables)?,
+                ))
+            })
+            .collect::<Result<_, AnalysisError>>()?;
+
+        variables.extend(type_extractor::extract_from_list_comprehension_branch(
+            branch,
+        )?);
+
+        transformed_branches.push(ListComprehensionBranch::new(
+            branch.names().to_vec(),
+            iteratees,
+            branch
+                .condition()
+                .map(|condition| transform_expression(condition, &variables))
+                .transpose()?,
+            branch.position().clone(),
+        ));
+    }
+
+    Ok((transformed_branches, variables))
+}
+
 fn transform_record_fields(
+    context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
     fields: &[RecordField],
     record_type: &Type,
     variables: &FnvHashMap<String, Type>,
-    context: &AnalysisContext,
 ) -> Result<Vec<RecordField>, AnalysisError> {
     let field_types =
         record_field_resolver::resolve(record_type, context.types(), context.records())?;
@@ -517,11 +598,22 @@ fn transform_record_fields(
                 field.name(),
                 coerce_expression(
                     context,
-                    &transform_expression(context, field.expression(), variables)?,
+                    generic_functions,
+                    &transform_expression(
+                        context,
+                        generic_functions,
+                        field.expression(),
+                        variables,
+                    )?,
                     field_types
                         .iter()
                         .find(|field_type| field_type.name() == field.name())
-                        .ok_or_else(|| AnalysisError::UnknownRecordField(field.position().clone()))?
+                        .ok_or_else(|| {
+                            AnalysisError::UnknownRecordField(
+                                field.position().clone(),
+                                name_suggester::suggest_record_fields(field.name(), &field_types),
+                            )
+                        })?
                         .type_(),
                     variables,
                 )?,
@@ -533,10 +625,23 @@ fn transform_record_fields(
 
 fn coerce_expression(
     context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
     expression: &Expression,
     upper_type: &Type,
     variables: &FnvHashMap<String, Type>,
 ) -> Result<Expression, AnalysisError> {
+    if let Expression::Variable(variable) = expression {
+        if generic_functions.contains(variable.name()) {
+            return instantiate_generic_function(
+                context,
+                generic_functions,
+                variable,
+                upper_type,
+                variables,
+            );
+        }
+    }
+
     let lower_type = type_extractor::extract_from_expression(context, expression, variables)?;
 
     Ok(
@@ -554,6 +659,64 @@ fn coerce_expression(
     )
 }
 
+// Generic functions used as values are wrapped in lambdas calling them with
+// their instance types.
+fn instantiate_generic_function(
+    context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
+    variable: &Variable,
+    upper_type: &Type,
+    variables: &FnvHashMap<String, Type>,
+) -> Result<Expression, AnalysisError> {
+    let generic_type = variables
+        .get(variable.name())
+        .ok_or_else(|| AnalysisError::VariableNotFound(variable.clone(), vec![]))?;
+    let position = variable.position();
+    let function_type = type_canonicalizer::canonicalize_function(upper_type, context.types())?
+        .ok_or_else(|| AnalysisError::GenericFunctionNotInstantiated(position.clone()))?;
+    let instance_type = type_instantiator::instantiate(
+        &type_canonicalizer::canonicalize_function(generic_type, context.types())?
+            .ok_or_else(|| AnalysisError::FunctionExpected(generic_type.clone()))?,
+        function_type.arguments(),
+        context.types(),
+    )?;
+    let arguments = function_type
+        .arguments()
+        .iter()
+        .enumerate()
+        .map(|(index, type_)| Argument::new(format!("$argument:{}", index), type_.clone()))
+        .collect::<Vec<_>>();
+
+    Ok(transform_lambda(
+        context,
+        generic_functions,
+        &Lambda::new(
+            arguments.clone(),
+            function_type.result().clone(),
+            Call::new(
+                Some(instance_type.into()),
+                variable.clone(),
+                arguments
+                    .iter()
+                    .map(|argument| Variable::new(argument.name(), position.clone()).into())
+                    .collect(),
+                position.clone(),
+            ),
+            position.clone(),
+        ),
+        variables,
+    )?
+    .into())
+}
+
+fn collect_generic_functions(variables: &FnvHashMap<String, Type>) -> FnvHashSet<String> {
+    variables
+        .iter()
+        .filter(|(_, type_)| type_instantiator::is_generic(type_))
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -615,6 +778,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn coerce_generic_function() {
+        let variable_type = types::Variable::new("a", Position::fake());
+        let number_type = types::Number::new(Position::fake());
+        let union_type = types::Union::new(
+            types::None::new(Position::fake()),
+            number_type.clone(),
+            Position::fake(),
+        );
+        let function_type = types::Function::new(
+            vec![number_type.clone().into()],
+            union_type.clone(),
+            Position::fake(),
+        );
+        let module = Module::empty().set_function_declarations(vec![FunctionDeclaration::new(
+            "f",
+            "f",
+            types::Function::new(
+                vec![variable_type.clone().into()],
+                variable_type,
+                Position::fake(),
+            ),
+            Position::fake(),
+        )]);
+
+        assert_eq!(
+            coerce_module(&module.clone().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "g",
+                    Lambda::new(
+                        vec![],
+                        function_type.clone(),
+                        Variable::new("f", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Ok(
+                module.set_function_definitions(vec![FunctionDefinition::fake(
+                    "g",
+                    Lambda::new(
+                        vec![],
+                        function_type,
+                        Lambda::new(
+                            vec![Argument::new("$argument:0", number_type.clone())],
+                            union_type.clone(),
+                            TypeCoercion::new(
+                                number_type.clone(),
+                                union_type,
+                                Call::new(
+                                    Some(
+                                        types::Function::new(
+                                            vec![number_type.clone().into()],
+                                            number_type,
+                                            Position::fake(),
+                                        )
+                                        .into()
+                                    ),
+                                    Variable::new("f", Position::fake()),
+                                    vec![Variable::new("$argument:0", Position::fake()).into()],
+                                    Position::fake(),
+                                ),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            )
+        );
+    }
+
+    #[test]
+    fn fail_to_coerce_generic_function_to_any() {
+        let variable_type = types::Variable::new("a", Position::fake());
+
+        assert_eq!(
+            coerce_module(
+                &Module::empty()
+                    .set_function_declarations(vec![FunctionDeclaration::new(
+                        "f",
+                        "f",
+                        types::Function::new(
+                            vec![variable_type.clone().into()],
+                            variable_type,
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "g",
+                        Lambda::new(
+                            vec![],
+                            types::Any::new(Position::fake()),
+                            Variable::new("f", Position::fake()),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+            ),
+            Err(AnalysisError::GenericFunctionNotInstantiated(
+                Position::fake()
+            ))
+        );
+    }
+
     #[test]
     fn coerce_function_result_of_variable() {
         let union_type = types::Union::new(
@@ -1467,6 +1739,8 @@ mod tests {
             let list_type = types::List::new(union_type.clone(), Position::fake());
             let empty_list =
                 List::new(types::None::new(Position::fake()), vec![], Position::fake());
+            let empty_list_type =
+                types::List::new(types::None::new(Position::fake()), Position::fake());
 
             assert_eq!(
                 coerce_module(&Module::empty().set_function_definitions(vec![
@@ -1476,11 +1750,17 @@ mod tests {
                             vec![],
                             list_type.clone(),
                             ListComprehension::new(
-                                Some(types::None::new(Position::fake()).into()),
                                 union_type.clone(),
                                 None::new(Position::fake()),
-                                "x",
-                                empty_list.clone(),
+                                vec![ListComprehensionBranch::new(
+                                    vec!["x".into()],
+                                    vec![ListComprehensionIteratee::new(
+                                        Some(empty_list_type.clone().into()),
+                                        empty_list.clone(),
+                                    )],
+                                    None,
+                                    Position::fake(),
+                                )],
                                 Position::fake(),
                             ),
                             Position::fake(),
@@ -1495,7 +1775,6 @@ mod tests {
                             vec![],
                             list_type,
                             ListComprehension::new(
-                                Some(types::None::new(Position::fake()).into()),
                                 union_type.clone(),
                                 TypeCoercion::new(
                                     types::None::new(Position::fake()),
@@ -1503,8 +1782,15 @@ mod tests {
                                     None::new(Position::fake()),
                                     Position::fake(),
                                 ),
-                                "x",
-                                empty_list,
+                                vec![ListComprehensionBranch::new(
+                                    vec!["x".into()],
+                                    vec![ListComprehensionIteratee::new(
+                                        Some(empty_list_type.into()),
+                                        empty_list,
+                                    )],
+                                    None,
+                                    Position::fake(),
+                                )],
                                 Position::fake(),
                             ),
                             Position::fake(),
@@ -1525,6 +1811,8 @@ mod tests {
             let list_type = types::List::new(union_type.clone(), Position::fake());
             let empty_list =
                 List::new(types::None::new(Position::fake()), vec![], Position::fake());
+            let empty_list_type =
+                types::List::new(types::None::new(Position::fake()), Position::fake());
 
             assert_eq!(
                 coerce_module(&Module::empty().set_function_definitions(
@@ -1534,7 +1822,6 @@ mod tests {
                             vec![],
                             list_type.clone(),
                             ListComprehension::new(
-                                Some(types::None::new(Position::fake()).into()),
                                 union_type.clone(),
                                 Call::new(
                                     Some(
@@ -1549,8 +1836,15 @@ mod tests {
                                     vec![],
                                     Position::fake(),
                                 ),
-                                "x",
-                                empty_list.clone(),
+                                vec![ListComprehensionBranch::new(
+                                    vec!["x".into()],
+                                    vec![ListComprehensionIteratee::new(
+                                        Some(empty_list_type.clone().into()),
+                                        empty_list.clone(),
+                                    )],
+                                    None,
+                                    Position::fake(),
+                                )],
                                 Position::fake(),
                             ),
                             Position::fake(),
@@ -1565,7 +1859,6 @@ mod tests {
                             vec![],
                             list_type,
                             ListComprehension::new(
-                                Some(types::None::new(Position::fake()).into()),
                                 union_type.clone(),
                                 TypeCoercion::new(
                                     types::None::new(Position::fake()),
@@ -1585,8 +1878,15 @@ mod tests {
                                     ),
                                     Position::fake(),
                                 ),
-                                "x",
-                                empty_list,
+                                vec![ListComprehensionBranch::new(
+                                    vec!["x".into()],
+                                    vec![ListComprehensionIteratee::new(
+                                        Some(empty_list_type.into()),
+                                        empty_list,
+                                    )],
+                                    None,
+                                    Position::fake(),
+                                )],
                                 Position::fake(),
                             ),
                             Position::fake(),
@@ -1937,6 +2237,11 @@ mod tests {
                     vec![],
                     Position::fake(),
                 );
+                let empty_map_type = types::Map::new(
+                    types::None::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                );
 
                 assert_eq!(
                     coerce_module(&Module::empty().set_function_definitions(vec![
@@ -1945,14 +2250,18 @@ mod tests {
                             Lambda::new(
                                 vec![],
                                 list_type.clone(),
-                                MapIterationComprehension::new(
-                                    Some(types::None::new(Position::fake()).into()),
-                                    Some(types::None::new(Position::fake()).into()),
+                                ListComprehension::new(
                                     union_type.clone(),
                                     None::new(Position::fake()),
-                                    "k",
-                                    "v",
-                                    empty_map.clone(),
+                                    vec![ListComprehensionBranch::new(
+                                        vec!["k".into(), "v".into()],
+                                        vec![ListComprehensionIteratee::new(
+                                            Some(empty_map_type.clone().into()),
+                                            empty_map.clone(),
+                                        )],
+                                        None,
+                                        Position::fake(),
+                                    )],
                                     Position::fake(),
                                 ),
                                 Position::fake(),
@@ -1966,9 +2275,7 @@ mod tests {
                             Lambda::new(
                                 vec![],
                                 list_type,
-                                MapIterationComprehension::new(
-                                    Some(types::None::new(Position::fake()).into()),
-                                    Some(types::None::new(Position::fake()).into()),
+                                ListComprehension::new(
                                     union_type.clone(),
                                     TypeCoercion::new(
                                         types::None::new(Position::fake()),
@@ -1976,9 +2283,15 @@ mod tests {
                                         None::new(Position::fake()),
                                         Position::fake(),
                                     ),
-                                    "k",
-                                    "v",
-                                    empty_map,
+                                    vec![ListComprehensionBranch::new(
+                                        vec!["k".into(), "v".into()],
+                                        vec![ListComprehensionIteratee::new(
+                                            Some(empty_map_type.into()),
+                                            empty_map,
+                                        )],
+                                        None,
+                                        Position::fake(),
+                                    )],
                                     Position::fake(),
                                 ),
                                 Position::fake(),
@@ -2003,6 +2316,11 @@ mod tests {
                     vec![],
                     Position::fake(),
                 );
+                let empty_map_type = types::Map::new(
+                    types::None::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                );
 
                 assert_eq!(
                     coerce_module(&Module::empty().set_function_definitions(vec![
@@ -2011,14 +2329,18 @@ mod tests {
                             Lambda::new(
                                 vec![],
                                 list_type.clone(),
-                                MapIterationComprehension::new(
-                                    Some(types::None::new(Position::fake()).into()),
-                                    Some(types::None::new(Position::fake()).into()),
+                                ListComprehension::new(
                                     union_type.clone(),
                                     Variable::new("k", Position::fake()),
-                                    "k",
-                                    "v",
-                                    empty_map.clone(),
+                                    vec![ListComprehensionBranch::new(
+                                        vec!["k".into(), "v".into()],
+                                        vec![ListComprehensionIteratee::new(
+                                            Some(empty_map_type.clone().into()),
+                                            empty_map.clone(),
+                                        )],
+                                        None,
+                                        Position::fake(),
+                                    )],
                                     Position::fake(),
                                 ),
                                 Position::fake(),
@@ -2032,9 +2354,7 @@ mod tests {
                             Lambda::new(
                                 vec![],
                                 list_type,
-                                MapIterationComprehension::new(
-                                    Some(types::None::new(Position::fake()).into()),
-                                    Some(types::None::new(Position::fake()).into()),
+                                ListComprehension::new(
                                     union_type.clone(),
                                     TypeCoercion::new(
                                         types::None::new(Position::fake()),
@@ -2042,9 +2362,15 @@ mod tests {
                                         Variable::new("k", Position::fake()),
                                         Position::fake(),
                                     ),
-                                    "k",
-                                    "v",
-                                    empty_map,
+                                    vec![ListComprehensionBranch::new(
+                                        vec!["k".into(), "v".into()],
+                                        vec![ListComprehensionIteratee::new(
+                                            Some(empty_map_type.into()),
+                                            empty_map,
+                                        )],
+                                        None,
+                                        Position::fake(),
+                                    )],
                                     Position::fake(),
                                 ),
                                 Position::fake(),
@@ -2069,6 +2395,11 @@ mod tests {
                     vec![],
                     Position::fake(),
                 );
+                let empty_map_type = types::Map::new(
+                    types::None::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                );
 
                 assert_eq!(
                     coerce_module(&Module::empty().set_function_definitions(vec![
@@ -2077,14 +2408,18 @@ mod tests {
                             Lambda::new(
                                 vec![],
                                 list_type.clone(),
-                                MapIterationComprehension::new(
-                                    Some(types::None::new(Position::fake()).into()),
-                                    Some(types::None::new(Position::fake()).into()),
+                                ListComprehension::new(
                                     union_type.clone(),
                                     Variable::new("v", Position::fake()),
-                                    "k",
-                                    "v",
-                                    empty_map.clone(),
+                                    vec![ListComprehensionBranch::new(
+                                        vec!["k".into(), "v".into()],
+                                        vec![ListComprehensionIteratee::new(
+                                            Some(empty_map_type.clone().into()),
+                                            empty_map.clone(),
+                                        )],
+                                        None,
+                                        Position::fake(),
+                                    )],
                                     Position::fake(),
                                 ),
                                 Position::fake(),
@@ -2098,9 +2433,7 @@ mod tests {
                             Lambda::new(
                                 vec![],
                                 list_type,
-                                MapIterationComprehension::new(
-                                    Some(types::None::new(Position::fake()).into()),
-                                    Some(types::None::new(Position::fake()).into()),
+                                ListComprehension::new(
                                     union_type.clone(),
                                     TypeCoercion::new(
                                         types::None::new(Position::fake()),
@@ -2108,9 +2441,15 @@ mod tests {
                                         Variable::new("v", Position::fake()),
                                         Position::fake(),
                                     ),
-                                    "k",
-                                    "v",
-                                    empty_map,
+                                    vec![ListComprehensionBranch::new(
+                                        vec!["k".into(), "v".into()],
+                                        vec![ListComprehensionIteratee::new(
+                                            Some(empty_map_type.into()),
+                                            empty_map,
+                                        )],
+                                        None,
+                                        Position::fake(),
+                                    )],
                                     Position::fake(),
                                 ),
                                 Position::fake(),
@@ -11,7 +11,18 @@ pub fn collect(module: &Module) -> FnvHashMap<String, Type> {
         .map(|definition| {
             (
                 definition.name().into(),
-                types::Record::new(definition.name(), definition.position().clone()).into(),
+                // Generic records refer to their own type parameters as arguments.
+                types::Record::new(definition.name(), definition.position().clone())
+                    .set_arguments(
+                        definition
+                            .type_parameters()
+                            .iter()
+                            .map(|name| {
+                                types::Variable::new(name, definition.position().clone()).into()
+                            })
+                            .collect(),
+                    )
+                    .into(),
             )
         })
         .chain(
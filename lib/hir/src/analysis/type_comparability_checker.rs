@@ -1,4 +1,4 @@
-use super::{record_field_resolver, type_resolver, AnalysisError};
+use super::{record_field_resolver, type_formatter, type_resolver, AnalysisError};
 use crate::types::{RecordField, Type};
 use fnv::{FnvHashMap, FnvHashSet};
 
@@ -30,16 +30,15 @@ fn check_with_cache(
                 && check_with_cache(map.value(), record_names)?
         }
         Type::None(_) => true,
-        Type::Number(_) => true,
-        Type::Record(record) => {
-            if record_names.contains(record.name()) {
+        Type::Integer(_) | Type::Number(_) => true,
+        Type::Record(_) => {
+            // Instances of generic records are distinguished by their arguments.
+            let name = type_formatter::format(type_);
+
+            if record_names.contains(&name) {
                 true
             } else {
-                let record_names = record_names
-                    .clone()
-                    .into_iter()
-                    .chain([record.name().into()])
-                    .collect();
+                let record_names = record_names.clone().into_iter().chain([name]).collect();
 
                 record_field_resolver::resolve(type_, types, record_types)?
                     .iter()
@@ -57,6 +56,7 @@ fn check_with_cache(
             check_with_cache(union.lhs(), record_names)?
                 && check_with_cache(union.rhs(), record_names)?
         }
+        Type::Variable(_) => false,
     })
 }
 
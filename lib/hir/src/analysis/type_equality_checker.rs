@@ -32,7 +32,17 @@ fn check_canonical(one: &Type, other: &Type) -> bool {
         (Type::Union(one), Type::Union(other)) => {
             check_canonical(one.lhs(), other.lhs()) && check_canonical(one.rhs(), other.rhs())
         }
-        (Type::Record(one), Type::Record(other)) => one.name() == other.name(),
+        (Type::Integer(one), Type::Integer(other)) => one.kind() == other.kind(),
+        (Type::Record(one), Type::Record(other)) => {
+            one.name() == other.name()
+                && one.arguments().len() == other.arguments().len()
+                && one
+                    .arguments()
+                    .iter()
+                    .zip(other.arguments())
+                    .all(|(one, other)| check_canonical(one, other))
+        }
+        (Type::Variable(one), Type::Variable(other)) => one.name() == other.name(),
         (Type::Any(_), Type::Any(_))
         | (Type::Boolean(_), Type::Boolean(_))
         | (Type::Error(_), Type::Error(_))
@@ -144,4 +154,33 @@ mod tests {
         )
         .unwrap());
     }
+
+    #[test]
+    fn check_records_with_arguments() {
+        let record = Record::new("x", Position::fake());
+
+        assert!(check(
+            &record
+                .clone()
+                .set_arguments(vec![Number::new(Position::fake()).into()])
+                .into(),
+            &record
+                .clone()
+                .set_arguments(vec![Number::new(Position::fake()).into()])
+                .into(),
+            &Default::default(),
+        )
+        .unwrap());
+        assert!(!check(
+            &record
+                .clone()
+                .set_arguments(vec![Number::new(Position::fake()).into()])
+                .into(),
+            &record
+                .set_arguments(vec![None::new(Position::fake()).into()])
+                .into(),
+            &Default::default(),
+        )
+        .unwrap());
+    }
 }
@@ -1,22 +1,53 @@
 use super::error::AnalysisError;
-use crate::{analysis::type_transformer, ir::*, types::Type};
-use fnv::FnvHashSet;
+use crate::{
+    analysis::{expression_visitor, name_suggester, type_transformer},
+    ir::*,
+    types::Type,
+};
+use fnv::{FnvHashMap, FnvHashSet};
 use std::cell::RefCell;
 
 pub fn validate(module: &Module) -> Result<(), AnalysisError> {
     let records = collect_existent_records(module);
     let types = collect_existent_types(module, &records);
+    let parameter_counts = collect_type_parameter_counts(module);
+    let inferred_types = collect_inferred_types(module);
 
     for type_ in &collect_types(module) {
         match type_ {
             Type::Record(record) => {
                 if !records.contains(record.name()) {
-                    return Err(AnalysisError::RecordNotFound(record.clone()));
+                    return Err(AnalysisError::RecordNotFound(
+                        record.clone(),
+                        name_suggester::suggest(record.name(), collect_record_names(module)),
+                    ));
+                } else if !record.arguments().is_empty()
+                    && parameter_counts.get(record.name()) != Some(&record.arguments().len())
+                {
+                    return Err(AnalysisError::WrongTypeArgumentCount(
+                        record.position().clone(),
+                    ));
                 }
             }
             Type::Reference(reference) => {
                 if !types.contains(reference.name()) {
-                    return Err(AnalysisError::TypeNotFound(reference.clone()));
+                    return Err(AnalysisError::TypeNotFound(
+                        reference.clone(),
+                        name_suggester::suggest(
+                            reference.name(),
+                            collect_record_names(module).chain(collect_type_alias_names(module)),
+                        ),
+                    ));
+                } else if parameter_counts
+                    .get(reference.name())
+                    .copied()
+                    .unwrap_or_default()
+                    != reference.arguments().len()
+                    && !inferred_types.contains(&type_)
+                {
+                    return Err(AnalysisError::WrongTypeArgumentCount(
+                        reference.position().clone(),
+                    ));
                 }
             }
             Type::Any(_)
@@ -26,9 +57,11 @@ pub fn validate(module: &Module) -> Result<(), AnalysisError> {
             | Type::List(_)
             | Type::Map(_)
             | Type::None(_)
+            | Type::Integer(_)
             | Type::Number(_)
             | Type::String(_)
-            | Type::Union(_) => {}
+            | Type::Union(_)
+            | Type::Variable(_) => {}
         }
     }
 
@@ -47,6 +80,26 @@ fn collect_types(module: &Module) -> Vec<Type> {
     types.into_inner()
 }
 
+// Type arguments of generic records are inferred from their fields in record
+// construction and update.
+fn collect_inferred_types(module: &Module) -> Vec<&Type> {
+    let mut types = vec![];
+
+    expression_visitor::visit(module, |expression| match expression {
+        Expression::RecordConstruction(construction) => types.push(construction.type_()),
+        Expression::RecordUpdate(update) => types.push(update.type_()),
+        _ => {}
+    });
+
+    types
+        .into_iter()
+        .filter(|type_| match type_ {
+            Type::Reference(reference) => reference.arguments().is_empty(),
+            _ => false,
+        })
+        .collect()
+}
+
 fn collect_existent_types<'a>(
     module: &'a Module,
     records: &'a FnvHashSet<&str>,
@@ -64,6 +117,20 @@ fn collect_existent_types<'a>(
         .collect()
 }
 
+fn collect_type_parameter_counts(module: &Module) -> FnvHashMap<&str, usize> {
+    module
+        .type_definitions()
+        .iter()
+        .map(|definition| (definition.name(), definition.type_parameters().len()))
+        .chain(
+            module
+                .type_aliases()
+                .iter()
+                .map(|alias| (alias.name(), alias.type_parameters().len())),
+        )
+        .collect()
+}
+
 fn collect_existent_records(module: &Module) -> FnvHashSet<&str> {
     module
         .type_definitions()
@@ -73,11 +140,29 @@ fn collect_existent_records(module: &Module) -> FnvHashSet<&str> {
         .collect()
 }
 
+// Names of types in imported modules are not suggested because we do not know
+// their prefixes here.
+fn collect_record_names(module: &Module) -> impl Iterator<Item = &str> {
+    module
+        .type_definitions()
+        .iter()
+        .filter(|definition| !definition.is_external())
+        .map(|definition| definition.original_name())
+}
+
+fn collect_type_alias_names(module: &Module) -> impl Iterator<Item = &str> {
+    module
+        .type_aliases()
+        .iter()
+        .filter(|alias| !alias.is_external())
+        .map(|alias| alias.original_name())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        test::{ModuleFake, TypeAliasFake, TypeDefinitionFake},
+        test::{FunctionDefinitionFake, ModuleFake, TypeAliasFake, TypeDefinitionFake},
         types,
     };
     use position::{test::PositionFake, Position};
@@ -91,10 +176,10 @@ mod tests {
                 false,
                 false
             )]),),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("foo", Position::fake()),
+                vec![]
+            ))
         );
     }
 
@@ -107,10 +192,10 @@ mod tests {
                 false,
                 false
             )]),),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("foo", Position::fake()),
+                vec![]
+            ))
         );
     }
 
@@ -129,10 +214,38 @@ mod tests {
                     false
                 )]),
             ),
-            Err(AnalysisError::RecordNotFound(types::Record::new(
-                "foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::RecordNotFound(
+                types::Record::new("foo", Position::fake()),
+                vec![]
+            ))
+        );
+    }
+
+    #[test]
+    fn suggest_type_names() {
+        assert_eq!(
+            validate(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::new(
+                        "foo:Foo",
+                        "Foo",
+                        vec![],
+                        false,
+                        false,
+                        false,
+                        Position::fake()
+                    )])
+                    .set_type_aliases(vec![TypeAlias::fake(
+                        "x",
+                        types::Reference::new("Fo", Position::fake()),
+                        false,
+                        false
+                    )]),
+            ),
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("Fo", Position::fake()),
+                vec!["Foo".into()]
+            ))
         );
     }
 
@@ -148,10 +261,10 @@ mod tests {
                     false
                 )
             ]),),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "Foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("Foo", Position::fake()),
+                vec![]
+            ))
         );
     }
 
@@ -174,10 +287,10 @@ mod tests {
                         false
                     )]),
             ),
-            Err(AnalysisError::TypeNotFound(types::Reference::new(
-                "Foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::TypeNotFound(
+                types::Reference::new("Foo", Position::fake()),
+                vec![]
+            ))
         );
     }
 
@@ -200,10 +313,10 @@ mod tests {
                         false
                     )]),
             ),
-            Err(AnalysisError::RecordNotFound(types::Record::new(
-                "Foo",
-                Position::fake()
-            )))
+            Err(AnalysisError::RecordNotFound(
+                types::Record::new("Foo", Position::fake()),
+                vec![]
+            ))
         );
     }
 
@@ -222,4 +335,100 @@ mod tests {
             Ok(())
         );
     }
+
+    #[test]
+    fn validate_reference_type_with_type_arguments() {
+        assert_eq!(
+            validate(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "Foo",
+                        vec![types::RecordField::new(
+                            "x",
+                            types::Variable::new("a", Position::fake())
+                        )],
+                        false,
+                        false,
+                        false
+                    )
+                    .set_type_parameters(vec!["a".into()])])
+                    .set_type_aliases(vec![TypeAlias::fake(
+                        "Bar",
+                        types::Reference::new("Foo", Position::fake())
+                            .set_arguments(vec![types::None::new(Position::fake()).into()]),
+                        false,
+                        false
+                    )]),
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_reference_type_with_wrong_number_of_type_arguments() {
+        assert_eq!(
+            validate(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "Foo",
+                        vec![types::RecordField::new(
+                            "x",
+                            types::Variable::new("a", Position::fake())
+                        )],
+                        false,
+                        false,
+                        false
+                    )
+                    .set_type_parameters(vec!["a".into()])])
+                    .set_type_aliases(vec![TypeAlias::fake(
+                        "Bar",
+                        types::Reference::new("Foo", Position::fake()),
+                        false,
+                        false
+                    )]),
+            ),
+            Err(AnalysisError::WrongTypeArgumentCount(Position::fake()))
+        );
+    }
+
+    #[test]
+    fn validate_record_construction_without_type_arguments() {
+        let record_type = types::Reference::new("Foo", Position::fake());
+
+        assert_eq!(
+            validate(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::fake(
+                        "Foo",
+                        vec![types::RecordField::new(
+                            "x",
+                            types::Variable::new("a", Position::fake())
+                        )],
+                        false,
+                        false,
+                        false
+                    )
+                    .set_type_parameters(vec!["a".into()])])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            types::Any::new(Position::fake()),
+                            RecordConstruction::new(
+                                record_type,
+                                vec![RecordField::new(
+                                    "x",
+                                    None::new(Position::fake()),
+                                    Position::fake(),
+                                )],
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )]),
+            ),
+            Ok(())
+        );
+    }
 }
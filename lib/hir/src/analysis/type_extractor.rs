@@ -1,6 +1,6 @@
 use super::{AnalysisContext, AnalysisError};
 use crate::{
-    analysis::{record_field_resolver, type_canonicalizer, union_type_creator},
+    analysis::{name_suggester, record_field_resolver, type_canonicalizer, union_type_creator},
     ir::*,
     types::{self, Type},
 };
@@ -129,12 +129,15 @@ pub fn extract_from_expression(
             if_.position(),
         )
         .unwrap(),
+        Expression::Integer(integer) => {
+            types::Integer::new(integer.kind(), integer.position().clone()).into()
+        }
         Expression::Lambda(lambda) => extract_from_lambda(lambda).into(),
         Expression::List(list) => {
             types::List::new(list.type_().clone(), list.position().clone()).into()
         }
         Expression::ListComprehension(comprehension) => types::List::new(
-            comprehension.output_type().clone(),
+            comprehension.type_().clone(),
             comprehension.position().clone(),
         )
         .into(),
@@ -165,15 +168,19 @@ pub fn extract_from_expression(
             map.position().clone(),
         )
         .into(),
-        Expression::MapIterationComprehension(comprehension) => types::List::new(
-            comprehension.element_type().clone(),
+        Expression::MapComprehension(comprehension) => types::Map::new(
+            comprehension.key_type().clone(),
+            comprehension.value_type().clone(),
             comprehension.position().clone(),
         )
         .into(),
         Expression::None(none) => types::None::new(none.position().clone()).into(),
         Expression::Number(number) => types::Number::new(number.position().clone()).into(),
         Expression::Operation(operation) => match operation {
-            Operation::Arithmetic(_) => types::Number::new(expression.position().clone()).into(),
+            Operation::Arithmetic(operation) => operation
+                .type_()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(operation.position().clone()))?
+                .clone(),
             Operation::Boolean(_)
             | Operation::Equality(_)
             | Operation::Not(_)
@@ -184,18 +191,27 @@ pub fn extract_from_expression(
                 .clone(),
         },
         Expression::RecordConstruction(construction) => construction.type_().clone(),
-        Expression::RecordDeconstruction(deconstruction) => record_field_resolver::resolve(
-            deconstruction
+        Expression::RecordDeconstruction(deconstruction) => {
+            let fields = record_field_resolver::resolve(
+                deconstruction.type_().ok_or_else(|| {
+                    AnalysisError::TypeNotInferred(deconstruction.position().clone())
+                })?,
+                context.types(),
+                context.records(),
+            )?;
+
+            fields
+                .iter()
+                .find(|field| field.name() == deconstruction.field_name())
+                .ok_or_else(|| {
+                    AnalysisError::UnknownRecordField(
+                        deconstruction.position().clone(),
+                        name_suggester::suggest_record_fields(deconstruction.field_name(), &fields),
+                    )
+                })?
                 .type_()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(deconstruction.position().clone()))?,
-            context.types(),
-            context.records(),
-        )?
-        .iter()
-        .find(|field| field.name() == deconstruction.field_name())
-        .ok_or_else(|| AnalysisError::UnknownRecordField(deconstruction.position().clone()))?
-        .type_()
-        .clone(),
+                .clone()
+        }
         Expression::RecordUpdate(update) => update.type_().clone(),
         Expression::String(string) => types::ByteString::new(string.position().clone()).into(),
         Expression::Thunk(thunk) => types::Function::new(
@@ -208,10 +224,54 @@ pub fn extract_from_expression(
         )
         .into(),
         Expression::TypeCoercion(coercion) => coercion.to().clone(),
-        Expression::Variable(variable) => variables
-            .get(variable.name())
-            .cloned()
-            .ok_or_else(|| AnalysisError::VariableNotFound(variable.clone()))?,
+        Expression::Variable(variable) => {
+            variables.get(variable.name()).cloned().ok_or_else(|| {
+                AnalysisError::VariableNotFound(
+                    variable.clone(),
+                    name_suggester::suggest(variable.name(), variables.keys().map(String::as_str)),
+                )
+            })?
+        }
+    })
+}
+
+// Elements of lists are bound as thunks while keys and values of maps are not.
+pub fn extract_from_list_comprehension_branch(
+    branch: &ListComprehensionBranch,
+) -> Result<Vec<(String, Type)>, AnalysisError> {
+    let position = branch.position();
+    let types = branch
+        .iteratees()
+        .iter()
+        .map(|iteratee| {
+            iteratee
+                .type_()
+                .cloned()
+                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match (branch.names(), types.as_slice()) {
+        ([key_name, value_name], [Type::Map(map_type)]) => vec![
+            (key_name.clone(), map_type.key().clone()),
+            (value_name.clone(), map_type.value().clone()),
+        ],
+        (names, types) => names
+            .iter()
+            .zip(types)
+            .map(|(name, type_)| {
+                let list_type = type_
+                    .clone()
+                    .into_list()
+                    .ok_or_else(|| AnalysisError::ListExpected(type_.clone()))?;
+
+                Ok((
+                    name.clone(),
+                    types::Function::new(vec![], list_type.element().clone(), position.clone())
+                        .into(),
+                ))
+            })
+            .collect::<Result<_, AnalysisError>>()?,
     })
 }
 
@@ -1,4 +1,4 @@
-use crate::types::Type;
+use crate::types::{RecordField, Type};
 
 pub fn format(type_: &Type) -> String {
     match type_ {
@@ -18,14 +18,39 @@ pub fn format(type_: &Type) -> String {
         Type::List(list) => format!("[{}]", format(list.element())),
         Type::Map(map) => format!("{{{}: {}}}", format(map.key()), format(map.value())),
         Type::None(_) => "none".into(),
+        Type::Integer(integer) => integer.kind().name().into(),
         Type::Number(_) => "number".into(),
-        Type::Record(record) => record.name().into(),
-        Type::Reference(reference) => reference.name().into(),
+        Type::Record(record) => format_arguments(record.name(), record.arguments()),
+        Type::Reference(reference) => format_arguments(reference.name(), reference.arguments()),
         Type::String(_) => "string".into(),
         Type::Union(union) => format!("{} | {}", format(union.lhs()), format(union.rhs())),
+        Type::Variable(variable) => variable.name().into(),
     }
 }
 
+fn format_arguments(name: &str, arguments: &[Type]) -> String {
+    if arguments.is_empty() {
+        name.into()
+    } else {
+        format!(
+            "{}<{}>",
+            name,
+            arguments.iter().map(format).collect::<Vec<_>>().join(", ")
+        )
+    }
+}
+
+pub fn format_anonymous_record(fields: &[RecordField]) -> String {
+    format!(
+        "{{{}}}",
+        fields
+            .iter()
+            .map(|field| format!("{} {}", field.name(), format(field.type_())))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,6 +145,21 @@ mod tests {
         assert_eq!(format(&Record::new("foo", Position::fake()).into(),), "foo");
     }
 
+    #[test]
+    fn format_record_with_arguments() {
+        assert_eq!(
+            format(
+                &Record::new("foo", Position::fake())
+                    .set_arguments(vec![
+                        Number::new(Position::fake()).into(),
+                        None::new(Position::fake()).into()
+                    ])
+                    .into(),
+            ),
+            "foo<number, none>"
+        );
+    }
+
     #[test]
     fn format_reference() {
         assert_eq!(
@@ -142,4 +182,15 @@ mod tests {
             "{number: none}"
         );
     }
+
+    #[test]
+    fn format_anonymous_record_type() {
+        assert_eq!(
+            format_anonymous_record(&[
+                RecordField::new("x", Number::new(Position::fake())),
+                RecordField::new("y", None::new(Position::fake())),
+            ]),
+            "{x number, y none}"
+        );
+    }
 }
@@ -45,14 +45,31 @@ fn calculate_string(type_: &Type) -> String {
             calculate_string(map.value())
         ),
         Type::None(_) => "none".into(),
+        Type::Integer(integer) => integer.kind().name().into(),
         Type::Number(_) => "number".into(),
-        Type::Record(record) => record.name().into(),
+        Type::Record(record) => {
+            if record.arguments().is_empty() {
+                record.name().into()
+            } else {
+                format!(
+                    "{}<{}>",
+                    record.name(),
+                    record
+                        .arguments()
+                        .iter()
+                        .map(calculate_string)
+                        .collect::<Vec<_>>()
+                        .join(",")
+                )
+            }
+        }
         Type::String(_) => "string".into(),
         Type::Union(union) => format!(
             "({}|{})",
             calculate_string(union.lhs()),
             calculate_string(union.rhs()),
         ),
+        Type::Variable(variable) => format!("'{}", variable.name()),
         Type::Reference(_) => unreachable!(),
     }
 }
@@ -104,6 +121,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn calculate_record_type_id_with_arguments() {
+        assert_eq!(
+            calculate_canonical_string(
+                &types::Record::new("foo", Position::fake())
+                    .set_arguments(vec![
+                        types::Number::new(Position::fake()).into(),
+                        types::None::new(Position::fake()).into()
+                    ])
+                    .into(),
+                &Default::default(),
+            ),
+            Ok("foo<number,none>".into())
+        );
+    }
+
     #[test]
     fn canonicalize_types_before_id_calculation() {
         assert_eq!(
@@ -1,26 +1,36 @@
 use super::{
-    context::AnalysisContext, module_environment_creator, type_canonicalizer,
-    type_difference_calculator, type_extractor, union_type_creator, AnalysisError,
+    collect_results, context::AnalysisContext, module_environment_creator, record_field_resolver,
+    type_canonicalizer, type_difference_calculator, type_extractor, type_instantiator,
+    union_type_creator, AnalysisError,
 };
 use crate::{
     ir::*,
     types::{self, Type},
 };
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
+use position::Position;
 
-pub fn infer(context: &AnalysisContext, module: &Module) -> Result<Module, AnalysisError> {
+pub fn infer(
+    context: &AnalysisContext,
+    module: &Module,
+    methods: &FnvHashMap<(String, String), String>,
+) -> Result<Module, AnalysisError> {
     let variables = module_environment_creator::create(module);
+    let generic_functions = variables
+        .iter()
+        .filter(|(_, type_)| type_instantiator::is_generic(type_))
+        .map(|(name, _)| name.clone())
+        .collect();
 
     Ok(Module::new(
         module.type_definitions().to_vec(),
         module.type_aliases().to_vec(),
         module.foreign_declarations().to_vec(),
         module.function_declarations().to_vec(),
-        module
-            .function_definitions()
-            .iter()
-            .map(|definition| infer_definition(context, definition, &variables))
-            .collect::<Result<_, _>>()?,
+        module.implementations().to_vec(),
+        collect_results(module.function_definitions().iter().map(|definition| {
+            infer_definition(context, definition, &variables, &generic_functions, methods)
+        }))?,
         module.position().clone(),
     ))
 }
@@ -29,11 +39,19 @@ fn infer_definition(
     context: &AnalysisContext,
     definition: &FunctionDefinition,
     variables: &FnvHashMap<String, Type>,
+    generic_functions: &FnvHashSet<String>,
+    methods: &FnvHashMap<(String, String), String>,
 ) -> Result<FunctionDefinition, AnalysisError> {
     Ok(FunctionDefinition::new(
         definition.name(),
         definition.original_name(),
-        infer_lambda(context, definition.lambda(), variables)?,
+        infer_lambda(
+            context,
+            definition.lambda(),
+            variables,
+            generic_functions,
+            methods,
+        )?,
         definition.foreign_definition_configuration().cloned(),
         definition.is_public(),
         definition.position().clone(),
@@ -44,12 +62,16 @@ fn infer_lambda(
     context: &AnalysisContext,
     lambda: &Lambda,
     variables: &FnvHashMap<String, Type>,
+    generic_functions: &FnvHashSet<String>,
+    methods: &FnvHashMap<(String, String), String>,
 ) -> Result<Lambda, AnalysisError> {
     Ok(Lambda::new(
         lambda.arguments().to_vec(),
         lambda.result_type().clone(),
         infer_expression(
             context,
+            generic_functions,
+            methods,
             lambda.body(),
             &variables
                 .clone()
@@ -68,30 +90,40 @@ fn infer_lambda(
 
 fn infer_expression(
     context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
+    methods: &FnvHashMap<(String, String), String>,
     expression: &Expression,
     variables: &FnvHashMap<String, Type>,
 ) -> Result<Expression, AnalysisError> {
-    let infer_expression =
-        |expression, variables: &_| infer_expression(context, expression, variables);
+    let infer_expression = |expression: &_, variables: &_| {
+        infer_expression(context, generic_functions, methods, expression, variables)
+    };
 
     Ok(match expression {
         Expression::Call(call) => {
             if let Expression::BuiltInFunction(function) = call.function() {
-                infer_built_in_call(context, call, function, variables)?.into()
+                infer_built_in_call(
+                    context,
+                    generic_functions,
+                    methods,
+                    call,
+                    function,
+                    variables,
+                )?
+                .into()
             } else {
-                let function = infer_expression(call.function(), variables)?;
-
-                Call::new(
-                    Some(type_extractor::extract_from_expression(
-                        context, &function, variables,
-                    )?),
-                    function.clone(),
+                infer_call(
+                    context,
+                    generic_functions,
+                    methods,
+                    call.function(),
                     call.arguments()
                         .iter()
                         .map(|argument| infer_expression(argument, variables))
                         .collect::<Result<_, _>>()?,
-                    call.position().clone(),
-                )
+                    call.position(),
+                    variables,
+                )?
                 .into()
             }
         }
@@ -235,8 +267,18 @@ fn infer_expression(
             )
             .into()
         }
-        Expression::Lambda(lambda) => infer_lambda(context, lambda, variables)?.into(),
+        Expression::Lambda(lambda) => {
+            infer_lambda(context, lambda, variables, generic_functions, methods)?.into()
+        }
         Expression::Let(let_) => {
+            if let Expression::Variable(variable) = let_.bound_expression() {
+                if generic_functions.contains(variable.name()) {
+                    return Err(AnalysisError::GenericFunctionNotInstantiated(
+                        variable.position().clone(),
+                    ));
+                }
+            }
+
             let bound_expression = infer_expression(let_.bound_expression(), variables)?;
             let bound_type =
                 type_extractor::extract_from_expression(context, &bound_expression, variables)?;
@@ -276,32 +318,18 @@ fn infer_expression(
         )
         .into(),
         Expression::ListComprehension(comprehension) => {
-            let list = infer_expression(comprehension.list(), variables)?;
-            let type_ = type_extractor::extract_from_expression(context, &list, variables)?;
-            let list_type = type_canonicalizer::canonicalize_list(&type_, context.types())?
-                .ok_or(AnalysisError::ListExpected(type_))?;
+            let (branches, variables) = infer_list_comprehension_branches(
+                context,
+                generic_functions,
+                methods,
+                comprehension.branches(),
+                variables,
+            )?;
 
             ListComprehension::new(
-                Some(list_type.element().clone()),
-                comprehension.output_type().clone(),
-                infer_expression(
-                    comprehension.element(),
-                    &variables
-                        .clone()
-                        .into_iter()
-                        .chain([(
-                            comprehension.element_name().into(),
-                            types::Function::new(
-                                vec![],
-                                list_type.element().clone(),
-                                comprehension.position().clone(),
-                            )
-                            .into(),
-                        )])
-                        .collect(),
-                )?,
-                comprehension.element_name(),
-                list,
+                comprehension.type_().clone(),
+                infer_expression(comprehension.element(), &variables)?,
+                branches,
                 comprehension.position().clone(),
             )
             .into()
@@ -328,42 +356,40 @@ fn infer_expression(
             map.position().clone(),
         )
         .into(),
-        Expression::MapIterationComprehension(comprehension) => {
-            let map = infer_expression(comprehension.map(), variables)?;
-            let type_ = type_extractor::extract_from_expression(context, &map, variables)?;
-            let map_type = type_canonicalizer::canonicalize_map(&type_, context.types())?
-                .ok_or(AnalysisError::MapExpected(type_))?;
+        Expression::MapComprehension(comprehension) => {
+            let (branches, variables) = infer_list_comprehension_branches(
+                context,
+                generic_functions,
+                methods,
+                comprehension.branches(),
+                variables,
+            )?;
 
-            MapIterationComprehension::new(
-                Some(map_type.key().clone()),
-                Some(map_type.value().clone()),
-                comprehension.element_type().clone(),
-                infer_expression(
-                    comprehension.element(),
-                    &variables
-                        .clone()
-                        .into_iter()
-                        .chain([
-                            (comprehension.key_name().into(), map_type.key().clone()),
-                            (comprehension.value_name().into(), map_type.value().clone()),
-                        ])
-                        .collect(),
-                )?,
-                comprehension.key_name(),
-                comprehension.value_name(),
-                map,
+            MapComprehension::new(
+                comprehension.key_type().clone(),
+                comprehension.value_type().clone(),
+                infer_expression(comprehension.key(), &variables)?,
+                infer_expression(comprehension.value(), &variables)?,
+                branches,
                 comprehension.position().clone(),
             )
             .into()
         }
         Expression::Operation(operation) => match operation {
-            Operation::Arithmetic(operation) => ArithmeticOperation::new(
-                operation.operator(),
-                infer_expression(operation.lhs(), variables)?,
-                infer_expression(operation.rhs(), variables)?,
-                operation.position().clone(),
-            )
-            .into(),
+            Operation::Arithmetic(operation) => {
+                let lhs = infer_expression(operation.lhs(), variables)?;
+
+                ArithmeticOperation::new(
+                    Some(type_extractor::extract_from_expression(
+                        context, &lhs, variables,
+                    )?),
+                    operation.operator(),
+                    lhs,
+                    infer_expression(operation.rhs(), variables)?,
+                    operation.position().clone(),
+                )
+                .into()
+            }
             Operation::Boolean(operation) => BooleanOperation::new(
                 operation.operator(),
                 infer_expression(operation.lhs(), variables)?,
@@ -396,13 +422,20 @@ fn infer_expression(
                 operation.position().clone(),
             )
             .into(),
-            Operation::Order(operation) => OrderOperation::new(
-                operation.operator(),
-                infer_expression(operation.lhs(), variables)?,
-                infer_expression(operation.rhs(), variables)?,
-                operation.position().clone(),
-            )
-            .into(),
+            Operation::Order(operation) => {
+                let lhs = infer_expression(operation.lhs(), variables)?;
+
+                OrderOperation::new(
+                    Some(type_extractor::extract_from_expression(
+                        context, &lhs, variables,
+                    )?),
+                    operation.operator(),
+                    lhs,
+                    infer_expression(operation.rhs(), variables)?,
+                    operation.position().clone(),
+                )
+                .into()
+            }
             Operation::Try(operation) => {
                 let position = operation.position();
                 let expression = infer_expression(operation.expression(), variables)?;
@@ -431,9 +464,8 @@ fn infer_expression(
                 .into()
             }
         },
-        Expression::RecordConstruction(construction) => RecordConstruction::new(
-            construction.type_().clone(),
-            construction
+        Expression::RecordConstruction(construction) => {
+            let fields = construction
                 .fields()
                 .iter()
                 .map(|field| {
@@ -443,10 +475,20 @@ fn infer_expression(
                         field.position().clone(),
                     ))
                 })
-                .collect::<Result<_, AnalysisError>>()?,
-            construction.position().clone(),
-        )
-        .into(),
+                .collect::<Result<Vec<_>, AnalysisError>>()?;
+            let type_ =
+                infer_record_construction_type(context, construction.type_(), &fields, variables)?;
+            let fields = fields
+                .into_iter()
+                .chain(complete_default_record_fields(
+                    context,
+                    &type_,
+                    construction,
+                )?)
+                .collect();
+
+            RecordConstruction::new(type_, fields, construction.position().clone()).into()
+        }
         Expression::RecordDeconstruction(deconstruction) => {
             let record = infer_expression(deconstruction.record(), variables)?;
 
@@ -460,33 +502,41 @@ fn infer_expression(
             )
             .into()
         }
-        Expression::RecordUpdate(update) => RecordUpdate::new(
-            update.type_().clone(),
-            infer_expression(update.record(), variables)?,
-            update
-                .fields()
-                .iter()
-                .map(|field| {
-                    Ok(RecordField::new(
-                        field.name(),
-                        infer_expression(field.expression(), variables)?,
-                        field.position().clone(),
-                    ))
-                })
-                .collect::<Result<_, AnalysisError>>()?,
-            update.position().clone(),
-        )
-        .into(),
-        Expression::Thunk(thunk) => Thunk::new(
-            Some(type_extractor::extract_from_expression(
-                context,
-                thunk.expression(),
-                variables,
-            )?),
-            infer_expression(thunk.expression(), variables)?,
-            thunk.position().clone(),
-        )
-        .into(),
+        Expression::RecordUpdate(update) => {
+            let record = infer_expression(update.record(), variables)?;
+
+            RecordUpdate::new(
+                infer_record_update_type(context, update.type_(), &record, variables)?,
+                record,
+                update
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        Ok(RecordField::new(
+                            field.name(),
+                            infer_expression(field.expression(), variables)?,
+                            field.position().clone(),
+                        ))
+                    })
+                    .collect::<Result<_, AnalysisError>>()?,
+                update.position().clone(),
+            )
+            .into()
+        }
+        Expression::Thunk(thunk) => {
+            let expression = infer_expression(thunk.expression(), variables)?;
+
+            Thunk::new(
+                Some(type_extractor::extract_from_expression(
+                    context,
+                    &expression,
+                    variables,
+                )?),
+                expression,
+                thunk.position().clone(),
+            )
+            .into()
+        }
         Expression::TypeCoercion(coercion) => TypeCoercion::new(
             coercion.from().clone(),
             coercion.to().clone(),
@@ -496,6 +546,7 @@ fn infer_expression(
         .into(),
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
+        | Expression::Integer(_)
         | Expression::None(_)
         | Expression::Number(_)
         | Expression::String(_)
@@ -503,8 +554,406 @@ fn infer_expression(
     })
 }
 
+fn infer_call(
+    context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
+    methods: &FnvHashMap<(String, String), String>,
+    function: &Expression,
+    arguments: Vec<Expression>,
+    position: &Position,
+    variables: &FnvHashMap<String, Type>,
+) -> Result<Call, AnalysisError> {
+    Ok(
+        if let Some(function_type) = match function {
+            Expression::Variable(variable) if generic_functions.contains(variable.name()) => {
+                variables.get(variable.name())
+            }
+            _ => None,
+        } {
+            let function_type =
+                type_canonicalizer::canonicalize_function(function_type, context.types())?
+                    .ok_or_else(|| AnalysisError::FunctionExpected(function_type.clone()))?;
+
+            Call::new(
+                Some(
+                    instantiate_function(
+                        context,
+                        generic_functions,
+                        &function_type,
+                        &arguments,
+                        variables,
+                    )?
+                    .into(),
+                ),
+                function.clone(),
+                arguments,
+                position.clone(),
+            )
+        } else {
+            let function =
+                infer_expression(context, generic_functions, methods, function, variables)?;
+
+            if let Expression::RecordDeconstruction(deconstruction) = &function {
+                if let Some(name) = resolve_method(context, methods, deconstruction)? {
+                    return infer_call(
+                        context,
+                        generic_functions,
+                        methods,
+                        &Variable::new(name, deconstruction.position().clone()).into(),
+                        [deconstruction.record().clone()]
+                            .into_iter()
+                            .chain(arguments)
+                            .collect(),
+                        position,
+                        variables,
+                    );
+                }
+            }
+
+            Call::new(
+                Some(type_extractor::extract_from_expression(
+                    context, &function, variables,
+                )?),
+                function,
+                arguments,
+                position.clone(),
+            )
+        },
+    )
+}
+
+// Generic functions passed as arguments are instantiated with parameter types
+// bound by the other arguments first.
+fn instantiate_function(
+    context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
+    function_type: &types::Function,
+    arguments: &[Expression],
+    variables: &FnvHashMap<String, Type>,
+) -> Result<types::Function, AnalysisError> {
+    let resolve_generic_function = |argument: &Expression| match argument {
+        Expression::Variable(variable) if generic_functions.contains(variable.name()) => {
+            variables.get(variable.name())
+        }
+        _ => None,
+    };
+    let argument_types = arguments
+        .iter()
+        .map(|argument| {
+            Ok(if resolve_generic_function(argument).is_some() {
+                types::Any::new(argument.position().clone()).into()
+            } else {
+                type_extractor::extract_from_expression(context, argument, variables)?
+            })
+        })
+        .collect::<Result<Vec<_>, AnalysisError>>()?;
+
+    let instance_type =
+        type_instantiator::instantiate(function_type, &argument_types, context.types())?;
+
+    if arguments
+        .iter()
+        .all(|argument| resolve_generic_function(argument).is_none())
+    {
+        return Ok(instance_type);
+    }
+
+    type_instantiator::instantiate(
+        function_type,
+        &arguments
+            .iter()
+            .zip(argument_types)
+            .zip(instance_type.arguments())
+            .map(|((argument, type_), parameter_type)| {
+                Ok(
+                    match (
+                        resolve_generic_function(argument),
+                        type_canonicalizer::canonicalize_function(parameter_type, context.types())?,
+                    ) {
+                        (Some(generic_type), Some(parameter_type)) => {
+                            type_instantiator::instantiate(
+                                &type_canonicalizer::canonicalize_function(
+                                    generic_type,
+                                    context.types(),
+                                )?
+                                .ok_or_else(|| {
+                                    AnalysisError::FunctionExpected(generic_type.clone())
+                                })?,
+                                parameter_type.arguments(),
+                                context.types(),
+                            )?
+                            .into()
+                        }
+                        _ => type_,
+                    },
+                )
+            })
+            .collect::<Result<Vec<_>, AnalysisError>>()?,
+        context.types(),
+    )
+}
+
+// Calls of record fields not found in record types are resolved into calls of
+// methods with records as their first arguments.
+fn resolve_method<'a>(
+    context: &AnalysisContext,
+    methods: &'a FnvHashMap<(String, String), String>,
+    deconstruction: &RecordDeconstruction,
+) -> Result<Option<&'a str>, AnalysisError> {
+    let type_ = deconstruction
+        .type_()
+        .ok_or_else(|| AnalysisError::TypeNotInferred(deconstruction.position().clone()))?;
+    let record_type = match type_canonicalizer::canonicalize_record(type_, context.types())? {
+        Some(record_type) => record_type,
+        None => return Ok(None),
+    };
+    let name = match methods.get(&(
+        record_type.name().into(),
+        deconstruction.field_name().into(),
+    )) {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+
+    if record_field_resolver::resolve_record(&record_type, context.types(), context.records())?
+        .iter()
+        .any(|field| field.name() == deconstruction.field_name())
+    {
+        return Err(AnalysisError::AmbiguousMethodCall(
+            deconstruction.position().clone(),
+        ));
+    }
+
+    Ok(Some(name))
+}
+
+// Type arguments of generic records are inferred from field values when they
+// are omitted.
+fn infer_record_construction_type(
+    context: &AnalysisContext,
+    type_: &Type,
+    fields: &[RecordField],
+    variables: &FnvHashMap<String, Type>,
+) -> Result<Type, AnalysisError> {
+    let record = match resolve_generic_record(context, type_)? {
+        Some(record) => record,
+        None => return Ok(type_.clone()),
+    };
+    let field_types =
+        record_field_resolver::resolve_record(&record, context.types(), context.records())?;
+    let mut parameters = vec![];
+    let mut arguments = vec![];
+
+    for field in fields {
+        if let Some(field_type) = field_types
+            .iter()
+            .find(|field_type| field_type.name() == field.name())
+        {
+            parameters.push(field_type.type_().clone());
+            arguments.push(type_extractor::extract_from_expression(
+                context,
+                field.expression(),
+                variables,
+            )?);
+        }
+    }
+
+    Ok(type_instantiator::instantiate(
+        &types::Function::new(parameters, record, type_.position().clone()),
+        &arguments,
+        context.types(),
+    )?
+    .result()
+    .clone())
+}
+
+fn infer_record_update_type(
+    context: &AnalysisContext,
+    type_: &Type,
+    record: &Expression,
+    variables: &FnvHashMap<String, Type>,
+) -> Result<Type, AnalysisError> {
+    let generic_record = match resolve_generic_record(context, type_)? {
+        Some(record) => record,
+        None => return Ok(type_.clone()),
+    };
+    let record_type = type_extractor::extract_from_expression(context, record, variables)?;
+
+    Ok(
+        match type_canonicalizer::canonicalize_record(&record_type, context.types())? {
+            Some(record) if record.name() == generic_record.name() => {
+                record.set_position(type_.position().clone()).into()
+            }
+            _ => type_.clone(),
+        },
+    )
+}
+
+// Resolve a generic record type whose arguments are omitted into one whose
+// arguments are its own type parameters.
+fn resolve_generic_record(
+    context: &AnalysisContext,
+    type_: &Type,
+) -> Result<Option<types::Record>, AnalysisError> {
+    if let Type::Reference(reference) = type_ {
+        if !reference.arguments().is_empty() {
+            return Ok(None);
+        }
+    }
+
+    Ok(
+        match type_canonicalizer::canonicalize_record(type_, context.types())? {
+            Some(record)
+                if !record.arguments().is_empty()
+                    && record.arguments().iter().all(Type::is_variable) =>
+            {
+                Some(record)
+            }
+            _ => None,
+        },
+    )
+}
+
+// Omitted fields with default values are filled with calls to their default
+// functions. The calls are typed with record types as default functions of
+// generic records cannot be instantiated by their arguments.
+fn complete_default_record_fields(
+    context: &AnalysisContext,
+    type_: &Type,
+    construction: &RecordConstruction,
+) -> Result<Vec<RecordField>, AnalysisError> {
+    let position = construction.position();
+    let record_type = type_canonicalizer::canonicalize_record(type_, context.types())?
+        .ok_or_else(|| AnalysisError::RecordExpected(type_.clone()))?;
+    let field_names = construction
+        .fields()
+        .iter()
+        .map(|field| field.name())
+        .collect::<FnvHashSet<_>>();
+
+    Ok(
+        record_field_resolver::resolve_record(&record_type, context.types(), context.records())?
+            .iter()
+            .filter(|field_type| {
+                field_type.has_default() && !field_names.contains(field_type.name())
+            })
+            .map(|field_type| {
+                RecordField::new(
+                    field_type.name(),
+                    Call::new(
+                        Some(
+                            types::Function::new(
+                                vec![],
+                                field_type.type_().clone(),
+                                position.clone(),
+                            )
+                            .into(),
+                        ),
+                        Variable::new(
+                            record_field_resolver::resolve_default_function_name(
+                                record_type.name(),
+                                field_type.name(),
+                            ),
+                            position.clone(),
+                        ),
+                        vec![],
+                        position.clone(),
+                    ),
+                    position.clone(),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn infer_list_comprehension_branches(
+    context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
+    methods: &FnvHashMap<(String, String), String>,
+    branches: &[ListComprehensionBranch],
+    variables: &FnvHashMap<String, Type>,
+) -> Result<(Vec<ListComprehensionBranch>, FnvHashMap<String, Type>), AnalysisError> {
+    let infer_expression = |expression, variables: &_| {
+        infer_expression(context, generic_functions, methods, expression, variables)
+    };
+    let mut variables = variables.clone();
+    let mut inferred_branches = vec![];
+
+    for branch in branches {
+        let iteratees = infer_list_comprehension_iteratees(
+            context,
+            branch,
+            branch
+                .iteratees()
+                .iter()
+                .map(|iteratee| infer_expression(iteratee.expression(), &variables))
+                .collect::<Result<_, _>>()?,
+            &variables,
+        )?;
+
+        variables.extend(type_extractor::extract_from_list_comprehension_branch(
+            &ListComprehensionBranch::new(
+                branch.names().to_vec(),
+                iteratees.clone(),
+                None,
+                branch.position().clone(),
+            ),
+        )?);
+
+        inferred_branches.push(ListComprehensionBranch::new(
+            branch.names().to_vec(),
+            iteratees,
+            branch
+                .condition()
+                .map(|condition| infer_expression(condition, &variables))
+                .transpose()?,
+            branch.position().clone(),
+        ));
+    }
+
+    Ok((inferred_branches, variables))
+}
+
+fn infer_list_comprehension_iteratees(
+    context: &AnalysisContext,
+    branch: &ListComprehensionBranch,
+    expressions: Vec<Expression>,
+    variables: &FnvHashMap<String, Type>,
+) -> Result<Vec<ListComprehensionIteratee>, AnalysisError> {
+    let types = expressions
+        .iter()
+        .map(|expression| type_extractor::extract_from_expression(context, expression, variables))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let types = match (branch.names(), types.as_slice()) {
+        ([_, _], [type_]) => vec![
+            type_canonicalizer::canonicalize_map(type_, context.types())?
+                .ok_or_else(|| AnalysisError::MapExpected(type_.clone()))?
+                .into(),
+        ],
+        _ => types
+            .iter()
+            .map(|type_| {
+                Ok(
+                    type_canonicalizer::canonicalize_list(type_, context.types())?
+                        .ok_or_else(|| AnalysisError::ListExpected(type_.clone()))?
+                        .into(),
+                )
+            })
+            .collect::<Result<_, AnalysisError>>()?,
+    };
+
+    Ok(expressions
+        .into_iter()
+        .zip(types)
+        .map(|(expression, type_)| ListComprehensionIteratee::new(Some(type_), expression))
+        .collect())
+}
+
 fn infer_built_in_call(
     context: &AnalysisContext,
+    generic_functions: &FnvHashSet<String>,
+    methods: &FnvHashMap<(String, String), String>,
     call: &Call,
     function: &BuiltInFunction,
     variables: &FnvHashMap<String, Type>,
@@ -513,7 +962,7 @@ fn infer_built_in_call(
     let arguments = call
         .arguments()
         .iter()
-        .map(|argument| infer_expression(context, argument, variables))
+        .map(|argument| infer_expression(context, generic_functions, methods, argument, variables))
         .collect::<Result<Vec<_>, _>>()?;
     let argument_types = arguments
         .iter()
@@ -523,6 +972,16 @@ fn infer_built_in_call(
     Ok(Call::new(
         Some(
             match function.name() {
+                BuiltInFunctionName::Byte => types::Function::new(
+                    argument_types,
+                    types::Integer::new(types::IntegerKind::Byte, position.clone()),
+                    position.clone(),
+                ),
+                BuiltInFunctionName::ConcatenateStrings => types::Function::new(
+                    vec![types::ByteString::new(position.clone()).into(); arguments.len()],
+                    types::ByteString::new(position.clone()),
+                    position.clone(),
+                ),
                 BuiltInFunctionName::Error => types::Function::new(
                     vec![types::Any::new(position.clone()).into()],
                     types::Error::new(position.clone()),
@@ -533,6 +992,16 @@ fn infer_built_in_call(
                     types::None::new(position.clone()),
                     position.clone(),
                 ),
+                BuiltInFunctionName::I64 => types::Function::new(
+                    argument_types,
+                    types::Integer::new(types::IntegerKind::I64, position.clone()),
+                    position.clone(),
+                ),
+                BuiltInFunctionName::Number => types::Function::new(
+                    argument_types,
+                    types::Number::new(position.clone()),
+                    position.clone(),
+                ),
                 BuiltInFunctionName::Race => {
                     let argument_type = argument_types
                         .first()
@@ -565,6 +1034,11 @@ fn infer_built_in_call(
 
                     types::Function::new(argument_types, result_type, position.clone())
                 }
+                BuiltInFunctionName::U64 => types::Function::new(
+                    argument_types,
+                    types::Integer::new(types::IntegerKind::U64, position.clone()),
+                    position.clone(),
+                ),
             }
             .into(),
         ),
@@ -578,19 +1052,26 @@ fn infer_built_in_call(
 mod tests {
     use super::*;
     use crate::{
-        analysis::type_collector,
+        analysis::{method_collector, type_collector},
         test::{FunctionDefinitionFake, ModuleFake},
     };
     use position::{test::PositionFake, Position};
     use pretty_assertions::assert_eq;
 
     fn infer_module(module: &Module) -> Result<Module, AnalysisError> {
+        let context = AnalysisContext::new(
+            type_collector::collect(module),
+            type_collector::collect_records(module),
+        );
+
         infer(
-            &AnalysisContext::new(
-                type_collector::collect(module),
-                type_collector::collect_records(module),
-            ),
+            &context,
             module,
+            &method_collector::collect(
+                &context,
+                module,
+                &module_environment_creator::create(module),
+            )?,
         )
     }
 
@@ -696,7 +1177,35 @@ mod tests {
     }
 
     #[test]
-    fn infer_let() {
+    fn infer_arithmetic_operation() {
+        let integer_type = types::Integer::new(types::IntegerKind::Byte, Position::fake());
+        let create_module = |type_: Option<Type>| {
+            Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "x",
+                Lambda::new(
+                    vec![Argument::new("x", integer_type.clone())],
+                    integer_type.clone(),
+                    ArithmeticOperation::new(
+                        type_,
+                        ArithmeticOperator::Add,
+                        Variable::new("x", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                ),
+                false,
+            )])
+        };
+
+        assert_eq!(
+            infer_module(&create_module(None)),
+            Ok(create_module(Some(integer_type.clone().into())))
+        );
+    }
+
+    #[test]
+    fn infer_let() {
         assert_eq!(
             infer_module(&Module::empty().set_function_definitions(vec![
                 FunctionDefinition::fake(
@@ -740,6 +1249,7 @@ mod tests {
     #[test]
     fn infer_let_with_call() {
         let declaration = FunctionDeclaration::new(
+            "f",
             "f",
             types::Function::new(vec![], types::None::new(Position::fake()), Position::fake()),
             Position::fake(),
@@ -797,10 +1307,180 @@ mod tests {
         );
     }
 
+    #[test]
+    fn infer_generic_function_call() {
+        let declaration = FunctionDeclaration::new(
+            "f",
+            "f",
+            types::Function::new(
+                vec![types::Variable::new("a", Position::fake()).into()],
+                types::Variable::new("a", Position::fake()),
+                Position::fake(),
+            ),
+            Position::fake(),
+        );
+
+        assert_eq!(
+            infer_module(
+                &Module::empty()
+                    .set_function_declarations(vec![declaration.clone()])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            types::Number::new(Position::fake()),
+                            Call::new(
+                                None,
+                                Variable::new("f", Position::fake()),
+                                vec![Number::new(42.0, Position::fake()).into()],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )],)
+            ),
+            Ok(Module::empty()
+                .set_function_declarations(vec![declaration])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        types::Number::new(Position::fake()),
+                        Call::new(
+                            Some(
+                                types::Function::new(
+                                    vec![types::Number::new(Position::fake()).into()],
+                                    types::Number::new(Position::fake()),
+                                    Position::fake()
+                                )
+                                .into()
+                            ),
+                            Variable::new("f", Position::fake()),
+                            vec![Number::new(42.0, Position::fake()).into()],
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )]))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_generic_function_bound_to_variable() {
+        assert_eq!(
+            infer_module(
+                &Module::empty()
+                    .set_function_declarations(vec![FunctionDeclaration::new(
+                        "f",
+                        "f",
+                        types::Function::new(
+                            vec![types::Variable::new("a", Position::fake()).into()],
+                            types::Variable::new("a", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            types::None::new(Position::fake()),
+                            Let::new(
+                                Some("g".into()),
+                                None,
+                                Variable::new("f", Position::fake()),
+                                None::new(Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )],)
+            ),
+            Err(AnalysisError::GenericFunctionNotInstantiated(
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn infer_generic_function_call_with_generic_function_argument() {
+        let variable_type = || types::Variable::new("a", Position::fake());
+        let identity_type = types::Function::new(
+            vec![variable_type().into()],
+            variable_type(),
+            Position::fake(),
+        );
+        let apply_type = types::Function::new(
+            vec![identity_type.clone().into(), variable_type().into()],
+            variable_type(),
+            Position::fake(),
+        );
+        let number_type = types::Number::new(Position::fake());
+        let instance_type = types::Function::new(
+            vec![number_type.clone().into()],
+            number_type.clone(),
+            Position::fake(),
+        );
+        let create_module = |type_| {
+            Module::empty()
+                .set_function_declarations(vec![
+                    FunctionDeclaration::new("f", "f", identity_type.clone(), Position::fake()),
+                    FunctionDeclaration::new("g", "g", apply_type.clone(), Position::fake()),
+                ])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        number_type.clone(),
+                        Call::new(
+                            type_,
+                            Variable::new("g", Position::fake()),
+                            vec![
+                                Variable::new("f", Position::fake()).into(),
+                                Number::new(42.0, Position::fake()).into(),
+                            ],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+        };
+
+        assert_eq!(
+            infer_module(&create_module(None)),
+            Ok(create_module(Some(
+                types::Function::new(
+                    vec![instance_type.into(), number_type.clone().into()],
+                    number_type.clone(),
+                    Position::fake(),
+                )
+                .into()
+            )))
+        );
+    }
+
     #[test]
     fn infer_list_comprehension() {
         let element_type = types::None::new(Position::fake());
         let list_type = types::List::new(element_type.clone(), Position::fake());
+        let element = |type_: Option<Type>| {
+            Let::new(
+                Some("y".into()),
+                type_.clone(),
+                Call::new(
+                    type_.map(|type_| types::Function::new(vec![], type_, Position::fake()).into()),
+                    Variable::new("x", Position::fake()),
+                    vec![],
+                    Position::fake(),
+                ),
+                Variable::new("y", Position::fake()),
+                Position::fake(),
+            )
+        };
 
         assert_eq!(
             infer_module(&Module::empty().set_function_definitions(vec![
@@ -810,22 +1490,84 @@ mod tests {
                         vec![],
                         list_type.clone(),
                         ListComprehension::new(
-                            None,
                             element_type.clone(),
-                            Let::new(
-                                Some("y".into()),
+                            element(None),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    None,
+                                    List::new(element_type.clone(), vec![], Position::fake()),
+                                )],
                                 None,
-                                Call::new(
+                                Position::fake(),
+                            )],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Ok(
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        list_type.clone(),
+                        ListComprehension::new(
+                            element_type.clone(),
+                            element(Some(element_type.clone().into())),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(list_type.into()),
+                                    List::new(element_type, vec![], Position::fake()),
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )],)
+            )
+        );
+    }
+
+    #[test]
+    fn infer_list_comprehension_with_condition() {
+        let element_type = types::Boolean::new(Position::fake());
+        let list_type = types::List::new(element_type.clone(), Position::fake());
+        let condition = |type_: Option<Type>| {
+            Call::new(
+                type_.map(|type_| types::Function::new(vec![], type_, Position::fake()).into()),
+                Variable::new("x", Position::fake()),
+                vec![],
+                Position::fake(),
+            )
+        };
+
+        assert_eq!(
+            infer_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        list_type.clone(),
+                        ListComprehension::new(
+                            element_type.clone(),
+                            Boolean::new(true, Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![ListComprehensionIteratee::new(
                                     None,
-                                    Variable::new("x", Position::fake()),
-                                    vec![],
-                                    Position::fake()
-                                ),
-                                Variable::new("y", Position::fake()),
+                                    List::new(element_type.clone(), vec![], Position::fake()),
+                                )],
+                                Some(condition(None).into()),
                                 Position::fake(),
-                            ),
-                            "x",
-                            List::new(element_type.clone(), vec![], Position::fake()),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -838,31 +1580,19 @@ mod tests {
                     "f",
                     Lambda::new(
                         vec![],
-                        list_type,
+                        list_type.clone(),
                         ListComprehension::new(
-                            Some(element_type.clone().into()),
                             element_type.clone(),
-                            Let::new(
-                                Some("y".into()),
-                                Some(element_type.clone().into()),
-                                Call::new(
-                                    Some(
-                                        types::Function::new(
-                                            vec![],
-                                            element_type.clone(),
-                                            Position::fake()
-                                        )
-                                        .into()
-                                    ),
-                                    Variable::new("x", Position::fake()),
-                                    vec![],
-                                    Position::fake()
-                                ),
-                                Variable::new("y", Position::fake()),
+                            Boolean::new(true, Position::fake()),
+                            vec![ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(list_type.into()),
+                                    List::new(element_type.clone(), vec![], Position::fake()),
+                                )],
+                                Some(condition(Some(element_type.into())).into()),
                                 Position::fake(),
-                            ),
-                            "x",
-                            List::new(element_type, vec![], Position::fake()),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -893,9 +1623,7 @@ mod tests {
                     Lambda::new(
                         vec![],
                         list_type.clone(),
-                        MapIterationComprehension::new(
-                            None,
-                            None,
+                        ListComprehension::new(
                             element_type.clone(),
                             Let::new(
                                 Some("x".into()),
@@ -904,9 +1632,12 @@ mod tests {
                                 Variable::new("x", Position::fake()),
                                 Position::fake(),
                             ),
-                            "k",
-                            "v",
-                            empty_map.clone(),
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![ListComprehensionIteratee::new(None, empty_map.clone())],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -920,20 +1651,27 @@ mod tests {
                     Lambda::new(
                         vec![],
                         list_type,
-                        MapIterationComprehension::new(
-                            Some(key_type.clone().into()),
-                            Some(value_type.into()),
+                        ListComprehension::new(
                             element_type,
                             Let::new(
                                 Some("x".into()),
-                                Some(key_type.into()),
+                                Some(key_type.clone().into()),
                                 Variable::new("k", Position::fake()),
                                 Variable::new("x", Position::fake()),
                                 Position::fake(),
                             ),
-                            "k",
-                            "v",
-                            empty_map,
+                            vec![ListComprehensionBranch::new(
+                                vec!["k".into(), "v".into()],
+                                vec![ListComprehensionIteratee::new(
+                                    Some(
+                                        types::Map::new(key_type, value_type, Position::fake())
+                                            .into()
+                                    ),
+                                    empty_map,
+                                )],
+                                None,
+                                Position::fake(),
+                            )],
                             Position::fake(),
                         ),
                         Position::fake(),
@@ -944,6 +1682,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn infer_record_construction_with_default_field() {
+        let type_definition = TypeDefinition::new(
+            "r",
+            "",
+            vec![
+                types::RecordField::new("x", types::None::new(Position::fake())).set_default(true),
+            ],
+            false,
+            false,
+            false,
+            Position::fake(),
+        );
+        let default_type =
+            types::Function::new(vec![], types::None::new(Position::fake()), Position::fake());
+        let declaration = FunctionDeclaration::new(
+            "r:default:x",
+            "r:default:x",
+            default_type.clone(),
+            Position::fake(),
+        );
+
+        assert_eq!(
+            infer_module(
+                &Module::empty()
+                    .set_type_definitions(vec![type_definition.clone()])
+                    .set_function_declarations(vec![declaration.clone()])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "x",
+                        Lambda::new(
+                            vec![],
+                            types::Record::new("r", Position::fake()),
+                            RecordConstruction::new(
+                                types::Record::new("r", Position::fake()),
+                                vec![],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+            ),
+            Ok(Module::empty()
+                .set_type_definitions(vec![type_definition])
+                .set_function_declarations(vec![declaration])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        types::Record::new("r", Position::fake()),
+                        RecordConstruction::new(
+                            types::Record::new("r", Position::fake()),
+                            vec![RecordField::new(
+                                "x",
+                                Call::new(
+                                    Some(default_type.into()),
+                                    Variable::new("r:default:x", Position::fake()),
+                                    vec![],
+                                    Position::fake()
+                                ),
+                                Position::fake()
+                            )],
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )]))
+        );
+    }
+
     #[test]
     fn infer_record_deconstruction() {
         let type_definition = TypeDefinition::new(
@@ -1005,6 +1814,119 @@ mod tests {
         );
     }
 
+    #[test]
+    fn infer_method_call() {
+        let record_type = types::Record::new("r", Position::fake());
+        let function_type = types::Function::new(
+            vec![record_type.clone().into()],
+            types::None::new(Position::fake()),
+            Position::fake(),
+        );
+        let type_definition =
+            TypeDefinition::new("r", "r", vec![], false, false, false, Position::fake());
+        let function_declaration =
+            FunctionDeclaration::new("f", "f", function_type.clone(), Position::fake());
+        let create_module = |body: Expression| {
+            Module::empty()
+                .set_type_definitions(vec![type_definition.clone()])
+                .set_function_declarations(vec![function_declaration.clone()])
+                .set_function_definitions(vec![FunctionDefinition::fake(
+                    "g",
+                    Lambda::new(
+                        vec![Argument::new("x", record_type.clone())],
+                        types::None::new(Position::fake()),
+                        body,
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+        };
+
+        assert_eq!(
+            infer_module(&create_module(
+                Call::new(
+                    None,
+                    RecordDeconstruction::new(
+                        None,
+                        Variable::new("x", Position::fake()),
+                        "f",
+                        Position::fake()
+                    ),
+                    vec![],
+                    Position::fake()
+                )
+                .into()
+            )),
+            Ok(create_module(
+                Call::new(
+                    Some(function_type.into()),
+                    Variable::new("f", Position::fake()),
+                    vec![Variable::new("x", Position::fake()).into()],
+                    Position::fake()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_infer_ambiguous_method_call() {
+        let record_type = types::Record::new("r", Position::fake());
+
+        assert_eq!(
+            infer_module(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::new(
+                        "r",
+                        "r",
+                        vec![types::RecordField::new(
+                            "f",
+                            types::Function::new(
+                                vec![],
+                                types::None::new(Position::fake()),
+                                Position::fake(),
+                            ),
+                        )],
+                        false,
+                        false,
+                        false,
+                        Position::fake(),
+                    )])
+                    .set_function_declarations(vec![FunctionDeclaration::new(
+                        "f",
+                        "f",
+                        types::Function::new(
+                            vec![record_type.clone().into()],
+                            types::None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::fake(
+                        "g",
+                        Lambda::new(
+                            vec![Argument::new("x", record_type)],
+                            types::None::new(Position::fake()),
+                            Call::new(
+                                None,
+                                RecordDeconstruction::new(
+                                    None,
+                                    Variable::new("x", Position::fake()),
+                                    "f",
+                                    Position::fake()
+                                ),
+                                vec![],
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        false,
+                    )])
+            ),
+            Err(AnalysisError::AmbiguousMethodCall(Position::fake()))
+        );
+    }
+
     #[test]
     fn infer_thunk() {
         let none_type = types::None::new(Position::fake());
@@ -1041,6 +1963,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn infer_thunk_with_let() {
+        let none_type = types::None::new(Position::fake());
+
+        assert_eq!(
+            infer_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        none_type.clone(),
+                        Thunk::new(
+                            None,
+                            Let::new(
+                                Some("y".into()),
+                                None,
+                                None::new(Position::fake()),
+                                Variable::new("y", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Ok(
+                Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "x",
+                    Lambda::new(
+                        vec![],
+                        none_type.clone(),
+                        Thunk::new(
+                            Some(none_type.clone().into()),
+                            Let::new(
+                                Some("y".into()),
+                                Some(none_type.into()),
+                                None::new(Position::fake()),
+                                Variable::new("y", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            )
+        );
+    }
+
     mod if_type {
         use super::*;
         use pretty_assertions::assert_eq;
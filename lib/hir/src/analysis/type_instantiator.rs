@@ -0,0 +1,506 @@
+use super::{
+    type_canonicalizer, type_difference_calculator, type_transformer, union_type_creator,
+    union_type_member_calculator, AnalysisError,
+};
+use crate::types::{self, Type};
+use fnv::FnvHashMap;
+
+#[derive(Default)]
+struct Bindings {
+    invariant: FnvHashMap<String, Type>,
+    covariant: FnvHashMap<String, Vec<Type>>,
+}
+
+pub fn is_generic(type_: &Type) -> bool {
+    match type_ {
+        Type::Function(function) => {
+            function.arguments().iter().any(is_generic) || is_generic(function.result())
+        }
+        Type::List(list) => is_generic(list.element()),
+        Type::Map(map) => is_generic(map.key()) || is_generic(map.value()),
+        Type::Union(union) => is_generic(union.lhs()) || is_generic(union.rhs()),
+        Type::Record(record) => record.arguments().iter().any(is_generic),
+        Type::Reference(reference) => reference.arguments().iter().any(is_generic),
+        Type::Variable(_) => true,
+        Type::Any(_)
+        | Type::Boolean(_)
+        | Type::Error(_)
+        | Type::None(_)
+        | Type::Integer(_)
+        | Type::Number(_)
+        | Type::String(_) => false,
+    }
+}
+
+// Instantiate a generic function type with argument types in a call. Type
+// variables not bound by any argument are instantiated into any types.
+pub fn instantiate(
+    function: &types::Function,
+    argument_types: &[Type],
+    types: &FnvHashMap<String, Type>,
+) -> Result<types::Function, AnalysisError> {
+    let function = type_canonicalizer::canonicalize(&function.clone().into(), types)?;
+    let mut bindings = Bindings::default();
+
+    if let Type::Function(function) = &function {
+        for (parameter, argument) in function.arguments().iter().zip(argument_types) {
+            bind(
+                parameter,
+                &type_canonicalizer::canonicalize(argument, types)?,
+                false,
+                &mut bindings,
+                types,
+            )?;
+        }
+    }
+
+    let Bindings {
+        invariant,
+        covariant,
+    } = bindings;
+
+    let bindings = covariant
+        .into_iter()
+        .filter_map(|(name, lower_types)| {
+            union_type_creator::create(&lower_types, function.position())
+                .map(|type_| Ok((name, type_canonicalizer::canonicalize(&type_, types)?)))
+        })
+        .collect::<Result<Vec<_>, AnalysisError>>()?
+        .into_iter()
+        .chain(invariant)
+        .collect::<FnvHashMap<_, _>>();
+
+    Ok(
+        type_transformer::transform_type(&function, &|type_| match type_ {
+            Type::Variable(variable) => bindings
+                .get(variable.name())
+                .cloned()
+                .unwrap_or_else(|| types::Any::new(variable.position().clone()).into()),
+            _ => type_.clone(),
+        })
+        .into_function()
+        .unwrap(),
+    )
+}
+
+// Substitute type variables with types. Variables not found are kept as they
+// are.
+pub fn substitute(type_: &Type, variables: &FnvHashMap<String, Type>) -> Type {
+    type_transformer::transform_type(type_, &|type_| match type_ {
+        Type::Variable(variable) => variables
+            .get(variable.name())
+            .cloned()
+            .unwrap_or_else(|| type_.clone()),
+        _ => type_.clone(),
+    })
+}
+
+// Resolve type variables of a generic function type from one of its instances.
+pub fn resolve_variables(
+    generic: &types::Function,
+    instance: &types::Function,
+    types: &FnvHashMap<String, Type>,
+) -> Result<FnvHashMap<String, Type>, AnalysisError> {
+    let mut variables = FnvHashMap::default();
+
+    resolve_variable(
+        &type_canonicalizer::canonicalize(&generic.clone().into(), types)?,
+        &type_canonicalizer::canonicalize(&instance.clone().into(), types)?,
+        &mut variables,
+    );
+
+    Ok(variables)
+}
+
+fn resolve_variable(generic: &Type, instance: &Type, variables: &mut FnvHashMap<String, Type>) {
+    match (generic, instance) {
+        (Type::Variable(variable), _) => {
+            variables
+                .entry(variable.name().into())
+                .or_insert_with(|| instance.clone());
+        }
+        (Type::Function(generic), Type::Function(instance))
+            if generic.arguments().len() == instance.arguments().len() =>
+        {
+            for (generic, instance) in generic.arguments().iter().zip(instance.arguments()) {
+                resolve_variable(generic, instance, variables);
+            }
+
+            resolve_variable(generic.result(), instance.result(), variables);
+        }
+        (Type::List(generic), Type::List(instance)) => {
+            resolve_variable(generic.element(), instance.element(), variables)
+        }
+        (Type::Map(generic), Type::Map(instance)) => {
+            resolve_variable(generic.key(), instance.key(), variables);
+            resolve_variable(generic.value(), instance.value(), variables);
+        }
+        (Type::Record(generic), Type::Record(instance)) if generic.name() == instance.name() => {
+            for (generic, instance) in generic.arguments().iter().zip(instance.arguments()) {
+                resolve_variable(generic, instance, variables);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn bind(
+    parameter: &Type,
+    argument: &Type,
+    invariant: bool,
+    bindings: &mut Bindings,
+    types: &FnvHashMap<String, Type>,
+) -> Result<(), AnalysisError> {
+    match (parameter, argument) {
+        (Type::Variable(variable), _) => bind_variable(variable, argument, invariant, bindings),
+        (Type::Function(parameter), Type::Function(argument))
+            if parameter.arguments().len() == argument.arguments().len() =>
+        {
+            for (parameter, argument) in parameter.arguments().iter().zip(argument.arguments()) {
+                bind(parameter, argument, true, bindings, types)?;
+            }
+
+            bind(parameter.result(), argument.result(), true, bindings, types)?;
+        }
+        (Type::List(parameter), Type::List(argument)) => bind(
+            parameter.element(),
+            argument.element(),
+            true,
+            bindings,
+            types,
+        )?,
+        (Type::Map(parameter), Type::Map(argument)) => {
+            bind(parameter.key(), argument.key(), true, bindings, types)?;
+            bind(parameter.value(), argument.value(), true, bindings, types)?;
+        }
+        (Type::Record(parameter), Type::Record(argument))
+            if parameter.name() == argument.name() =>
+        {
+            for (parameter, argument) in parameter.arguments().iter().zip(argument.arguments()) {
+                bind(parameter, argument, true, bindings, types)?;
+            }
+        }
+        (Type::Union(_), _) if is_generic(parameter) => {
+            let (variables, others) = union_type_member_calculator::calculate(parameter, types)?
+                .into_iter()
+                .partition::<Vec<_>, _>(|type_| type_.is_variable());
+
+            for member in union_type_member_calculator::calculate(argument, types)? {
+                for other in &others {
+                    bind(other, &member, invariant, bindings, types)?;
+                }
+            }
+
+            if let Some(argument) = match union_type_creator::create(&others, parameter.position())
+            {
+                Some(others) => type_difference_calculator::calculate(argument, &others, types)?,
+                None => Some(argument.clone()),
+            } {
+                for variable in &variables {
+                    if let Type::Variable(variable) = variable {
+                        bind_variable(variable, &argument, invariant, bindings);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn bind_variable(
+    variable: &types::Variable,
+    argument: &Type,
+    invariant: bool,
+    bindings: &mut Bindings,
+) {
+    if invariant {
+        bindings
+            .invariant
+            .entry(variable.name().into())
+            .or_insert_with(|| argument.clone());
+    } else {
+        bindings
+            .covariant
+            .entry(variable.name().into())
+            .or_default()
+            .push(argument.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn variable(name: &str) -> Type {
+        types::Variable::new(name, Position::fake()).into()
+    }
+
+    #[test]
+    fn instantiate_variable() {
+        assert_eq!(
+            instantiate(
+                &types::Function::new(vec![variable("a")], variable("a"), Position::fake()),
+                &[types::Number::new(Position::fake()).into()],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![types::Number::new(Position::fake()).into()],
+                types::Number::new(Position::fake()),
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn instantiate_unbound_variable() {
+        assert_eq!(
+            instantiate(
+                &types::Function::new(vec![], variable("a"), Position::fake()),
+                &[],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![],
+                types::Any::new(Position::fake()),
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn instantiate_variable_with_multiple_arguments() {
+        let union_type = type_canonicalizer::canonicalize(
+            &types::Union::new(
+                types::Number::new(Position::fake()),
+                types::None::new(Position::fake()),
+                Position::fake(),
+            )
+            .into(),
+            &Default::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            instantiate(
+                &types::Function::new(
+                    vec![variable("a"), variable("a")],
+                    variable("a"),
+                    Position::fake()
+                ),
+                &[
+                    types::Number::new(Position::fake()).into(),
+                    types::None::new(Position::fake()).into()
+                ],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![union_type.clone(), union_type.clone()],
+                union_type,
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn instantiate_variable_in_list() {
+        let list_type = types::List::new(types::Number::new(Position::fake()), Position::fake());
+
+        assert_eq!(
+            instantiate(
+                &types::Function::new(
+                    vec![
+                        types::List::new(variable("a"), Position::fake()).into(),
+                        variable("a")
+                    ],
+                    variable("a"),
+                    Position::fake()
+                ),
+                &[
+                    list_type.clone().into(),
+                    types::Number::new(Position::fake()).into()
+                ],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![
+                    list_type.into(),
+                    types::Number::new(Position::fake()).into()
+                ],
+                types::Number::new(Position::fake()),
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn instantiate_variable_in_union() {
+        assert_eq!(
+            instantiate(
+                &types::Function::new(
+                    vec![types::Union::new(
+                        variable("a"),
+                        types::None::new(Position::fake()),
+                        Position::fake()
+                    )
+                    .into()],
+                    variable("a"),
+                    Position::fake()
+                ),
+                &[types::Union::new(
+                    types::Number::new(Position::fake()),
+                    types::None::new(Position::fake()),
+                    Position::fake()
+                )
+                .into()],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![types::Union::new(
+                    types::None::new(Position::fake()),
+                    types::Number::new(Position::fake()),
+                    Position::fake()
+                )
+                .into()],
+                types::Number::new(Position::fake()),
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn instantiate_variable_in_function() {
+        let function_type = types::Function::new(
+            vec![types::Number::new(Position::fake()).into()],
+            types::ByteString::new(Position::fake()),
+            Position::fake(),
+        );
+
+        assert_eq!(
+            instantiate(
+                &types::Function::new(
+                    vec![types::Function::new(
+                        vec![variable("a")],
+                        variable("b"),
+                        Position::fake()
+                    )
+                    .into()],
+                    variable("b"),
+                    Position::fake()
+                ),
+                &[function_type.clone().into()],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![function_type.into()],
+                types::ByteString::new(Position::fake()),
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn instantiate_variable_in_record() {
+        let record_type = types::Record::new("foo", Position::fake());
+
+        assert_eq!(
+            instantiate(
+                &types::Function::new(
+                    vec![record_type
+                        .clone()
+                        .set_arguments(vec![variable("a")])
+                        .into()],
+                    variable("a"),
+                    Position::fake()
+                ),
+                &[record_type
+                    .clone()
+                    .set_arguments(vec![types::Number::new(Position::fake()).into()])
+                    .into()],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![record_type
+                    .set_arguments(vec![types::Number::new(Position::fake()).into()])
+                    .into()],
+                types::Number::new(Position::fake()),
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn instantiate_variable_in_record_in_union() {
+        let record_type = types::Record::new("foo", Position::fake());
+        let argument_type = record_type
+            .clone()
+            .set_arguments(vec![types::Number::new(Position::fake()).into()]);
+
+        assert_eq!(
+            instantiate(
+                &types::Function::new(
+                    vec![types::Union::new(
+                        record_type.set_arguments(vec![variable("a")]),
+                        types::None::new(Position::fake()),
+                        Position::fake()
+                    )
+                    .into()],
+                    variable("a"),
+                    Position::fake()
+                ),
+                &[types::Union::new(
+                    argument_type.clone(),
+                    types::None::new(Position::fake()),
+                    Position::fake()
+                )
+                .into()],
+                &Default::default(),
+            ),
+            Ok(types::Function::new(
+                vec![types::Union::new(
+                    types::None::new(Position::fake()),
+                    argument_type,
+                    Position::fake()
+                )
+                .into()],
+                types::Number::new(Position::fake()),
+                Position::fake()
+            ))
+        );
+    }
+
+    #[test]
+    fn resolve_variables_in_function() {
+        assert_eq!(
+            resolve_variables(
+                &types::Function::new(
+                    vec![
+                        variable("a"),
+                        types::List::new(variable("b"), Position::fake()).into()
+                    ],
+                    variable("a"),
+                    Position::fake()
+                ),
+                &types::Function::new(
+                    vec![
+                        types::Number::new(Position::fake()).into(),
+                        types::List::new(types::None::new(Position::fake()), Position::fake())
+                            .into()
+                    ],
+                    types::Number::new(Position::fake()),
+                    Position::fake()
+                ),
+                &Default::default()
+            ),
+            Ok([
+                ("a".into(), types::Number::new(Position::fake()).into()),
+                ("b".into(), types::None::new(Position::fake()).into())
+            ]
+            .into_iter()
+            .collect())
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use super::{record_field_resolver, type_formatter, type_resolver, AnalysisError};
+use crate::types::{RecordField, Type};
+use fnv::{FnvHashMap, FnvHashSet};
+
+pub fn check(
+    type_: &Type,
+    types: &FnvHashMap<String, Type>,
+    record_types: &FnvHashMap<String, Vec<RecordField>>,
+) -> Result<bool, AnalysisError> {
+    check_with_cache(type_, &Default::default(), types, record_types)
+}
+
+fn check_with_cache(
+    type_: &Type,
+    record_names: &FnvHashSet<String>,
+    types: &FnvHashMap<String, Type>,
+    record_types: &FnvHashMap<String, Vec<RecordField>>,
+) -> Result<bool, AnalysisError> {
+    let check_with_cache =
+        |type_, record_names| check_with_cache(type_, record_names, types, record_types);
+
+    Ok(match type_ {
+        Type::Integer(_) | Type::Number(_) | Type::String(_) => true,
+        Type::List(list) => check_with_cache(list.element(), record_names)?,
+        Type::Record(_) => {
+            // Instances of generic records are distinguished by their arguments.
+            let name = type_formatter::format(type_);
+
+            if record_names.contains(&name) {
+                true
+            } else {
+                let record_names = record_names.clone().into_iter().chain([name]).collect();
+
+                record_field_resolver::resolve(type_, types, record_types)?
+                    .iter()
+                    .map(|field| check_with_cache(field.type_(), &record_names))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .all(|flag| flag)
+            }
+        }
+        Type::Reference(reference) => {
+            check_with_cache(&type_resolver::resolve(reference, types)?, record_names)?
+        }
+        Type::Any(_)
+        | Type::Boolean(_)
+        | Type::Error(_)
+        | Type::Function(_)
+        | Type::Map(_)
+        | Type::None(_)
+        | Type::Union(_)
+        | Type::Variable(_) => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types;
+    use position::{test::PositionFake, Position};
+
+    #[test]
+    fn check_string_type() {
+        assert!(check(
+            &types::ByteString::new(Position::fake()).into(),
+            &Default::default(),
+            &Default::default(),
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn check_list_type() {
+        assert!(check(
+            &types::List::new(types::Number::new(Position::fake()), Position::fake()).into(),
+            &Default::default(),
+            &Default::default(),
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn check_record_type() {
+        assert!(check(
+            &types::Record::new("foo", Position::fake()).into(),
+            &Default::default(),
+            &[(
+                "foo".into(),
+                vec![types::RecordField::new(
+                    "x",
+                    types::ByteString::new(Position::fake())
+                )]
+            )]
+            .into_iter()
+            .collect()
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn check_recursive_record_type() {
+        assert!(check(
+            &types::Record::new("foo", Position::fake()).into(),
+            &Default::default(),
+            &[(
+                "foo".into(),
+                vec![types::RecordField::new(
+                    "x",
+                    types::List::new(
+                        types::Record::new("foo", Position::fake()),
+                        Position::fake()
+                    )
+                )]
+            )]
+            .into_iter()
+            .collect()
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn check_record_type_with_boolean_field() {
+        assert!(!check(
+            &types::Record::new("foo", Position::fake()).into(),
+            &Default::default(),
+            &[(
+                "foo".into(),
+                vec![types::RecordField::new(
+                    "x",
+                    types::Boolean::new(Position::fake())
+                )]
+            )]
+            .into_iter()
+            .collect()
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn check_union_type() {
+        assert!(!check(
+            &types::Union::new(
+                types::Number::new(Position::fake()),
+                types::None::new(Position::fake()),
+                Position::fake()
+            )
+            .into(),
+            &Default::default(),
+            &Default::default(),
+        )
+        .unwrap());
+    }
+}
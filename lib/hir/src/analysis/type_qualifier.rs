@@ -43,6 +43,8 @@ pub fn qualify(module: &Module, prefix: &str) -> Module {
                         definition.is_external(),
                         definition.position().clone(),
                     )
+                    .set_newtype(definition.is_newtype())
+                    .set_type_parameters(definition.type_parameters().to_vec())
                 })
                 .collect(),
             module
@@ -60,10 +62,12 @@ pub fn qualify(module: &Module, prefix: &str) -> Module {
                         alias.is_external(),
                         alias.position().clone(),
                     )
+                    .set_type_parameters(alias.type_parameters().to_vec())
                 })
                 .collect(),
             module.foreign_declarations().to_vec(),
             module.function_declarations().to_vec(),
+            module.implementations().to_vec(),
             module.function_definitions().to_vec(),
             module.position().clone(),
         ),
@@ -75,6 +79,7 @@ pub fn qualify(module: &Module, prefix: &str) -> Module {
                     .unwrap_or_else(|| record.name()),
                 record.position().clone(),
             )
+            .set_arguments(record.arguments().to_vec())
             .into(),
             Type::Reference(reference) => types::Reference::new(
                 names
@@ -83,6 +88,7 @@ pub fn qualify(module: &Module, prefix: &str) -> Module {
                     .unwrap_or_else(|| reference.name()),
                 reference.position().clone(),
             )
+            .set_arguments(reference.arguments().to_vec())
             .into(),
             _ => type_.clone(),
         },
@@ -6,9 +6,16 @@ pub fn resolve(
     reference: &Reference,
     types: &FnvHashMap<String, Type>,
 ) -> Result<Type, AnalysisError> {
-    Ok(types
+    let type_ = types
         .get(reference.name())
-        .ok_or_else(|| AnalysisError::TypeNotFound(reference.clone()))?
-        .clone()
-        .set_position(reference.position().clone()))
+        .ok_or_else(|| AnalysisError::TypeNotFound(reference.clone(), vec![]))?;
+
+    Ok(match type_ {
+        Type::Record(record) if !reference.arguments().is_empty() => record
+            .clone()
+            .set_arguments(reference.arguments().to_vec())
+            .into(),
+        _ => type_.clone(),
+    }
+    .set_position(reference.position().clone()))
 }
@@ -23,16 +23,21 @@ pub fn transform(module: &Module, transform: impl Fn(&Type) -> Type) -> Module {
             .map(|declaration| transform_foreign_declaration(declaration, &transform))
             .collect(),
         module.function_declarations().to_vec(),
+        module
+            .implementations()
+            .iter()
+            .map(|implementation| transform_implementation(implementation, &transform))
+            .collect(),
         module
             .function_definitions()
             .iter()
-            .map(|definition| transform_function_definition(definition, &transform))
+            .map(|definition| transform_definition(definition, &transform))
             .collect(),
         module.position().clone(),
     )
 }
 
-fn transform_type(type_: &Type, transform: &impl Fn(&Type) -> Type) -> Type {
+pub fn transform_type(type_: &Type, transform: &impl Fn(&Type) -> Type) -> Type {
     let transform_deeply = |type_| transform_type(type_, transform);
 
     transform(&match type_ {
@@ -57,13 +62,24 @@ fn transform_type(type_: &Type, transform: &impl Fn(&Type) -> Type) -> Type {
             union.position().clone(),
         )
         .into(),
+        Type::Variable(variable) => variable
+            .clone()
+            .set_constraint(variable.constraint().map(transform_deeply))
+            .into(),
+        Type::Record(record) => record
+            .clone()
+            .set_arguments(record.arguments().iter().map(transform_deeply).collect())
+            .into(),
+        Type::Reference(reference) => reference
+            .clone()
+            .set_arguments(reference.arguments().iter().map(transform_deeply).collect())
+            .into(),
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
         | Type::None(_)
+        | Type::Integer(_)
         | Type::Number(_)
-        | Type::Record(_)
-        | Type::Reference(_)
         | Type::String(_) => type_.clone(),
     })
 }
@@ -81,13 +97,18 @@ fn transform_type_definition(
             definition
                 .fields()
                 .iter()
-                .map(|field| types::RecordField::new(field.name(), transform(field.type_())))
+                .map(|field| {
+                    types::RecordField::new(field.name(), transform(field.type_()))
+                        .set_default(field.has_default())
+                })
                 .collect(),
             definition.is_open(),
             definition.is_public(),
             definition.is_external(),
             definition.position().clone(),
         )
+        .set_newtype(definition.is_newtype())
+        .set_type_parameters(definition.type_parameters().to_vec())
     }
 }
 
@@ -103,6 +124,24 @@ fn transform_type_alias(alias: &TypeAlias, transform: &impl Fn(&Type) -> Type) -
             alias.is_external(),
             alias.position().clone(),
         )
+        .set_type_parameters(alias.type_parameters().to_vec())
+    }
+}
+
+fn transform_implementation(
+    implementation: &Implementation,
+    transform: &impl Fn(&Type) -> Type,
+) -> Implementation {
+    if implementation.is_external() {
+        implementation.clone()
+    } else {
+        Implementation::new(
+            implementation.name(),
+            transform(implementation.interface()),
+            transform(implementation.type_()),
+            implementation.is_external(),
+            implementation.position().clone(),
+        )
     }
 }
 
@@ -119,7 +158,14 @@ fn transform_foreign_declaration(
     )
 }
 
-fn transform_function_definition(
+pub fn transform_function_definition(
+    definition: &FunctionDefinition,
+    transform: impl Fn(&Type) -> Type,
+) -> FunctionDefinition {
+    transform_definition(definition, &|type_| transform_type(type_, &transform))
+}
+
+fn transform_definition(
     definition: &FunctionDefinition,
     transform: &impl Fn(&Type) -> Type,
 ) -> FunctionDefinition {
@@ -233,11 +279,13 @@ fn transform_expression(expression: &Expression, transform: &impl Fn(&Type) -> T
         )
         .into(),
         Expression::ListComprehension(comprehension) => ListComprehension::new(
-            comprehension.input_type().map(transform),
-            transform(comprehension.output_type()),
+            transform(comprehension.type_()),
             transform_expression(comprehension.element()),
-            comprehension.element_name(),
-            transform_expression(comprehension.list()),
+            comprehension
+                .branches()
+                .iter()
+                .map(|branch| transform_list_comprehension_branch(branch, transform))
+                .collect(),
             comprehension.position().clone(),
         )
         .into(),
@@ -259,14 +307,16 @@ fn transform_expression(expression: &Expression, transform: &impl Fn(&Type) -> T
             map.position().clone(),
         )
         .into(),
-        Expression::MapIterationComprehension(comprehension) => MapIterationComprehension::new(
-            comprehension.key_type().map(transform),
-            comprehension.value_type().map(transform),
-            transform(comprehension.element_type()),
-            transform_expression(comprehension.element()),
-            comprehension.key_name(),
-            comprehension.value_name(),
-            transform_expression(comprehension.map()),
+        Expression::MapComprehension(comprehension) => MapComprehension::new(
+            transform(comprehension.key_type()),
+            transform(comprehension.value_type()),
+            transform_expression(comprehension.key()),
+            transform_expression(comprehension.value()),
+            comprehension
+                .branches()
+                .iter()
+                .map(|branch| transform_list_comprehension_branch(branch, transform))
+                .collect(),
             comprehension.position().clone(),
         )
         .into(),
@@ -327,17 +377,42 @@ fn transform_expression(expression: &Expression, transform: &impl Fn(&Type) -> T
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::String(_)
+        | Expression::Integer(_)
         | Expression::None(_)
         | Expression::Number(_)
         | Expression::Variable(_) => expression.clone(),
     }
 }
 
+fn transform_list_comprehension_branch(
+    branch: &ListComprehensionBranch,
+    transform: &impl Fn(&Type) -> Type,
+) -> ListComprehensionBranch {
+    ListComprehensionBranch::new(
+        branch.names().to_vec(),
+        branch
+            .iteratees()
+            .iter()
+            .map(|iteratee| {
+                ListComprehensionIteratee::new(
+                    iteratee.type_().map(transform),
+                    transform_expression(iteratee.expression(), transform),
+                )
+            })
+            .collect(),
+        branch
+            .condition()
+            .map(|condition| transform_expression(condition, transform)),
+        branch.position().clone(),
+    )
+}
+
 fn transform_operation(operation: &Operation, transform: &impl Fn(&Type) -> Type) -> Operation {
     let transform_expression = |expression| transform_expression(expression, transform);
 
     match operation {
         Operation::Arithmetic(operation) => ArithmeticOperation::new(
+            operation.type_().map(transform),
             operation.operator(),
             transform_expression(operation.lhs()),
             transform_expression(operation.rhs()),
@@ -365,6 +440,7 @@ fn transform_operation(operation: &Operation, transform: &impl Fn(&Type) -> Type
         )
         .into(),
         Operation::Order(operation) => OrderOperation::new(
+            operation.type_().map(transform),
             operation.operator(),
             transform_expression(operation.lhs()),
             transform_expression(operation.rhs()),
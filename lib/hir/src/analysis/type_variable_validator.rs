@@ -0,0 +1,513 @@
+use super::{
+    context::AnalysisContext, error::AnalysisError, expression_visitor, type_canonicalizer,
+    type_equality_checker, type_instantiator, type_visitor, union_type_member_calculator,
+};
+use crate::{
+    ir::*,
+    types::{self, Type},
+};
+
+// Type variables are erased into any types in code generation. So values of
+// types containing them cannot be distinguished by their types at runtime.
+pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
+    for definition in module.function_definitions() {
+        let lambda = definition.lambda();
+
+        for type_ in lambda
+            .arguments()
+            .iter()
+            .map(|argument| argument.type_())
+            .chain([lambda.result_type()])
+        {
+            validate_signature_type(context, type_)?;
+        }
+    }
+
+    let mut keys = vec![];
+    let mut type_arguments = vec![];
+    let mut unions = vec![];
+
+    type_visitor::visit(module, |type_| match type_ {
+        Type::Map(map) => keys.push(map.key()),
+        Type::Record(record) => type_arguments.push(record.arguments()),
+        Type::Reference(reference) => type_arguments.push(reference.arguments()),
+        Type::Union(_) => unions.push(type_),
+        _ => {}
+    });
+
+    let mut if_types = vec![];
+    let mut coercions = vec![];
+
+    expression_visitor::visit(module, |expression| match expression {
+        Expression::IfType(if_) => if_types.push(if_),
+        Expression::TypeCoercion(coercion) => coercions.push(coercion),
+        _ => {}
+    });
+
+    for type_ in keys {
+        if type_instantiator::is_generic(type_) {
+            return Err(AnalysisError::UnsupportedTypeVariable(type_.clone()));
+        }
+    }
+
+    for coercion in coercions {
+        let from = type_canonicalizer::canonicalize(coercion.from(), context.types())?;
+
+        if type_instantiator::is_generic(&from)
+            && (from.is_function() || from.is_list() || from.is_map())
+            && type_canonicalizer::canonicalize(coercion.to(), context.types())?.is_variant()
+        {
+            return Err(AnalysisError::UnsupportedTypeVariable(
+                coercion.from().clone(),
+            ));
+        }
+    }
+
+    // Values of type variables instantiated with function, list, or map types
+    // are not converted in records.
+    for arguments in type_arguments {
+        for type_ in arguments {
+            if is_generic_collection(&type_canonicalizer::canonicalize(type_, context.types())?) {
+                return Err(AnalysisError::UnsupportedTypeVariable(type_.clone()));
+            }
+        }
+    }
+
+    for union in unions {
+        validate_union(context, union)?;
+    }
+
+    for if_ in if_types {
+        validate_if_type(context, if_)?;
+    }
+
+    Ok(())
+}
+
+fn validate_signature_type(context: &AnalysisContext, type_: &Type) -> Result<(), AnalysisError> {
+    if !type_instantiator::is_generic(type_) {
+        return Ok(());
+    }
+
+    match type_canonicalizer::canonicalize(type_, context.types())? {
+        Type::Function(function) => {
+            for type_ in function.arguments().iter().chain([function.result()]) {
+                validate_signature_type(context, type_)?;
+            }
+        }
+        Type::List(list) => validate_signature_type(context, list.element())?,
+        Type::Map(map) => validate_signature_type(context, map.value())?,
+        Type::Record(record) => {
+            for type_ in record.arguments() {
+                validate_signature_type(context, type_)?;
+            }
+        }
+        Type::Union(_) => {
+            for member in union_type_member_calculator::calculate(type_, context.types())? {
+                if is_generic_collection(&member) {
+                    return Err(AnalysisError::UnsupportedTypeVariable(type_.clone()));
+                }
+
+                validate_signature_type(context, &member)?;
+            }
+        }
+        Type::Variable(_) => {}
+        _ => return Err(AnalysisError::UnsupportedTypeVariable(type_.clone())),
+    }
+
+    Ok(())
+}
+
+// Instances of a generic record share a type tag at runtime.
+fn validate_union(context: &AnalysisContext, type_: &Type) -> Result<(), AnalysisError> {
+    let records = collect_generic_records(context, type_)?;
+
+    for (index, one) in records.iter().enumerate() {
+        for other in &records[index + 1..] {
+            if one.name() == other.name()
+                && !type_equality_checker::check(
+                    &one.clone().into(),
+                    &other.clone().into(),
+                    context.types(),
+                )?
+            {
+                return Err(AnalysisError::AmbiguousGenericRecord(type_.clone()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Instances of generic records can be downcast from unions as they have their
+// record names as type tags.
+fn validate_if_type(context: &AnalysisContext, if_: &IfType) -> Result<(), AnalysisError> {
+    for branch in if_.branches() {
+        if union_type_member_calculator::calculate(branch.type_(), context.types())?
+            .iter()
+            .any(|type_| type_instantiator::is_generic(type_) && !type_.is_record())
+        {
+            return Err(AnalysisError::UnsupportedTypeVariable(
+                branch.type_().clone(),
+            ));
+        }
+    }
+
+    let type_ = match if_.else_().and_then(|branch| branch.type_()) {
+        Some(type_) => type_,
+        None => return Ok(()),
+    };
+
+    if !union_type_member_calculator::calculate(type_, context.types())?
+        .iter()
+        .any(|type_| type_.is_any())
+    {
+        return Ok(());
+    }
+
+    for branch in if_.branches() {
+        if !collect_generic_records(context, branch.type_())?.is_empty() {
+            return Err(AnalysisError::AmbiguousGenericRecord(
+                branch.type_().clone(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_generic_records(
+    context: &AnalysisContext,
+    type_: &Type,
+) -> Result<Vec<types::Record>, AnalysisError> {
+    Ok(
+        union_type_member_calculator::calculate(type_, context.types())?
+            .into_iter()
+            .filter_map(|type_| match type_ {
+                Type::Record(record) if !record.arguments().is_empty() => Some(record),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+fn is_generic_collection(type_: &Type) -> bool {
+    type_instantiator::is_generic(type_)
+        && (type_.is_function() || type_.is_list() || type_.is_map())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        analysis::type_collector,
+        test::{FunctionDefinitionFake, ModuleFake},
+        types,
+    };
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn validate_module(module: &Module) -> Result<(), AnalysisError> {
+        validate(
+            &AnalysisContext::new(
+                type_collector::collect(module),
+                type_collector::collect_records(module),
+            ),
+            module,
+        )
+    }
+
+    fn variable_type() -> Type {
+        types::Variable::new("a", Position::fake()).into()
+    }
+
+    fn validate_signature(argument_type: impl Into<Type>) -> Result<(), AnalysisError> {
+        validate_module(
+            &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![Argument::new("x", argument_type)],
+                    types::None::new(Position::fake()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            )]),
+        )
+    }
+
+    #[test]
+    fn validate_type_variable() {
+        assert_eq!(validate_signature(variable_type()), Ok(()));
+    }
+
+    #[test]
+    fn validate_list_of_type_variable() {
+        assert_eq!(
+            validate_signature(types::List::new(variable_type(), Position::fake())),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_function_of_type_variables() {
+        assert_eq!(
+            validate_signature(types::Function::new(
+                vec![types::List::new(variable_type(), Position::fake()).into()],
+                variable_type(),
+                Position::fake()
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_union_of_type_variable() {
+        assert_eq!(
+            validate_signature(types::Union::new(
+                variable_type(),
+                types::None::new(Position::fake()),
+                Position::fake()
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_nested_list_of_type_variable() {
+        assert_eq!(
+            validate_signature(types::List::new(
+                types::List::new(variable_type(), Position::fake()),
+                Position::fake(),
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_map_value_of_list_of_type_variable() {
+        assert_eq!(
+            validate_signature(types::Map::new(
+                types::None::new(Position::fake()),
+                types::List::new(variable_type(), Position::fake()),
+                Position::fake(),
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_generic_record_of_type_variable() {
+        assert_eq!(
+            validate_signature(
+                types::Record::new("r", Position::fake()).set_arguments(vec![variable_type()])
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_generic_record_of_list_of_type_variable() {
+        let type_ = types::List::new(variable_type(), Position::fake());
+
+        assert_eq!(
+            validate_signature(
+                types::Record::new("r", Position::fake()).set_arguments(vec![type_.clone().into()])
+            ),
+            Err(AnalysisError::UnsupportedTypeVariable(type_.into()))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_union_of_list_of_type_variable() {
+        let type_ = types::Union::new(
+            types::List::new(variable_type(), Position::fake()),
+            types::None::new(Position::fake()),
+            Position::fake(),
+        );
+
+        assert_eq!(
+            validate_signature(type_.clone()),
+            Err(AnalysisError::UnsupportedTypeVariable(type_.into()))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_union_of_generic_record_instances() {
+        let type_ = types::Union::new(
+            types::Record::new("r", Position::fake())
+                .set_arguments(vec![types::Number::new(Position::fake()).into()]),
+            types::Record::new("r", Position::fake())
+                .set_arguments(vec![types::None::new(Position::fake()).into()]),
+            Position::fake(),
+        );
+
+        assert_eq!(
+            validate_signature(type_.clone()),
+            Err(AnalysisError::AmbiguousGenericRecord(type_.into()))
+        );
+    }
+
+    #[test]
+    fn validate_union_of_same_generic_record_instances() {
+        let record_type = types::Record::new("r", Position::fake())
+            .set_arguments(vec![types::Number::new(Position::fake()).into()]);
+
+        assert_eq!(
+            validate_signature(types::Union::new(
+                record_type.clone(),
+                types::Union::new(
+                    record_type,
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                Position::fake(),
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_map_key_of_type_variable() {
+        assert_eq!(
+            validate_signature(types::Map::new(
+                variable_type(),
+                types::None::new(Position::fake()),
+                Position::fake()
+            )),
+            Err(AnalysisError::UnsupportedTypeVariable(variable_type()))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_if_type_branch_of_type_variable() {
+        assert_eq!(
+            validate_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", types::Any::new(Position::fake()))],
+                        types::None::new(Position::fake()),
+                        IfType::new(
+                            "y",
+                            Variable::new("x", Position::fake()),
+                            vec![IfTypeBranch::new(
+                                variable_type(),
+                                None::new(Position::fake())
+                            )],
+                            Some(ElseBranch::new(
+                                Some(types::Any::new(Position::fake()).into()),
+                                None::new(Position::fake()),
+                                Position::fake()
+                            )),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Err(AnalysisError::UnsupportedTypeVariable(variable_type()))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_coercion_of_list_of_type_variable() {
+        let list_type = types::List::new(variable_type(), Position::fake());
+
+        assert_eq!(
+            validate_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", list_type.clone())],
+                        types::Any::new(Position::fake()),
+                        TypeCoercion::new(
+                            list_type.clone(),
+                            types::Any::new(Position::fake()),
+                            Variable::new("x", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Err(AnalysisError::UnsupportedTypeVariable(list_type.into()))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_if_type_branch_of_generic_record_instance_from_any() {
+        let record_type = types::Record::new("r", Position::fake())
+            .set_arguments(vec![types::Number::new(Position::fake()).into()]);
+
+        assert_eq!(
+            validate_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", types::Any::new(Position::fake()))],
+                        types::None::new(Position::fake()),
+                        IfType::new(
+                            "y",
+                            Variable::new("x", Position::fake()),
+                            vec![IfTypeBranch::new(
+                                record_type.clone(),
+                                None::new(Position::fake())
+                            )],
+                            Some(ElseBranch::new(
+                                Some(types::Any::new(Position::fake()).into()),
+                                None::new(Position::fake()),
+                                Position::fake()
+                            )),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Err(AnalysisError::AmbiguousGenericRecord(record_type.into()))
+        );
+    }
+
+    #[test]
+    fn validate_if_type_branch_of_generic_record_instance_from_union() {
+        let record_type = types::Record::new("r", Position::fake())
+            .set_arguments(vec![types::Number::new(Position::fake()).into()]);
+
+        assert_eq!(
+            validate_module(&Module::empty().set_function_definitions(vec![
+                FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            types::Union::new(
+                                record_type.clone(),
+                                types::None::new(Position::fake()),
+                                Position::fake(),
+                            )
+                        )],
+                        types::None::new(Position::fake()),
+                        IfType::new(
+                            "y",
+                            Variable::new("x", Position::fake()),
+                            vec![IfTypeBranch::new(record_type, None::new(Position::fake()))],
+                            Some(ElseBranch::new(
+                                Some(types::None::new(Position::fake()).into()),
+                                None::new(Position::fake()),
+                                Position::fake()
+                            )),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )
+            ])),
+            Ok(())
+        );
+    }
+}
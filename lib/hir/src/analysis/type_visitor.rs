@@ -20,6 +20,10 @@ pub fn visit<'a>(module: &'a Module, mut visit: impl FnMut(&'a Type)) {
         visit_function_declaration(declaration, &mut visit);
     }
 
+    for implementation in module.implementations() {
+        visit_implementation(implementation, &mut visit);
+    }
+
     for definition in module.function_definitions() {
         visit_function_definition(definition, &mut visit);
     }
@@ -49,6 +53,11 @@ fn visit_function_declaration<'a>(
     visit_function_type(declaration.type_(), visit);
 }
 
+fn visit_implementation<'a>(implementation: &'a Implementation, visit: &mut impl FnMut(&'a Type)) {
+    visit_type(implementation.interface(), visit);
+    visit_type(implementation.type_(), visit);
+}
+
 fn visit_function_definition<'a>(
     definition: &'a FunctionDefinition,
     visit: &mut impl FnMut(&'a Type),
@@ -152,14 +161,22 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a T
             }
         }
         Expression::ListComprehension(comprehension) => {
-            if let Some(type_) = comprehension.input_type() {
-                visit_type(type_, visit);
-            }
+            visit_type(comprehension.type_(), visit);
+            visit_expression(comprehension.element(), visit);
 
-            visit_type(comprehension.output_type(), visit);
+            for branch in comprehension.branches() {
+                for iteratee in branch.iteratees() {
+                    if let Some(type_) = iteratee.type_() {
+                        visit_type(type_, visit);
+                    }
 
-            visit_expression(comprehension.element(), visit);
-            visit_expression(comprehension.list(), visit);
+                    visit_expression(iteratee.expression(), visit);
+                }
+
+                if let Some(condition) = branch.condition() {
+                    visit_expression(condition, visit);
+                }
+            }
         }
         Expression::Map(map) => {
             visit_type(map.key_type(), visit);
@@ -176,19 +193,25 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a T
                 }
             }
         }
-        Expression::MapIterationComprehension(comprehension) => {
-            if let Some(type_) = comprehension.key_type() {
-                visit_type(type_, visit);
-            }
-
-            if let Some(type_) = comprehension.value_type() {
-                visit_type(type_, visit);
-            }
+        Expression::MapComprehension(comprehension) => {
+            visit_type(comprehension.key_type(), visit);
+            visit_type(comprehension.value_type(), visit);
+            visit_expression(comprehension.key(), visit);
+            visit_expression(comprehension.value(), visit);
+
+            for branch in comprehension.branches() {
+                for iteratee in branch.iteratees() {
+                    if let Some(type_) = iteratee.type_() {
+                        visit_type(type_, visit);
+                    }
 
-            visit_type(comprehension.element_type(), visit);
+                    visit_expression(iteratee.expression(), visit);
+                }
 
-            visit_expression(comprehension.element(), visit);
-            visit_expression(comprehension.map(), visit);
+                if let Some(condition) = branch.condition() {
+                    visit_expression(condition, visit);
+                }
+            }
         }
         Expression::Operation(operation) => match operation {
             Operation::Arithmetic(operation) => {
@@ -244,6 +267,7 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a T
         }
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
+        | Expression::Integer(_)
         | Expression::None(_)
         | Expression::Number(_)
         | Expression::String(_)
@@ -251,7 +275,7 @@ fn visit_expression<'a>(expression: &'a Expression, visit: &mut impl FnMut(&'a T
     }
 }
 
-fn visit_type<'a>(type_: &'a Type, visit: &mut impl FnMut(&'a Type)) {
+pub fn visit_type<'a>(type_: &'a Type, visit: &mut impl FnMut(&'a Type)) {
     visit(type_);
 
     let mut visit_type = |type_| visit_type(type_, visit);
@@ -267,13 +291,27 @@ fn visit_type<'a>(type_: &'a Type, visit: &mut impl FnMut(&'a Type)) {
             visit_type(union.lhs());
             visit_type(union.rhs())
         }
+        Type::Variable(variable) => {
+            if let Some(constraint) = variable.constraint() {
+                visit_type(constraint)
+            }
+        }
+        Type::Record(record) => {
+            for type_ in record.arguments() {
+                visit_type(type_)
+            }
+        }
+        Type::Reference(reference) => {
+            for type_ in reference.arguments() {
+                visit_type(type_)
+            }
+        }
         Type::Any(_)
         | Type::Boolean(_)
         | Type::Error(_)
         | Type::None(_)
+        | Type::Integer(_)
         | Type::Number(_)
-        | Type::Record(_)
-        | Type::Reference(_)
         | Type::String(_) => {}
     }
 }
@@ -0,0 +1,280 @@
+use super::{
+    expression_visitor, record_field_resolver, type_visitor, warning::AnalysisWarning,
+    warning_configuration::WarningConfiguration,
+};
+use crate::{ir::*, types::Type};
+use fnv::FnvHashSet;
+
+pub fn check(module: &Module, configuration: &WarningConfiguration) -> Vec<AnalysisWarning> {
+    let mut warnings = vec![];
+
+    if configuration.unused_functions {
+        warnings.extend(check_functions(module));
+    }
+
+    if configuration.unused_types {
+        warnings.extend(check_types(module));
+    }
+
+    warnings
+}
+
+fn check_functions(module: &Module) -> Vec<AnalysisWarning> {
+    let mut names = FnvHashSet::default();
+    let mut method_names = FnvHashSet::default();
+
+    // Recursive calls do not make functions used.
+    for definition in module.function_definitions() {
+        expression_visitor::visit_definition(definition, &mut |expression| match expression {
+            Expression::Call(call) => {
+                // Methods are resolved only after type inference.
+                if let Expression::RecordDeconstruction(deconstruction) = call.function() {
+                    method_names.insert(deconstruction.field_name());
+                }
+            }
+            Expression::Variable(variable) if variable.name() != definition.name() => {
+                names.insert(variable.name());
+            }
+            _ => {}
+        });
+    }
+
+    // Default functions of record fields are used implicitly by record
+    // constructions.
+    let default_names = record_field_resolver::collect_default_function_names(module);
+
+    module
+        .function_definitions()
+        .iter()
+        .filter(|definition| {
+            !definition.is_public()
+                && definition.foreign_definition_configuration().is_none()
+                && !names.contains(definition.name())
+                && !method_names.contains(definition.original_name())
+                && !default_names.contains(definition.name())
+        })
+        .map(|definition| {
+            AnalysisWarning::UnusedFunction(
+                definition.original_name().into(),
+                definition.position().clone(),
+            )
+        })
+        .collect()
+}
+
+fn check_types(module: &Module) -> Vec<AnalysisWarning> {
+    let mut names = FnvHashSet::default();
+
+    type_visitor::visit(module, |type_| match type_ {
+        Type::Record(record) => {
+            names.insert(record.name());
+        }
+        Type::Reference(reference) => {
+            names.insert(reference.name());
+        }
+        _ => {}
+    });
+
+    module
+        .type_definitions()
+        .iter()
+        .filter(|definition| {
+            !definition.is_public()
+                && !definition.is_external()
+                && !names.contains(definition.name())
+        })
+        .map(|definition| {
+            AnalysisWarning::UnusedType(
+                definition.original_name().into(),
+                definition.position().clone(),
+            )
+        })
+        .chain(
+            module
+                .type_aliases()
+                .iter()
+                .filter(|alias| {
+                    !alias.is_public() && !alias.is_external() && !names.contains(alias.name())
+                })
+                .map(|alias| {
+                    AnalysisWarning::UnusedType(
+                        alias.original_name().into(),
+                        alias.position().clone(),
+                    )
+                }),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test::ModuleFake, types};
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn check_module(module: &Module) -> Vec<AnalysisWarning> {
+        check(module, &Default::default())
+    }
+
+    fn create_function_definition(
+        name: &str,
+        body: impl Into<Expression>,
+        public: bool,
+    ) -> FunctionDefinition {
+        FunctionDefinition::new(
+            name,
+            name,
+            Lambda::new(
+                vec![],
+                types::None::new(Position::fake()),
+                body,
+                Position::fake(),
+            ),
+            None,
+            public,
+            Position::fake(),
+        )
+    }
+
+    #[test]
+    fn check_unused_function() {
+        assert_eq!(
+            check_module(&Module::empty().set_function_definitions(vec![
+                create_function_definition("f", None::new(Position::fake()), false)
+            ])),
+            vec![AnalysisWarning::UnusedFunction(
+                "f".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn check_used_function() {
+        assert_eq!(
+            check_module(&Module::empty().set_function_definitions(vec![
+                create_function_definition("f", None::new(Position::fake()), false),
+                create_function_definition("g", Variable::new("f", Position::fake()), true),
+            ])),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_function_used_as_method() {
+        assert_eq!(
+            check_module(&Module::empty().set_function_definitions(vec![
+                create_function_definition("f", None::new(Position::fake()), false),
+                create_function_definition(
+                    "g",
+                    Call::new(
+                        None,
+                        RecordDeconstruction::new(
+                            None,
+                            Variable::new("x", Position::fake()),
+                            "f",
+                            Position::fake()
+                        ),
+                        vec![],
+                        Position::fake()
+                    ),
+                    true
+                ),
+            ])),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_recursive_function() {
+        assert_eq!(
+            check_module(&Module::empty().set_function_definitions(vec![
+                create_function_definition("f", Variable::new("f", Position::fake()), false)
+            ])),
+            vec![AnalysisWarning::UnusedFunction(
+                "f".into(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn check_unused_types() {
+        assert_eq!(
+            check_module(
+                &Module::empty()
+                    .set_type_definitions(vec![TypeDefinition::new(
+                        "a",
+                        "a",
+                        vec![],
+                        false,
+                        false,
+                        false,
+                        Position::fake()
+                    )])
+                    .set_type_aliases(vec![TypeAlias::new(
+                        "b",
+                        "b",
+                        types::None::new(Position::fake()),
+                        false,
+                        false,
+                        Position::fake()
+                    )])
+            ),
+            vec![
+                AnalysisWarning::UnusedType("a".into(), Position::fake()),
+                AnalysisWarning::UnusedType("b".into(), Position::fake()),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_used_type() {
+        assert_eq!(
+            check_module(
+                &Module::empty()
+                    .set_type_aliases(vec![TypeAlias::new(
+                        "b",
+                        "b",
+                        types::None::new(Position::fake()),
+                        false,
+                        false,
+                        Position::fake()
+                    )])
+                    .set_function_definitions(vec![FunctionDefinition::new(
+                        "f",
+                        "f",
+                        Lambda::new(
+                            vec![],
+                            types::Reference::new("b", Position::fake()),
+                            None::new(Position::fake()),
+                            Position::fake(),
+                        ),
+                        None,
+                        true,
+                        Position::fake(),
+                    )])
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn check_unused_public_type() {
+        assert_eq!(
+            check_module(
+                &Module::empty().set_type_definitions(vec![TypeDefinition::new(
+                    "a",
+                    "a",
+                    vec![],
+                    false,
+                    true,
+                    false,
+                    Position::fake()
+                )])
+            ),
+            vec![]
+        );
+    }
+}
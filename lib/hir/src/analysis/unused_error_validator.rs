@@ -1,47 +1,53 @@
 use super::{context::AnalysisContext, AnalysisError};
 use crate::{
-    analysis::{expression_visitor, type_canonicalizer, type_subsumption_checker},
+    analysis::{collect_results, expression_visitor, type_canonicalizer, type_subsumption_checker},
     ir::*,
     types,
 };
 
 pub fn validate(context: &AnalysisContext, module: &Module) -> Result<(), AnalysisError> {
-    for expression in collect_expressions(module) {
-        if let Expression::Let(let_) = expression {
-            let position = let_.position();
-            let expression = let_.bound_expression();
-            let type_ = let_
-                .type_()
-                .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+    collect_results(
+        collect_lets(module)
+            .iter()
+            .map(|let_| validate_let(context, let_)),
+    )?;
 
-            if let_.name().is_none()
-                && !type_canonicalizer::canonicalize(type_, context.types())?.is_any()
-                && type_subsumption_checker::check(
-                    &types::Error::new(position.clone()).into(),
-                    type_,
-                    context.types(),
-                )?
-            {
-                return Err(AnalysisError::UnusedErrorValue(
-                    expression.position().clone(),
-                ));
-            }
-        }
+    Ok(())
+}
+
+fn validate_let(context: &AnalysisContext, let_: &Let) -> Result<(), AnalysisError> {
+    let position = let_.position();
+    let expression = let_.bound_expression();
+    let type_ = let_
+        .type_()
+        .ok_or_else(|| AnalysisError::TypeNotInferred(position.clone()))?;
+
+    if let_.name().is_none()
+        && !type_canonicalizer::canonicalize(type_, context.types())?.is_any()
+        && type_subsumption_checker::check(
+            &types::Error::new(position.clone()).into(),
+            type_,
+            context.types(),
+        )?
+    {
+        return Err(AnalysisError::UnusedErrorValue(
+            expression.position().clone(),
+        ));
     }
 
     Ok(())
 }
 
-fn collect_expressions(module: &Module) -> Vec<Expression> {
-    let mut expressions = vec![];
+fn collect_lets(module: &Module) -> Vec<Let> {
+    let mut lets = vec![];
 
     expression_visitor::visit(module, |expression| {
-        if matches!(expression, Expression::Let(_)) {
-            expressions.push(expression.clone())
+        if let Expression::Let(let_) = expression {
+            lets.push(let_.clone())
         }
     });
 
-    expressions
+    lets
 }
 
 #[cfg(test)]
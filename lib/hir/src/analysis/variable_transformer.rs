@@ -6,6 +6,7 @@ pub fn transform(module: &Module, transform: &dyn Fn(&Variable) -> Expression) -
         module.type_aliases().to_vec(),
         module.foreign_declarations().to_vec(),
         module.function_declarations().to_vec(),
+        module.implementations().to_vec(),
         module
             .function_definitions()
             .iter()
@@ -166,21 +167,9 @@ fn transform_expression(
             list.position().clone(),
         )
         .into(),
-        Expression::ListComprehension(comprehension) => ListComprehension::new(
-            comprehension.input_type().cloned(),
-            comprehension.output_type().clone(),
-            transform_expression(comprehension.element(), &|variable| {
-                if comprehension.element_name() == variable.name() {
-                    variable.clone().into()
-                } else {
-                    transform(variable)
-                }
-            }),
-            comprehension.element_name(),
-            transform_expression(comprehension.list(), transform),
-            comprehension.position().clone(),
-        )
-        .into(),
+        Expression::ListComprehension(comprehension) => {
+            transform_list_comprehension(comprehension, transform).into()
+        }
         Expression::Map(map) => Map::new(
             map.key_type().clone(),
             map.value_type().clone(),
@@ -201,24 +190,9 @@ fn transform_expression(
             map.position().clone(),
         )
         .into(),
-        Expression::MapIterationComprehension(comprehension) => MapIterationComprehension::new(
-            comprehension.key_type().cloned(),
-            comprehension.value_type().cloned(),
-            comprehension.element_type().clone(),
-            transform_expression(comprehension.element(), &|variable| {
-                if [comprehension.key_name(), comprehension.value_name()].contains(&variable.name())
-                {
-                    variable.clone().into()
-                } else {
-                    transform(variable)
-                }
-            }),
-            comprehension.key_name(),
-            comprehension.value_name(),
-            transform_expression(comprehension.map(), transform),
-            comprehension.position().clone(),
-        )
-        .into(),
+        Expression::MapComprehension(comprehension) => {
+            transform_map_comprehension(comprehension, transform).into()
+        }
         Expression::Operation(operation) => transform_operation(operation, transform).into(),
         Expression::RecordConstruction(construction) => RecordConstruction::new(
             construction.type_().clone(),
@@ -277,17 +251,99 @@ fn transform_expression(
         Expression::Boolean(_)
         | Expression::BuiltInFunction(_)
         | Expression::String(_)
+        | Expression::Integer(_)
         | Expression::None(_)
         | Expression::Number(_) => expression.clone(),
     }
 }
 
+fn transform_list_comprehension(
+    comprehension: &ListComprehension,
+    transform: &dyn Fn(&Variable) -> Expression,
+) -> ListComprehension {
+    let (branches, names) =
+        transform_list_comprehension_branches(comprehension.branches(), transform);
+
+    ListComprehension::new(
+        comprehension.type_().clone(),
+        transform_scoped_expression(comprehension.element(), &names, transform),
+        branches,
+        comprehension.position().clone(),
+    )
+}
+
+fn transform_map_comprehension(
+    comprehension: &MapComprehension,
+    transform: &dyn Fn(&Variable) -> Expression,
+) -> MapComprehension {
+    let (branches, names) =
+        transform_list_comprehension_branches(comprehension.branches(), transform);
+
+    MapComprehension::new(
+        comprehension.key_type().clone(),
+        comprehension.value_type().clone(),
+        transform_scoped_expression(comprehension.key(), &names, transform),
+        transform_scoped_expression(comprehension.value(), &names, transform),
+        branches,
+        comprehension.position().clone(),
+    )
+}
+
+fn transform_list_comprehension_branches<'a>(
+    branches: &'a [ListComprehensionBranch],
+    transform: &dyn Fn(&Variable) -> Expression,
+) -> (Vec<ListComprehensionBranch>, Vec<&'a str>) {
+    let mut names = vec![];
+    let mut transformed_branches = vec![];
+
+    for branch in branches {
+        let iteratees = branch
+            .iteratees()
+            .iter()
+            .map(|iteratee| {
+                ListComprehensionIteratee::new(
+                    iteratee.type_().cloned(),
+                    transform_scoped_expression(iteratee.expression(), &names, transform),
+                )
+            })
+            .collect();
+
+        names.extend(branch.names().iter().map(String::as_str));
+
+        transformed_branches.push(ListComprehensionBranch::new(
+            branch.names().to_vec(),
+            iteratees,
+            branch
+                .condition()
+                .map(|condition| transform_scoped_expression(condition, &names, transform)),
+            branch.position().clone(),
+        ));
+    }
+
+    (transformed_branches, names)
+}
+
+fn transform_scoped_expression(
+    expression: &Expression,
+    names: &[&str],
+    transform: &dyn Fn(&Variable) -> Expression,
+) -> Expression {
+    transform_expression(expression, &|variable| {
+        if names.contains(&variable.name()) {
+            variable.clone().into()
+        } else {
+            transform(variable)
+        }
+    })
+}
+
 fn transform_operation(
     operation: &Operation,
     transform: &dyn Fn(&Variable) -> Expression,
 ) -> Operation {
     match operation {
         Operation::Arithmetic(operation) => ArithmeticOperation::new(
+            operation.type_().cloned(),
             operation.operator(),
             transform_expression(operation.lhs(), transform),
             transform_expression(operation.rhs(), transform),
@@ -315,6 +371,7 @@ fn transform_operation(
         )
         .into(),
         Operation::Order(operation) => OrderOperation::new(
+            operation.type_().cloned(),
             operation.operator(),
             transform_expression(operation.lhs(), transform),
             transform_expression(operation.rhs(), transform),
@@ -462,11 +519,17 @@ mod tests {
                 vec![],
                 types::None::new(Position::fake()),
                 ListComprehension::new(
-                    None,
                     types::None::new(Position::fake()),
                     Variable::new("x", Position::fake()),
-                    "x",
-                    Variable::new("xs", Position::fake()),
+                    vec![ListComprehensionBranch::new(
+                        vec!["x".into()],
+                        vec![ListComprehensionIteratee::new(
+                            None,
+                            Variable::new("xs", Position::fake()),
+                        )],
+                        None,
+                        Position::fake(),
+                    )],
                     Position::fake(),
                 ),
                 Position::fake(),
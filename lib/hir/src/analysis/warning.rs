@@ -0,0 +1,93 @@
+use position::Position;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnalysisWarning {
+    RedundantBranch(Position),
+    ShadowedVariable(String, Position),
+    UnusedFunction(String, Position),
+    UnusedImport(String, Position),
+    UnusedType(String, Position),
+    UnusedUnqualifiedName(String, Position),
+    UnusedVariable(String, Position),
+}
+
+impl AnalysisWarning {
+    pub fn position(&self) -> &Position {
+        match self {
+            Self::RedundantBranch(position) => position,
+            Self::ShadowedVariable(_, position)
+            | Self::UnusedFunction(_, position)
+            | Self::UnusedImport(_, position)
+            | Self::UnusedType(_, position)
+            | Self::UnusedUnqualifiedName(_, position)
+            | Self::UnusedVariable(_, position) => position,
+        }
+    }
+
+    // Codes are stable across versions. Never reuse codes of removed warnings.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnusedVariable(..) => "W0001",
+            Self::UnusedImport(..) => "W0002",
+            Self::UnusedUnqualifiedName(..) => "W0003",
+            Self::UnusedFunction(..) => "W0004",
+            Self::UnusedType(..) => "W0005",
+            Self::ShadowedVariable(..) => "W0006",
+            Self::RedundantBranch(_) => "W0007",
+        }
+    }
+}
+
+impl Display for AnalysisWarning {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "[{}] ", self.code())?;
+
+        match self {
+            Self::RedundantBranch(position) => {
+                write!(
+                    formatter,
+                    "redundant branch in if-type expression\n{}",
+                    position
+                )
+            }
+            Self::ShadowedVariable(name, position) => {
+                write!(formatter, "variable \"{}\" shadowed\n{}", name, position)
+            }
+            Self::UnusedFunction(name, position) => {
+                write!(formatter, "unused function \"{}\"\n{}", name, position)
+            }
+            Self::UnusedImport(path, position) => {
+                write!(formatter, "unused import \"{}\"\n{}", path, position)
+            }
+            Self::UnusedType(name, position) => {
+                write!(formatter, "unused type \"{}\"\n{}", name, position)
+            }
+            Self::UnusedUnqualifiedName(name, position) => {
+                write!(
+                    formatter,
+                    "unused unqualified name \"{}\"\n{}",
+                    name, position
+                )
+            }
+            Self::UnusedVariable(name, position) => {
+                write!(formatter, "unused variable \"{}\"\n{}", name, position)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::test::PositionFake;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display_warning() {
+        assert_eq!(
+            AnalysisWarning::UnusedVariable("x".into(), Position::fake()).to_string(),
+            format!("[W0001] unused variable \"x\"\n{}", Position::fake())
+        );
+    }
+}
@@ -0,0 +1,22 @@
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WarningConfiguration {
+    pub unused_variables: bool,
+    pub unused_imports: bool,
+    pub unused_functions: bool,
+    pub unused_types: bool,
+    pub shadowed_variables: bool,
+    pub redundant_branches: bool,
+}
+
+impl Default for WarningConfiguration {
+    fn default() -> Self {
+        Self {
+            unused_variables: true,
+            unused_imports: true,
+            unused_functions: true,
+            unused_types: true,
+            shadowed_variables: true,
+            redundant_branches: true,
+        }
+    }
+}
@@ -17,15 +17,19 @@ mod if_list;
 mod if_map;
 mod if_type;
 mod if_type_branch;
+mod implementation;
+mod integer;
 mod lambda;
 mod let_;
 mod list;
 mod list_comprehension;
+mod list_comprehension_branch;
+mod list_comprehension_iteratee;
 mod list_element;
 mod map;
+mod map_comprehension;
 mod map_element;
 mod map_entry;
-mod map_iteration_comprehension;
 mod module;
 mod none;
 mod not_operation;
@@ -63,15 +67,19 @@ pub use if_list::*;
 pub use if_map::*;
 pub use if_type::*;
 pub use if_type_branch::*;
+pub use implementation::*;
+pub use integer::*;
 pub use lambda::*;
 pub use let_::*;
 pub use list::*;
 pub use list_comprehension::*;
+pub use list_comprehension_branch::*;
+pub use list_comprehension_iteratee::*;
 pub use list_element::*;
 pub use map::*;
+pub use map_comprehension::*;
 pub use map_element::*;
 pub use map_entry::*;
-pub use map_iteration_comprehension::*;
 pub use module::*;
 pub use none::*;
 pub use not_operation::*;
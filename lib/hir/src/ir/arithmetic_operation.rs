@@ -1,4 +1,5 @@
 use super::expression::Expression;
+use crate::types::Type;
 use position::Position;
 use std::sync::Arc;
 
@@ -8,10 +9,17 @@ pub enum ArithmeticOperator {
     Subtract,
     Multiply,
     Divide,
+    Remainder,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    LeftShift,
+    RightShift,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ArithmeticOperation {
+    type_: Option<Type>,
     operator: ArithmeticOperator,
     lhs: Arc<Expression>,
     rhs: Arc<Expression>,
@@ -20,12 +28,14 @@ pub struct ArithmeticOperation {
 
 impl ArithmeticOperation {
     pub fn new(
+        type_: Option<Type>,
         operator: ArithmeticOperator,
         lhs: impl Into<Expression>,
         rhs: impl Into<Expression>,
         position: Position,
     ) -> Self {
         Self {
+            type_,
             operator,
             lhs: lhs.into().into(),
             rhs: rhs.into().into(),
@@ -33,6 +43,10 @@ impl ArithmeticOperation {
         }
     }
 
+    pub fn type_(&self) -> Option<&Type> {
+        self.type_.as_ref()
+    }
+
     pub fn operator(&self) -> ArithmeticOperator {
         self.operator
     }
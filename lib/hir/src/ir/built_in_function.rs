@@ -2,12 +2,17 @@ use position::Position;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum BuiltInFunctionName {
+    Byte,
+    ConcatenateStrings,
     Debug,
     Error,
+    I64,
+    Number,
     Race,
     Size,
     Source,
     Spawn,
+    U64,
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -1,10 +1,9 @@
 use super::{
     boolean::Boolean, call::Call, if_::If, if_list::IfList, if_map::IfMap, if_type::IfType,
-    list::List, map::Map, none::None, number::Number, operation::Operation,
+    integer::Integer, list::List, map::Map, none::None, number::Number, operation::Operation,
     record_construction::RecordConstruction, record_deconstruction::RecordDeconstruction,
     record_update::RecordUpdate, string::ByteString, type_coercion::TypeCoercion,
-    variable::Variable, BuiltInFunction, Lambda, Let, ListComprehension, MapIterationComprehension,
-    Thunk,
+    variable::Variable, BuiltInFunction, Lambda, Let, ListComprehension, MapComprehension, Thunk,
 };
 use position::Position;
 
@@ -17,12 +16,13 @@ pub enum Expression {
     IfList(IfList),
     IfMap(IfMap),
     IfType(IfType),
+    Integer(Integer),
     Lambda(Lambda),
     Let(Let),
     List(List),
     ListComprehension(ListComprehension),
     Map(Map),
-    MapIterationComprehension(MapIterationComprehension),
+    MapComprehension(MapComprehension),
     None(None),
     Number(Number),
     Operation(Operation),
@@ -45,12 +45,13 @@ impl Expression {
             Self::IfList(if_) => if_.position(),
             Self::IfMap(if_) => if_.position(),
             Self::IfType(if_) => if_.position(),
+            Self::Integer(integer) => integer.position(),
             Self::Lambda(lambda) => lambda.position(),
             Self::Let(let_) => let_.position(),
             Self::List(list) => list.position(),
             Self::ListComprehension(comprehension) => comprehension.position(),
             Self::Map(map) => map.position(),
-            Self::MapIterationComprehension(comprehension) => comprehension.position(),
+            Self::MapComprehension(comprehension) => comprehension.position(),
             Self::None(none) => none.position(),
             Self::Number(number) => number.position(),
             Self::Operation(operation) => operation.position(),
@@ -131,6 +132,12 @@ impl From<IfMap> for Expression {
     }
 }
 
+impl From<Integer> for Expression {
+    fn from(integer: Integer) -> Self {
+        Self::Integer(integer)
+    }
+}
+
 impl From<Lambda> for Expression {
     fn from(lambda: Lambda) -> Self {
         Self::Lambda(lambda)
@@ -161,9 +168,9 @@ impl From<Map> for Expression {
     }
 }
 
-impl From<MapIterationComprehension> for Expression {
-    fn from(comprehension: MapIterationComprehension) -> Self {
-        Self::MapIterationComprehension(comprehension)
+impl From<MapComprehension> for Expression {
+    fn from(comprehension: MapComprehension) -> Self {
+        Self::MapComprehension(comprehension)
     }
 }
 
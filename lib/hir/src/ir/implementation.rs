@@ -0,0 +1,50 @@
+use crate::types::Type;
+use position::Position;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct Implementation {
+    name: String,
+    interface: Type,
+    type_: Type,
+    external: bool,
+    position: Position,
+}
+
+impl Implementation {
+    pub fn new(
+        name: impl Into<String>,
+        interface: impl Into<Type>,
+        type_: impl Into<Type>,
+        external: bool,
+        position: Position,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            interface: interface.into(),
+            type_: type_.into(),
+            external,
+            position,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn interface(&self) -> &Type {
+        &self.interface
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn is_external(&self) -> bool {
+        self.external
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
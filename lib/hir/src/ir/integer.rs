@@ -0,0 +1,32 @@
+use crate::types::IntegerKind;
+use position::Position;
+
+// Values of signed integers are represented in two's complement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Integer {
+    kind: IntegerKind,
+    value: u64,
+    position: Position,
+}
+
+impl Integer {
+    pub fn new(kind: IntegerKind, value: u64, position: Position) -> Self {
+        Self {
+            kind,
+            value,
+            position,
+        }
+    }
+
+    pub fn kind(&self) -> IntegerKind {
+        self.kind
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
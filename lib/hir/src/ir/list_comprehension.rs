@@ -1,55 +1,41 @@
-use super::expression::Expression;
+use super::{expression::Expression, ListComprehensionBranch};
 use crate::types::Type;
 use position::Position;
 use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ListComprehension {
-    input_type: Option<Type>,
-    output_type: Type,
+    type_: Type,
     element: Arc<Expression>,
-    element_name: String,
-    list: Arc<Expression>,
+    branches: Vec<ListComprehensionBranch>,
     position: Position,
 }
 
 impl ListComprehension {
     pub fn new(
-        input_type: Option<Type>,
-        output_type: impl Into<Type>,
+        type_: impl Into<Type>,
         element: impl Into<Expression>,
-        element_name: impl Into<String>,
-        list: impl Into<Expression>,
+        branches: Vec<ListComprehensionBranch>,
         position: Position,
     ) -> Self {
         Self {
-            input_type,
-            output_type: output_type.into(),
+            type_: type_.into(),
             element: element.into().into(),
-            element_name: element_name.into(),
-            list: list.into().into(),
+            branches,
             position,
         }
     }
 
-    pub fn input_type(&self) -> Option<&Type> {
-        self.input_type.as_ref()
-    }
-
-    pub fn output_type(&self) -> &Type {
-        &self.output_type
+    pub fn type_(&self) -> &Type {
+        &self.type_
     }
 
     pub fn element(&self) -> &Expression {
         &self.element
     }
 
-    pub fn element_name(&self) -> &str {
-        &self.element_name
-    }
-
-    pub fn list(&self) -> &Expression {
-        &self.list
+    pub fn branches(&self) -> &[ListComprehensionBranch] {
+        &self.branches
     }
 
     pub fn position(&self) -> &Position {
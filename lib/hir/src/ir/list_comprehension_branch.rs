@@ -0,0 +1,44 @@
+use super::{expression::Expression, ListComprehensionIteratee};
+use position::Position;
+
+// A branch iterates over either a map with key and value names or lists with
+// the same number of names.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListComprehensionBranch {
+    names: Vec<String>,
+    iteratees: Vec<ListComprehensionIteratee>,
+    condition: Option<Expression>,
+    position: Position,
+}
+
+impl ListComprehensionBranch {
+    pub fn new(
+        names: Vec<String>,
+        iteratees: Vec<ListComprehensionIteratee>,
+        condition: Option<Expression>,
+        position: Position,
+    ) -> Self {
+        Self {
+            names,
+            iteratees,
+            condition,
+            position,
+        }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn iteratees(&self) -> &[ListComprehensionIteratee] {
+        &self.iteratees
+    }
+
+    pub fn condition(&self) -> Option<&Expression> {
+        self.condition.as_ref()
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+}
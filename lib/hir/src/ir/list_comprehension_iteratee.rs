@@ -0,0 +1,25 @@
+use super::expression::Expression;
+use crate::types::Type;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListComprehensionIteratee {
+    type_: Option<Type>,
+    expression: Expression,
+}
+
+impl ListComprehensionIteratee {
+    pub fn new(type_: Option<Type>, expression: impl Into<Expression>) -> Self {
+        Self {
+            type_,
+            expression: expression.into(),
+        }
+    }
+
+    pub fn type_(&self) -> Option<&Type> {
+        self.type_.as_ref()
+    }
+
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
     function_definition::FunctionDefinition, type_definition::TypeDefinition, ForeignDeclaration,
-    FunctionDeclaration, TypeAlias,
+    FunctionDeclaration, Implementation, TypeAlias,
 };
 use position::Position;
 
@@ -10,6 +10,7 @@ pub struct Module {
     type_aliases: Vec<TypeAlias>,
     foreign_declarations: Vec<ForeignDeclaration>,
     function_declarations: Vec<FunctionDeclaration>,
+    implementations: Vec<Implementation>,
     function_definitions: Vec<FunctionDefinition>,
     position: Position,
 }
@@ -20,6 +21,7 @@ impl Module {
         type_aliases: Vec<TypeAlias>,
         foreign_declarations: Vec<ForeignDeclaration>,
         function_declarations: Vec<FunctionDeclaration>,
+        implementations: Vec<Implementation>,
         function_definitions: Vec<FunctionDefinition>,
         position: Position,
     ) -> Self {
@@ -28,6 +30,7 @@ impl Module {
             type_aliases,
             foreign_declarations,
             function_declarations,
+            implementations,
             function_definitions,
             position,
         }
@@ -49,6 +52,10 @@ impl Module {
         &self.function_declarations
     }
 
+    pub fn implementations(&self) -> &[Implementation] {
+        &self.implementations
+    }
+
     pub fn function_definitions(&self) -> &[FunctionDefinition] {
         &self.function_definitions
     }
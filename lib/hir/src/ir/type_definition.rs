@@ -6,10 +6,12 @@ use serde::{Deserialize, Serialize};
 pub struct TypeDefinition {
     name: String,
     original_name: String,
+    type_parameters: Vec<String>,
     fields: Vec<types::RecordField>,
     open: bool,
     public: bool,
     external: bool,
+    newtype: bool,
     position: Position,
 }
 
@@ -26,10 +28,12 @@ impl TypeDefinition {
         Self {
             name: name.into(),
             original_name: original_name.into(),
+            type_parameters: vec![],
             fields,
             open,
             public,
             external,
+            newtype: false,
             position,
         }
     }
@@ -42,6 +46,10 @@ impl TypeDefinition {
         &self.original_name
     }
 
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
     pub fn fields(&self) -> &[types::RecordField] {
         &self.fields
     }
@@ -58,7 +66,21 @@ impl TypeDefinition {
         self.external
     }
 
+    pub fn is_newtype(&self) -> bool {
+        self.newtype
+    }
+
     pub fn position(&self) -> &Position {
         &self.position
     }
+
+    pub fn set_newtype(mut self, newtype: bool) -> Self {
+        self.newtype = newtype;
+        self
+    }
+
+    pub fn set_type_parameters(mut self, parameters: Vec<String>) -> Self {
+        self.type_parameters = parameters;
+        self
+    }
 }
@@ -16,13 +16,24 @@ pub trait ModuleFake {
     #[must_use]
     fn set_function_declarations(&self, declarations: Vec<FunctionDeclaration>) -> Self;
 
+    #[must_use]
+    fn set_implementations(&self, implementations: Vec<Implementation>) -> Self;
+
     #[must_use]
     fn set_function_definitions(&self, definitions: Vec<FunctionDefinition>) -> Self;
 }
 
 impl ModuleFake for Module {
     fn empty() -> Self {
-        Self::new(vec![], vec![], vec![], vec![], vec![], Position::fake())
+        Self::new(
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            Position::fake(),
+        )
     }
 
     fn set_type_definitions(&self, type_definitions: Vec<TypeDefinition>) -> Self {
@@ -31,6 +42,7 @@ impl ModuleFake for Module {
             self.type_aliases().to_vec(),
             self.foreign_declarations().to_vec(),
             self.function_declarations().to_vec(),
+            self.implementations().to_vec(),
             self.function_definitions().to_vec(),
             self.position().clone(),
         )
@@ -42,6 +54,7 @@ impl ModuleFake for Module {
             type_aliases,
             self.foreign_declarations().to_vec(),
             self.function_declarations().to_vec(),
+            self.implementations().to_vec(),
             self.function_definitions().to_vec(),
             self.position().clone(),
         )
@@ -53,6 +66,7 @@ impl ModuleFake for Module {
             self.type_aliases().to_vec(),
             declarations,
             self.function_declarations().to_vec(),
+            self.implementations().to_vec(),
             self.function_definitions().to_vec(),
             self.position().clone(),
         )
@@ -64,6 +78,19 @@ impl ModuleFake for Module {
             self.type_aliases().to_vec(),
             self.foreign_declarations().to_vec(),
             declarations,
+            self.implementations().to_vec(),
+            self.function_definitions().to_vec(),
+            self.position().clone(),
+        )
+    }
+
+    fn set_implementations(&self, implementations: Vec<Implementation>) -> Self {
+        Self::new(
+            self.type_definitions().to_vec(),
+            self.type_aliases().to_vec(),
+            self.foreign_declarations().to_vec(),
+            self.function_declarations().to_vec(),
+            implementations,
             self.function_definitions().to_vec(),
             self.position().clone(),
         )
@@ -75,6 +102,7 @@ impl ModuleFake for Module {
             self.type_aliases().to_vec(),
             self.foreign_declarations().to_vec(),
             self.function_declarations().to_vec(),
+            self.implementations().to_vec(),
             definitions,
             self.position().clone(),
         )
@@ -3,6 +3,7 @@ mod boolean;
 mod byte_string;
 mod error;
 mod function;
+mod integer;
 mod list;
 mod map;
 mod none;
@@ -12,12 +13,14 @@ mod record_field;
 mod reference;
 mod type_;
 mod union;
+mod variable;
 
 pub use any::*;
 pub use boolean::*;
 pub use byte_string::*;
 pub use error::*;
 pub use function::*;
+pub use integer::*;
 pub use list::*;
 pub use map::*;
 pub use none::*;
@@ -27,3 +30,4 @@ pub use record_field::*;
 pub use reference::*;
 pub use type_::*;
 pub use union::*;
+pub use variable::*;
@@ -0,0 +1,44 @@
+use position::Position;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum IntegerKind {
+    Byte,
+    I64,
+    U64,
+}
+
+impl IntegerKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Byte => "byte",
+            Self::I64 => "i64",
+            Self::U64 => "u64",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Integer {
+    kind: IntegerKind,
+    position: Position,
+}
+
+impl Integer {
+    pub fn new(kind: IntegerKind, position: Position) -> Self {
+        Self { kind, position }
+    }
+
+    pub fn kind(&self) -> IntegerKind {
+        self.kind
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn set_position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+}
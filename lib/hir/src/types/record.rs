@@ -1,9 +1,12 @@
+use super::Type;
 use position::Position;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct Record {
     name: String,
+    arguments: Arc<Vec<Type>>,
     position: Position,
 }
 
@@ -11,6 +14,7 @@ impl Record {
     pub fn new(name: impl Into<String>, position: Position) -> Self {
         Self {
             name: name.into(),
+            arguments: Arc::new(vec![]),
             position,
         }
     }
@@ -19,10 +23,19 @@ impl Record {
         &self.name
     }
 
+    pub fn arguments(&self) -> &[Type] {
+        &self.arguments
+    }
+
     pub fn position(&self) -> &Position {
         &self.position
     }
 
+    pub fn set_arguments(mut self, arguments: Vec<Type>) -> Self {
+        self.arguments = Arc::new(arguments);
+        self
+    }
+
     pub fn set_position(mut self, position: Position) -> Self {
         self.position = position;
         self
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 pub struct RecordField {
     name: String,
     type_: Type,
+    default: bool,
 }
 
 impl RecordField {
@@ -12,6 +13,7 @@ impl RecordField {
         Self {
             name: name.into(),
             type_: type_.into(),
+            default: false,
         }
     }
 
@@ -22,4 +24,13 @@ impl RecordField {
     pub fn type_(&self) -> &Type {
         &self.type_
     }
+
+    pub fn has_default(&self) -> bool {
+        self.default
+    }
+
+    pub fn set_default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
 }
@@ -1,7 +1,7 @@
 use super::{
     any::Any, boolean::Boolean, byte_string::ByteString, error::Error, function::Function,
-    list::List, map::Map, none::None, number::Number, record::Record, reference::Reference,
-    union::Union,
+    integer::Integer, list::List, map::Map, none::None, number::Number, record::Record,
+    reference::Reference, union::Union, variable::Variable,
 };
 use position::Position;
 use serde::{Deserialize, Serialize};
@@ -12,6 +12,7 @@ pub enum Type {
     Boolean(Boolean),
     Error(Error),
     Function(Function),
+    Integer(Integer),
     List(List),
     Map(Map),
     None(None),
@@ -20,6 +21,7 @@ pub enum Type {
     Reference(Reference),
     String(ByteString),
     Union(Union),
+    Variable(Variable),
 }
 
 impl Type {
@@ -29,6 +31,7 @@ impl Type {
             Self::Boolean(boolean) => boolean.position(),
             Self::Error(error) => error.position(),
             Self::Function(function) => function.position(),
+            Self::Integer(integer) => integer.position(),
             Self::List(list) => list.position(),
             Self::Map(map) => map.position(),
             Self::None(none) => none.position(),
@@ -37,6 +40,7 @@ impl Type {
             Self::Reference(reference) => reference.position(),
             Self::String(string) => string.position(),
             Self::Union(union) => union.position(),
+            Self::Variable(variable) => variable.position(),
         }
     }
 
@@ -46,6 +50,7 @@ impl Type {
             Self::Boolean(boolean) => boolean.set_position(position).into(),
             Self::Error(error) => error.set_position(position).into(),
             Self::Function(function) => function.set_position(position).into(),
+            Self::Integer(integer) => integer.set_position(position).into(),
             Self::List(list) => list.set_position(position).into(),
             Self::Map(map) => map.set_position(position).into(),
             Self::None(none) => none.set_position(position).into(),
@@ -54,6 +59,7 @@ impl Type {
             Self::Reference(reference) => reference.set_position(position).into(),
             Self::String(string) => string.set_position(position).into(),
             Self::Union(union) => union.set_position(position).into(),
+            Self::Variable(variable) => variable.set_position(position).into(),
         }
     }
 
@@ -109,6 +115,10 @@ impl Type {
         matches!(self, Self::Union(_))
     }
 
+    pub fn is_variable(&self) -> bool {
+        matches!(self, Self::Variable(_))
+    }
+
     pub fn is_variant(&self) -> bool {
         self.is_any() || self.is_union()
     }
@@ -144,6 +154,12 @@ impl From<Function> for Type {
     }
 }
 
+impl From<Integer> for Type {
+    fn from(integer: Integer) -> Self {
+        Self::Integer(integer)
+    }
+}
+
 impl From<List> for Type {
     fn from(list: List) -> Self {
         Self::List(list)
@@ -185,3 +201,9 @@ impl From<Union> for Type {
         Self::Union(union)
     }
 }
+
+impl From<Variable> for Type {
+    fn from(variable: Variable) -> Self {
+        Self::Variable(variable)
+    }
+}
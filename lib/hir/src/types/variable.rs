@@ -0,0 +1,42 @@
+use super::Type;
+use position::Position;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Variable {
+    name: String,
+    constraint: Option<Box<Type>>,
+    position: Position,
+}
+
+impl Variable {
+    pub fn new(name: impl Into<String>, position: Position) -> Self {
+        Self {
+            name: name.into(),
+            constraint: None,
+            position,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn constraint(&self) -> Option<&Type> {
+        self.constraint.as_deref()
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn set_constraint(mut self, constraint: Option<Type>) -> Self {
+        self.constraint = constraint.map(Box::new);
+        self
+    }
+
+    pub fn set_position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+}
@@ -2,8 +2,8 @@ use super::error::InfrastructureError;
 use crate::FilePathConverter;
 use std::{
     error::Error,
-    io::{stderr, stdout, Write},
-    process::{Command, Stdio},
+    io::{self, stderr, stdout, Write},
+    process::{Command, ExitStatus, Stdio},
     sync::Arc,
 };
 
@@ -29,6 +29,48 @@ impl app::infra::CommandRunner for CommandRunner {
 
         Ok(())
     }
+
+    fn run_application(
+        &self,
+        executable_file: &app::infra::FilePath,
+        runner: Option<&str>,
+        arguments: &[String],
+    ) -> Result<Option<i32>, Box<dyn Error>> {
+        let executable_file = self.file_path_converter.convert_to_os_path(executable_file);
+        let mut command = if let Some(runner) = runner {
+            let mut command = Command::new(runner);
+            command.arg(&executable_file);
+            command
+        } else {
+            Command::new(&executable_file)
+        };
+
+        Ok(convert_exit_status(
+            command
+                .args(arguments)
+                .status()
+                .map_err(|error| match error.kind() {
+                    io::ErrorKind::NotFound => InfrastructureError::CommandNotFound(
+                        runner
+                            .map(From::from)
+                            .unwrap_or_else(|| executable_file.display().to_string()),
+                    )
+                    .into(),
+                    _ => Box::<dyn Error>::from(error),
+                })?,
+        ))
+    }
+}
+
+// Processes terminated by signals are reported with exit codes of 128 plus
+// signal numbers as shells do.
+fn convert_exit_status(status: ExitStatus) -> Option<i32> {
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return Some(128 + signal);
+    }
+
+    status.code()
 }
 
 pub fn run_command(command: &mut Command) -> Result<String, Box<dyn Error>> {
@@ -46,3 +88,78 @@ pub fn run_command(command: &mut Command) -> Result<String, Box<dyn Error>> {
     }
     .into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use app::infra::CommandRunner as _;
+    use std::{fs, os::unix::fs::PermissionsExt};
+
+    const SCRIPT: &str = "#!/bin/sh\necho \"$@\" > \"$(dirname \"$0\")/arguments.txt\"\nexit 42\n";
+
+    fn run_script(runner: Option<&str>, executable: bool) -> (Option<i32>, String) {
+        run_source(SCRIPT, runner, executable)
+    }
+
+    fn run_source(source: &str, runner: Option<&str>, executable: bool) -> (Option<i32>, String) {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("app");
+
+        fs::write(&path, source).unwrap();
+
+        if executable {
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let status_code = CommandRunner::new(FilePathConverter::new(directory.path()).into())
+            .run_application(
+                &app::infra::FilePath::new(["app"]),
+                runner,
+                &["foo".into(), "bar baz".into()],
+            )
+            .unwrap();
+
+        (
+            status_code,
+            fs::read_to_string(directory.path().join("arguments.txt")).unwrap_or_default(),
+        )
+    }
+
+    #[test]
+    fn run_application() {
+        assert_eq!(run_script(None, true), (Some(42), "foo bar baz\n".into()));
+    }
+
+    #[test]
+    fn run_application_with_runner() {
+        assert_eq!(
+            run_script(Some("sh"), false),
+            (Some(42), "foo bar baz\n".into())
+        );
+    }
+
+    #[test]
+    fn run_application_terminated_by_signal() {
+        assert_eq!(
+            run_source("#!/bin/sh\nkill -TERM $$\n", None, true),
+            (Some(143), "".into())
+        );
+    }
+
+    #[test]
+    fn fail_to_run_application_with_missing_runner() {
+        let directory = tempfile::tempdir().unwrap();
+
+        assert_eq!(
+            CommandRunner::new(FilePathConverter::new(directory.path()).into())
+                .run_application(
+                    &app::infra::FilePath::new(["app"]),
+                    Some("pen-missing-runner"),
+                    &[],
+                )
+                .unwrap_err()
+                .to_string(),
+            "command \"pen-missing-runner\" not found"
+        );
+    }
+}
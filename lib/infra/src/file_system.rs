@@ -1,5 +1,5 @@
 use super::{error::InfrastructureError, file_path_converter::FilePathConverter};
-use std::{io::Error, sync::Arc};
+use std::{io::Error, sync::Arc, time::SystemTime};
 
 pub struct FileSystem {
     file_path_converter: Arc<FilePathConverter>,
@@ -76,6 +76,17 @@ impl app::infra::FileSystem for FileSystem {
             .map_err(|source| InfrastructureError::ReadFile { path, source })?)
     }
 
+    fn modified_time(
+        &self,
+        file_path: &app::infra::FilePath,
+    ) -> Result<SystemTime, Box<dyn std::error::Error>> {
+        let path = self.file_path_converter.convert_to_os_path(file_path);
+
+        Ok(std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|source| InfrastructureError::ReadFile { path, source })?)
+    }
+
     fn write(
         &self,
         file_path: &app::infra::FilePath,
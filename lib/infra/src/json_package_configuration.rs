@@ -29,17 +29,75 @@ impl From<JsonPackageType> for app::PackageType {
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonWarningConfiguration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_variables: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_imports: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_functions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_types: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadowed_variables: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redundant_branches: Option<bool>,
+}
+
+impl From<&app::WarningConfiguration> for JsonWarningConfiguration {
+    fn from(configuration: &app::WarningConfiguration) -> Self {
+        Self {
+            unused_variables: Some(configuration.unused_variables),
+            unused_imports: Some(configuration.unused_imports),
+            unused_functions: Some(configuration.unused_functions),
+            unused_types: Some(configuration.unused_types),
+            shadowed_variables: Some(configuration.shadowed_variables),
+            redundant_branches: Some(configuration.redundant_branches),
+        }
+    }
+}
+
+impl From<JsonWarningConfiguration> for app::WarningConfiguration {
+    fn from(configuration: JsonWarningConfiguration) -> Self {
+        let default = Self::default();
+
+        Self {
+            unused_variables: configuration
+                .unused_variables
+                .unwrap_or(default.unused_variables),
+            unused_imports: configuration
+                .unused_imports
+                .unwrap_or(default.unused_imports),
+            unused_functions: configuration
+                .unused_functions
+                .unwrap_or(default.unused_functions),
+            unused_types: configuration.unused_types.unwrap_or(default.unused_types),
+            shadowed_variables: configuration
+                .shadowed_variables
+                .unwrap_or(default.shadowed_variables),
+            redundant_branches: configuration
+                .redundant_branches
+                .unwrap_or(default.redundant_branches),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JsonPackageConfiguration {
     #[serde(rename = "type")]
     pub type_: JsonPackageType,
     pub dependencies: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<JsonWarningConfiguration>,
 }
 
 impl JsonPackageConfiguration {
     pub fn new(
         type_: impl Into<JsonPackageType>,
         dependencies: BTreeMap<String, url::Url>,
+        warnings: &app::WarningConfiguration,
     ) -> Self {
         Self {
             type_: type_.into(),
@@ -47,6 +105,7 @@ impl JsonPackageConfiguration {
                 .iter()
                 .map(|(name, url)| (name.clone(), url.as_str().into()))
                 .collect(),
+            warnings: (warnings != &Default::default()).then(|| warnings.into()),
         }
     }
 
@@ -65,12 +124,29 @@ impl JsonPackageConfiguration {
                     ))
                 })
                 .collect::<Result<_, url::ParseError>>()?,
+            self.warnings.unwrap_or_default().into(),
         ))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_warning_configuration() {
+        assert_eq!(
+            app::WarningConfiguration::from(
+                serde_json::from_str::<JsonWarningConfiguration>(r#"{"unusedImports":false}"#)
+                    .unwrap()
+            ),
+            app::WarningConfiguration {
+                unused_imports: false,
+                ..Default::default()
+            }
+        );
+    }
+
     #[test]
     fn parse_relative_path() {
         assert_eq!(
@@ -31,6 +31,7 @@ impl app::infra::PackageConfigurationWriter for JsonPackageConfigurationWriter {
             (serde_json::to_string_pretty(&JsonPackageConfiguration::new(
                 configuration.type_(),
                 configuration.dependencies().clone(),
+                configuration.warnings(),
             ))? + "\n")
                 .as_bytes(),
         )?;
@@ -21,6 +21,22 @@ pub fn log_error(error: &dyn std::error::Error) -> Result<(), Box<dyn std::error
     Ok(())
 }
 
+pub fn log_warning(warning: &dyn std::fmt::Display) -> Result<(), Box<dyn std::error::Error>> {
+    let mut stderr = StandardStream::stderr(ColorChoice::Auto);
+
+    stderr.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)))?;
+    write!(stderr, "warning")?;
+    stderr.set_color(ColorSpec::new().set_fg(None))?;
+
+    writeln!(
+        stderr,
+        ": {}",
+        format!("{}", warning).replace('\n', "\n  ").trim()
+    )?;
+
+    Ok(())
+}
+
 pub fn log_info(log: &str) -> Result<(), Box<dyn std::error::Error>> {
     let mut stderr = StandardStream::stderr(ColorChoice::Auto);
 
@@ -32,3 +48,9 @@ pub fn log_info(log: &str) -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+pub fn log_diagnostic(diagnostic: &app::Diagnostic) -> Result<(), Box<dyn std::error::Error>> {
+    writeln!(std::io::stderr(), "{}", serde_json::to_string(diagnostic)?)?;
+
+    Ok(())
+}
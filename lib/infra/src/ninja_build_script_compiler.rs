@@ -74,11 +74,11 @@ impl NinjaBuildScriptCompiler {
                 }
             ),
             "rule compile",
-            "  command = pen compile --target $target $in $out",
+            "  command = pen compile --target $target -p $package_directory $in $out",
             "  description = compiling module $module_name $in_package_name",
             "rule compile_main",
             "  command = pen compile-main --target $target \
-                 $context_options $in $out",
+                 -p $package_directory $context_options $in $out",
             "  description = compiling module $module_name",
             "rule compile_prelude",
             "  command = pen compile-prelude --target $target $in $out",
@@ -175,6 +175,7 @@ impl NinjaBuildScriptCompiler {
                     ),
                     format!("  dyndep = {}", ninja_dependency_file.display()),
                     format!("  srcdep = {}", target.source_file()),
+                    format!("  package_directory = {}", package_directory.display()),
                     format!("  module_name = {}", target.source().module_name()),
                     self.format_in_package_name_variable(target.source().package_name()),
                 ]
@@ -260,6 +261,9 @@ impl NinjaBuildScriptCompiler {
         let ninja_dependency_file =
             object_file.with_extension(self.ninja_dynamic_dependency_file_extension);
         let bit_code_file = object_file.with_extension(self.bit_code_file_extension);
+        let package_directory = self
+            .file_path_converter
+            .convert_to_os_path(package_directory);
         let context_interface_files = target
             .context_interface_files()
             .iter()
@@ -293,24 +297,21 @@ impl NinjaBuildScriptCompiler {
                     .join(" ")
             ),
             format!("  dyndep = {}", ninja_dependency_file.display()),
+            format!("  package_directory = {}", package_directory.display()),
             format!("  module_name = {}", target.source().module_name()),
             format!("  srcdep = {}", target.source_file()),
         ]
         .into_iter()
         .chain(self.compile_object_file(&bit_code_file, &object_file))
-        .chain(
-            self.compile_dependency(
-                &source_file,
-                &bit_code_file,
-                &dependency_file,
-                &ninja_dependency_file,
-                &self
-                    .file_path_converter
-                    .convert_to_os_path(package_directory),
-                target.source_file(),
-                target.source(),
-            ),
-        )
+        .chain(self.compile_dependency(
+            &source_file,
+            &bit_code_file,
+            &dependency_file,
+            &ninja_dependency_file,
+            &package_directory,
+            target.source_file(),
+            target.source(),
+        ))
         .collect())
     }
 
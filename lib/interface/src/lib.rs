@@ -1,9 +1,11 @@
 mod function_declaration;
+mod implementation;
 mod module;
 mod type_alias;
 mod type_definition;
 
 pub use function_declaration::*;
+pub use implementation::*;
 pub use module::*;
 pub use type_alias::*;
 pub use type_definition::*;
@@ -1,4 +1,4 @@
-use super::{FunctionDeclaration, TypeAlias, TypeDefinition};
+use super::{FunctionDeclaration, Implementation, TypeAlias, TypeDefinition};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -6,6 +6,7 @@ pub struct Module {
     type_definitions: Vec<TypeDefinition>,
     type_aliases: Vec<TypeAlias>,
     function_declarations: Vec<FunctionDeclaration>,
+    implementations: Vec<Implementation>,
 }
 
 impl Module {
@@ -13,11 +14,13 @@ impl Module {
         type_definitions: Vec<TypeDefinition>,
         type_aliases: Vec<TypeAlias>,
         declarations: Vec<FunctionDeclaration>,
+        implementations: Vec<Implementation>,
     ) -> Self {
         Self {
             type_definitions,
             type_aliases,
             function_declarations: declarations,
+            implementations,
         }
     }
 
@@ -32,4 +35,8 @@ impl Module {
     pub fn function_declarations(&self) -> &[FunctionDeclaration] {
         &self.function_declarations
     }
+
+    pub fn implementations(&self) -> &[Implementation] {
+        &self.implementations
+    }
 }
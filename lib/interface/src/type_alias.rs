@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 pub struct TypeAlias {
     name: String,
     original_name: String,
+    type_parameters: Vec<String>,
     type_: Type,
     public: bool,
     position: Position,
@@ -22,6 +23,7 @@ impl TypeAlias {
         Self {
             name: name.into(),
             original_name: original_name.into(),
+            type_parameters: vec![],
             type_: type_.into(),
             public,
             position,
@@ -36,6 +38,10 @@ impl TypeAlias {
         &self.original_name
     }
 
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
     pub fn type_(&self) -> &Type {
         &self.type_
     }
@@ -47,4 +53,9 @@ impl TypeAlias {
     pub fn position(&self) -> &Position {
         &self.position
     }
+
+    pub fn set_type_parameters(mut self, parameters: Vec<String>) -> Self {
+        self.type_parameters = parameters;
+        self
+    }
 }
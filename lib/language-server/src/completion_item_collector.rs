@@ -0,0 +1,328 @@
+use crate::type_formatter;
+use fnv::FnvHashMap;
+use lsp_types::{CompletionItem, CompletionItemKind};
+
+pub fn collect(
+    module: &ast::Module,
+    interfaces: &FnvHashMap<ast::ModulePath, interface::Module>,
+) -> Vec<CompletionItem> {
+    let imports = module
+        .imports()
+        .iter()
+        .filter_map(|import| Some((import, interfaces.get(import.module_path())?)))
+        .collect::<Vec<_>>();
+    let names = imports
+        .iter()
+        .flat_map(|(import, interface)| {
+            interface
+                .type_definitions()
+                .iter()
+                .map(|definition| (definition.name(), definition.original_name()))
+                .chain(
+                    interface
+                        .type_aliases()
+                        .iter()
+                        .map(|alias| (alias.name(), alias.original_name())),
+                )
+                .map(|(name, original_name)| (name.into(), qualify_name(import, original_name)))
+        })
+        .collect::<FnvHashMap<String, String>>();
+
+    imports
+        .iter()
+        .flat_map(|(import, interface)| {
+            interface
+                .function_declarations()
+                .iter()
+                .filter(|declaration| ast::analysis::is_name_public(declaration.original_name()))
+                .map(|declaration| CompletionItem {
+                    label: qualify_name(import, declaration.original_name()),
+                    kind: Some(CompletionItemKind::FUNCTION),
+                    detail: Some(type_formatter::format(
+                        &declaration.type_().clone().into(),
+                        &names,
+                    )),
+                    ..Default::default()
+                })
+                .chain(
+                    interface
+                        .type_definitions()
+                        .iter()
+                        .filter(|definition| definition.is_public())
+                        .map(|definition| {
+                            create_item(
+                                qualify_name(import, definition.original_name()),
+                                CompletionItemKind::STRUCT,
+                            )
+                        }),
+                )
+                .chain(
+                    interface
+                        .type_aliases()
+                        .iter()
+                        .filter(|alias| alias.is_public())
+                        .map(|alias| {
+                            create_item(
+                                qualify_name(import, alias.original_name()),
+                                CompletionItemKind::TYPE_PARAMETER,
+                            )
+                        }),
+                )
+                .collect::<Vec<_>>()
+        })
+        .chain(
+            module
+                .type_definitions()
+                .iter()
+                .map(|definition| match definition {
+                    ast::TypeDefinition::InterfaceDefinition(definition) => {
+                        create_item(definition.name(), CompletionItemKind::INTERFACE)
+                    }
+                    ast::TypeDefinition::RecordDefinition(definition) => {
+                        create_item(definition.name(), CompletionItemKind::STRUCT)
+                    }
+                    ast::TypeDefinition::TypeAlias(alias) => {
+                        create_item(alias.name(), CompletionItemKind::TYPE_PARAMETER)
+                    }
+                }),
+        )
+        .chain(
+            module
+                .foreign_imports()
+                .iter()
+                .map(|import| create_item(import.name(), CompletionItemKind::FUNCTION)),
+        )
+        .chain(
+            module
+                .function_definitions()
+                .iter()
+                .map(|definition| create_item(definition.name(), CompletionItemKind::FUNCTION)),
+        )
+        .chain(
+            module
+                .type_definitions()
+                .iter()
+                .filter_map(|definition| match definition {
+                    ast::TypeDefinition::InterfaceDefinition(definition) => Some(definition),
+                    ast::TypeDefinition::RecordDefinition(_)
+                    | ast::TypeDefinition::TypeAlias(_) => None,
+                })
+                .flat_map(|definition| definition.methods())
+                .map(|method| create_item(method.name(), CompletionItemKind::FUNCTION)),
+        )
+        .collect()
+}
+
+fn qualify_name(import: &ast::Import, name: &str) -> String {
+    if import
+        .unqualified_names()
+        .iter()
+        .any(|unqualified_name| unqualified_name.name() == name)
+    {
+        name.into()
+    } else {
+        format!(
+            "{}'{}",
+            import
+                .prefix()
+                .unwrap_or_else(|| match import.module_path() {
+                    ast::ModulePath::External(path) => path.components().last().unwrap(),
+                    ast::ModulePath::Internal(path) => path.components().last().unwrap(),
+                }),
+            name
+        )
+    }
+}
+
+fn create_item(label: impl Into<String>, kind: CompletionItemKind) -> CompletionItem {
+    CompletionItem {
+        label: label.into(),
+        kind: Some(kind),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hir::types;
+    use position::{test::PositionFake, Position};
+    use pretty_assertions::assert_eq;
+
+    fn collect_labels(
+        module: &ast::Module,
+        interfaces: &FnvHashMap<ast::ModulePath, interface::Module>,
+    ) -> Vec<String> {
+        collect(module, interfaces)
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    fn create_interface() -> interface::Module {
+        interface::Module::new(
+            vec![
+                interface::TypeDefinition::new(
+                    "foo.pen:Foo",
+                    "Foo",
+                    vec![],
+                    false,
+                    true,
+                    Position::fake(),
+                ),
+                interface::TypeDefinition::new(
+                    "foo.pen:bar",
+                    "bar",
+                    vec![],
+                    false,
+                    false,
+                    Position::fake(),
+                ),
+            ],
+            vec![],
+            vec![interface::FunctionDeclaration::new(
+                "foo.pen:Baz",
+                "Baz",
+                types::Function::new(
+                    vec![types::Record::new("foo.pen:Foo", Position::fake()).into()],
+                    types::None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                Position::fake(),
+            )],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn collect_nothing() {
+        assert_eq!(
+            collect_labels(
+                &ast::Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake()),
+                &Default::default()
+            ),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn collect_module_definitions() {
+        assert_eq!(
+            collect_labels(
+                &ast::Module::new(
+                    vec![],
+                    vec![],
+                    vec![ast::TypeAlias::new(
+                        "foo",
+                        ast::types::Reference::new("none", Position::fake()),
+                        Position::fake()
+                    )
+                    .into()],
+                    vec![],
+                    vec![ast::FunctionDefinition::new(
+                        "bar",
+                        vec![],
+                        ast::Lambda::new(
+                            vec![],
+                            ast::types::Reference::new("none", Position::fake()),
+                            ast::Block::new(
+                                vec![],
+                                ast::Variable::new("none", Position::fake()),
+                                Position::fake()
+                            ),
+                            Position::fake(),
+                        ),
+                        None,
+                        Position::fake(),
+                    )],
+                    Position::fake()
+                ),
+                &Default::default()
+            ),
+            vec!["foo", "bar"]
+        );
+    }
+
+    #[test]
+    fn collect_imported_names() {
+        let path = ast::ModulePath::from(ast::InternalModulePath::new(vec!["Foo".into()]));
+
+        assert_eq!(
+            collect(
+                &ast::Module::new(
+                    vec![ast::Import::new(
+                        path.clone(),
+                        None,
+                        vec![],
+                        Position::fake()
+                    )],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    Position::fake()
+                ),
+                &[(path, create_interface())].into_iter().collect()
+            ),
+            vec![
+                CompletionItem {
+                    label: "Foo'Baz".into(),
+                    kind: Some(CompletionItemKind::FUNCTION),
+                    detail: Some("\\(Foo'Foo) none".into()),
+                    ..Default::default()
+                },
+                create_item("Foo'Foo", CompletionItemKind::STRUCT),
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_names_imported_with_custom_prefix() {
+        let path = ast::ModulePath::from(ast::ExternalModulePath::new("Foo", vec!["Bar".into()]));
+
+        assert_eq!(
+            collect_labels(
+                &ast::Module::new(
+                    vec![ast::Import::new(
+                        path.clone(),
+                        Some("Qux".into()),
+                        vec![],
+                        Position::fake()
+                    )],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    Position::fake()
+                ),
+                &[(path, create_interface())].into_iter().collect()
+            ),
+            vec!["Qux'Baz", "Qux'Foo"]
+        );
+    }
+
+    #[test]
+    fn collect_unqualified_names() {
+        let path = ast::ModulePath::from(ast::InternalModulePath::new(vec!["Foo".into()]));
+
+        assert_eq!(
+            collect_labels(
+                &ast::Module::new(
+                    vec![ast::Import::new(
+                        path.clone(),
+                        None,
+                        vec![ast::UnqualifiedName::new("Foo", Position::fake())],
+                        Position::fake()
+                    )],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    Position::fake()
+                ),
+                &[(path, create_interface())].into_iter().collect()
+            ),
+            vec!["Foo'Baz", "Foo"]
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::position_converter;
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+use std::error::Error;
+
+const SOURCE: &str = "pen";
+
+pub fn convert(error: &(dyn Error + 'static), path: &str) -> Vec<Diagnostic> {
+    app::diagnostic_converter::convert(error)
+        .iter()
+        .map(|diagnostic| convert_diagnostic(diagnostic, path))
+        .collect()
+}
+
+fn convert_diagnostic(diagnostic: &app::Diagnostic, path: &str) -> Diagnostic {
+    let message = [diagnostic.message()]
+        .into_iter()
+        .chain(diagnostic.notes().iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let (range, message) = match diagnostic.primary_span() {
+        Some(span) if span.position().path() == path => (
+            position_converter::convert_to_range(span.position()),
+            message,
+        ),
+        Some(span) => (
+            Default::default(),
+            format!("{}\n{}", message, span.position()),
+        ),
+        None => (Default::default(), message),
+    };
+
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        code: diagnostic
+            .code()
+            .map(|code| NumberOrString::String(code.into())),
+        source: Some(SOURCE.into()),
+        message,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hir::analysis::AnalysisError;
+    use position::Position;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn convert_parse_error() {
+        let error = parse::parse("x", "foo.pen").unwrap_err();
+
+        assert_eq!(
+            convert(&error, "foo.pen")[0].range,
+            lsp_types::Range::new(
+                lsp_types::Position::new(0, 1),
+                lsp_types::Position::new(0, 1)
+            )
+        );
+    }
+
+    #[test]
+    fn convert_analysis_error() {
+        let diagnostic = &convert(
+            &hir_mir::CompileError::from(AnalysisError::UnreachableCode(Position::new(
+                "foo.pen", 2, 3, "  foo()",
+            ))),
+            "foo.pen",
+        )[0];
+
+        assert_eq!(
+            diagnostic.range,
+            lsp_types::Range::new(
+                lsp_types::Position::new(1, 2),
+                lsp_types::Position::new(1, 5)
+            )
+        );
+        assert_eq!(diagnostic.message, "unreachable code");
+        assert_eq!(
+            diagnostic.code,
+            Some(NumberOrString::String("E0027".into()))
+        );
+    }
+
+    #[test]
+    fn convert_error_in_other_file() {
+        let position = Position::new("bar.pen", 2, 3, "  foo()");
+        let diagnostic = &convert(
+            &hir_mir::CompileError::from(AnalysisError::UnreachableCode(position.clone())),
+            "foo.pen",
+        )[0];
+
+        assert_eq!(diagnostic.range, Default::default());
+        assert_eq!(
+            diagnostic.message,
+            format!("unreachable code\n{}", position)
+        );
+    }
+
+    #[test]
+    fn convert_error_without_position() {
+        let diagnostic = &convert(
+            &hir_mir::CompileError::CompileConfigurationNotProvided,
+            "foo.pen",
+        )[0];
+
+        assert_eq!(diagnostic.range, Default::default());
+        assert_eq!(diagnostic.message, "compile configuration not provided");
+    }
+
+    #[test]
+    fn convert_multiple_analysis_errors() {
+        let diagnostics = convert(
+            &hir_mir::CompileError::from(AnalysisError::Multiple(vec![
+                AnalysisError::UnreachableCode(Position::new("foo.pen", 1, 1, "foo()")),
+                AnalysisError::UnusedErrorValue(Position::new("foo.pen", 2, 1, "bar()")),
+            ])),
+            "foo.pen",
+        );
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message.as_str())
+                .collect::<Vec<_>>(),
+            vec!["unreachable code", "unused error value"]
+        );
+        assert_eq!(
+            diagnostics[1].range,
+            lsp_types::Range::new(
+                lsp_types::Position::new(1, 0),
+                lsp_types::Position::new(1, 3)
+            )
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{position_converter, symbol_collector::Symbol};
+use fnv::FnvHashMap;
+
+#[derive(Clone, Debug, Default)]
+pub struct Document {
+    source: String,
+    module: Option<ast::Module>,
+    symbols: Vec<Symbol>,
+    type_names: FnvHashMap<String, String>,
+}
+
+impl Document {
+    pub fn new(
+        source: impl Into<String>,
+        module: Option<ast::Module>,
+        symbols: Vec<Symbol>,
+        type_names: FnvHashMap<String, String>,
+    ) -> Self {
+        Self {
+            source: source.into(),
+            module,
+            symbols,
+            type_names,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn module(&self) -> Option<&ast::Module> {
+        self.module.as_ref()
+    }
+
+    pub fn type_names(&self) -> &FnvHashMap<String, String> {
+        &self.type_names
+    }
+
+    pub fn find_symbol(&self, position: &lsp_types::Position) -> Option<&Symbol> {
+        let (line_number, column_number) =
+            position_converter::find_identifier(&self.source, position)?;
+
+        self.symbols.iter().find(|symbol| {
+            symbol.position().line_number() == line_number
+                && symbol.position().column_number() == column_number
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hir::types;
+    use position::Position;
+
+    #[test]
+    fn find_symbol() {
+        let symbol = Symbol::new(
+            types::None::new(Position::new("foo.pen", 1, 1, "x")),
+            Position::new("foo.pen", 2, 3, "  x"),
+            Position::new("foo.pen", 1, 1, "x"),
+        );
+
+        assert_eq!(
+            Document::new("x\n  x", None, vec![symbol.clone()], Default::default())
+                .find_symbol(&lsp_types::Position::new(1, 3)),
+            Some(&symbol)
+        );
+    }
+
+    #[test]
+    fn fail_to_find_symbol() {
+        assert_eq!(
+            Document::new(
+                "x\n  x",
+                None,
+                vec![Symbol::new(
+                    types::None::new(Position::new("foo.pen", 1, 1, "x")),
+                    Position::new("foo.pen", 2, 3, "  x"),
+                    Position::new("foo.pen", 1, 1, "x"),
+                )],
+                Default::default()
+            )
+            .find_symbol(&lsp_types::Position::new(0, 0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,9 @@
+mod completion_item_collector;
+mod diagnostic_converter;
+mod document;
+mod position_converter;
+mod server;
+mod symbol_collector;
+mod type_formatter;
+
+pub use server::run;
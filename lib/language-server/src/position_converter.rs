@@ -0,0 +1,197 @@
+use position::Position;
+
+pub fn convert_to_range(position: &Position) -> lsp_types::Range {
+    let characters = position.line().chars().collect::<Vec<_>>();
+    let start = (position.column_number() - 1).min(characters.len());
+    // Spans continuing to the following lines are shortened to identifiers.
+    let end = if position.has_span() && position.end_line_number() == position.line_number() {
+        position.end_column_number() - 1
+    } else {
+        start
+            + characters[start..]
+                .iter()
+                .take_while(|&&character| is_identifier_character(character))
+                .count()
+                .max(1)
+    };
+    let end = end.min(characters.len());
+    let line = (position.line_number() - 1) as u32;
+
+    lsp_types::Range::new(
+        lsp_types::Position::new(line, count_utf16_units(&characters[..start])),
+        lsp_types::Position::new(line, count_utf16_units(&characters[..end])),
+    )
+}
+
+// Find a line and column numbers of an identifier at a given position.
+pub fn find_identifier(source: &str, position: &lsp_types::Position) -> Option<(usize, usize)> {
+    let characters = source
+        .split('\n')
+        .nth(position.line as usize)?
+        .chars()
+        .collect::<Vec<_>>();
+    let mut index = 0;
+    let mut units = 0;
+
+    while index < characters.len() && units < position.character as usize {
+        units += characters[index].len_utf16();
+        index += 1;
+    }
+
+    let start = characters[..index]
+        .iter()
+        .rev()
+        .take_while(|&&character| is_identifier_character(character))
+        .count();
+
+    if start == 0
+        && !characters
+            .get(index)
+            .copied()
+            .map(is_identifier_character)
+            .unwrap_or_default()
+    {
+        return None;
+    }
+
+    Some((position.line as usize + 1, index - start + 1))
+}
+
+fn is_identifier_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_' || character == '\''
+}
+
+fn count_utf16_units(characters: &[char]) -> u32 {
+    characters
+        .iter()
+        .map(|character| character.len_utf16() as u32)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn convert_identifier_position() {
+        assert_eq!(
+            convert_to_range(&Position::new("foo.pen", 2, 3, "  foo + 1")),
+            lsp_types::Range::new(
+                lsp_types::Position::new(1, 2),
+                lsp_types::Position::new(1, 5)
+            )
+        );
+    }
+
+    #[test]
+    fn convert_qualified_identifier_position() {
+        assert_eq!(
+            convert_to_range(&Position::new("foo.pen", 1, 1, "Foo'Bar()")),
+            lsp_types::Range::new(
+                lsp_types::Position::new(0, 0),
+                lsp_types::Position::new(0, 7)
+            )
+        );
+    }
+
+    #[test]
+    fn convert_span() {
+        assert_eq!(
+            convert_to_range(&Position::new("foo.pen", 2, 3, "  foo(x) + 1").set_end(2, 9)),
+            lsp_types::Range::new(
+                lsp_types::Position::new(1, 2),
+                lsp_types::Position::new(1, 8)
+            )
+        );
+    }
+
+    #[test]
+    fn convert_multi_line_span() {
+        assert_eq!(
+            convert_to_range(&Position::new("foo.pen", 1, 1, "foo(").set_end(2, 2)),
+            lsp_types::Range::new(
+                lsp_types::Position::new(0, 0),
+                lsp_types::Position::new(0, 3)
+            )
+        );
+    }
+
+    #[test]
+    fn convert_symbol_position() {
+        assert_eq!(
+            convert_to_range(&Position::new("foo.pen", 1, 3, "x + y")),
+            lsp_types::Range::new(
+                lsp_types::Position::new(0, 2),
+                lsp_types::Position::new(0, 3)
+            )
+        );
+    }
+
+    #[test]
+    fn convert_position_at_end_of_line() {
+        assert_eq!(
+            convert_to_range(&Position::new("foo.pen", 1, 4, "foo")),
+            lsp_types::Range::new(
+                lsp_types::Position::new(0, 3),
+                lsp_types::Position::new(0, 3)
+            )
+        );
+    }
+
+    #[test]
+    fn convert_position_after_multi_byte_character() {
+        assert_eq!(
+            convert_to_range(&Position::new("foo.pen", 1, 7, "\"🐧\" + x")),
+            lsp_types::Range::new(
+                lsp_types::Position::new(0, 7),
+                lsp_types::Position::new(0, 8)
+            )
+        );
+    }
+
+    #[test]
+    fn find_identifier_at_start() {
+        assert_eq!(
+            find_identifier("x\nfoo + 1", &lsp_types::Position::new(1, 0)),
+            Some((2, 1))
+        );
+    }
+
+    #[test]
+    fn find_identifier_in_middle() {
+        assert_eq!(
+            find_identifier("x + Foo'Bar", &lsp_types::Position::new(0, 8)),
+            Some((1, 5))
+        );
+    }
+
+    #[test]
+    fn find_identifier_at_end() {
+        assert_eq!(
+            find_identifier("x + foo", &lsp_types::Position::new(0, 7)),
+            Some((1, 5))
+        );
+    }
+
+    #[test]
+    fn find_identifier_after_multi_byte_character() {
+        assert_eq!(
+            find_identifier("\"🐧\" + x", &lsp_types::Position::new(0, 7)),
+            Some((1, 7))
+        );
+    }
+
+    #[test]
+    fn fail_to_find_identifier() {
+        assert_eq!(
+            find_identifier("x + y", &lsp_types::Position::new(0, 2)),
+            None
+        );
+    }
+
+    #[test]
+    fn fail_to_find_identifier_in_missing_line() {
+        assert_eq!(find_identifier("x", &lsp_types::Position::new(1, 0)), None);
+    }
+}
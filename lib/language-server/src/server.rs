@@ -0,0 +1,781 @@
+use crate::{
+    completion_item_collector, diagnostic_converter, document::Document, position_converter,
+    symbol_collector, type_formatter,
+};
+use app::{
+    infra::{FilePath, Infrastructure},
+    module_analyzer::InterfaceCache,
+    ApplicationConfiguration,
+};
+use fnv::FnvHashMap;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+        Notification as _, PublishDiagnostics,
+    },
+    request::{Completion, GotoDefinition, HoverRequest, Request as _},
+    CompletionOptions, CompletionParams, CompletionResponse, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, GotoDefinitionParams,
+    GotoDefinitionResponse, Hover, HoverContents, HoverParams, HoverProviderCapability, Location,
+    MarkupContent, MarkupKind, OneOf, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use std::error::Error;
+
+const COMPLETION_TRIGGER_CHARACTER: &str = "'";
+
+pub struct LanguageServer<'a> {
+    infrastructure: &'a Infrastructure,
+    file_path_converter: &'a infra::FilePathConverter,
+    package_directory: &'a FilePath,
+    output_directory: &'a FilePath,
+    prelude_package_url: &'a url::Url,
+    application_configuration: &'a ApplicationConfiguration,
+    documents: FnvHashMap<Url, Document>,
+    interface_cache: InterfaceCache,
+}
+
+pub fn run(
+    infrastructure: &Infrastructure,
+    file_path_converter: &infra::FilePathConverter,
+    package_directory: &FilePath,
+    output_directory: &FilePath,
+    prelude_package_url: &url::Url,
+    application_configuration: &ApplicationConfiguration,
+) -> Result<(), Box<dyn Error>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    connection.initialize(serde_json::to_value(ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec![COMPLETION_TRIGGER_CHARACTER.into()]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })?)?;
+
+    LanguageServer {
+        infrastructure,
+        file_path_converter,
+        package_directory,
+        output_directory,
+        prelude_package_url,
+        application_configuration,
+        documents: Default::default(),
+        interface_cache: InterfaceCache::new(),
+    }
+    .serve(&connection)?;
+
+    drop(connection);
+    io_threads.join()?;
+
+    Ok(())
+}
+
+impl LanguageServer<'_> {
+    fn serve(&mut self, connection: &Connection) -> Result<(), Box<dyn Error>> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        break;
+                    }
+
+                    let id = request.id.clone();
+
+                    connection.sender.send(
+                        self.handle_request(request)
+                            .unwrap_or_else(|error| {
+                                Response::new_err(
+                                    id,
+                                    ErrorCode::InternalError as i32,
+                                    error.to_string(),
+                                )
+                            })
+                            .into(),
+                    )?;
+                }
+                Message::Notification(notification) => {
+                    match self.handle_notification(notification) {
+                        Ok(diagnostics) => {
+                            for diagnostics in diagnostics {
+                                connection.sender.send(
+                                    Notification::new(
+                                        PublishDiagnostics::METHOD.into(),
+                                        diagnostics,
+                                    )
+                                    .into(),
+                                )?;
+                            }
+                        }
+                        Err(error) => infra::log_error(error.as_ref())?,
+                    }
+                }
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> Result<Response, Box<dyn Error>> {
+        Ok(match request.method.as_str() {
+            HoverRequest::METHOD => Response::new_ok(
+                request.id,
+                self.hover(&serde_json::from_value(request.params)?),
+            ),
+            GotoDefinition::METHOD => Response::new_ok(
+                request.id,
+                self.find_definition(&serde_json::from_value(request.params)?)?,
+            ),
+            Completion::METHOD => Response::new_ok(
+                request.id,
+                self.complete(&serde_json::from_value(request.params)?)?,
+            ),
+            method => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("method not found: {}", method),
+            ),
+        })
+    }
+
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Result<Vec<PublishDiagnosticsParams>, Box<dyn Error>> {
+        Ok(match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params =
+                    serde_json::from_value::<DidOpenTextDocumentParams>(notification.params)?;
+
+                vec![self.update_document(params.text_document.uri, params.text_document.text)?]
+            }
+            DidChangeTextDocument::METHOD => {
+                let params =
+                    serde_json::from_value::<DidChangeTextDocumentParams>(notification.params)?;
+
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    vec![self.update_document(params.text_document.uri, change.text)?]
+                } else {
+                    vec![]
+                }
+            }
+            // Other modules might depend on a saved module.
+            DidSaveTextDocument::METHOD => {
+                self.interface_cache.invalidate(self.output_directory);
+
+                self.documents
+                    .iter()
+                    .map(|(uri, document)| (uri.clone(), document.source().to_owned()))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|(uri, source)| self.update_document(uri, source))
+                    .collect::<Result<_, _>>()?
+            }
+            DidCloseTextDocument::METHOD => {
+                let params =
+                    serde_json::from_value::<DidCloseTextDocumentParams>(notification.params)?;
+
+                self.documents.remove(&params.text_document.uri);
+
+                vec![PublishDiagnosticsParams::new(
+                    params.text_document.uri,
+                    vec![],
+                    None,
+                )]
+            }
+            _ => vec![],
+        })
+    }
+
+    fn update_document(
+        &mut self,
+        uri: Url,
+        source: String,
+    ) -> Result<PublishDiagnosticsParams, Box<dyn Error>> {
+        let source_file = self.convert_to_file_path(&uri)?;
+        let path = self
+            .infrastructure
+            .file_path_displayer
+            .display(&source_file);
+        let mut diagnostics = vec![];
+
+        let (module, errors) = parse::parse_with_recovery(&source, &path);
+
+        let document = if errors.is_empty() {
+            match app::module_analyzer::analyze(
+                self.infrastructure,
+                self.package_directory,
+                self.output_directory,
+                &source_file,
+                &module,
+                self.prelude_package_url,
+                self.application_configuration,
+                &mut self.interface_cache,
+            ) {
+                Ok(hir_module) => Document::new(
+                    source,
+                    Some(module),
+                    symbol_collector::collect(&hir_module),
+                    hir_module
+                        .type_definitions()
+                        .iter()
+                        .map(|definition| (definition.name(), definition.original_name()))
+                        .chain(
+                            hir_module
+                                .type_aliases()
+                                .iter()
+                                .map(|alias| (alias.name(), alias.original_name())),
+                        )
+                        .map(|(name, original_name)| (name.into(), original_name.into()))
+                        .collect(),
+                ),
+                Err(error) => {
+                    diagnostics.extend(diagnostic_converter::convert(error.as_ref(), &path));
+
+                    Document::new(source, Some(module), vec![], Default::default())
+                }
+            }
+        } else {
+            for error in &errors {
+                diagnostics.extend(diagnostic_converter::convert(error, &path));
+            }
+
+            // Analyze only complete modules as partial ones miss some definitions.
+            Document::new(source, Some(module), vec![], Default::default())
+        };
+
+        self.documents.insert(uri.clone(), document);
+
+        Ok(PublishDiagnosticsParams::new(uri, diagnostics, None))
+    }
+
+    fn hover(&self, params: &HoverParams) -> Option<Hover> {
+        let position = &params.text_document_position_params;
+        let document = self.documents.get(&position.text_document.uri)?;
+        let symbol = document.find_symbol(&position.position)?;
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!(
+                    "```pen\n{}\n```",
+                    type_formatter::format(symbol.type_(), document.type_names())
+                ),
+            }),
+            range: Some(position_converter::convert_to_range(symbol.position())),
+        })
+    }
+
+    fn find_definition(
+        &self,
+        params: &GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>, Box<dyn Error>> {
+        let position = &params.text_document_position_params;
+        let symbol = if let Some(symbol) = self
+            .documents
+            .get(&position.text_document.uri)
+            .and_then(|document| document.find_symbol(&position.position))
+        {
+            symbol
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(GotoDefinitionResponse::Scalar(Location::new(
+            Url::from_file_path(
+                self.file_path_converter.convert_to_os_path(
+                    &self
+                        .file_path_converter
+                        .convert_to_file_path(symbol.definition_position().path())?,
+                ),
+            )
+            .map_err(|_| {
+                format!(
+                    "invalid definition path: {}",
+                    symbol.definition_position().path()
+                )
+            })?,
+            position_converter::convert_to_range(symbol.definition_position()),
+        ))))
+    }
+
+    fn complete(
+        &mut self,
+        params: &CompletionParams,
+    ) -> Result<Option<CompletionResponse>, Box<dyn Error>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let source_file = self.convert_to_file_path(uri)?;
+        let module = if let Some(module) = self
+            .documents
+            .get(uri)
+            .and_then(|document| document.module())
+        {
+            module
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(CompletionResponse::Array(
+            completion_item_collector::collect(
+                module,
+                &app::module_analyzer::compile_interfaces(
+                    self.infrastructure,
+                    self.package_directory,
+                    self.output_directory,
+                    &source_file,
+                    module,
+                    self.prelude_package_url,
+                    &mut self.interface_cache,
+                )?,
+            ),
+        )))
+    }
+
+    fn convert_to_file_path(&self, uri: &Url) -> Result<FilePath, Box<dyn Error>> {
+        self.file_path_converter.convert_to_file_path(
+            uri.to_file_path()
+                .map_err(|_| format!("invalid document URI: {}", uri))?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use app::infra::FilePathConfiguration;
+    use lsp_server::RequestId;
+    use lsp_types::{
+        DidSaveTextDocumentParams, Position, Range, TextDocumentContentChangeEvent,
+        TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams,
+        VersionedTextDocumentIdentifier,
+    };
+    use pretty_assertions::assert_eq;
+    use std::{fs, path::PathBuf, process, sync::Arc, thread};
+
+    const BUILD_CONFIGURATION_FILENAME: &str = "pen.json";
+    const LIBRARY_CONFIGURATION: &str = r#"{"type":"library","dependencies":{}}"#;
+
+    struct Workspace {
+        directory: PathBuf,
+    }
+
+    impl Workspace {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let workspace = Self {
+                directory: std::env::temp_dir().join(format!(
+                    "pen-language-server-{}-{}",
+                    process::id(),
+                    name
+                )),
+            };
+
+            for (path, content) in [
+                (BUILD_CONFIGURATION_FILENAME, LIBRARY_CONFIGURATION),
+                (".pen/packages/pen_prelude/pen.json", LIBRARY_CONFIGURATION),
+            ]
+            .iter()
+            .chain(files)
+            {
+                workspace.write(path, content);
+            }
+
+            workspace
+        }
+
+        fn uri(&self, path: &str) -> Url {
+            Url::from_file_path(self.directory.join(path)).unwrap()
+        }
+
+        fn write(&self, path: &str, content: &str) {
+            let path = self.directory.join(path);
+
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        fn serve(&self, run: impl FnOnce(&Client) + Send) {
+            let file_path_converter = Arc::new(infra::FilePathConverter::new(&self.directory));
+            let infrastructure =
+                create_infrastructure(file_path_converter.clone(), &self.directory);
+            let package_directory = FilePath::empty();
+            let output_directory = FilePath::new([".pen"]);
+            let prelude_package_url = url::Url::parse("pen:///prelude").unwrap();
+            let application_configuration = ApplicationConfiguration {
+                application_filename: "app".into(),
+                main_module_basename: "main".into(),
+                context_module_basename: "Context".into(),
+                main_module: app::MainModuleConfiguration {
+                    source_main_function_name: "main".into(),
+                    object_main_function_name: "_pen_main".into(),
+                    main_context_type_name: "context".into(),
+                    system_context_type_name: "Context".into(),
+                    new_system_context_function_name: "UnsafeNew".into(),
+                },
+            };
+            let (server, client) = Connection::memory();
+
+            thread::scope(|scope| {
+                scope.spawn(|| run(&Client { connection: client }));
+
+                LanguageServer {
+                    infrastructure: &infrastructure,
+                    file_path_converter: &file_path_converter,
+                    package_directory: &package_directory,
+                    output_directory: &output_directory,
+                    prelude_package_url: &prelude_package_url,
+                    application_configuration: &application_configuration,
+                    documents: Default::default(),
+                    interface_cache: InterfaceCache::new(),
+                }
+                .serve(&server)
+                .unwrap();
+            })
+        }
+    }
+
+    impl Drop for Workspace {
+        fn drop(&mut self) {
+            fs::remove_dir_all(&self.directory).unwrap();
+        }
+    }
+
+    struct Client {
+        connection: Connection,
+    }
+
+    impl Client {
+        fn notify(&self, method: &str, params: impl serde::Serialize) {
+            self.connection
+                .sender
+                .send(Notification::new(method.into(), params).into())
+                .unwrap();
+        }
+
+        fn request<T: serde::de::DeserializeOwned>(
+            &self,
+            method: &str,
+            params: impl serde::Serialize,
+        ) -> T {
+            self.connection
+                .sender
+                .send(Request::new(RequestId::from(0), method.into(), params).into())
+                .unwrap();
+
+            match self.connection.receiver.recv().unwrap() {
+                Message::Response(response) => {
+                    serde_json::from_value(response.result.unwrap()).unwrap()
+                }
+                message => panic!("unexpected message: {:?}", message),
+            }
+        }
+
+        fn receive_diagnostics(&self) -> PublishDiagnosticsParams {
+            match self.connection.receiver.recv().unwrap() {
+                Message::Notification(notification) => {
+                    assert_eq!(notification.method, PublishDiagnostics::METHOD);
+
+                    serde_json::from_value(notification.params).unwrap()
+                }
+                message => panic!("unexpected message: {:?}", message),
+            }
+        }
+
+        fn open(&self, uri: &Url, source: &str) -> PublishDiagnosticsParams {
+            self.notify(
+                DidOpenTextDocument::METHOD,
+                DidOpenTextDocumentParams {
+                    text_document: TextDocumentItem::new(
+                        uri.clone(),
+                        "pen".into(),
+                        0,
+                        source.into(),
+                    ),
+                },
+            );
+
+            self.receive_diagnostics()
+        }
+    }
+
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn create_infrastructure(
+        file_path_converter: Arc<infra::FilePathConverter>,
+        directory: &std::path::Path,
+    ) -> Infrastructure {
+        let file_system = Arc::new(infra::FileSystem::new(file_path_converter.clone()));
+
+        Infrastructure {
+            build_script_dependency_compiler: Arc::new(
+                infra::NinjaBuildScriptDependencyCompiler::new(file_path_converter.clone()),
+            ),
+            external_package_initializer: Arc::new(infra::ExternalPackageInitializer::new(
+                file_system.clone(),
+                file_path_converter.clone(),
+                "pen",
+                "PEN_ROOT",
+                "packages",
+            )),
+            file_system: file_system.clone(),
+            file_path_displayer: Arc::new(infra::FilePathDisplayer::new(
+                file_path_converter.clone(),
+                directory,
+            )),
+            file_path_configuration: FilePathConfiguration {
+                source_file_extension: "pen",
+                object_file_extension: "o",
+                interface_file_extension: "i",
+                test_information_file_extension: "test.json",
+                archive_file_extension: "a",
+                build_script_file_extension: "ninja",
+                test_file_extension: "test.pen",
+            }
+            .into(),
+            build_script_runner: Arc::new(infra::NinjaBuildScriptRunner::new(
+                file_path_converter.clone(),
+            )),
+            build_script_compiler: Arc::new(infra::NinjaBuildScriptCompiler::new(
+                file_path_converter.clone(),
+                "bc",
+                "d",
+                "ninja",
+                "pen-ffi",
+                "pen-link",
+            )),
+            package_configuration_reader: Arc::new(infra::JsonPackageConfigurationReader::new(
+                file_system.clone(),
+                file_path_converter.clone(),
+                BUILD_CONFIGURATION_FILENAME,
+            )),
+            package_configuration_writer: Arc::new(infra::JsonPackageConfigurationWriter::new(
+                file_system,
+                BUILD_CONFIGURATION_FILENAME,
+            )),
+            command_runner: Arc::new(infra::CommandRunner::new(file_path_converter.clone())),
+            test_linker: Arc::new(infra::TestLinker::new(file_path_converter, "PEN_ROOT")),
+        }
+    }
+
+    fn position_params(uri: &Url, line: u32, character: u32) -> TextDocumentPositionParams {
+        TextDocumentPositionParams::new(
+            TextDocumentIdentifier::new(uri.clone()),
+            Position::new(line, character),
+        )
+    }
+
+    #[test]
+    fn publish_no_diagnostics() {
+        let workspace = Workspace::new("publish_no_diagnostics", &[]);
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            assert_eq!(
+                client.open(&uri, "f = \\() number { 42 }"),
+                PublishDiagnosticsParams::new(uri.clone(), vec![], None)
+            );
+        });
+    }
+
+    #[test]
+    fn publish_diagnostics_of_type_error() {
+        let workspace = Workspace::new("publish_diagnostics_of_type_error", &[]);
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            let diagnostics = client.open(&uri, "f = \\() number { none }");
+
+            assert_eq!(diagnostics.uri, uri);
+            assert_eq!(diagnostics.diagnostics.len(), 1);
+            assert_eq!(diagnostics.diagnostics[0].range.start.line, 0);
+        });
+    }
+
+    #[test]
+    fn publish_diagnostics_of_syntax_errors() {
+        let workspace = Workspace::new("publish_diagnostics_of_syntax_errors", &[]);
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            assert_eq!(
+                client
+                    .open(
+                        &uri,
+                        "f = \\() none {\n  x = )\n  none\n}\n\ng = \\() none {\n  y = )\n  none\n}",
+                    )
+                    .diagnostics
+                    .len(),
+                2
+            );
+        });
+    }
+
+    #[test]
+    fn clear_diagnostics_on_close() {
+        let workspace = Workspace::new("clear_diagnostics_on_close", &[]);
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            client.open(&uri, "f = \\() number { none }");
+            client.notify(
+                DidCloseTextDocument::METHOD,
+                DidCloseTextDocumentParams {
+                    text_document: TextDocumentIdentifier::new(uri.clone()),
+                },
+            );
+
+            assert_eq!(
+                client.receive_diagnostics(),
+                PublishDiagnosticsParams::new(uri.clone(), vec![], None)
+            );
+        });
+    }
+
+    #[test]
+    fn hover_variable() {
+        let workspace = Workspace::new("hover_variable", &[]);
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            client.open(&uri, "f = \\(x number) number {\n  x\n}");
+
+            assert_eq!(
+                client.request::<Option<Hover>>(
+                    HoverRequest::METHOD,
+                    HoverParams {
+                        text_document_position_params: position_params(&uri, 1, 2),
+                        work_done_progress_params: Default::default(),
+                    },
+                ),
+                Some(Hover {
+                    contents: HoverContents::Markup(MarkupContent {
+                        kind: MarkupKind::Markdown,
+                        value: "```pen\nnumber\n```".into(),
+                    }),
+                    range: Some(Range::new(Position::new(1, 2), Position::new(1, 3))),
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn hover_nothing() {
+        let workspace = Workspace::new("hover_nothing", &[]);
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            client.open(&uri, "f = \\(x number) number {\n  x\n}");
+
+            assert_eq!(
+                client.request::<Option<Hover>>(
+                    HoverRequest::METHOD,
+                    HoverParams {
+                        text_document_position_params: position_params(&uri, 2, 1),
+                        work_done_progress_params: Default::default(),
+                    },
+                ),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn find_definition_of_variable() {
+        let workspace = Workspace::new("find_definition_of_variable", &[]);
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            client.open(&uri, "f = \\(x number) number {\n  x\n}");
+
+            assert_eq!(
+                client.request::<Option<GotoDefinitionResponse>>(
+                    GotoDefinition::METHOD,
+                    GotoDefinitionParams {
+                        text_document_position_params: position_params(&uri, 1, 2),
+                        work_done_progress_params: Default::default(),
+                        partial_result_params: Default::default(),
+                    },
+                ),
+                Some(GotoDefinitionResponse::Scalar(Location::new(
+                    uri.clone(),
+                    Range::new(Position::new(0, 4), Position::new(0, 5)),
+                )))
+            );
+        });
+    }
+
+    #[test]
+    fn find_definition_in_other_module() {
+        let workspace = Workspace::new(
+            "find_definition_in_other_module",
+            &[("Bar.pen", "Bar = \\() number { 42 }")],
+        );
+        let uri = workspace.uri("Foo.pen");
+
+        workspace.serve(|client| {
+            assert_eq!(
+                client
+                    .open(&uri, "import 'Bar\n\nf = \\() number {\n  Bar'Bar()\n}")
+                    .diagnostics,
+                vec![]
+            );
+            assert_eq!(
+                client.request::<Option<GotoDefinitionResponse>>(
+                    GotoDefinition::METHOD,
+                    GotoDefinitionParams {
+                        text_document_position_params: position_params(&uri, 3, 6),
+                        work_done_progress_params: Default::default(),
+                        partial_result_params: Default::default(),
+                    },
+                ),
+                Some(GotoDefinitionResponse::Scalar(Location::new(
+                    workspace.uri("Bar.pen"),
+                    Range::new(Position::new(0, 0), Position::new(0, 23)),
+                )))
+            );
+        });
+    }
+
+    #[test]
+    fn reuse_dependency_interfaces_until_save() {
+        let workspace = Workspace::new(
+            "reuse_dependency_interfaces_until_save",
+            &[("Bar.pen", "Bar = \\() number { 42 }")],
+        );
+        let uri = workspace.uri("Foo.pen");
+        let source = "import 'Bar\n\nf = \\() number {\n  Bar'Bar()\n}";
+
+        workspace.serve(|client| {
+            assert_eq!(client.open(&uri, source).diagnostics, vec![]);
+
+            workspace.write("Bar.pen", "Bar = \\() none { none }");
+
+            client.notify(
+                DidChangeTextDocument::METHOD,
+                DidChangeTextDocumentParams {
+                    text_document: VersionedTextDocumentIdentifier::new(uri.clone(), 1),
+                    content_changes: vec![TextDocumentContentChangeEvent {
+                        range: None,
+                        range_length: None,
+                        text: source.into(),
+                    }],
+                },
+            );
+
+            assert_eq!(client.receive_diagnostics().diagnostics, vec![]);
+
+            client.notify(
+                DidSaveTextDocument::METHOD,
+                DidSaveTextDocumentParams {
+                    text_document: TextDocumentIdentifier::new(workspace.uri("Bar.pen")),
+                    text: None,
+                },
+            );
+
+            assert_eq!(client.receive_diagnostics().diagnostics.len(), 1);
+        });
+    }
+}
@@ -0,0 +1,482 @@
+use fnv::FnvHashMap;
+use hir::{analysis::type_extractor, ir::*, types, types::Type};
+use position::Position;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Symbol {
+    type_: Type,
+    position: Position,
+    definition_position: Position,
+}
+
+impl Symbol {
+    pub fn new(type_: impl Into<Type>, position: Position, definition_position: Position) -> Self {
+        Self {
+            type_: type_.into(),
+            position,
+            definition_position,
+        }
+    }
+
+    pub fn type_(&self) -> &Type {
+        &self.type_
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn definition_position(&self) -> &Position {
+        &self.definition_position
+    }
+}
+
+pub fn collect(module: &Module) -> Vec<Symbol> {
+    let variables = module
+        .function_declarations()
+        .iter()
+        .map(|declaration| {
+            (
+                declaration.name().into(),
+                (
+                    declaration.type_().clone().into(),
+                    declaration.position().clone(),
+                ),
+            )
+        })
+        .chain(module.foreign_declarations().iter().map(|declaration| {
+            (
+                declaration.name().into(),
+                (declaration.type_().clone(), declaration.position().clone()),
+            )
+        }))
+        .chain(module.function_definitions().iter().map(|definition| {
+            (
+                definition.name().into(),
+                (
+                    type_extractor::extract_from_lambda(definition.lambda()).into(),
+                    definition.position().clone(),
+                ),
+            )
+        }))
+        .collect::<FnvHashMap<String, (Type, Position)>>();
+    let mut symbols = vec![];
+
+    for definition in module.function_definitions() {
+        symbols.push(Symbol::new(
+            type_extractor::extract_from_lambda(definition.lambda()),
+            definition.position().clone(),
+            definition.position().clone(),
+        ));
+
+        collect_lambda(definition.lambda(), &variables, &mut symbols);
+    }
+
+    symbols
+}
+
+fn collect_lambda(
+    lambda: &Lambda,
+    variables: &FnvHashMap<String, (Type, Position)>,
+    symbols: &mut Vec<Symbol>,
+) {
+    collect_expression(
+        lambda.body(),
+        &variables
+            .clone()
+            .into_iter()
+            .chain(lambda.arguments().iter().map(|argument| {
+                (
+                    argument.name().into(),
+                    (argument.type_().clone(), lambda.position().clone()),
+                )
+            }))
+            .collect(),
+        symbols,
+    )
+}
+
+fn collect_expression(
+    expression: &Expression,
+    variables: &FnvHashMap<String, (Type, Position)>,
+    symbols: &mut Vec<Symbol>,
+) {
+    let mut collect =
+        |expression, variables: &_| collect_expression(expression, variables, symbols);
+
+    match expression {
+        Expression::Call(call) => {
+            collect(call.function(), variables);
+
+            for argument in call.arguments() {
+                collect(argument, variables);
+            }
+        }
+        Expression::If(if_) => {
+            collect(if_.condition(), variables);
+            collect(if_.then(), variables);
+            collect(if_.else_(), variables);
+        }
+        Expression::IfList(if_) => {
+            collect(if_.list(), variables);
+            collect(
+                if_.then(),
+                &variables
+                    .clone()
+                    .into_iter()
+                    .chain(if_.type_().into_iter().flat_map(|type_| {
+                        [
+                            (
+                                if_.first_name().into(),
+                                (
+                                    types::Function::new(
+                                        vec![],
+                                        type_.clone(),
+                                        if_.position().clone(),
+                                    )
+                                    .into(),
+                                    if_.position().clone(),
+                                ),
+                            ),
+                            (
+                                if_.rest_name().into(),
+                                (
+                                    types::List::new(type_.clone(), if_.position().clone()).into(),
+                                    if_.position().clone(),
+                                ),
+                            ),
+                        ]
+                    }))
+                    .collect(),
+            );
+            collect(if_.else_(), variables);
+        }
+        Expression::IfMap(if_) => {
+            collect(if_.map(), variables);
+            collect(if_.key(), variables);
+            collect(
+                if_.then(),
+                &variables
+                    .clone()
+                    .into_iter()
+                    .chain(
+                        if_.value_type().map(|type_| {
+                            (if_.name().into(), (type_.clone(), if_.position().clone()))
+                        }),
+                    )
+                    .collect(),
+            );
+            collect(if_.else_(), variables);
+        }
+        Expression::IfType(if_) => {
+            collect(if_.argument(), variables);
+
+            for branch in if_.branches() {
+                collect(
+                    branch.expression(),
+                    &variables
+                        .clone()
+                        .into_iter()
+                        .chain([(
+                            if_.name().into(),
+                            (branch.type_().clone(), if_.position().clone()),
+                        )])
+                        .collect(),
+                );
+            }
+
+            if let Some(branch) = if_.else_() {
+                collect(
+                    branch.expression(),
+                    &variables
+                        .clone()
+                        .into_iter()
+                        .chain(branch.type_().map(|type_| {
+                            (if_.name().into(), (type_.clone(), if_.position().clone()))
+                        }))
+                        .collect(),
+                );
+            }
+        }
+        Expression::Lambda(lambda) => collect_lambda(lambda, variables, symbols),
+        Expression::Let(let_) => {
+            collect(let_.bound_expression(), variables);
+
+            let variable = let_
+                .name()
+                .zip(let_.type_())
+                .map(|(name, type_)| (name.to_owned(), (type_.clone(), let_.position().clone())));
+
+            if let Some((_, (type_, position))) = &variable {
+                symbols.push(Symbol::new(
+                    type_.clone(),
+                    position.clone(),
+                    position.clone(),
+                ));
+            }
+
+            collect_expression(
+                let_.expression(),
+                &variables.clone().into_iter().chain(variable).collect(),
+                symbols,
+            );
+        }
+        Expression::List(list) => {
+            for element in list.elements() {
+                collect(
+                    match element {
+                        ListElement::Multiple(expression) => expression,
+                        ListElement::Single(expression) => expression,
+                    },
+                    variables,
+                );
+            }
+        }
+        Expression::ListComprehension(comprehension) => {
+            let variables =
+                collect_list_comprehension_branches(comprehension.branches(), variables, symbols);
+
+            collect_expression(comprehension.element(), &variables, symbols);
+        }
+        Expression::Map(map) => {
+            for element in map.elements() {
+                match element {
+                    MapElement::Insertion(entry) => {
+                        collect(entry.key(), variables);
+                        collect(entry.value(), variables);
+                    }
+                    MapElement::Map(expression) => collect(expression, variables),
+                    MapElement::Removal(expression) => collect(expression, variables),
+                }
+            }
+        }
+        Expression::MapComprehension(comprehension) => {
+            let variables =
+                collect_list_comprehension_branches(comprehension.branches(), variables, symbols);
+
+            collect_expression(comprehension.key(), &variables, symbols);
+            collect_expression(comprehension.value(), &variables, symbols);
+        }
+        Expression::Operation(operation) => match operation {
+            Operation::Arithmetic(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Boolean(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Equality(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Not(operation) => collect(operation.expression(), variables),
+            Operation::Order(operation) => {
+                collect(operation.lhs(), variables);
+                collect(operation.rhs(), variables);
+            }
+            Operation::Try(operation) => collect(operation.expression(), variables),
+        },
+        Expression::RecordConstruction(construction) => {
+            for field in construction.fields() {
+                collect(field.expression(), variables);
+            }
+        }
+        Expression::RecordDeconstruction(deconstruction) => {
+            collect(deconstruction.record(), variables)
+        }
+        Expression::RecordUpdate(update) => {
+            collect(update.record(), variables);
+
+            for field in update.fields() {
+                collect(field.expression(), variables);
+            }
+        }
+        Expression::Thunk(thunk) => collect(thunk.expression(), variables),
+        Expression::TypeCoercion(coercion) => collect(coercion.argument(), variables),
+        Expression::Variable(variable) => {
+            if let Some((type_, position)) = variables.get(variable.name()) {
+                symbols.push(Symbol::new(
+                    type_.clone(),
+                    variable.position().clone(),
+                    position.clone(),
+                ));
+            }
+        }
+        Expression::Boolean(_)
+        | Expression::BuiltInFunction(_)
+        | Expression::Integer(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::String(_) => {}
+    }
+}
+
+fn collect_list_comprehension_branches(
+    branches: &[ListComprehensionBranch],
+    variables: &FnvHashMap<String, (Type, Position)>,
+    symbols: &mut Vec<Symbol>,
+) -> FnvHashMap<String, (Type, Position)> {
+    let mut variables = variables.clone();
+
+    for branch in branches {
+        for iteratee in branch.iteratees() {
+            collect_expression(iteratee.expression(), &variables, symbols);
+        }
+
+        variables.extend(
+            type_extractor::extract_from_list_comprehension_branch(branch)
+                .into_iter()
+                .flatten()
+                .map(|(name, type_)| (name, (type_, branch.position().clone()))),
+        );
+
+        if let Some(condition) = branch.condition() {
+            collect_expression(condition, &variables, symbols);
+        }
+    }
+
+    variables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hir::test::{FunctionDefinitionFake, ModuleFake};
+    use position::test::PositionFake;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn collect_function_definition() {
+        let function_type =
+            types::Function::new(vec![], types::None::new(Position::fake()), Position::fake());
+
+        assert_eq!(
+            collect(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        None::new(Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )])
+            ),
+            vec![Symbol::new(
+                function_type,
+                Position::fake(),
+                Position::fake()
+            )]
+        );
+    }
+
+    #[test]
+    fn collect_argument() {
+        let symbols =
+            collect(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![Argument::new("x", types::Number::new(Position::fake()))],
+                        types::Number::new(Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )]),
+            );
+
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(
+            symbols[1].type_(),
+            &types::Number::new(Position::fake()).into()
+        );
+    }
+
+    #[test]
+    fn collect_let() {
+        let symbols =
+            collect(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        Let::new(
+                            Some("x".into()),
+                            Some(types::None::new(Position::fake()).into()),
+                            None::new(Position::fake()),
+                            Variable::new("x", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
+                    ),
+                    false,
+                )]),
+            );
+
+        assert_eq!(symbols.len(), 3);
+        assert_eq!(
+            symbols[1].type_(),
+            &types::None::new(Position::fake()).into()
+        );
+        assert_eq!(
+            symbols[2].type_(),
+            &types::None::new(Position::fake()).into()
+        );
+    }
+
+    #[test]
+    fn collect_global_function() {
+        let symbols = collect(&Module::empty().set_function_definitions(vec![
+            FunctionDefinition::fake(
+                "f",
+                Lambda::new(
+                    vec![],
+                    types::None::new(Position::fake()),
+                    None::new(Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            ),
+            FunctionDefinition::fake(
+                "g",
+                Lambda::new(
+                    vec![],
+                    types::None::new(Position::fake()),
+                    Variable::new("f", Position::fake()),
+                    Position::fake(),
+                ),
+                false,
+            ),
+        ]));
+
+        assert_eq!(symbols.len(), 3);
+        assert_eq!(
+            symbols[2].type_(),
+            &types::Function::new(vec![], types::None::new(Position::fake()), Position::fake())
+                .into()
+        );
+    }
+
+    #[test]
+    fn collect_no_unknown_variable() {
+        let symbols =
+            collect(
+                &Module::empty().set_function_definitions(vec![FunctionDefinition::fake(
+                    "f",
+                    Lambda::new(
+                        vec![],
+                        types::None::new(Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        Position::fake(),
+                    ),
+                    false,
+                )]),
+            );
+
+        assert_eq!(symbols.len(), 1);
+    }
+}
@@ -0,0 +1,63 @@
+use fnv::FnvHashMap;
+use hir::{
+    analysis::{type_formatter, type_transformer},
+    types::{self, Type},
+};
+
+// Format a type with names written in source files rather than qualified ones.
+pub fn format(type_: &Type, names: &FnvHashMap<String, String>) -> String {
+    type_formatter::format(&type_transformer::transform_type(type_, &|type_| {
+        match type_ {
+            Type::Record(record) => names.get(record.name()).map(|name| {
+                types::Record::new(name, record.position().clone())
+                    .set_arguments(record.arguments().to_vec())
+                    .into()
+            }),
+            Type::Reference(reference) => names.get(reference.name()).map(|name| {
+                types::Reference::new(name, reference.position().clone())
+                    .set_arguments(reference.arguments().to_vec())
+                    .into()
+            }),
+            _ => None,
+        }
+        .unwrap_or_else(|| type_.clone())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use position::{test::PositionFake, Position};
+
+    #[test]
+    fn format_record_with_original_name() {
+        assert_eq!(
+            format(
+                &types::Function::new(
+                    vec![types::Record::new("foo.pen:Foo", Position::fake()).into()],
+                    types::Reference::new("bar.pen:Bar", Position::fake()),
+                    Position::fake()
+                )
+                .into(),
+                &[
+                    ("foo.pen:Foo".into(), "Foo".into()),
+                    ("bar.pen:Bar".into(), "Bar'Bar".into())
+                ]
+                .into_iter()
+                .collect()
+            ),
+            "\\(Foo) Bar'Bar"
+        );
+    }
+
+    #[test]
+    fn format_unknown_record() {
+        assert_eq!(
+            format(
+                &types::Record::new("foo", Position::fake()).into(),
+                &Default::default()
+            ),
+            "foo"
+        );
+    }
+}
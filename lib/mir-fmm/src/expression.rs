@@ -15,7 +15,7 @@ pub fn compile(
 
     Ok(match expression {
         mir::ir::Expression::ArithmeticOperation(operation) => {
-            compile_arithmetic_operation(context, builder, operation, variables)?.into()
+            compile_arithmetic_operation(context, builder, operation, variables)?
         }
         mir::ir::Expression::Boolean(boolean) => fmm::ir::Primitive::Boolean(*boolean).into(),
         mir::ir::Expression::Case(case) => compile_case(context, builder, case, variables)?,
@@ -70,6 +70,8 @@ pub fn compile(
         mir::ir::Expression::Synchronize(synchronize) => {
             compile_synchronize(context, builder, synchronize, variables)?
         }
+        mir::ir::Expression::Integer8(integer) => fmm::ir::Primitive::Integer8(*integer).into(),
+        mir::ir::Expression::Integer64(integer) => fmm::ir::Primitive::Integer64(*integer).into(),
         mir::ir::Expression::None => fmm::ir::Undefined::new(type_::compile_none()).into(),
         mir::ir::Expression::Number(number) => fmm::ir::Primitive::Float64(*number).into(),
         mir::ir::Expression::Record(record) => compile_record(context, builder, record, variables)?,
@@ -461,26 +463,45 @@ fn compile_arithmetic_operation(
     builder: &fmm::build::InstructionBuilder,
     operation: &mir::ir::ArithmeticOperation,
     variables: &FnvHashMap<String, fmm::build::TypedExpression>,
-) -> Result<fmm::ir::ArithmeticOperation, CompileError> {
+) -> Result<fmm::build::TypedExpression, CompileError> {
     let compile = |expression| compile(context, builder, expression, variables);
 
     let lhs = compile(operation.lhs())?;
     let rhs = compile(operation.rhs())?;
-
-    Ok(match operation.operator() {
-        mir::ir::ArithmeticOperator::Add => {
-            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Add, lhs, rhs)?
-        }
+    let arithmetic_operation = |operator| -> Result<_, CompileError> {
+        Ok(fmm::build::arithmetic_operation(operator, lhs.clone(), rhs.clone())?.into())
+    };
+    let bitwise_operation = |operator| -> Result<_, CompileError> {
+        Ok(fmm::build::bitwise_operation(operator, lhs.clone(), rhs.clone())?.into())
+    };
+
+    match operation.operator() {
+        mir::ir::ArithmeticOperator::Add => arithmetic_operation(fmm::ir::ArithmeticOperator::Add),
         mir::ir::ArithmeticOperator::Subtract => {
-            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Subtract, lhs, rhs)?
+            arithmetic_operation(fmm::ir::ArithmeticOperator::Subtract)
         }
         mir::ir::ArithmeticOperator::Multiply => {
-            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Multiply, lhs, rhs)?
+            arithmetic_operation(fmm::ir::ArithmeticOperator::Multiply)
         }
         mir::ir::ArithmeticOperator::Divide => {
-            fmm::build::arithmetic_operation(fmm::ir::ArithmeticOperator::Divide, lhs, rhs)?
+            arithmetic_operation(fmm::ir::ArithmeticOperator::Divide)
         }
-    })
+        mir::ir::ArithmeticOperator::Remainder => {
+            arithmetic_operation(fmm::ir::ArithmeticOperator::Remainder)
+        }
+        mir::ir::ArithmeticOperator::BitwiseAnd => bitwise_operation(fmm::ir::BitwiseOperator::And),
+        mir::ir::ArithmeticOperator::BitwiseOr => bitwise_operation(fmm::ir::BitwiseOperator::Or),
+        mir::ir::ArithmeticOperator::BitwiseXor => bitwise_operation(fmm::ir::BitwiseOperator::Xor),
+        mir::ir::ArithmeticOperator::LeftShift => {
+            bitwise_operation(fmm::ir::BitwiseOperator::LeftShift)
+        }
+        mir::ir::ArithmeticOperator::LogicalRightShift => {
+            bitwise_operation(fmm::ir::BitwiseOperator::RightShift(false))
+        }
+        mir::ir::ArithmeticOperator::ArithmeticRightShift => {
+            bitwise_operation(fmm::ir::BitwiseOperator::RightShift(true))
+        }
+    }
 }
 
 fn compile_comparison_operation(
@@ -493,20 +514,25 @@ fn compile_comparison_operation(
 
     let lhs = compile(operation.lhs())?;
     let rhs = compile(operation.rhs())?;
+    // Integers are always compared as signed ones in MIR.
+    let signed = !matches!(
+        lhs.type_(),
+        fmm::types::Type::Primitive(fmm::types::Primitive::Float64)
+    );
 
     Ok(fmm::build::comparison_operation(
         match operation.operator() {
             mir::ir::ComparisonOperator::Equal => fmm::ir::ComparisonOperator::Equal,
             mir::ir::ComparisonOperator::NotEqual => fmm::ir::ComparisonOperator::NotEqual,
             mir::ir::ComparisonOperator::GreaterThan => {
-                fmm::ir::ComparisonOperator::GreaterThan(false)
+                fmm::ir::ComparisonOperator::GreaterThan(signed)
             }
             mir::ir::ComparisonOperator::GreaterThanOrEqual => {
-                fmm::ir::ComparisonOperator::GreaterThanOrEqual(false)
+                fmm::ir::ComparisonOperator::GreaterThanOrEqual(signed)
             }
-            mir::ir::ComparisonOperator::LessThan => fmm::ir::ComparisonOperator::LessThan(false),
+            mir::ir::ComparisonOperator::LessThan => fmm::ir::ComparisonOperator::LessThan(signed),
             mir::ir::ComparisonOperator::LessThanOrEqual => {
-                fmm::ir::ComparisonOperator::LessThanOrEqual(false)
+                fmm::ir::ComparisonOperator::LessThanOrEqual(signed)
             }
         },
         lhs,
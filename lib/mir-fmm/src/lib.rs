@@ -170,6 +170,13 @@ mod tests {
         )
     });
 
+    static UNBOXED_RECORD_DEFINITION: Lazy<mir::ir::TypeDefinition> = Lazy::new(|| {
+        mir::ir::TypeDefinition::new(
+            "a",
+            mir::types::RecordBody::new(vec![mir::types::Type::Variant]).set_unboxed(true),
+        )
+    });
+
     fn compile_module(module: &mir::ir::Module) {
         let module = compile(module, &CONFIGURATION).unwrap();
 
@@ -308,6 +315,23 @@ mod tests {
                     )]),
             );
         }
+
+        #[test]
+        fn compile_with_explicitly_unboxed_record_argument() {
+            compile_module(
+                &mir::ir::Module::empty()
+                    .set_type_definitions(vec![UNBOXED_RECORD_DEFINITION.clone()])
+                    .set_foreign_declarations(vec![mir::ir::ForeignDeclaration::new(
+                        "f",
+                        "g",
+                        mir::types::Function::new(
+                            vec![mir::types::Record::new("a").into()],
+                            mir::types::Record::new("a"),
+                        ),
+                        mir::ir::CallingConvention::Target,
+                    )]),
+            );
+        }
     }
 
     mod foreign_definition {
@@ -785,6 +809,30 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn compile_explicitly_unboxed_record() {
+                let record_type = mir::types::Record::new("a");
+
+                compile_module(
+                    &mir::ir::Module::empty()
+                        .set_type_definitions(vec![UNBOXED_RECORD_DEFINITION.clone()])
+                        .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![mir::ir::Argument::new("x", mir::types::Type::Variant)],
+                            record_type.clone(),
+                            mir::ir::Case::new(
+                                mir::ir::Variable::new("x"),
+                                vec![mir::ir::Alternative::new(
+                                    vec![record_type.into()],
+                                    "x",
+                                    mir::ir::Variable::new("x"),
+                                )],
+                                None,
+                            ),
+                        )]),
+                );
+            }
+
             #[test]
             fn compile_string() {
                 compile_module(&mir::ir::Module::empty().set_function_definitions(vec![
@@ -931,6 +979,25 @@ mod tests {
                         )]),
                 );
             }
+
+            #[test]
+            fn compile_explicitly_unboxed() {
+                let record_type = mir::types::Record::new("a");
+
+                compile_module(
+                    &mir::ir::Module::empty()
+                        .set_type_definitions(vec![UNBOXED_RECORD_DEFINITION.clone()])
+                        .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![mir::ir::Argument::new("x", mir::types::Type::Variant)],
+                            record_type.clone(),
+                            mir::ir::Record::new(
+                                record_type,
+                                vec![mir::ir::Variable::new("x").into()],
+                            ),
+                        )]),
+                );
+            }
         }
 
         mod record_field {
@@ -976,6 +1043,22 @@ mod tests {
                         )]),
                 );
             }
+
+            #[test]
+            fn compile_with_explicitly_unboxed_record() {
+                let record_type = mir::types::Record::new("a");
+
+                compile_module(
+                    &mir::ir::Module::empty()
+                        .set_type_definitions(vec![UNBOXED_RECORD_DEFINITION.clone()])
+                        .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![mir::ir::Argument::new("x", record_type.clone())],
+                            mir::types::Type::Variant,
+                            mir::ir::RecordField::new(record_type, 0, mir::ir::Variable::new("x")),
+                        )]),
+                );
+            }
         }
 
         mod record_update {
@@ -1118,6 +1201,32 @@ mod tests {
                         )]),
                 );
             }
+
+            #[test]
+            fn compile_explicitly_unboxed_record() {
+                let record_type = mir::types::Record::new("a");
+
+                compile_module(
+                    &mir::ir::Module::empty()
+                        .set_type_definitions(vec![UNBOXED_RECORD_DEFINITION.clone()])
+                        .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![
+                                mir::ir::Argument::new("x", record_type.clone()),
+                                mir::ir::Argument::new("y", mir::types::Type::Variant),
+                            ],
+                            record_type.clone(),
+                            mir::ir::RecordUpdate::new(
+                                record_type,
+                                mir::ir::Variable::new("x"),
+                                vec![mir::ir::RecordUpdateField::new(
+                                    0,
+                                    mir::ir::Variable::new("y"),
+                                )],
+                            ),
+                        )]),
+                );
+            }
         }
 
         mod variant {
@@ -1135,6 +1244,30 @@ mod tests {
                 ]));
             }
 
+            #[test]
+            fn compile_with_integer_8() {
+                compile_module(&mir::ir::Module::empty().set_function_definitions(vec![
+                    mir::ir::FunctionDefinition::new(
+                        "f",
+                        vec![],
+                        mir::types::Type::Variant,
+                        mir::ir::Variant::new(mir::types::Type::Integer8, 42u8),
+                    ),
+                ]));
+            }
+
+            #[test]
+            fn compile_with_integer_64() {
+                compile_module(&mir::ir::Module::empty().set_function_definitions(vec![
+                    mir::ir::FunctionDefinition::new(
+                        "f",
+                        vec![],
+                        mir::types::Type::Variant,
+                        mir::ir::Variant::new(mir::types::Type::Integer64, 42u64),
+                    ),
+                ]));
+            }
+
             #[test]
             fn compile_with_empty_unboxed_record() {
                 let record_type = mir::types::Record::new("foo");
@@ -1196,6 +1329,64 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn convert_word_sized_explicitly_unboxed_record() {
+                let record_type = mir::types::Record::new("a");
+
+                compile_module(
+                    &mir::ir::Module::empty()
+                        .set_type_definitions(vec![mir::ir::TypeDefinition::new(
+                            "a",
+                            mir::types::RecordBody::new(vec![mir::types::Type::ByteString])
+                                .set_unboxed(true),
+                        )])
+                        .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![mir::ir::Argument::new("x", record_type.clone())],
+                            record_type.clone(),
+                            mir::ir::Case::new(
+                                mir::ir::Variant::new(
+                                    record_type.clone(),
+                                    mir::ir::Variable::new("x"),
+                                ),
+                                vec![mir::ir::Alternative::new(
+                                    vec![record_type.into()],
+                                    "y",
+                                    mir::ir::Variable::new("y"),
+                                )],
+                                None,
+                            ),
+                        )]),
+                );
+            }
+
+            #[test]
+            fn convert_explicitly_unboxed_record_of_variant() {
+                let record_type = mir::types::Record::new("a");
+
+                compile_module(
+                    &mir::ir::Module::empty()
+                        .set_type_definitions(vec![UNBOXED_RECORD_DEFINITION.clone()])
+                        .set_function_definitions(vec![mir::ir::FunctionDefinition::new(
+                            "f",
+                            vec![mir::ir::Argument::new("x", record_type.clone())],
+                            record_type.clone(),
+                            mir::ir::Case::new(
+                                mir::ir::Variant::new(
+                                    record_type.clone(),
+                                    mir::ir::Variable::new("x"),
+                                ),
+                                vec![mir::ir::Alternative::new(
+                                    vec![record_type.into()],
+                                    "y",
+                                    mir::ir::Variable::new("y"),
+                                )],
+                                None,
+                            ),
+                        )]),
+                );
+            }
+
             #[test]
             fn compile_unboxed_large_record() {
                 let record_type = mir::types::Record::new("a");
@@ -1369,6 +1560,38 @@ mod tests {
             ]));
         }
 
+        #[test]
+        fn compile_integer_comparison() {
+            compile_module(&mir::ir::Module::empty().set_function_definitions(vec![
+                mir::ir::FunctionDefinition::new(
+                    "f",
+                    vec![mir::ir::Argument::new("x", mir::types::Type::Integer64)],
+                    mir::types::Type::Boolean,
+                    mir::ir::ComparisonOperation::new(
+                        mir::ir::ComparisonOperator::LessThan,
+                        mir::ir::Variable::new("x"),
+                        42u64,
+                    ),
+                ),
+            ]));
+        }
+
+        #[test]
+        fn compile_bitwise_operation() {
+            compile_module(&mir::ir::Module::empty().set_function_definitions(vec![
+                mir::ir::FunctionDefinition::new(
+                    "f",
+                    vec![mir::ir::Argument::new("x", mir::types::Type::Integer64)],
+                    mir::types::Type::Integer64,
+                    mir::ir::ArithmeticOperation::new(
+                        mir::ir::ArithmeticOperator::ArithmeticRightShift,
+                        mir::ir::Variable::new("x"),
+                        1u64,
+                    ),
+                ),
+            ]));
+        }
+
         #[test]
         fn compile_try_operation() {
             compile_module(&mir::ir::Module::empty().set_function_definitions(vec![
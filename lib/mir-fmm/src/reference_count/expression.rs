@@ -39,9 +39,11 @@ pub fn clone(
                 },
             )?
         }
-        mir::types::Type::Boolean | mir::types::Type::None | mir::types::Type::Number => {
-            expression.clone()
-        }
+        mir::types::Type::Boolean
+        | mir::types::Type::Integer8
+        | mir::types::Type::Integer64
+        | mir::types::Type::None
+        | mir::types::Type::Number => expression.clone(),
     })
 }
 
@@ -72,7 +74,11 @@ pub fn drop(
                 vec![variant::get_payload(builder, expression)?],
             )?;
         }
-        mir::types::Type::Boolean | mir::types::Type::None | mir::types::Type::Number => {}
+        mir::types::Type::Boolean
+        | mir::types::Type::Integer8
+        | mir::types::Type::Integer64
+        | mir::types::Type::None
+        | mir::types::Type::Number => {}
     }
 
     Ok(())
@@ -109,7 +115,11 @@ pub fn synchronize(
                 vec![variant::get_payload(builder, expression)?],
             )?;
         }
-        mir::types::Type::Boolean | mir::types::Type::None | mir::types::Type::Number => {}
+        mir::types::Type::Boolean
+        | mir::types::Type::Integer8
+        | mir::types::Type::Integer64
+        | mir::types::Type::None
+        | mir::types::Type::Number => {}
     }
 
     Ok(())
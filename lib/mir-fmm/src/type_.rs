@@ -14,6 +14,8 @@ pub fn compile(
         mir::types::Type::Function(function) => {
             fmm::types::Pointer::new(compile_unsized_closure(function, types)).into()
         }
+        mir::types::Type::Integer8 => fmm::types::Primitive::Integer8.into(),
+        mir::types::Type::Integer64 => fmm::types::Primitive::Integer64.into(),
         mir::types::Type::None => compile_none(),
         mir::types::Type::Number => fmm::types::Primitive::Float64.into(),
         mir::types::Type::Record(record) => compile_record(record, types),
@@ -95,7 +97,7 @@ pub fn is_record_boxed(
     let body_type = &types[record.name()];
 
     // TODO Unbox small records.
-    !body_type.fields().is_empty()
+    !body_type.is_unboxed() && !body_type.fields().is_empty()
 }
 
 pub fn compile_boxed_record() -> fmm::types::Type {
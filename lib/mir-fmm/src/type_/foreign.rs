@@ -46,6 +46,8 @@ pub fn is_payload_boxed(
         mir::types::Type::Boolean
         | mir::types::Type::ByteString
         | mir::types::Type::Function(_)
+        | mir::types::Type::Integer8
+        | mir::types::Type::Integer64
         | mir::types::Type::None
         | mir::types::Type::Number => false,
     })
@@ -55,5 +57,9 @@ fn is_record_boxed(
     record: &mir::types::Record,
     types: &FnvHashMap<String, mir::types::RecordBody>,
 ) -> bool {
-    !types[record.name()].fields().is_empty()
+    let body_type = &types[record.name()];
+
+    // Unboxed records are passed as they are so that foreign functions do not
+    // allocate them again.
+    !body_type.is_unboxed() && !body_type.fields().is_empty()
 }
@@ -30,6 +30,8 @@ pub fn is_payload_boxed(
         mir::types::Type::Boolean
         | mir::types::Type::ByteString
         | mir::types::Type::Function(_)
+        | mir::types::Type::Integer8
+        | mir::types::Type::Integer64
         | mir::types::Type::None
         | mir::types::Type::Number => false,
     })
@@ -42,6 +44,105 @@ fn is_record_boxed(
 ) -> bool {
     let body_type = &types[record.name()];
 
-    // TODO Unbox small records.
-    !body_type.fields().is_empty()
+    if body_type.is_unboxed() {
+        !is_word_sized(record, types)
+    } else {
+        // TODO Unbox small records.
+        !body_type.fields().is_empty()
+    }
+}
+
+// Unboxed records of single word-sized fields are stored in payloads as they
+// are without any heap allocation.
+fn is_word_sized(
+    record: &mir::types::Record,
+    types: &FnvHashMap<String, mir::types::RecordBody>,
+) -> bool {
+    match types[record.name()].fields() {
+        [type_] => match type_ {
+            mir::types::Type::Record(record) => {
+                !types[record.name()].is_unboxed() || is_word_sized(record, types)
+            }
+            mir::types::Type::Boolean
+            | mir::types::Type::ByteString
+            | mir::types::Type::Function(_)
+            | mir::types::Type::Integer8
+            | mir::types::Type::Integer64
+            | mir::types::Type::Number => true,
+            mir::types::Type::None | mir::types::Type::Variant => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_record_payload_boxed(types: &[(&str, mir::types::RecordBody)]) -> bool {
+        is_payload_boxed(
+            &mir::types::Record::new(types[0].0).into(),
+            &types
+                .iter()
+                .map(|(name, body)| (name.to_string(), body.clone()))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn unbox_word_sized_explicitly_unboxed_record() {
+        for type_ in [
+            mir::types::Type::Boolean,
+            mir::types::Type::ByteString,
+            mir::types::Type::Integer64,
+            mir::types::Type::Number,
+        ] {
+            assert!(!is_record_payload_boxed(&[(
+                "a",
+                mir::types::RecordBody::new(vec![type_]).set_unboxed(true)
+            )]));
+        }
+    }
+
+    #[test]
+    fn unbox_explicitly_unboxed_record_of_boxed_record() {
+        assert!(!is_record_payload_boxed(&[
+            (
+                "a",
+                mir::types::RecordBody::new(vec![mir::types::Record::new("b").into()])
+                    .set_unboxed(true)
+            ),
+            (
+                "b",
+                mir::types::RecordBody::new(vec![
+                    mir::types::Type::Number,
+                    mir::types::Type::Number
+                ])
+            ),
+        ]));
+    }
+
+    #[test]
+    fn box_explicitly_unboxed_record_of_variant() {
+        assert!(is_record_payload_boxed(&[(
+            "a",
+            mir::types::RecordBody::new(vec![mir::types::Type::Variant]).set_unboxed(true)
+        )]));
+    }
+
+    #[test]
+    fn box_explicitly_unboxed_record_of_explicitly_unboxed_record_of_variant() {
+        assert!(is_record_payload_boxed(&[
+            (
+                "a",
+                mir::types::RecordBody::new(vec![mir::types::Record::new("b").into()])
+                    .set_unboxed(true)
+            ),
+            (
+                "b",
+                mir::types::RecordBody::new(vec![mir::types::Type::Variant]).set_unboxed(true)
+            ),
+        ]));
+    }
 }
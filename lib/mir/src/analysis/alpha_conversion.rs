@@ -246,6 +246,8 @@ fn transform_expression<'a>(
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_) => expression.clone(),
     }
 }
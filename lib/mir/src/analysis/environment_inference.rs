@@ -221,6 +221,8 @@ fn transform_expression(
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_)
         | Expression::Variable(_) => expression.clone(),
     }
@@ -65,6 +65,8 @@ fn find_in_expression(expression: &Expression) -> FnvHashSet<String> {
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_) => FnvHashSet::default(),
     }
 }
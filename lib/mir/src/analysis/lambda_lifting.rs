@@ -218,6 +218,8 @@ fn transform_expression(context: &mut Context, expression: &Expression) -> Expre
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_)
         | Expression::Variable(_) => expression.clone(),
     }
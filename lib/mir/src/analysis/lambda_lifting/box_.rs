@@ -63,6 +63,8 @@ pub fn is_boxed(expression: &Expression, name: &str) -> bool {
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_) => false,
     }
 }
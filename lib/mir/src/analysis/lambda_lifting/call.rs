@@ -144,6 +144,8 @@ pub fn transform(expression: &Expression, name: &str, free_variables: &[Argument
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_)
         | Expression::Variable(_) => expression.clone(),
     }
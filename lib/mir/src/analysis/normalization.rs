@@ -226,6 +226,8 @@ fn transform_expression(
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_)
         | Expression::Variable(_) => continue_(expression.clone()),
     }
@@ -244,6 +246,8 @@ fn transform_bound_expression(
             Expression::Boolean(_)
             | Expression::ByteString(_)
             | Expression::None
+            | Expression::Integer8(_)
+            | Expression::Integer64(_)
             | Expression::Number(_)
             | Expression::Variable(_) => continue_(bound_expression),
             _ => {
@@ -130,6 +130,8 @@ fn transform_expression(expression: &Expression, variables: &hamt::Map<&str, &st
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_) => expression.clone(),
     }
 }
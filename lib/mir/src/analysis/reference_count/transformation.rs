@@ -547,6 +547,8 @@ fn transform_expression(
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_) => (expression.clone(), moved_variables.clone()),
         Expression::CloneVariables(_) | Expression::DropVariables(_) => {
             return Err(ReferenceCountError::ExpressionNotSupported(
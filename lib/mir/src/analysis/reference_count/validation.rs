@@ -146,7 +146,7 @@ fn move_expression(
             move_expression(synchronize.expression(), variables)?;
         }
         Expression::None => {}
-        Expression::Number(_) => {}
+        Expression::Integer8(_) | Expression::Integer64(_) | Expression::Number(_) => {}
         Expression::Record(record) => {
             move_record(record, variables)?;
         }
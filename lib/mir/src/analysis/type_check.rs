@@ -84,16 +84,22 @@ fn check_expression(
 
     Ok(match expression {
         Expression::ArithmeticOperation(operation) => {
-            check_equality(
-                &check_expression(operation.lhs(), variables)?,
-                &Type::Number,
-            )?;
-            check_equality(
-                &check_expression(operation.rhs(), variables)?,
-                &Type::Number,
-            )?;
-
-            Type::Number
+            let lhs = check_expression(operation.lhs(), variables)?;
+            let rhs = check_expression(operation.rhs(), variables)?;
+
+            match operation.operator() {
+                ArithmeticOperator::Add
+                | ArithmeticOperator::Subtract
+                | ArithmeticOperator::Multiply
+                | ArithmeticOperator::Divide
+                | ArithmeticOperator::Remainder => check_numeric_operands(&lhs, &rhs)?,
+                ArithmeticOperator::BitwiseAnd
+                | ArithmeticOperator::BitwiseOr
+                | ArithmeticOperator::BitwiseXor
+                | ArithmeticOperator::LeftShift
+                | ArithmeticOperator::LogicalRightShift
+                | ArithmeticOperator::ArithmeticRightShift => check_integer_operands(&lhs, &rhs)?,
+            }
         }
         Expression::Boolean(_) => Type::Boolean,
         Expression::Case(case) => check_case(case, variables, result_type, types)?,
@@ -105,13 +111,9 @@ fn check_expression(
             check_expression(clone.expression(), variables)?
         }
         Expression::ComparisonOperation(operation) => {
-            check_equality(
+            check_numeric_operands(
                 &check_expression(operation.lhs(), variables)?,
-                &Type::Number,
-            )?;
-            check_equality(
                 &check_expression(operation.rhs(), variables)?,
-                &Type::Number,
             )?;
 
             Type::Boolean
@@ -186,6 +188,8 @@ fn check_expression(
 
             type_
         }
+        Expression::Integer8(_) => Type::Integer8,
+        Expression::Integer64(_) => Type::Integer64,
         Expression::None => Type::None,
         Expression::Number(_) => Type::Number,
         Expression::Record(record) => check_record(record, variables, result_type, types)?,
@@ -360,6 +364,29 @@ fn check_variable(
         .ok_or_else(|| TypeCheckError::VariableNotFound(variable.clone()))
 }
 
+fn check_numeric_operands(lhs: &Type, rhs: &Type) -> Result<Type, TypeCheckError> {
+    if !matches!(lhs, Type::Integer8 | Type::Integer64 | Type::Number) {
+        return Err(TypeCheckError::TypesNotMatched(lhs.clone(), Type::Number));
+    }
+
+    check_equality(lhs, rhs)?;
+
+    Ok(lhs.clone())
+}
+
+fn check_integer_operands(lhs: &Type, rhs: &Type) -> Result<Type, TypeCheckError> {
+    if !matches!(lhs, Type::Integer8 | Type::Integer64) {
+        return Err(TypeCheckError::TypesNotMatched(
+            lhs.clone(),
+            Type::Integer64,
+        ));
+    }
+
+    check_equality(lhs, rhs)?;
+
+    Ok(lhs.clone())
+}
+
 fn check_equality(one: &Type, other: &Type) -> Result<(), TypeCheckError> {
     if one == other {
         Ok(())
@@ -1052,6 +1079,66 @@ mod tests {
         assert_eq!(check(&module), Ok(()));
     }
 
+    #[test]
+    fn check_add_operator_with_integers() {
+        let module = Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+            "f",
+            vec![Argument::new("x", Type::Integer64)],
+            Type::Integer64,
+            ArithmeticOperation::new(ArithmeticOperator::Add, Variable::new("x"), 42u64),
+        )
+        .set_environment(vec![])]);
+        assert_eq!(check(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_add_operator_with_different_types() {
+        let module = Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+            "f",
+            vec![Argument::new("x", Type::Integer64)],
+            Type::Integer64,
+            ArithmeticOperation::new(ArithmeticOperator::Add, Variable::new("x"), 42.0),
+        )
+        .set_environment(vec![])]);
+        assert_eq!(
+            check(&module),
+            Err(TypeCheckError::TypesNotMatched(
+                Type::Integer64,
+                Type::Number
+            ))
+        );
+    }
+
+    #[test]
+    fn check_bitwise_operator() {
+        let module = Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+            "f",
+            vec![Argument::new("x", Type::Integer8)],
+            Type::Integer8,
+            ArithmeticOperation::new(ArithmeticOperator::BitwiseAnd, Variable::new("x"), 42u8),
+        )
+        .set_environment(vec![])]);
+        assert_eq!(check(&module), Ok(()));
+    }
+
+    #[test]
+    fn fail_to_check_bitwise_operator_with_numbers() {
+        let module = Module::empty().set_function_definitions(vec![FunctionDefinition::new(
+            "f",
+            vec![Argument::new("x", Type::Number)],
+            Type::Number,
+            ArithmeticOperation::new(ArithmeticOperator::BitwiseOr, Variable::new("x"), 42.0),
+        )
+        .set_environment(vec![])]);
+        assert_eq!(
+            check(&module),
+            Err(TypeCheckError::TypesNotMatched(
+                Type::Number,
+                Type::Integer64
+            ))
+        );
+    }
+
     #[test]
     fn check_equality_operator() {
         let module = Module::empty().set_function_definitions(vec![FunctionDefinition::new(
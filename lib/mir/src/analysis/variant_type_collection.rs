@@ -73,6 +73,8 @@ fn collect_from_expression(expression: &Expression) -> FnvHashSet<Type> {
         Expression::Boolean(_)
         | Expression::ByteString(_)
         | Expression::None
+        | Expression::Integer8(_)
+        | Expression::Integer64(_)
         | Expression::Number(_)
         | Expression::Variable(_) => Default::default(),
     }
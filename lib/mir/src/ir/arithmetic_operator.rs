@@ -4,4 +4,11 @@ pub enum ArithmeticOperator {
     Subtract,
     Multiply,
     Divide,
+    Remainder,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    LeftShift,
+    LogicalRightShift,
+    ArithmeticRightShift,
 }
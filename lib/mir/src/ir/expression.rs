@@ -17,6 +17,8 @@ pub enum Expression {
     ComparisonOperation(ComparisonOperation),
     DropVariables(DropVariables),
     If(If),
+    Integer8(u8),
+    Integer64(u64),
     Let(Let),
     LetRecursive(LetRecursive),
     Synchronize(Synchronize),
@@ -90,6 +92,18 @@ impl From<If> for Expression {
     }
 }
 
+impl From<u8> for Expression {
+    fn from(integer: u8) -> Self {
+        Self::Integer8(integer)
+    }
+}
+
+impl From<u64> for Expression {
+    fn from(integer: u64) -> Self {
+        Self::Integer64(integer)
+    }
+}
+
 impl From<LetRecursive> for Expression {
     fn from(let_recursive: LetRecursive) -> Self {
         Self::LetRecursive(let_recursive)
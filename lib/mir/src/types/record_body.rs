@@ -3,14 +3,27 @@ use super::type_::Type;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RecordBody {
     fields: Vec<Type>,
+    unboxed: bool,
 }
 
 impl RecordBody {
     pub const fn new(fields: Vec<Type>) -> Self {
-        Self { fields }
+        Self {
+            fields,
+            unboxed: false,
+        }
     }
 
     pub fn fields(&self) -> &[Type] {
         &self.fields
     }
+
+    pub fn is_unboxed(&self) -> bool {
+        self.unboxed
+    }
+
+    pub fn set_unboxed(mut self, unboxed: bool) -> Self {
+        self.unboxed = unboxed;
+        self
+    }
 }
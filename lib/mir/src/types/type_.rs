@@ -5,6 +5,8 @@ pub enum Type {
     Boolean,
     ByteString,
     Function(Function),
+    Integer8,
+    Integer64,
     None,
     Number,
     Record(Record),
@@ -46,6 +46,18 @@ impl ParseError {
             ),
         }
     }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
 }
 
 impl Error for ParseError {}
@@ -71,3 +83,34 @@ impl Display for ParseError {
         )
     }
 }
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseErrors {
+    errors: Vec<ParseError>,
+}
+
+impl ParseErrors {
+    pub fn new(errors: Vec<ParseError>) -> Self {
+        Self { errors }
+    }
+
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+}
+
+impl Error for ParseErrors {}
+
+impl Display for ParseErrors {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            formatter,
+            "{}",
+            self.errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        )
+    }
+}
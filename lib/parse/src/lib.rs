@@ -5,9 +5,9 @@ mod stream;
 
 use ast::Comment;
 use combine::Parser;
-pub use error::ParseError;
+pub use error::{ParseError, ParseErrors};
 use parsers::{comments, module};
-use stream::stream;
+use stream::{recoverable_stream, stream};
 
 pub fn parse(source: &str, path: &str) -> Result<ast::Module, ParseError> {
     module()
@@ -16,6 +16,38 @@ pub fn parse(source: &str, path: &str) -> Result<ast::Module, ParseError> {
         .map_err(|error| ParseError::new(source, path, error))
 }
 
+// Parse a module recovering from errors at statement and definition boundaries.
+// A returned module contains only definitions without any errors.
+pub fn parse_with_recovery(source: &str, path: &str) -> (ast::Module, Vec<ParseError>) {
+    match module().parse(recoverable_stream(source, path)) {
+        Ok((module, stream)) => (
+            module,
+            stream
+                .0
+                .state
+                .errors
+                .into_iter()
+                .map(|error| ParseError::new(source, path, error))
+                .collect(),
+        ),
+        Err(error) => {
+            let error = ParseError::new(source, path, error);
+
+            (
+                ast::Module::new(
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    vec![],
+                    error.position().clone(),
+                ),
+                vec![error],
+            )
+        }
+    }
+}
+
 pub fn parse_comments(source: &str, path: &str) -> Result<Vec<Comment>, ParseError> {
     comments()
         .parse(stream(source, path))
@@ -38,8 +70,109 @@ mod tests {
                 vec![],
                 vec![],
                 vec![],
+                vec![],
                 Position::fake()
             ))
         );
     }
+
+    mod recovery {
+        use super::*;
+        use indoc::indoc;
+
+        fn parse_error_lines(source: &str) -> (Module, Vec<usize>) {
+            let (module, errors) = parse_with_recovery(source, "");
+
+            (
+                module,
+                errors
+                    .iter()
+                    .map(|error| error.position().line_number())
+                    .collect(),
+            )
+        }
+
+        #[test]
+        fn parse_module_without_error() {
+            let source = "f = \\() none { none }";
+
+            assert_eq!(
+                parse_with_recovery(source, ""),
+                (parse(source, "").unwrap(), vec![])
+            );
+        }
+
+        #[test]
+        fn recover_from_error_in_import() {
+            let (module, lines) = parse_error_lines(indoc!(
+                "
+                import Foo'Bar
+                import )
+                import Baz'Qux
+                "
+            ));
+
+            assert_eq!(module.imports().len(), 2);
+            assert_eq!(lines, vec![2]);
+        }
+
+        #[test]
+        fn recover_from_errors_in_definitions() {
+            let (module, lines) = parse_error_lines(indoc!(
+                "
+                f = \\() none {
+                  x =
+                }
+
+                g = \\() none {
+                  none
+                }
+
+                h = \\() none { ) }
+                "
+            ));
+
+            assert_eq!(
+                module
+                    .function_definitions()
+                    .iter()
+                    .map(|definition| definition.name())
+                    .collect::<Vec<_>>(),
+                vec!["g"]
+            );
+            assert_eq!(lines, vec![3, 9]);
+        }
+
+        #[test]
+        fn recover_from_errors_in_statements() {
+            let (module, lines) = parse_error_lines(indoc!(
+                "
+                f = \\() none {
+                  x = )
+                  y = foo(
+                    ),
+                  )
+                  none
+                }
+                "
+            ));
+
+            assert_eq!(module.function_definitions(), &[]);
+            assert_eq!(lines, vec![2, 4, 5]);
+        }
+
+        #[test]
+        fn recover_from_unexpected_character_at_top_level() {
+            let (module, lines) = parse_error_lines(indoc!(
+                "
+                f = \\() none { none }
+                )
+                g = \\() none { none }
+                "
+            ));
+
+            assert_eq!(module.function_definitions().len(), 2);
+            assert_eq!(lines, vec![2]);
+        }
+    }
 }
@@ -5,27 +5,46 @@ use ast::{
     *,
 };
 use combine::{
-    attempt, choice, look_ahead, many, many1, none_of, one_of, optional,
+    attempt, choice,
+    error::{ParseResult, Tracked},
+    look_ahead, many, many1, none_of, one_of, optional,
     parser::{
         char::{alpha_num, char as character, digit, letter, newline, space, spaces, string},
         combinator::{lazy, no_partial, not_followed_by},
         regex::find,
         sequence::between,
     },
-    sep_end_by, sep_end_by1, unexpected_any, value, Parser, Positioned,
+    sep_by1, sep_end_by, sep_end_by1,
+    stream::{ResetStream, StreamOnce},
+    unexpected_any, value, ErrorOffset, Parser, Positioned,
 };
 use fnv::FnvHashSet;
 use once_cell::sync::Lazy;
 use position::Position;
 
 static KEYWORDS: &[&str] = &[
-    "as", "else", "export", "for", "foreign", "if", "in", "import", "type",
+    "as",
+    "else",
+    "export",
+    "for",
+    "foreign",
+    "if",
+    "implement",
+    "in",
+    "import",
+    "interface",
+    "match",
+    "newtype",
+    "type",
 ];
-const OPERATOR_CHARACTERS: &str = "+-*/=<>&|!?";
+const OPERATOR_CHARACTERS: &str = "+-*/%=<>&|^!?";
 
 static BINARY_REGEX: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r"^0b[01]+").unwrap());
 static HEXADECIMAL_REGEX: Lazy<regex::Regex> =
     Lazy::new(|| regex::Regex::new(r"^0x[0-9a-fA-F]+").unwrap());
+static INTEGER_REGEX: Lazy<regex::Regex> = Lazy::new(|| {
+    regex::Regex::new(r"^(0b[01]+|0x[0-9a-fA-F]+?|-?([1-9][0-9]*|0))(byte|i64|u64)").unwrap()
+});
 static DECIMAL_REGEX: Lazy<regex::Regex> =
     Lazy::new(|| regex::Regex::new(r"^-?([1-9][0-9]*|0)(\.[0-9]+)?").unwrap());
 static STRING_CHARACTER_REGEX: Lazy<regex::Regex> =
@@ -37,22 +56,37 @@ pub fn module<'a>() -> impl Parser<Stream<'a>, Output = Module> {
     (
         position(),
         blank(),
-        many(import()),
-        many(foreign_import()),
-        many(choice((
-            type_alias().map(TypeDefinition::from),
-            record_definition().map(TypeDefinition::from),
-        ))),
-        many(definition()),
+        many(recover(import(), |_| false)),
+        many(recover(foreign_import(), |_| false)),
+        many(recover(
+            choice((
+                type_alias().map(TypeDefinition::from),
+                record_definition().map(TypeDefinition::from),
+                newtype_definition().map(TypeDefinition::from),
+                interface_definition().map(TypeDefinition::from),
+            )),
+            |_| false,
+        )),
+        many(recover(implementation(), |_| false)),
+        many(recover(definition(), |_| true)),
     )
         .skip(eof())
         .map(
-            |(position, _, imports, foreign_imports, type_definitions, definitions)| {
+            |(
+                position,
+                _,
+                imports,
+                foreign_imports,
+                type_definitions,
+                implementations,
+                definitions,
+            ): (_, _, Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>)| {
                 Module::new(
-                    imports,
-                    foreign_imports,
-                    type_definitions,
-                    definitions,
+                    imports.into_iter().flatten().collect(),
+                    foreign_imports.into_iter().flatten().collect(),
+                    type_definitions.into_iter().flatten().collect(),
+                    implementations.into_iter().flatten().collect(),
+                    definitions.into_iter().flatten().collect(),
                     position,
                 )
             },
@@ -74,9 +108,8 @@ pub fn comments<'a>() -> impl Parser<Stream<'a>, Output = Vec<Comment>> {
 }
 
 fn import<'a>() -> impl Parser<Stream<'a>, Output = Import> {
-    (
+    spanned((
         attempt((
-            position(),
             keyword("import"),
             not_followed_by(keyword("foreign").with(value("foreign"))),
         )),
@@ -87,16 +120,16 @@ fn import<'a>() -> impl Parser<Stream<'a>, Output = Import> {
             sign("}"),
             sep_end_by1(unqualified_name(), sign(",")),
         )),
-    )
-        .map(|((position, _, _), path, prefix, names)| {
-            Import::new(path, prefix, names.unwrap_or_default(), position)
-        })
-        .expected("import statement")
+    ))
+    .map(|((_, path, prefix, names), position)| {
+        Import::new(path, prefix, names.unwrap_or_default(), position)
+    })
+    .expected("import statement")
 }
 
 fn unqualified_name<'a>() -> impl Parser<Stream<'a>, Output = UnqualifiedName> {
-    token(attempt((position(), identifier())))
-        .map(|(position, identifier)| UnqualifiedName::new(identifier, position))
+    token(attempt(spanned(identifier())))
+        .map(|(identifier, position)| UnqualifiedName::new(identifier, position))
         .expected("unqualified name")
 }
 
@@ -139,21 +172,21 @@ fn public_module_path_component<'a>() -> impl Parser<Stream<'a>, Output = String
 }
 
 fn foreign_import<'a>() -> impl Parser<Stream<'a>, Output = ForeignImport> {
-    (
-        attempt(position().skip((keyword("import"), keyword("foreign")))),
+    spanned((
+        attempt((keyword("import"), keyword("foreign"))),
         optional(calling_convention()),
         identifier(),
         type_(),
-    )
-        .map(|(position, calling_convention, name, type_)| {
-            ForeignImport::new(
-                &name,
-                calling_convention.unwrap_or_default(),
-                type_,
-                position,
-            )
-        })
-        .expected("foreign import statement")
+    ))
+    .map(|((_, calling_convention, name, type_), position)| {
+        ForeignImport::new(
+            &name,
+            calling_convention.unwrap_or_default(),
+            type_,
+            position,
+        )
+    })
+    .expected("foreign import statement")
 }
 
 fn calling_convention<'a>() -> impl Parser<Stream<'a>, Output = CallingConvention> {
@@ -171,17 +204,55 @@ fn calling_convention<'a>() -> impl Parser<Stream<'a>, Output = CallingConventio
 fn definition<'a>() -> impl Parser<Stream<'a>, Output = FunctionDefinition> {
     (
         optional(foreign_export()),
-        position(),
-        identifier(),
-        sign("="),
-        lambda(),
+        spanned((
+            identifier(),
+            optional(type_parameters()),
+            sign("="),
+            lambda(),
+        )),
     )
-        .map(|(foreign_export, position, name, _, lambda)| {
-            FunctionDefinition::new(name, lambda, foreign_export, position)
-        })
+        .map(
+            |(foreign_export, ((name, type_parameters, _, lambda), position))| {
+                FunctionDefinition::new(
+                    name,
+                    type_parameters.unwrap_or_default(),
+                    lambda,
+                    foreign_export,
+                    position,
+                )
+            },
+        )
         .expected("definition")
 }
 
+fn type_parameters<'a>() -> impl Parser<Stream<'a>, Output = Vec<TypeParameter>> {
+    between(
+        sign("<"),
+        sign(">"),
+        sep_end_by1(type_parameter(), sign(",")),
+    )
+    .then(|parameters: Vec<TypeParameter>| {
+        if parameters
+            .iter()
+            .map(|parameter| parameter.name())
+            .collect::<FnvHashSet<_>>()
+            .len()
+            == parameters.len()
+        {
+            value(parameters).left()
+        } else {
+            unexpected_any("duplicate type parameters").right()
+        }
+    })
+    .expected("type parameters")
+}
+
+fn type_parameter<'a>() -> impl Parser<Stream<'a>, Output = TypeParameter> {
+    (identifier(), optional(sign(":").with(reference_type())))
+        .map(|(name, constraint)| TypeParameter::new(name, constraint))
+        .expected("type parameter")
+}
+
 fn foreign_export<'a>() -> impl Parser<Stream<'a>, Output = ForeignExport> {
     keyword("foreign")
         .with(optional(calling_convention()))
@@ -189,35 +260,121 @@ fn foreign_export<'a>() -> impl Parser<Stream<'a>, Output = ForeignExport> {
 }
 
 fn record_definition<'a>() -> impl Parser<Stream<'a>, Output = RecordDefinition> {
-    (
-        attempt((position(), keyword("type"))),
+    spanned((
+        attempt(keyword("type")),
         identifier(),
+        optional(type_definition_parameters()),
         sign("{"),
-        many((identifier(), type_())),
+        many(record_field_definition()),
         sign("}"),
+    ))
+    .map(|((_, name, parameters, _, fields, _), position)| {
+        RecordDefinition::new(name, fields, position)
+            .set_type_parameters(parameters.unwrap_or_default())
+    })
+    .expected("record definition")
+}
+
+fn type_definition_parameters<'a>() -> impl Parser<Stream<'a>, Output = Vec<String>> {
+    between(
+        sign("<"),
+        sign(">"),
+        sep_end_by1(identifier(), sign(",")),
     )
-        .map(
-            |((position, _), name, _, fields, _): (_, _, _, Vec<_>, _)| {
-                RecordDefinition::new(
-                    name,
-                    fields
-                        .into_iter()
-                        .map(|(name, type_)| types::RecordField::new(name, type_))
-                        .collect(),
-                    position,
-                )
-            },
-        )
-        .expected("record definition")
+    .then(|parameters: Vec<String>| {
+        if parameters.iter().collect::<FnvHashSet<_>>().len() == parameters.len() {
+            value(parameters).left()
+        } else {
+            unexpected_any("duplicate type parameters").right()
+        }
+    })
+    .expected("type parameters")
+}
+
+fn record_field_definition<'a>() -> impl Parser<Stream<'a>, Output = RecordFieldDefinition> {
+    spanned((
+        identifier(),
+        type_(),
+        optional((sign("="), expression()).map(|(_, expression)| expression)),
+    ))
+    .map(|((name, type_, default), position)| {
+        RecordFieldDefinition::new(name, type_, default, position)
+    })
+    .expected("record field definition")
+}
+
+fn newtype_definition<'a>() -> impl Parser<Stream<'a>, Output = RecordDefinition> {
+    spanned((
+        attempt(keyword("newtype")),
+        identifier(),
+        sign("{"),
+        record_field_definition(),
+        sign("}"),
+    ))
+    .map(|((_, name, _, field, _), position)| {
+        RecordDefinition::new(name, vec![field], position).set_newtype(true)
+    })
+    .expected("newtype definition")
+}
+
+fn interface_definition<'a>() -> impl Parser<Stream<'a>, Output = InterfaceDefinition> {
+    spanned((
+        attempt(keyword("interface")),
+        identifier(),
+        between(sign("<"), sign(">"), identifier()),
+        sign("{"),
+        many(interface_method()),
+        sign("}"),
+    ))
+    .map(|((_, name, type_parameter, _, methods, _), position)| {
+        InterfaceDefinition::new(name, type_parameter, methods, position)
+    })
+    .expected("interface definition")
+}
+
+fn interface_method<'a>() -> impl Parser<Stream<'a>, Output = InterfaceMethod> {
+    spanned((identifier(), function_type()))
+        .map(|((name, type_), position)| InterfaceMethod::new(name, type_, position))
+        .expected("interface method")
+}
+
+fn implementation<'a>() -> impl Parser<Stream<'a>, Output = Implementation> {
+    spanned((
+        attempt(keyword("implement")),
+        token(qualified_identifier()),
+        between(sign("<"), sign(">"), type_()),
+        sign("{"),
+        many(implementation_function()),
+        sign("}"),
+    ))
+    .map(|((_, interface, type_, _, definitions, _), position)| {
+        Implementation::new(interface, type_, definitions, position)
+    })
+    .expected("implementation")
+}
+
+fn implementation_function<'a>() -> impl Parser<Stream<'a>, Output = FunctionDefinition> {
+    spanned((identifier(), sign("="), lambda()))
+        .map(|((name, _, lambda), position)| {
+            FunctionDefinition::new(name, vec![], lambda, None, position)
+        })
+        .expected("implementation function")
 }
 
 fn type_alias<'a>() -> impl Parser<Stream<'a>, Output = TypeAlias> {
-    (
-        attempt((position(), keyword("type"), identifier(), sign("="))),
+    spanned((
+        attempt((
+            keyword("type"),
+            identifier(),
+            optional(type_definition_parameters()),
+            sign("="),
+        )),
         type_(),
-    )
-        .map(|((position, _, name, _), type_)| TypeAlias::new(name, type_, position))
-        .expected("type alias")
+    ))
+    .map(|(((_, name, parameters, _), type_), position)| {
+        TypeAlias::new(name, type_, position).set_type_parameters(parameters.unwrap_or_default())
+    })
+    .expected("type alias")
 }
 
 fn type_<'a>() -> impl Parser<Stream<'a>, Output = Type> {
@@ -227,14 +384,14 @@ fn type_<'a>() -> impl Parser<Stream<'a>, Output = Type> {
 }
 
 fn function_type<'a>() -> impl Parser<Stream<'a>, Output = types::Function> {
-    (
-        attempt(position().skip(sign("\\("))),
+    spanned((
+        attempt(sign("\\(")),
         sep_end_by(type_(), sign(",")),
         sign(")"),
         type_(),
-    )
-        .map(|(position, arguments, _, result)| types::Function::new(arguments, result, position))
-        .expected("function type")
+    ))
+    .map(|((_, arguments, _, result), position)| types::Function::new(arguments, result, position))
+    .expected("function type")
 }
 
 fn union_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
@@ -243,7 +400,12 @@ fn union_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
             types
                 .into_iter()
                 .reduce(|lhs, rhs| {
-                    types::Union::new(lhs.clone(), rhs, lhs.position().clone()).into()
+                    let position = lhs.position().clone().set_end(
+                        rhs.position().end_line_number(),
+                        rhs.position().end_column_number(),
+                    );
+
+                    types::Union::new(lhs, rhs, position).into()
                 })
                 .unwrap()
         })
@@ -251,60 +413,103 @@ fn union_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
 }
 
 fn list_type<'a>() -> impl Parser<Stream<'a>, Output = types::List> {
-    (attempt(position().skip(sign("["))), type_(), sign("]"))
-        .map(|(position, element, _)| types::List::new(element, position))
+    spanned((attempt(sign("[")), type_(), sign("]")))
+        .map(|((_, element, _), position)| types::List::new(element, position))
         .expected("list type")
 }
 
 fn map_type<'a>() -> impl Parser<Stream<'a>, Output = types::Map> {
-    (
-        attempt(position().skip(sign("{"))),
-        type_(),
-        sign(":"),
-        type_(),
-        sign("}"),
-    )
-        .map(|(position, key, _, value, _)| types::Map::new(key, value, position))
+    spanned((attempt(sign("{")), type_(), sign(":"), type_(), sign("}")))
+        .map(|((_, key, _, value, _), position)| types::Map::new(key, value, position))
         .expected("map type")
 }
 
+fn anonymous_record_type<'a>() -> impl Parser<Stream<'a>, Output = types::AnonymousRecord> {
+    spanned((
+        attempt((sign("{"), look_ahead((identifier(), type_())))),
+        sep_end_by1((identifier(), type_()), sign(",")),
+        sign("}"),
+    ))
+    .then(|((_, fields, _), position): ((_, Vec<_>, _), _)| {
+        if fields
+            .iter()
+            .map(|(name, _)| name)
+            .collect::<FnvHashSet<_>>()
+            .len()
+            == fields.len()
+        {
+            value(types::AnonymousRecord::new(
+                fields
+                    .into_iter()
+                    .map(|(name, type_)| types::RecordField::new(name, type_))
+                    .collect(),
+                position,
+            ))
+            .left()
+        } else {
+            unexpected_any("duplicate fields in anonymous record type").right()
+        }
+    })
+    .expected("anonymous record type")
+}
+
 fn atomic_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
     choice((
         reference_type().map(Type::from),
         list_type().map(Type::from),
+        anonymous_record_type().map(Type::from),
         map_type().map(Type::from),
         between(sign("("), sign(")"), type_()),
     ))
 }
 
 fn reference_type<'a>() -> impl Parser<Stream<'a>, Output = types::Reference> {
-    token(attempt((position(), qualified_identifier())))
-        .map(|(position, identifier)| types::Reference::new(identifier, position))
+    (
+        token(attempt(spanned(qualified_identifier()))),
+        optional(type_arguments()),
+    )
+        .map(|((identifier, position), arguments)| {
+            types::Reference::new(identifier, position).set_arguments(arguments.unwrap_or_default())
+        })
         .expected("reference type")
 }
 
-fn block<'a>() -> impl Parser<Stream<'a>, Output = Block> {
-    (
-        position(),
-        between(sign("{"), sign("}"), many1(statement())),
+// Closing brackets are not signs because they can be followed by others in
+// nested type arguments.
+fn type_arguments<'a>() -> impl Parser<Stream<'a>, Output = Vec<Type>> {
+    between(
+        sign("<"),
+        token(combine::parser::char::char('>')),
+        sep_end_by1(type_(), sign(",")),
     )
-        .then(|(position, statements): (_, Vec<_>)| {
-            if let Some(statement) = statements.last() {
-                if statement.name().is_none() {
-                    value(Block::new(
-                        statements[..statements.len() - 1].to_vec(),
-                        statement.expression().clone(),
-                        position,
-                    ))
-                    .left()
-                } else {
-                    unexpected_any("end of block").right()
-                }
+    .expected("type arguments")
+}
+
+fn block<'a>() -> impl Parser<Stream<'a>, Output = Block> {
+    spanned(between(
+        sign("{"),
+        sign("}"),
+        many1(recover(statement(), |character| character != '}')),
+    ))
+    .then(|(statements, position): (Vec<Option<_>>, _)| {
+        let statements = statements.into_iter().flatten().collect::<Vec<_>>();
+
+        if let Some(statement) = statements.last() {
+            if statement.pattern().is_none() {
+                value(Block::new(
+                    statements[..statements.len() - 1].to_vec(),
+                    statement.expression().clone(),
+                    position,
+                ))
+                .left()
             } else {
                 unexpected_any("end of block").right()
             }
-        })
-        .expected("block")
+        } else {
+            unexpected_any("end of block").right()
+        }
+    })
+    .expected("block")
 }
 
 fn statement<'a>() -> impl Parser<Stream<'a>, Output = Statement> {
@@ -312,13 +517,31 @@ fn statement<'a>() -> impl Parser<Stream<'a>, Output = Statement> {
 }
 
 fn statement_with_result<'a>() -> impl Parser<Stream<'a>, Output = Statement> {
-    (attempt((position(), identifier(), sign("="))), expression())
-        .map(|((position, name, _), expression)| Statement::new(Some(name), expression, position))
+    spanned((attempt((pattern(), sign("="))), expression())).map(
+        |(((pattern, _), expression), position)| {
+            Statement::new(Some(pattern), expression, position)
+        },
+    )
+}
+
+fn pattern<'a>() -> impl Parser<Stream<'a>, Output = Pattern> {
+    choice((
+        identifier().map(Pattern::Variable),
+        between(sign("{"), sign("}"), sep_end_by1(identifier(), sign(","))).then(
+            |names: Vec<_>| {
+                if names.iter().collect::<FnvHashSet<_>>().len() == names.len() {
+                    value(Pattern::Record(names)).left()
+                } else {
+                    unexpected_any("duplicate names in record pattern").right()
+                }
+            },
+        ),
+    ))
+    .expected("pattern")
 }
 
 fn statement_without_result<'a>() -> impl Parser<Stream<'a>, Output = Statement> {
-    (position(), expression())
-        .map(|(position, expression)| Statement::new(None, expression, position))
+    spanned(expression()).map(|(expression, position)| Statement::new(None, expression, position))
 }
 
 fn expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
@@ -331,11 +554,8 @@ fn binary_operation_like<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
     (
         prefix_operation_like(),
         many(
-            (
-                attempt((position(), binary_operator())),
-                prefix_operation_like(),
-            )
-                .map(|((position, operator), expression)| (operator, expression, position)),
+            (attempt(spanned(binary_operator())), prefix_operation_like())
+                .map(|((operator, position), expression)| (operator, expression, position)),
         ),
     )
         .map(|(expression, pairs): (_, Vec<_>)| reduce_operations(expression, &pairs))
@@ -347,6 +567,7 @@ fn binary_operator<'a>() -> impl Parser<Stream<'a>, Output = BinaryOperator> {
         concrete_binary_operator("-", BinaryOperator::Subtract),
         concrete_binary_operator("*", BinaryOperator::Multiply),
         concrete_binary_operator("/", BinaryOperator::Divide),
+        concrete_binary_operator("%", BinaryOperator::Remainder),
         concrete_binary_operator("==", BinaryOperator::Equal),
         concrete_binary_operator("!=", BinaryOperator::NotEqual),
         concrete_binary_operator("<", BinaryOperator::LessThan),
@@ -355,6 +576,12 @@ fn binary_operator<'a>() -> impl Parser<Stream<'a>, Output = BinaryOperator> {
         concrete_binary_operator(">=", BinaryOperator::GreaterThanOrEqual),
         concrete_binary_operator("&", BinaryOperator::And),
         concrete_binary_operator("|", BinaryOperator::Or),
+        concrete_binary_operator("&&&", BinaryOperator::BitwiseAnd),
+        concrete_binary_operator("|||", BinaryOperator::BitwiseOr),
+        concrete_binary_operator("^^^", BinaryOperator::BitwiseXor),
+        concrete_binary_operator("<<<", BinaryOperator::LeftShift),
+        concrete_binary_operator(">>>", BinaryOperator::RightShift),
+        concrete_binary_operator("|>", BinaryOperator::Pipe),
     ))
     .expected("binary operator")
 }
@@ -385,13 +612,9 @@ fn prefix_operation_like<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
 }
 
 fn prefix_operation<'a>() -> impl Parser<Stream<'a>, Output = UnaryOperation> {
-    (
-        attempt((position(), prefix_operator())),
-        prefix_operation_like(),
+    spanned((attempt(prefix_operator()), prefix_operation_like())).map(
+        |((operator, expression), position)| UnaryOperation::new(operator, expression, position),
     )
-        .map(|((position, operator), expression)| {
-            UnaryOperation::new(operator, expression, position)
-        })
 }
 
 fn prefix_operator<'a>() -> impl Parser<Stream<'a>, Output = UnaryOperator> {
@@ -438,20 +661,20 @@ fn suffix_operator<'a>() -> impl Parser<Stream<'a>, Output = SuffixOperator> {
 }
 
 fn call_operator<'a>() -> impl Parser<Stream<'a>, Output = SuffixOperator> {
-    (
-        attempt(position().skip(sign("("))),
+    spanned((
+        attempt(sign("(")),
         sep_end_by(expression(), sign(",")).skip(sign(")")),
-    )
-        .map(|(position, arguments)| SuffixOperator::Call(arguments, position))
+    ))
+    .map(|((_, arguments), position)| SuffixOperator::Call(arguments, position))
 }
 
 fn record_field_operator<'a>() -> impl Parser<Stream<'a>, Output = SuffixOperator> {
-    (attempt(position().skip(sign("."))), identifier())
-        .map(|(position, identifier)| SuffixOperator::RecordField(identifier, position))
+    spanned((attempt(sign(".")), identifier()))
+        .map(|((_, identifier), position)| SuffixOperator::RecordField(identifier, position))
 }
 
 fn try_operator<'a>() -> impl Parser<Stream<'a>, Output = SuffixOperator> {
-    position().skip(sign("?")).map(SuffixOperator::Try)
+    spanned(sign("?")).map(|(_, position)| SuffixOperator::Try(position))
 }
 
 fn atomic_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
@@ -461,13 +684,16 @@ fn atomic_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
             if_map().map(Expression::from),
             if_type().map(Expression::from),
             if_().map(Expression::from),
+            match_().map(Expression::from),
             lambda().map(Expression::from),
             record().map(Expression::from),
             list_comprehension().map(Expression::from),
             list_literal().map(Expression::from),
+            map_comprehension().map(Expression::from),
             map_literal().map(Expression::from),
+            integer_literal().map(Expression::from),
             number_literal().map(Expression::from),
-            string_literal().map(Expression::from),
+            string_interpolation(),
             variable().map(Expression::from),
             between(sign("("), sign(")"), expression()),
         )))
@@ -476,17 +702,17 @@ fn atomic_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
 }
 
 fn lambda<'a>() -> impl Parser<Stream<'a>, Output = Lambda> {
-    (
-        attempt(position().skip(sign("\\("))),
+    spanned((
+        attempt(sign("\\(")),
         sep_end_by(argument(), sign(",")),
         sign(")"),
         type_(),
         block(),
-    )
-        .map(|(position, arguments, _, result_type, body)| {
-            Lambda::new(arguments, result_type, body, position)
-        })
-        .expected("function expression")
+    ))
+    .map(|((_, arguments, _, result_type, body), position)| {
+        Lambda::new(arguments, result_type, body, position)
+    })
+    .expected("function expression")
 }
 
 fn argument<'a>() -> impl Parser<Stream<'a>, Output = Argument> {
@@ -494,23 +720,23 @@ fn argument<'a>() -> impl Parser<Stream<'a>, Output = Argument> {
 }
 
 fn if_<'a>() -> impl Parser<Stream<'a>, Output = If> {
-    (
-        attempt(position().skip(keyword("if"))),
+    spanned((
+        attempt(keyword("if")),
         if_branch(),
         many(attempt((keyword("else"), keyword("if"))).with(if_branch())),
         keyword("else"),
         block(),
+    ))
+    .map(
+        |((_, first_branch, branches, _, else_block), position): ((_, _, Vec<_>, _, _), _)| {
+            If::new(
+                [first_branch].into_iter().chain(branches).collect(),
+                else_block,
+                position,
+            )
+        },
     )
-        .map(
-            |(position, first_branch, branches, _, else_block): (_, _, Vec<_>, _, _)| {
-                If::new(
-                    [first_branch].into_iter().chain(branches).collect(),
-                    else_block,
-                    position,
-                )
-            },
-        )
-        .expected("if expression")
+    .expected("if expression")
 }
 
 fn if_branch<'a>() -> impl Parser<Stream<'a>, Output = IfBranch> {
@@ -518,8 +744,8 @@ fn if_branch<'a>() -> impl Parser<Stream<'a>, Output = IfBranch> {
 }
 
 fn if_list<'a>() -> impl Parser<Stream<'a>, Output = IfList> {
-    (
-        attempt(position().skip((keyword("if"), sign("[")))),
+    spanned((
+        attempt((keyword("if"), sign("["))),
         identifier(),
         sign(","),
         sign("..."),
@@ -530,19 +756,18 @@ fn if_list<'a>() -> impl Parser<Stream<'a>, Output = IfList> {
         block(),
         keyword("else"),
         block(),
+    ))
+    .map(
+        |((_, first_name, _, _, rest_name, _, _, argument, then, _, else_), position)| {
+            IfList::new(argument, first_name, rest_name, then, else_, position)
+        },
     )
-        .map(
-            |(position, first_name, _, _, rest_name, _, _, argument, then, _, else_)| {
-                IfList::new(argument, first_name, rest_name, then, else_, position)
-            },
-        )
-        .expected("if-list expression")
+    .expected("if-list expression")
 }
 
 fn if_map<'a>() -> impl Parser<Stream<'a>, Output = IfMap> {
-    (
+    spanned((
         attempt((
-            position(),
             keyword("if"),
             identifier(),
             sign("="),
@@ -554,50 +779,167 @@ fn if_map<'a>() -> impl Parser<Stream<'a>, Output = IfMap> {
         block(),
         keyword("else"),
         block(),
-    )
-        .map(|((position, _, name, _, map, _), key, _, then, _, else_)| {
+    ))
+    .map(
+        |(((_, name, _, map, _), key, _, then, _, else_), position)| {
             IfMap::new(name, map, key, then, else_, position)
-        })
-        .expected("if-map expression")
+        },
+    )
+    .expected("if-map expression")
 }
 
 fn if_type<'a>() -> impl Parser<Stream<'a>, Output = IfType> {
-    (
-        attempt((position(), keyword("if"), identifier(), sign("="))),
+    spanned((
+        attempt((keyword("if"), identifier(), sign("="))),
         expression(),
         keyword("as"),
         if_type_branch(),
         many(attempt((keyword("else"), keyword("if"))).with(if_type_branch())),
         optional(keyword("else").with(block())),
+    ))
+    .map(
+        |(((_, identifier, _), argument, _, first_branch, branches, else_), position): (
+            (_, _, _, _, Vec<_>, _),
+            _,
+        )| {
+            IfType::new(
+                identifier,
+                argument,
+                [first_branch].into_iter().chain(branches).collect(),
+                else_,
+                position,
+            )
+        },
     )
-        .map(
-            |((position, _, identifier, _), argument, _, first_branch, branches, else_): (
-                _,
-                _,
-                _,
-                _,
-                Vec<_>,
-                _,
-            )| {
-                IfType::new(
-                    identifier,
-                    argument,
-                    [first_branch].into_iter().chain(branches).collect(),
-                    else_,
-                    position,
-                )
-            },
-        )
-        .expected("if-type expression")
+    .expected("if-type expression")
 }
 
 fn if_type_branch<'a>() -> impl Parser<Stream<'a>, Output = IfTypeBranch> {
     (type_(), block()).map(|(type_, block)| IfTypeBranch::new(type_, block))
 }
 
+fn match_<'a>() -> impl Parser<Stream<'a>, Output = Match> {
+    spanned((
+        attempt(keyword("match")),
+        expression(),
+        sign("{"),
+        many1(match_branch()),
+        sign("}"),
+    ))
+    .map(|((_, argument, _, branches, _), position)| Match::new(argument, branches, position))
+    .expected("match expression")
+}
+
+fn match_branch<'a>() -> impl Parser<Stream<'a>, Output = MatchBranch> {
+    spanned((
+        match_pattern(),
+        optional(keyword("if").with(expression())),
+        block(),
+    ))
+    .map(|((pattern, guard, block), position)| MatchBranch::new(pattern, guard, block, position))
+}
+
+fn match_pattern<'a>() -> impl Parser<Stream<'a>, Output = MatchPattern> {
+    lazy(|| {
+        no_partial(
+            spanned((
+                atomic_match_pattern(),
+                optional(keyword("as").with(type_())),
+            ))
+            .map(|((pattern, type_), position)| {
+                if let Some(type_) = type_ {
+                    TypePattern::new(pattern, type_, position).into()
+                } else {
+                    pattern
+                }
+            }),
+        )
+    })
+    .boxed()
+    .expected("pattern")
+}
+
+fn atomic_match_pattern<'a>() -> impl Parser<Stream<'a>, Output = MatchPattern> {
+    choice((
+        record_pattern().map(MatchPattern::from),
+        empty_list_pattern(),
+        list_pattern().map(MatchPattern::from),
+        number_literal().map(MatchPattern::from),
+        string_literal().map(MatchPattern::from),
+        spanned(identifier()).map(|(name, position)| Variable::new(name, position).into()),
+    ))
+}
+
+fn record_pattern<'a>() -> impl Parser<Stream<'a>, Output = RecordPattern> {
+    spanned((
+        attempt(sign("{")),
+        sep_end_by1(record_field_pattern(), sign(",")),
+        sign("}"),
+    ))
+    .then(|((_, fields, _), position)| {
+        let fields: Vec<RecordFieldPattern> = fields;
+
+        if fields
+            .iter()
+            .map(|field| field.name())
+            .collect::<FnvHashSet<_>>()
+            .len()
+            == fields.len()
+        {
+            value(RecordPattern::new(fields, position)).left()
+        } else {
+            unexpected_any("duplicate fields in record pattern").right()
+        }
+    })
+    .expected("record pattern")
+}
+
+fn record_field_pattern<'a>() -> impl Parser<Stream<'a>, Output = RecordFieldPattern> {
+    spanned((
+        spanned(identifier()),
+        optional(sign(":").with(match_pattern())),
+    ))
+    .map(|(((name, name_position), pattern), position)| {
+        RecordFieldPattern::new(
+            &name,
+            pattern.unwrap_or_else(|| Variable::new(&name, name_position).into()),
+            position,
+        )
+    })
+}
+
+fn empty_list_pattern<'a>() -> impl Parser<Stream<'a>, Output = MatchPattern> {
+    spanned(attempt((sign("["), sign("]")))).map(|(_, position)| MatchPattern::EmptyList(position))
+}
+
+fn list_pattern<'a>() -> impl Parser<Stream<'a>, Output = ListPattern> {
+    spanned((
+        attempt(sign("[")),
+        match_pattern(),
+        sign(","),
+        sign("..."),
+        match_pattern(),
+        sign("]"),
+    ))
+    .map(|((_, first, _, _, rest, _), position)| ListPattern::new(first, rest, position))
+    .expected("list pattern")
+}
+
 fn record<'a>() -> impl Parser<Stream<'a>, Output = Record> {
-    (
-        attempt((position(), qualified_identifier(), sign("{"))),
+    spanned((
+        attempt((
+            choice((
+                (spanned(qualified_identifier()), optional(type_arguments())).map(
+                    |((name, position), arguments)| {
+                        types::Reference::new(name, position)
+                            .set_arguments(arguments.unwrap_or_default())
+                            .into()
+                    },
+                ),
+                anonymous_record_type().map(Type::from),
+            )),
+            sign("{"),
+        )),
         choice((
             (
                 between(sign("..."), sign(","), expression()).map(Some),
@@ -606,39 +948,66 @@ fn record<'a>() -> impl Parser<Stream<'a>, Output = Record> {
             (value(None), sep_end_by(record_field(), sign(","))),
         )),
         sign("}"),
-    )
-        .then(|((position, name, _), (record, fields), _)| {
-            let fields: Vec<_> = fields;
-
-            if fields
-                .iter()
-                .map(|field| field.name())
-                .collect::<FnvHashSet<_>>()
-                .len()
-                == fields.len()
-            {
-                value(Record::new(name, record, fields, position)).left()
-            } else {
-                unexpected_any("duplicate keys in record literal").right()
-            }
-        })
-        .expected("record literal")
+    ))
+    .then(|(((type_, _), (record, fields), _), position)| {
+        let fields: Vec<_> = fields;
+
+        if fields
+            .iter()
+            .map(|field| field.name())
+            .collect::<FnvHashSet<_>>()
+            .len()
+            == fields.len()
+        {
+            value(Record::new(type_, record, fields, position)).left()
+        } else {
+            unexpected_any("duplicate keys in record literal").right()
+        }
+    })
+    .expected("record literal")
 }
 
 fn record_field<'a>() -> impl Parser<Stream<'a>, Output = RecordField> {
-    (attempt((position(), identifier())), sign(":"), expression())
-        .map(|((position, name), _, expression)| RecordField::new(name, expression, position))
+    spanned((attempt(identifier()), sign(":"), expression()))
+        .map(|((name, _, expression), position)| RecordField::new(name, expression, position))
+}
+
+fn integer_literal<'a>() -> impl Parser<Stream<'a>, Output = Integer> {
+    let regex: &'static regex::Regex = &INTEGER_REGEX;
+
+    token(attempt(spanned(find(regex))).skip(not_followed_by(alpha_num())))
+        .map(|(string, position): (&str, _)| {
+            let (value, kind) = [IntegerKind::Byte, IntegerKind::I64, IntegerKind::U64]
+                .into_iter()
+                .find_map(|kind| Some((string.strip_suffix(kind.name())?, kind)))
+                .unwrap();
+
+            Integer::new(
+                if let Some(value) = value.strip_prefix("0b") {
+                    NumberRepresentation::Binary(value.into())
+                } else if let Some(value) = value.strip_prefix("0x") {
+                    NumberRepresentation::Hexadecimal(value.to_lowercase())
+                } else {
+                    NumberRepresentation::FloatingPoint(value.into())
+                },
+                kind,
+                position,
+            )
+        })
+        .silent()
+        .expected("integer literal")
 }
 
 fn number_literal<'a>() -> impl Parser<Stream<'a>, Output = Number> {
     token(
-        attempt((
-            position(),
-            choice((binary_literal(), hexadecimal_literal(), decimal_literal())),
-        ))
+        attempt(spanned(choice((
+            binary_literal(),
+            hexadecimal_literal(),
+            decimal_literal(),
+        ))))
         .skip(not_followed_by(digit())),
     )
-    .map(|(position, number)| Number::new(number, position))
+    .map(|(number, position)| Number::new(number, position))
     .silent()
     .expected("number literal")
 }
@@ -665,28 +1034,71 @@ fn string_literal<'a>() -> impl Parser<Stream<'a>, Output = ByteString> {
     token(raw_string_literal())
 }
 
-fn raw_string_literal<'a>() -> impl Parser<Stream<'a>, Output = ByteString> {
+fn raw_string_literal<'a>() -> impl Parser<Stream<'a>, Output = ByteString> {
+    spanned((
+        attempt(character('"')),
+        many(string_character()),
+        character('"'),
+    ))
+    .map(|((_, strings, _), position): ((_, Vec<String>, _), _)| {
+        ByteString::new(strings.concat(), position)
+    })
+    .expected("string literal")
+}
+
+fn string_interpolation<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
+    token(spanned((
+        attempt(character('"')),
+        many(choice((
+            (attempt(string("${")), blank(), expression(), character('}')).map(
+                |(_, _, expression, _)| StringInterpolationPart::Expression(expression.into()),
+            ),
+            string_character().map(StringInterpolationPart::String),
+        ))),
+        // Make a span end at the closing quote rather than at the last
+        // interpolated expression.
+        token(character('"')),
+    )))
+    .map(
+        |((_, parts, _), position): ((_, Vec<StringInterpolationPart>, _), _)| {
+            let mut merged_parts = vec![];
+
+            for part in parts {
+                match (merged_parts.last_mut(), part) {
+                    (
+                        Some(StringInterpolationPart::String(last)),
+                        StringInterpolationPart::String(string),
+                    ) => last.push_str(&string),
+                    (_, part) => merged_parts.push(part),
+                }
+            }
+
+            match merged_parts.as_slice() {
+                [] => ByteString::new("", position).into(),
+                [StringInterpolationPart::String(string)] => {
+                    ByteString::new(string.clone(), position).into()
+                }
+                _ => StringInterpolation::new(merged_parts, position).into(),
+            }
+        },
+    )
+    .expected("string literal")
+}
+
+fn string_character<'a>() -> impl Parser<Stream<'a>, Output = String> {
     let string_regex: &'static regex::Regex = &STRING_CHARACTER_REGEX;
     let byte_regex: &'static regex::Regex = &BYTE_CHARACTER_REGEX;
 
-    (
-        attempt(position().skip(character('"'))),
-        many(choice((
-            find(string_regex).map(String::from),
-            special_string_character("\\\\"),
-            special_string_character("\\\""),
-            special_string_character("\\n"),
-            special_string_character("\\r"),
-            special_string_character("\\t"),
-            (attempt(string("\\x")), find(byte_regex))
-                .map(|(prefix, byte)| prefix.to_owned() + byte),
-        ))),
-        character('"'),
-    )
-        .map(|(position, strings, _): (_, Vec<String>, _)| {
-            ByteString::new(strings.concat(), position)
-        })
-        .expected("string literal")
+    choice((
+        find(string_regex).map(String::from),
+        special_string_character("\\\\"),
+        special_string_character("\\\""),
+        special_string_character("\\$"),
+        special_string_character("\\n"),
+        special_string_character("\\r"),
+        special_string_character("\\t"),
+        (attempt(string("\\x")), find(byte_regex)).map(|(prefix, byte)| prefix.to_owned() + byte),
+    ))
 }
 
 fn special_string_character<'a>(escape: &'static str) -> impl Parser<Stream<'a>, Output = String> {
@@ -694,14 +1106,14 @@ fn special_string_character<'a>(escape: &'static str) -> impl Parser<Stream<'a>,
 }
 
 fn list_literal<'a>() -> impl Parser<Stream<'a>, Output = List> {
-    (
-        attempt(position().skip(sign("["))),
+    spanned((
+        attempt(sign("[")),
         type_(),
         sep_end_by(list_element(), sign(",")),
         sign("]"),
-    )
-        .map(|(position, type_, elements, _)| List::new(type_, elements, position))
-        .expected("list literal")
+    ))
+    .map(|((_, type_, elements, _), position)| List::new(type_, elements, position))
+    .expected("list literal")
 }
 
 fn list_element<'a>() -> impl Parser<Stream<'a>, Output = ListElement> {
@@ -711,65 +1123,81 @@ fn list_element<'a>() -> impl Parser<Stream<'a>, Output = ListElement> {
     ))
 }
 
-fn list_comprehension<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
-    (
-        attempt((position(), sign("["), type_(), expression(), keyword("for"))),
-        identifier(),
-        optional(sign(",").with(identifier())),
-        keyword("in"),
-        expression(),
+fn list_comprehension<'a>() -> impl Parser<Stream<'a>, Output = ListComprehension> {
+    spanned((
+        attempt((sign("["), type_(), expression(), look_ahead(keyword("for")))),
+        many1(list_comprehension_branch()),
         sign("]"),
+    ))
+    .map(|(((_, type_, element, _), branches, _), position)| {
+        ListComprehension::new(type_, element, branches, position)
+    })
+    .expected("list comprehension")
+}
+
+fn list_comprehension_branch<'a>() -> impl Parser<Stream<'a>, Output = ListComprehensionBranch> {
+    spanned((
+        keyword("for"),
+        sep_by1(identifier(), sign(",")),
+        keyword("in"),
+        sep_by1(expression(), sign(",")),
+        optional(keyword("if").with(expression())),
+    ))
+    .map(|((_, names, _, iteratees, condition), position)| {
+        ListComprehensionBranch::new(names, iteratees, condition, position)
+    })
+}
+
+fn map_comprehension<'a>() -> impl Parser<Stream<'a>, Output = MapComprehension> {
+    spanned((
+        attempt((
+            sign("{"),
+            type_(),
+            sign(":"),
+            type_(),
+            expression(),
+            sign(":"),
+            expression(),
+            look_ahead(keyword("for")),
+        )),
+        many1(list_comprehension_branch()),
+        sign("}"),
+    ))
+    .map(
+        |(((_, key_type, _, value_type, key, _, value, _), branches, _), position)| {
+            MapComprehension::new(key_type, value_type, key, value, branches, position)
+        },
     )
-        .map(
-            |((position, _, type_, element, _), element_name, value_name, _, iterator, _)| {
-                if let Some(value_name) = value_name {
-                    MapIterationComprehension::new(
-                        type_,
-                        element,
-                        element_name,
-                        value_name,
-                        iterator,
-                        position,
-                    )
-                    .into()
-                } else {
-                    ListComprehension::new(type_, element, element_name, iterator, position).into()
-                }
-            },
-        )
-        .expected("list comprehension")
+    .expected("map comprehension")
 }
 
 fn map_literal<'a>() -> impl Parser<Stream<'a>, Output = Map> {
-    (
-        attempt(position().skip(sign("{"))),
+    spanned((
+        attempt(sign("{")),
         type_(),
         sign(":"),
         type_(),
         sep_end_by(map_element(), sign(",")),
         sign("}"),
-    )
-        .map(|(position, key_type, _, value_type, elements, _)| {
-            Map::new(key_type, value_type, elements, position)
-        })
-        .expected("map literal")
+    ))
+    .map(|((_, key_type, _, value_type, elements, _), position)| {
+        Map::new(key_type, value_type, elements, position)
+    })
+    .expected("map literal")
 }
 
 fn map_element<'a>() -> impl Parser<Stream<'a>, Output = MapElement> {
     choice((
-        (
-            attempt((position(), expression()).skip(sign(":"))),
-            expression(),
-        )
-            .map(|((position, key), value)| MapEntry::new(key, value, position).into()),
+        spanned((attempt(expression().skip(sign(":"))), expression()))
+            .map(|((key, value), position)| MapEntry::new(key, value, position).into()),
         sign("...").with(expression()).map(MapElement::Map),
         expression().map(MapElement::Removal),
     ))
 }
 
 fn variable<'a>() -> impl Parser<Stream<'a>, Output = Variable> {
-    token(attempt((position(), qualified_identifier())))
-        .map(|(position, identifier)| Variable::new(identifier, position))
+    token(attempt(spanned(qualified_identifier())))
+        .map(|(identifier, position)| Variable::new(identifier, position))
         .expected("variable")
 }
 
@@ -845,22 +1273,174 @@ fn sign<'a>(sign: &'static str) -> impl Parser<Stream<'a>, Output = ()> {
 }
 
 fn token<'a, O, P: Parser<Stream<'a>, Output = O>>(p: P) -> impl Parser<Stream<'a>, Output = O> {
-    p.skip(blank())
+    p.map_input(|output, stream: &mut Stream<'a>| {
+        stream.0.state.last_token_end = stream.position();
+        output
+    })
+    .skip(blank())
 }
 
 fn position<'a>() -> impl Parser<Stream<'a>, Output = Position> {
     value(()).map_input(|_, stream: &mut Stream<'a>| {
         let position = stream.position();
-
-        Position::new(
+        let lines = &stream.0.state.lines;
+        let line_number = position.line as usize;
+        let position = Position::new(
             &stream.0.state.path,
-            position.line as usize,
+            line_number,
             position.column as usize,
-            stream.0.state.lines[position.line as usize - 1],
-        )
+            lines[line_number - 1],
+        );
+
+        match line_number.checked_sub(2).map(|index| lines[index]) {
+            Some(line) if !line.trim().is_empty() => position.set_previous_line(line),
+            _ => position,
+        }
+    })
+}
+
+// Parse a node with a position spanning to the end of its last token.
+//
+// A last token end can be stale if parsers backtrack after parsing tokens. In
+// that case, we use a current position instead as an end.
+fn spanned<'a, O>(
+    parser: impl Parser<Stream<'a>, Output = O>,
+) -> impl Parser<Stream<'a>, Output = (O, Position)> {
+    (position(), parser).map_input(|(position, output), stream: &mut Stream<'a>| {
+        let start = (position.line_number(), position.column_number());
+        let current = stream.position();
+        let current = (current.line as usize, current.column as usize);
+        let token_end = stream.0.state.last_token_end;
+        let token_end = (token_end.line as usize, token_end.column as usize);
+        let (line_number, column_number) = if start < token_end && token_end <= current {
+            token_end
+        } else {
+            current
+        };
+
+        (output, position.set_end(line_number, column_number))
     })
 }
 
+// Recover from an error by skipping lines until the next one at the same or
+// lower indentation level so that we can report errors in the following ones.
+// Errors without any consumed input are recovered only at characters accepted
+// by a given predicate. Any results containing errors are discarded.
+fn recover<'a, O>(
+    parser: impl Parser<Stream<'a>, Output = O>,
+    recoverable: impl Fn(char) -> bool,
+) -> impl Parser<Stream<'a>, Output = Option<O>> {
+    Recover {
+        parser,
+        recoverable,
+    }
+}
+
+struct Recover<P, F> {
+    parser: P,
+    recoverable: F,
+}
+
+impl<'a, O, P: Parser<Stream<'a>, Output = O>, F: Fn(char) -> bool> Parser<Stream<'a>>
+    for Recover<P, F>
+{
+    type Output = Option<O>;
+    type PartialState = ();
+
+    fn parse_lazy(
+        &mut self,
+        stream: &mut Stream<'a>,
+    ) -> ParseResult<Self::Output, <Stream<'a> as StreamOnce>::Error> {
+        if !stream.0.state.recovery {
+            return self.parser.parse_lazy(stream).map(Some);
+        }
+
+        let checkpoint = stream.checkpoint();
+        let position = stream.position();
+        let error_count = stream.0.state.errors.len();
+
+        let error = match self.parser.parse_stream(stream) {
+            ParseResult::CommitOk(output) => {
+                return ParseResult::CommitOk(
+                    (stream.0.state.errors.len() == error_count).then_some(output),
+                )
+            }
+            ParseResult::PeekOk(output) => {
+                return ParseResult::PeekOk(
+                    (stream.0.state.errors.len() == error_count).then_some(output),
+                )
+            }
+            ParseResult::CommitErr(error) => error,
+            ParseResult::PeekErr(error)
+                if peek_character(stream).is_some_and(&self.recoverable) =>
+            {
+                error.error
+            }
+            ParseResult::PeekErr(error) => return ParseResult::PeekErr(error),
+        };
+
+        // Errors after recovered ones are often caused by them.
+        if stream.0.state.errors.len() == error_count {
+            stream.0.state.errors.push(error);
+        }
+
+        if let Err(error) = stream.reset(checkpoint) {
+            return ParseResult::CommitErr(error);
+        }
+
+        skip_lines(stream, position.line as usize, position.column as usize);
+
+        match blank().parse_stream(stream) {
+            ParseResult::CommitOk(_) | ParseResult::PeekOk(_) => ParseResult::CommitOk(None),
+            ParseResult::CommitErr(error) | ParseResult::PeekErr(Tracked { error, .. }) => {
+                ParseResult::CommitErr(error)
+            }
+        }
+    }
+
+    fn add_error(&mut self, error: &mut Tracked<<Stream<'a> as StreamOnce>::Error>) {
+        self.parser.add_error(error)
+    }
+
+    fn add_committed_expected_error(
+        &mut self,
+        error: &mut Tracked<<Stream<'a> as StreamOnce>::Error>,
+    ) {
+        self.parser.add_committed_expected_error(error)
+    }
+
+    fn parser_count(&self) -> ErrorOffset {
+        self.parser.parser_count()
+    }
+}
+
+fn skip_lines(stream: &mut Stream, line_number: usize, column_number: usize) {
+    let next_line_number = stream.0.state.lines[line_number..]
+        .iter()
+        .position(|line| {
+            let content = line.trim_start();
+            let column = line.len() - content.len() + 1;
+
+            !content.is_empty()
+                && (column < column_number
+                    || column == column_number && !content.starts_with(['}', ')', ']']))
+        })
+        .map(|index| line_number + index + 1);
+
+    while next_line_number
+        .map(|line_number| (stream.position().line as usize) < line_number)
+        .unwrap_or(true)
+        && stream.uncons().is_ok()
+    {}
+}
+
+fn peek_character(stream: &mut Stream) -> Option<char> {
+    let checkpoint = stream.checkpoint();
+    let character = stream.uncons().ok();
+
+    stream.reset(checkpoint).ok().and(character)
+}
+
 fn eof<'a>() -> impl Parser<Stream<'a>, Output = ()> {
     combine::eof().expected("end of file")
 }
@@ -889,6 +1469,7 @@ mod tests {
     use indoc::indoc;
     use position::test::PositionFake;
     use pretty_assertions::assert_eq;
+    use std::sync::Arc;
 
     mod module {
         use super::*;
@@ -898,15 +1479,15 @@ mod tests {
         fn parse_module() {
             assert_eq!(
                 module().parse(stream("", "")).unwrap().0,
-                Module::new(vec![], vec![], vec![], vec![], Position::fake())
+                Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake())
             );
             assert_eq!(
                 module().parse(stream(" ", "")).unwrap().0,
-                Module::new(vec![], vec![], vec![], vec![], Position::fake())
+                Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake())
             );
             assert_eq!(
                 module().parse(stream("\n", "")).unwrap().0,
-                Module::new(vec![], vec![], vec![], vec![], Position::fake())
+                Module::new(vec![], vec![], vec![], vec![], vec![], Position::fake())
             );
             assert_eq!(
                 module().parse(stream("import Foo'Bar", "")).unwrap().0,
@@ -920,6 +1501,7 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )
             );
@@ -935,6 +1517,7 @@ mod tests {
                     )
                     .into()],
                     vec![],
+                    vec![],
                     Position::fake()
                 )
             );
@@ -947,8 +1530,10 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![FunctionDefinition::new(
                         "x",
+                        vec![],
                         Lambda::new(
                             vec![Argument::new(
                                 "x",
@@ -983,9 +1568,11 @@ mod tests {
                     vec![],
                     vec![],
                     vec![],
+                    vec![],
                     vec![
                         FunctionDefinition::new(
                             "x",
+                            vec![],
                             Lambda::new(
                                 vec![Argument::new(
                                     "x",
@@ -1007,6 +1594,7 @@ mod tests {
                         ),
                         FunctionDefinition::new(
                             "y",
+                            vec![],
                             Lambda::new(
                                 vec![Argument::new(
                                     "y",
@@ -1058,6 +1646,7 @@ mod tests {
                     )],
                     vec![],
                     vec![],
+                    vec![],
                     Position::fake()
                 )
             );
@@ -1083,6 +1672,7 @@ mod tests {
                         RecordDefinition::new("bar", vec![], Position::fake()).into(),
                     ],
                     vec![],
+                    vec![],
                     Position::fake()
                 )
             );
@@ -1272,6 +1862,7 @@ mod tests {
                     .0,
                 FunctionDefinition::new(
                     "x",
+                    vec![],
                     Lambda::new(
                         vec![Argument::new(
                             "x",
@@ -1303,6 +1894,7 @@ mod tests {
                     .0,
                 FunctionDefinition::new(
                     "x",
+                    vec![],
                     Lambda::new(
                         vec![Argument::new(
                             "x",
@@ -1334,6 +1926,7 @@ mod tests {
                     .0,
                 FunctionDefinition::new(
                     "x",
+                    vec![],
                     Lambda::new(
                         vec![Argument::new(
                             "x",
@@ -1356,6 +1949,61 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_type_parameters() {
+            assert_eq!(
+                definition()
+                    .parse(stream("f<a, b> = \\(x a) b { g(x) }", ""))
+                    .unwrap()
+                    .0,
+                FunctionDefinition::new(
+                    "f",
+                    vec![TypeParameter::new("a", None), TypeParameter::new("b", None)],
+                    Lambda::new(
+                        vec![Argument::new(
+                            "x",
+                            types::Reference::new("a", Position::fake())
+                        )],
+                        types::Reference::new("b", Position::fake()),
+                        Block::new(
+                            vec![],
+                            Call::new(
+                                Variable::new("g", Position::fake()),
+                                vec![Variable::new("x", Position::fake()).into()],
+                                Position::fake()
+                            ),
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    ),
+                    None,
+                    Position::fake()
+                ),
+            );
+        }
+
+        #[test]
+        fn parse_constrained_type_parameter() {
+            assert_eq!(
+                definition()
+                    .parse(stream("f<a: Show> = \\(x a) a { x }", ""))
+                    .unwrap()
+                    .0
+                    .type_parameters(),
+                &[TypeParameter::new(
+                    "a",
+                    Some(types::Reference::new("Show", Position::fake()))
+                )]
+            );
+        }
+
+        #[test]
+        fn fail_to_parse_duplicate_type_parameters() {
+            assert!(definition()
+                .parse(stream("f<a, a> = \\(x a) a { x }", ""))
+                .is_err());
+        }
+
         #[test]
         fn parse_keyword_like_name() {
             assert_eq!(
@@ -1365,6 +2013,7 @@ mod tests {
                     .0,
                 FunctionDefinition::new(
                     "importA",
+                    vec![],
                     Lambda::new(
                         vec![],
                         types::Reference::new("number", Position::fake()),
@@ -1396,33 +2045,201 @@ mod tests {
                 "type Foo {foo number}",
                 RecordDefinition::new(
                     "Foo",
-                    vec![types::RecordField::new(
+                    vec![RecordFieldDefinition::new(
                         "foo",
                         types::Reference::new("number", Position::fake()),
+                        None,
+                        Position::fake(),
+                    )],
+                    Position::fake(),
+                ),
+            ),
+            (
+                "type Foo {foo number bar number}",
+                RecordDefinition::new(
+                    "Foo",
+                    vec![
+                        RecordFieldDefinition::new(
+                            "foo",
+                            types::Reference::new("number", Position::fake()),
+                            None,
+                            Position::fake(),
+                        ),
+                        RecordFieldDefinition::new(
+                            "bar",
+                            types::Reference::new("number", Position::fake()),
+                            None,
+                            Position::fake(),
+                        ),
+                    ],
+                    Position::fake(),
+                ),
+            ),
+            (
+                "type Foo {foo number = 42 bar number}",
+                RecordDefinition::new(
+                    "Foo",
+                    vec![
+                        RecordFieldDefinition::new(
+                            "foo",
+                            types::Reference::new("number", Position::fake()),
+                            Some(
+                                Number::new(
+                                    NumberRepresentation::FloatingPoint("42".into()),
+                                    Position::fake(),
+                                )
+                                .into(),
+                            ),
+                            Position::fake(),
+                        ),
+                        RecordFieldDefinition::new(
+                            "bar",
+                            types::Reference::new("number", Position::fake()),
+                            None,
+                            Position::fake(),
+                        ),
+                    ],
+                    Position::fake(),
+                ),
+            ),
+            (
+                "type Foo<a, b> {foo a bar b}",
+                RecordDefinition::new(
+                    "Foo",
+                    vec![
+                        RecordFieldDefinition::new(
+                            "foo",
+                            types::Reference::new("a", Position::fake()),
+                            None,
+                            Position::fake(),
+                        ),
+                        RecordFieldDefinition::new(
+                            "bar",
+                            types::Reference::new("b", Position::fake()),
+                            None,
+                            Position::fake(),
+                        ),
+                    ],
+                    Position::fake(),
+                )
+                .set_type_parameters(vec!["a".into(), "b".into()]),
+            ),
+        ] {
+            assert_eq!(
+                &record_definition().parse(stream(source, "")).unwrap().0,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn fail_to_parse_record_definition_with_duplicate_type_parameters() {
+        assert!(record_definition()
+            .parse(stream("type Foo<a, a> {}", ""))
+            .is_err());
+    }
+
+    #[test]
+    fn parse_newtype_definition() {
+        assert_eq!(
+            newtype_definition()
+                .parse(stream("newtype Foo {foo number}", ""))
+                .unwrap()
+                .0,
+            RecordDefinition::new(
+                "Foo",
+                vec![RecordFieldDefinition::new(
+                    "foo",
+                    types::Reference::new("number", Position::fake()),
+                    None,
+                    Position::fake(),
+                )],
+                Position::fake(),
+            )
+            .set_newtype(true)
+        );
+        assert!(newtype_definition()
+            .parse(stream("newtype Foo {}", ""))
+            .is_err());
+        assert!(newtype_definition()
+            .parse(stream("newtype Foo {foo number bar number}", ""))
+            .is_err());
+    }
+
+    #[test]
+    fn parse_interface_definition() {
+        for (source, expected) in &[
+            (
+                "interface Foo<a> {}",
+                InterfaceDefinition::new("Foo", "a", vec![], Position::fake()),
+            ),
+            (
+                "interface Foo<a> { Bar \\(a) string }",
+                InterfaceDefinition::new(
+                    "Foo",
+                    "a",
+                    vec![InterfaceMethod::new(
+                        "Bar",
+                        types::Function::new(
+                            vec![types::Reference::new("a", Position::fake()).into()],
+                            types::Reference::new("string", Position::fake()),
+                            Position::fake(),
+                        ),
+                        Position::fake(),
                     )],
                     Position::fake(),
                 ),
             ),
+        ] {
+            assert_eq!(
+                &interface_definition().parse(stream(source, "")).unwrap().0,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn parse_implementation() {
+        for (source, expected) in &[
+            (
+                "implement Foo<number> {}",
+                Implementation::new(
+                    "Foo",
+                    types::Reference::new("number", Position::fake()),
+                    vec![],
+                    Position::fake(),
+                ),
+            ),
             (
-                "type Foo {foo number bar number}",
-                RecordDefinition::new(
-                    "Foo",
-                    vec![
-                        types::RecordField::new(
-                            "foo",
-                            types::Reference::new("number", Position::fake()),
-                        ),
-                        types::RecordField::new(
-                            "bar",
-                            types::Reference::new("number", Position::fake()),
+                "implement foo'Foo<number> { Bar = \\(x number) string { \"\" } }",
+                Implementation::new(
+                    "foo'Foo",
+                    types::Reference::new("number", Position::fake()),
+                    vec![FunctionDefinition::new(
+                        "Bar",
+                        vec![],
+                        Lambda::new(
+                            vec![Argument::new(
+                                "x",
+                                types::Reference::new("number", Position::fake()),
+                            )],
+                            types::Reference::new("string", Position::fake()),
+                            Block::new(
+                                vec![],
+                                ByteString::new("", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
                         ),
-                    ],
+                        None,
+                        Position::fake(),
+                    )],
                     Position::fake(),
                 ),
             ),
         ] {
             assert_eq!(
-                &record_definition().parse(stream(source, "")).unwrap().0,
+                &implementation().parse(stream(source, "")).unwrap().0,
                 expected
             );
         }
@@ -1459,6 +2276,19 @@ mod tests {
                     Position::fake(),
                 ),
             ),
+            (
+                "type foo<a> = a | none",
+                TypeAlias::new(
+                    "foo",
+                    types::Union::new(
+                        types::Reference::new("a", Position::fake()),
+                        types::Reference::new("none", Position::fake()),
+                        Position::fake(),
+                    ),
+                    Position::fake(),
+                )
+                .set_type_parameters(vec!["a".into()]),
+            ),
         ] {
             assert_eq!(&type_alias().parse(stream(source, "")).unwrap().0, expected);
         }
@@ -1601,6 +2431,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_reference_type_with_arguments() {
+            assert_eq!(
+                type_().parse(stream("Foo<number, none>", "")).unwrap().0,
+                types::Reference::new("Foo", Position::fake())
+                    .set_arguments(vec![
+                        types::Reference::new("number", Position::fake()).into(),
+                        types::Reference::new("none", Position::fake()).into(),
+                    ])
+                    .into()
+            );
+            assert_eq!(
+                type_().parse(stream("Foo<Bar<number>>", "")).unwrap().0,
+                types::Reference::new("Foo", Position::fake())
+                    .set_arguments(vec![types::Reference::new("Bar", Position::fake())
+                        .set_arguments(vec![types::Reference::new(
+                            "number",
+                            Position::fake()
+                        )
+                        .into()])
+                        .into()])
+                    .into()
+            );
+        }
+
         #[test]
         fn parse_list_type() {
             assert_eq!(
@@ -1670,6 +2525,41 @@ mod tests {
                 .into()
             );
         }
+
+        #[test]
+        fn parse_anonymous_record_type() {
+            assert!(type_().parse(stream("{}", "")).is_err());
+            assert!(type_().parse(stream("{x number, x none}", "")).is_err());
+
+            assert_eq!(
+                type_().parse(stream("{x number}", "")).unwrap().0,
+                types::AnonymousRecord::new(
+                    vec![types::RecordField::new(
+                        "x",
+                        types::Reference::new("number", Position::fake())
+                    )],
+                    Position::fake()
+                )
+                .into()
+            );
+            assert_eq!(
+                type_().parse(stream("{x number, y none,}", "")).unwrap().0,
+                types::AnonymousRecord::new(
+                    vec![
+                        types::RecordField::new(
+                            "x",
+                            types::Reference::new("number", Position::fake())
+                        ),
+                        types::RecordField::new(
+                            "y",
+                            types::Reference::new("none", Position::fake())
+                        )
+                    ],
+                    Position::fake()
+                )
+                .into()
+            );
+        }
     }
 
     mod expressions {
@@ -1921,6 +2811,23 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_statement_with_record_pattern() {
+            assert_eq!(
+                statement().parse(stream("{x, y} = z", "")).unwrap().0,
+                Statement::new(
+                    Some(Pattern::Record(vec!["x".into(), "y".into()])),
+                    Variable::new("z", Position::fake()),
+                    Position::fake()
+                ),
+            );
+        }
+
+        #[test]
+        fn fail_to_parse_statement_with_duplicate_names_in_record_pattern() {
+            assert!(statement().parse(stream("{x, x} = z", "")).is_err());
+        }
+
         #[test]
         fn parse_if() {
             assert_eq!(
@@ -2221,6 +3128,191 @@ mod tests {
             );
         }
 
+        mod match_ {
+            use super::*;
+            use pretty_assertions::assert_eq;
+
+            fn none_block() -> Block {
+                Block::new(
+                    vec![],
+                    Variable::new("none", Position::fake()),
+                    Position::fake(),
+                )
+            }
+
+            fn parse_pattern(source: &str) -> MatchPattern {
+                let source = format!("{} {{none}}", source);
+                let branch = match_branch().parse(stream(&source, "")).unwrap().0;
+
+                branch.pattern().clone()
+            }
+
+            #[test]
+            fn parse_match() {
+                assert_eq!(
+                    match_()
+                        .parse(stream("match x { y {none} }", ""))
+                        .unwrap()
+                        .0,
+                    Match::new(
+                        Variable::new("x", Position::fake()),
+                        vec![MatchBranch::new(
+                            Variable::new("y", Position::fake()),
+                            None,
+                            none_block(),
+                            Position::fake(),
+                        )],
+                        Position::fake(),
+                    )
+                );
+            }
+
+            #[test]
+            fn parse_match_with_multiple_branches() {
+                assert_eq!(
+                    match_()
+                        .parse(stream("match x { 0 {none} _ {none} }", ""))
+                        .unwrap()
+                        .0
+                        .branches()
+                        .len(),
+                    2
+                );
+            }
+
+            #[test]
+            fn parse_match_in_expression() {
+                assert!(expression()
+                    .parse(stream("match f(x) { y {none} }", ""))
+                    .is_ok());
+            }
+
+            #[test]
+            fn parse_guard() {
+                assert_eq!(
+                    match_branch()
+                        .parse(stream("x if x > 0 {none}", ""))
+                        .unwrap()
+                        .0,
+                    MatchBranch::new(
+                        Variable::new("x", Position::fake()),
+                        Some(
+                            BinaryOperation::new(
+                                BinaryOperator::GreaterThan,
+                                Variable::new("x", Position::fake()),
+                                Number::new(
+                                    NumberRepresentation::FloatingPoint("0".into()),
+                                    Position::fake()
+                                ),
+                                Position::fake()
+                            )
+                            .into()
+                        ),
+                        none_block(),
+                        Position::fake(),
+                    )
+                );
+            }
+
+            #[test]
+            fn parse_literal_patterns() {
+                assert_eq!(
+                    parse_pattern("42"),
+                    Number::new(
+                        NumberRepresentation::FloatingPoint("42".into()),
+                        Position::fake()
+                    )
+                    .into()
+                );
+                assert_eq!(
+                    parse_pattern("\"foo\""),
+                    ByteString::new("foo", Position::fake()).into()
+                );
+            }
+
+            #[test]
+            fn parse_type_pattern() {
+                assert_eq!(
+                    parse_pattern("x as number | none"),
+                    TypePattern::new(
+                        Variable::new("x", Position::fake()),
+                        types::Union::new(
+                            types::Reference::new("number", Position::fake()),
+                            types::Reference::new("none", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    )
+                    .into()
+                );
+            }
+
+            #[test]
+            fn parse_list_patterns() {
+                assert_eq!(
+                    parse_pattern("[]"),
+                    MatchPattern::EmptyList(Position::fake())
+                );
+                assert_eq!(
+                    parse_pattern("[x, ...xs]"),
+                    ListPattern::new(
+                        Variable::new("x", Position::fake()),
+                        Variable::new("xs", Position::fake()),
+                        Position::fake()
+                    )
+                    .into()
+                );
+            }
+
+            #[test]
+            fn parse_record_pattern() {
+                assert_eq!(
+                    parse_pattern("{x, y: 0}"),
+                    RecordPattern::new(
+                        vec![
+                            RecordFieldPattern::new(
+                                "x",
+                                Variable::new("x", Position::fake()),
+                                Position::fake()
+                            ),
+                            RecordFieldPattern::new(
+                                "y",
+                                Number::new(
+                                    NumberRepresentation::FloatingPoint("0".into()),
+                                    Position::fake()
+                                ),
+                                Position::fake()
+                            ),
+                        ],
+                        Position::fake()
+                    )
+                    .into()
+                );
+            }
+
+            #[test]
+            fn parse_variable_pattern_followed_by_block() {
+                assert_eq!(
+                    match_branch().parse(stream("x {y}", "")).unwrap().0,
+                    MatchBranch::new(
+                        Variable::new("x", Position::fake()),
+                        None,
+                        Block::new(
+                            vec![],
+                            Variable::new("y", Position::fake()),
+                            Position::fake()
+                        ),
+                        Position::fake(),
+                    )
+                );
+            }
+
+            #[test]
+            fn fail_to_parse_record_pattern_with_duplicate_fields() {
+                assert!(match_branch().parse(stream("{x, x} {none}", "")).is_err());
+            }
+        }
+
         mod call {
             use super::*;
             use pretty_assertions::assert_eq;
@@ -2683,6 +3775,26 @@ mod tests {
                     )
                     .into(),
                 ),
+                (
+                    "x|>f|y|>g",
+                    BinaryOperation::new(
+                        BinaryOperator::Pipe,
+                        BinaryOperation::new(
+                            BinaryOperator::Pipe,
+                            Variable::new("x", Position::fake()),
+                            BinaryOperation::new(
+                                BinaryOperator::Or,
+                                Variable::new("f", Position::fake()),
+                                Variable::new("y", Position::fake()),
+                                Position::fake(),
+                            ),
+                            Position::fake(),
+                        ),
+                        Variable::new("g", Position::fake()),
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
             ] {
                 assert_eq!(expression().parse(stream(source, "")).unwrap().0, target);
             }
@@ -2698,6 +3810,7 @@ mod tests {
                 ("-", BinaryOperator::Subtract),
                 ("*", BinaryOperator::Multiply),
                 ("/", BinaryOperator::Divide),
+                ("%", BinaryOperator::Remainder),
                 ("==", BinaryOperator::Equal),
                 ("!=", BinaryOperator::NotEqual),
                 ("<", BinaryOperator::LessThan),
@@ -2706,6 +3819,12 @@ mod tests {
                 (">=", BinaryOperator::GreaterThanOrEqual),
                 ("&", BinaryOperator::And),
                 ("|", BinaryOperator::Or),
+                ("&&&", BinaryOperator::BitwiseAnd),
+                ("|||", BinaryOperator::BitwiseOr),
+                ("^^^", BinaryOperator::BitwiseXor),
+                ("<<<", BinaryOperator::LeftShift),
+                (">>>", BinaryOperator::RightShift),
+                ("|>", BinaryOperator::Pipe),
             ] {
                 assert_eq!(
                     binary_operator().parse(stream(source, "")).unwrap().0,
@@ -2718,15 +3837,43 @@ mod tests {
         fn parse_record() {
             assert!(record().parse(stream("Foo", "")).is_err());
 
+            assert_eq!(
+                expression().parse(stream("x<y", "")).unwrap().0,
+                BinaryOperation::new(
+                    BinaryOperator::LessThan,
+                    Variable::new("x", Position::fake()),
+                    Variable::new("y", Position::fake()),
+                    Position::fake(),
+                )
+                .into()
+            );
+
+            assert_eq!(
+                record().parse(stream("Foo<number>{}", "")).unwrap().0,
+                Record::new(
+                    types::Reference::new("Foo", Position::fake()).set_arguments(vec![
+                        types::Reference::new("number", Position::fake()).into()
+                    ]),
+                    None,
+                    vec![],
+                    Position::fake()
+                )
+            );
+
             assert_eq!(
                 record().parse(stream("Foo{}", "")).unwrap().0,
-                Record::new("Foo", None, vec![], Position::fake())
+                Record::new(
+                    types::Reference::new("Foo", Position::fake()),
+                    None,
+                    vec![],
+                    Position::fake()
+                )
             );
 
             assert_eq!(
                 expression().parse(stream("Foo{foo:42}", "")).unwrap().0,
                 Record::new(
-                    "Foo",
+                    types::Reference::new("Foo", Position::fake()),
                     None,
                     vec![RecordField::new(
                         "foo",
@@ -2744,7 +3891,7 @@ mod tests {
             assert_eq!(
                 record().parse(stream("Foo{foo:42}", "")).unwrap().0,
                 Record::new(
-                    "Foo",
+                    types::Reference::new("Foo", Position::fake()),
                     None,
                     vec![RecordField::new(
                         "foo",
@@ -2761,7 +3908,7 @@ mod tests {
             assert_eq!(
                 record().parse(stream("Foo{foo:42,bar:42}", "")).unwrap().0,
                 Record::new(
-                    "Foo",
+                    types::Reference::new("Foo", Position::fake()),
                     None,
                     vec![
                         RecordField::new(
@@ -2795,7 +3942,7 @@ mod tests {
                 Call::new(
                     Variable::new("foo", Position::fake()),
                     vec![Record::new(
-                        "Foo",
+                        types::Reference::new("Foo", Position::fake()),
                         None,
                         vec![RecordField::new(
                             "foo",
@@ -2816,7 +3963,7 @@ mod tests {
             assert_eq!(
                 record().parse(stream("Foo{foo:bar(42)}", "")).unwrap().0,
                 Record::new(
-                    "Foo",
+                    types::Reference::new("Foo", Position::fake()),
                     None,
                     vec![RecordField::new(
                         "foo",
@@ -2840,7 +3987,7 @@ mod tests {
             assert_eq!(
                 record().parse(stream("Foo{...foo,bar:42}", "")).unwrap().0,
                 Record::new(
-                    "Foo",
+                    types::Reference::new("Foo", Position::fake()),
                     Some(Variable::new("foo", Position::fake()).into()),
                     vec![RecordField::new(
                         "bar",
@@ -2857,7 +4004,7 @@ mod tests {
             assert_eq!(
                 record().parse(stream("Foo{...foo,bar:42,}", "")).unwrap().0,
                 Record::new(
-                    "Foo",
+                    types::Reference::new("Foo", Position::fake()),
                     Some(Variable::new("foo", Position::fake()).into()),
                     vec![RecordField::new(
                         "bar",
@@ -2877,7 +4024,7 @@ mod tests {
                     .unwrap()
                     .0,
                 Record::new(
-                    "Foo",
+                    types::Reference::new("Foo", Position::fake()),
                     Some(Variable::new("foo", Position::fake()).into()),
                     vec![RecordField::new(
                         "bar",
@@ -2905,6 +4052,49 @@ mod tests {
                 .is_ok());
         }
 
+        #[test]
+        fn parse_anonymous_record() {
+            assert_eq!(
+                expression()
+                    .parse(stream("{x number}{x: 42}", ""))
+                    .unwrap()
+                    .0,
+                Record::new(
+                    types::AnonymousRecord::new(
+                        vec![types::RecordField::new(
+                            "x",
+                            types::Reference::new("number", Position::fake())
+                        )],
+                        Position::fake()
+                    ),
+                    None,
+                    vec![RecordField::new(
+                        "x",
+                        Number::new(
+                            NumberRepresentation::FloatingPoint("42".into()),
+                            Position::fake()
+                        ),
+                        Position::fake()
+                    )],
+                    Position::fake()
+                )
+                .into()
+            );
+        }
+
+        #[test]
+        fn parse_map_instead_of_anonymous_record() {
+            assert_eq!(
+                expression().parse(stream("{x: y}", "")).unwrap().0,
+                Map::new(
+                    types::Reference::new("x", Position::fake()),
+                    types::Reference::new("y", Position::fake()),
+                    vec![],
+                    Position::fake()
+                )
+                .into()
+            );
+        }
         #[test]
         fn parse_variable() {
             assert!(variable().parse(stream("", "")).is_err());
@@ -2949,6 +4139,76 @@ mod tests {
             }
         }
 
+        #[test]
+        fn parse_integer_literal() {
+            assert!(integer_literal().parse(stream("", "")).is_err());
+            assert!(integer_literal().parse(stream("42", "")).is_err());
+            assert!(integer_literal().parse(stream("4.2i64", "")).is_err());
+            assert!(integer_literal().parse(stream("42i32", "")).is_err());
+            assert!(integer_literal().parse(stream("42i64x", "")).is_err());
+
+            for (source, value, kind) in [
+                (
+                    "0byte",
+                    NumberRepresentation::FloatingPoint("0".into()),
+                    IntegerKind::Byte,
+                ),
+                (
+                    "-42i64",
+                    NumberRepresentation::FloatingPoint("-42".into()),
+                    IntegerKind::I64,
+                ),
+                (
+                    "9007199254740993u64",
+                    NumberRepresentation::FloatingPoint("9007199254740993".into()),
+                    IntegerKind::U64,
+                ),
+                (
+                    "0b101byte",
+                    NumberRepresentation::Binary("101".into()),
+                    IntegerKind::Byte,
+                ),
+                (
+                    "0xFFbyte",
+                    NumberRepresentation::Hexadecimal("ff".into()),
+                    IntegerKind::Byte,
+                ),
+                (
+                    "0xbbyte",
+                    NumberRepresentation::Hexadecimal("b".into()),
+                    IntegerKind::Byte,
+                ),
+                (
+                    "0xffu64",
+                    NumberRepresentation::Hexadecimal("ff".into()),
+                    IntegerKind::U64,
+                ),
+            ] {
+                assert_eq!(
+                    integer_literal().parse(stream(source, "")).unwrap().0,
+                    Integer::new(value, kind, Position::fake())
+                );
+            }
+        }
+
+        #[test]
+        fn parse_integer_literal_in_expression() {
+            assert_eq!(
+                expression().parse(stream("x + 1i64", "")).unwrap().0,
+                BinaryOperation::new(
+                    BinaryOperator::Add,
+                    Variable::new("x", Position::fake()),
+                    Integer::new(
+                        NumberRepresentation::FloatingPoint("1".into()),
+                        IntegerKind::I64,
+                        Position::fake()
+                    ),
+                    Position::fake()
+                )
+                .into()
+            );
+        }
+
         #[test]
         fn parse_string_literal() {
             assert!(string_literal().parse(stream("", "")).is_err());
@@ -2965,6 +4225,7 @@ mod tests {
                 (r#""\\""#, "\\\\"),
                 (r#""\x42""#, "\\x42"),
                 (r#""\n\n""#, "\\n\\n"),
+                (r#""\$""#, "\\$"),
             ] {
                 assert_eq!(
                     string_literal().parse(stream(source, "")).unwrap().0,
@@ -2973,6 +4234,74 @@ mod tests {
             }
         }
 
+        #[test]
+        fn parse_string_interpolation() {
+            for (source, target) in [
+                (r#""foo""#, ByteString::new("foo", Position::fake()).into()),
+                (r#""$x""#, ByteString::new("$x", Position::fake()).into()),
+                (
+                    r#""\${x}""#,
+                    ByteString::new("\\${x}", Position::fake()).into(),
+                ),
+                (
+                    r#""${x}""#,
+                    StringInterpolation::new(
+                        vec![StringInterpolationPart::Expression(Arc::new(
+                            Variable::new("x", Position::fake()).into(),
+                        ))],
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                (
+                    r#""foo ${ x } bar""#,
+                    StringInterpolation::new(
+                        vec![
+                            StringInterpolationPart::String("foo ".into()),
+                            StringInterpolationPart::Expression(Arc::new(
+                                Variable::new("x", Position::fake()).into(),
+                            )),
+                            StringInterpolationPart::String(" bar".into()),
+                        ],
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+                (
+                    r#""${f("}")}${y}""#,
+                    StringInterpolation::new(
+                        vec![
+                            StringInterpolationPart::Expression(Arc::new(
+                                Call::new(
+                                    Variable::new("f", Position::fake()),
+                                    vec![ByteString::new("}", Position::fake()).into()],
+                                    Position::fake(),
+                                )
+                                .into(),
+                            )),
+                            StringInterpolationPart::Expression(Arc::new(
+                                Variable::new("y", Position::fake()).into(),
+                            )),
+                        ],
+                        Position::fake(),
+                    )
+                    .into(),
+                ),
+            ] {
+                assert_eq!(
+                    string_interpolation().parse(stream(source, "")).unwrap().0,
+                    target
+                );
+            }
+        }
+
+        #[test]
+        fn fail_to_parse_unclosed_string_interpolation() {
+            assert!(string_interpolation()
+                .parse(stream(r#""${x""#, ""))
+                .is_err());
+        }
+
         #[test]
         fn parse_list() {
             for (source, target) in vec![
@@ -3106,8 +4435,12 @@ mod tests {
                     ListComprehension::new(
                         types::Reference::new("none", Position::fake()),
                         Variable::new("x", Position::fake()),
-                        "x",
-                        Variable::new("xs", Position::fake()),
+                        vec![ListComprehensionBranch::new(
+                            vec!["x".into()],
+                            vec![Variable::new("xs", Position::fake()).into()],
+                            None,
+                            Position::fake(),
+                        )],
                         Position::fake(),
                     ),
                 ),
@@ -3124,19 +4457,97 @@ mod tests {
                             ),
                             Position::fake(),
                         ),
-                        "x",
-                        Variable::new("xs", Position::fake()),
+                        vec![ListComprehensionBranch::new(
+                            vec!["x".into()],
+                            vec![Variable::new("xs", Position::fake()).into()],
+                            None,
+                            Position::fake(),
+                        )],
+                        Position::fake(),
+                    ),
+                ),
+                (
+                    "[none x for x in xs if x]",
+                    ListComprehension::new(
+                        types::Reference::new("none", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        vec![ListComprehensionBranch::new(
+                            vec!["x".into()],
+                            vec![Variable::new("xs", Position::fake()).into()],
+                            Some(Variable::new("x", Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Position::fake(),
+                    ),
+                ),
+                (
+                    "[none y for x in xs for y in x]",
+                    ListComprehension::new(
+                        types::Reference::new("none", Position::fake()),
+                        Variable::new("y", Position::fake()),
+                        vec![
+                            ListComprehensionBranch::new(
+                                vec!["x".into()],
+                                vec![Variable::new("xs", Position::fake()).into()],
+                                None,
+                                Position::fake(),
+                            ),
+                            ListComprehensionBranch::new(
+                                vec!["y".into()],
+                                vec![Variable::new("x", Position::fake()).into()],
+                                None,
+                                Position::fake(),
+                            ),
+                        ],
+                        Position::fake(),
+                    ),
+                ),
+                (
+                    "[none x for x, y in xs, ys]",
+                    ListComprehension::new(
+                        types::Reference::new("none", Position::fake()),
+                        Variable::new("x", Position::fake()),
+                        vec![ListComprehensionBranch::new(
+                            vec!["x".into(), "y".into()],
+                            vec![
+                                Variable::new("xs", Position::fake()).into(),
+                                Variable::new("ys", Position::fake()).into(),
+                            ],
+                            None,
+                            Position::fake(),
+                        )],
                         Position::fake(),
                     ),
                 ),
             ] {
                 assert_eq!(
                     list_comprehension().parse(stream(source, "")).unwrap().0,
-                    target.into()
+                    target
                 );
             }
         }
 
+        #[test]
+        fn parse_map_iteration_comprehension() {
+            assert_eq!(
+                list_comprehension()
+                    .parse(stream("[none v for k, v in xs]", ""))
+                    .unwrap()
+                    .0,
+                ListComprehension::new(
+                    types::Reference::new("none", Position::fake()),
+                    Variable::new("v", Position::fake()),
+                    vec![ListComprehensionBranch::new(
+                        vec!["k".into(), "v".into()],
+                        vec![Variable::new("xs", Position::fake()).into()],
+                        None,
+                        Position::fake(),
+                    )],
+                    Position::fake(),
+                )
+            );
+        }
+
         #[test]
         fn parse_map() {
             for (source, target) in vec![
@@ -3224,22 +4635,50 @@ mod tests {
         }
 
         #[test]
-        fn parse_map_iteration_comprehension() {
-            assert_eq!(
-                list_comprehension()
-                    .parse(stream("[none v for k, v in xs]", ""))
-                    .unwrap()
-                    .0,
-                MapIterationComprehension::new(
-                    types::Reference::new("none", Position::fake()),
-                    Variable::new("v", Position::fake()),
-                    "k",
-                    "v",
-                    Variable::new("xs", Position::fake()),
-                    Position::fake(),
-                )
-                .into()
-            );
+        fn parse_map_comprehension() {
+            for (source, target) in [
+                (
+                    "{none:none k:v for k, v in m}",
+                    MapComprehension::new(
+                        types::Reference::new("none", Position::fake()),
+                        types::Reference::new("none", Position::fake()),
+                        Variable::new("k", Position::fake()),
+                        Variable::new("v", Position::fake()),
+                        vec![ListComprehensionBranch::new(
+                            vec!["k".into(), "v".into()],
+                            vec![Variable::new("m", Position::fake()).into()],
+                            None,
+                            Position::fake(),
+                        )],
+                        Position::fake(),
+                    ),
+                ),
+                (
+                    "{none:none f(x):x for x in xs if x}",
+                    MapComprehension::new(
+                        types::Reference::new("none", Position::fake()),
+                        types::Reference::new("none", Position::fake()),
+                        Call::new(
+                            Variable::new("f", Position::fake()),
+                            vec![Variable::new("x", Position::fake()).into()],
+                            Position::fake(),
+                        ),
+                        Variable::new("x", Position::fake()),
+                        vec![ListComprehensionBranch::new(
+                            vec!["x".into()],
+                            vec![Variable::new("xs", Position::fake()).into()],
+                            Some(Variable::new("x", Position::fake()).into()),
+                            Position::fake(),
+                        )],
+                        Position::fake(),
+                    ),
+                ),
+            ] {
+                assert_eq!(
+                    expression().parse(stream(source, "")).unwrap().0,
+                    target.into()
+                );
+            }
         }
     }
 
@@ -3280,6 +4719,99 @@ mod tests {
         assert!(position().parse(stream("", "")).is_ok());
     }
 
+    mod span {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn parse_span(source: &str) -> (usize, usize, usize, usize) {
+            let position = expression()
+                .parse(stream(source, ""))
+                .unwrap()
+                .0
+                .position()
+                .clone();
+
+            (
+                position.line_number(),
+                position.column_number(),
+                position.end_line_number(),
+                position.end_column_number(),
+            )
+        }
+
+        #[test]
+        fn parse_variable_span() {
+            assert_eq!(parse_span("foo "), (1, 1, 1, 4));
+        }
+
+        #[test]
+        fn parse_string_span() {
+            assert_eq!(parse_span("\"foo\" "), (1, 1, 1, 6));
+        }
+
+        #[test]
+        fn parse_string_interpolation_span() {
+            assert_eq!(
+                parse_span("\"user ${name} has ${n} items\" "),
+                (1, 1, 1, 30)
+            );
+        }
+
+        #[test]
+        fn parse_call_span() {
+            assert_eq!(parse_span("f(x, y) "), (1, 2, 1, 8));
+        }
+
+        #[test]
+        fn parse_binary_operation_span() {
+            assert_eq!(parse_span("x + y"), (1, 3, 1, 4));
+        }
+
+        #[test]
+        fn parse_lambda_span() {
+            assert_eq!(
+                parse_span(indoc!(
+                    "
+                    \\() none {
+                      none
+                    }
+                    # foo
+                    "
+                )),
+                (1, 1, 3, 2)
+            );
+        }
+
+        #[test]
+        fn parse_union_type_span() {
+            let position = type_()
+                .parse(stream("number | none ", ""))
+                .unwrap()
+                .0
+                .position()
+                .clone();
+
+            assert_eq!(position.column_number(), 1);
+            assert_eq!(position.end_column_number(), 14);
+        }
+
+        #[test]
+        fn parse_previous_line() {
+            let position = expression()
+                .parse(stream("\\() none {\n  x\n}", ""))
+                .unwrap()
+                .0
+                .position()
+                .clone();
+
+            assert_eq!(position.previous_line(), None);
+
+            let block = block().parse(stream("{\n  x\n}", "")).unwrap().0;
+
+            assert_eq!(block.expression().position().previous_line(), Some("{"));
+        }
+    }
+
     #[test]
     fn parse_blank() {
         assert!(blank().with(eof()).parse(stream(" ", "")).is_ok());
@@ -9,17 +9,31 @@ use combine::{
 pub struct State<'a> {
     pub path: String,
     pub lines: Vec<&'a str>,
+    pub recovery: bool,
+    pub errors: Vec<easy::Errors<char, &'a str, SourcePosition>>,
+    pub last_token_end: SourcePosition,
 }
 
 pub type Stream<'a> =
     easy::Stream<state::Stream<position::Stream<&'a str, SourcePosition>, State<'a>>>;
 
 pub fn stream<'a>(source: &'a str, path: &str) -> Stream<'a> {
+    create_stream(source, path, false)
+}
+
+pub fn recoverable_stream<'a>(source: &'a str, path: &str) -> Stream<'a> {
+    create_stream(source, path, true)
+}
+
+fn create_stream<'a>(source: &'a str, path: &str, recovery: bool) -> Stream<'a> {
     state::Stream {
         stream: position::Stream::new(source),
         state: State {
             path: path.into(),
             lines: source.split('\n').collect(),
+            recovery,
+            errors: vec![],
+            last_token_end: Default::default(),
         },
     }
     .into()
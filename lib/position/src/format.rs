@@ -2,11 +2,16 @@ use crate::Position;
 
 pub fn format(position: &Position) -> String {
     format!(
-        "{}\n{}\t{}\n{}^",
+        "{}\n{}{}\t{}\n{}{}",
         position.path(),
+        position
+            .previous_line()
+            .map(|line| format!("{}\t{}\n", padding(position), line))
+            .unwrap_or_default(),
         line_information(position),
         position.line(),
         offset(position),
+        "^".repeat(caret_count(position)),
     )
 }
 
@@ -18,14 +23,32 @@ fn line_information(position: &Position) -> String {
     format!("{}:{}:", position.line_number(), position.column_number())
 }
 
+fn padding(position: &Position) -> String {
+    " ".repeat(line_information(position).len())
+}
+
 fn offset(position: &Position) -> String {
     format!(
         "{}\t{}",
-        " ".repeat(line_information(position).len()),
+        padding(position),
         " ".repeat(position.column_number() - 1)
     )
 }
 
+// Carets span to the end of a first line if a span continues to the following
+// lines.
+fn caret_count(position: &Position) -> usize {
+    let end_column_number = if position.end_line_number() > position.line_number() {
+        position.line().chars().count() + 1
+    } else {
+        position.end_column_number()
+    };
+
+    end_column_number
+        .saturating_sub(position.column_number())
+        .max(1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,4 +73,36 @@ mod tests {
             "file\n1:2:\tx\n    \t ^\n    \t foo"
         );
     }
+
+    #[test]
+    fn format_span() {
+        assert_eq!(
+            format(&Position::new("file", 1, 2, " foo").set_end(1, 5)),
+            "file\n1:2:\t foo\n    \t ^^^"
+        );
+    }
+
+    #[test]
+    fn format_multi_line_span() {
+        assert_eq!(
+            format(&Position::new("file", 1, 2, " foo(").set_end(2, 2)),
+            "file\n1:2:\t foo(\n    \t ^^^^"
+        );
+    }
+
+    #[test]
+    fn format_span_with_message() {
+        assert_eq!(
+            format_message(&Position::new("file", 1, 2, " foo").set_end(1, 5), "bar"),
+            "file\n1:2:\t foo\n    \t ^^^\n    \t bar"
+        );
+    }
+
+    #[test]
+    fn format_previous_line() {
+        assert_eq!(
+            format(&Position::new("file", 2, 1, "bar").set_previous_line("foo")),
+            "file\n    \tfoo\n2:1:\tbar\n    \t^"
+        );
+    }
 }
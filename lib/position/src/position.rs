@@ -2,16 +2,29 @@ use crate::format;
 use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
-    fmt::{self, Display, Formatter},
+    fmt::{self, Debug, Display, Formatter},
     hash::{Hash, Hasher},
+    sync::Arc,
 };
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+// Positions are shared as they are attached to almost all nodes in syntax
+// trees and types as well as errors.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct Position {
+    inner: Arc<PositionInner>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct PositionInner {
     path: String,
     line_number: usize,
     column_number: usize,
     line: String,
+    // An end of a span is exclusive.
+    end_line_number: usize,
+    end_column_number: usize,
+    previous_line: Option<String>,
 }
 
 impl Position {
@@ -22,27 +35,78 @@ impl Position {
         line: impl Into<String>,
     ) -> Self {
         Self {
-            path: path.into(),
-            line_number,
-            column_number,
-            line: line.into(),
+            inner: PositionInner {
+                path: path.into(),
+                line_number,
+                column_number,
+                line: line.into(),
+                end_line_number: line_number,
+                end_column_number: column_number,
+                previous_line: None,
+            }
+            .into(),
         }
     }
 
     pub fn path(&self) -> &str {
-        &self.path
+        &self.inner.path
     }
 
     pub fn line_number(&self) -> usize {
-        self.line_number
+        self.inner.line_number
     }
 
     pub fn column_number(&self) -> usize {
-        self.column_number
+        self.inner.column_number
     }
 
     pub fn line(&self) -> &str {
-        &self.line
+        &self.inner.line
+    }
+
+    pub fn end_line_number(&self) -> usize {
+        self.inner.end_line_number
+    }
+
+    pub fn end_column_number(&self) -> usize {
+        self.inner.end_column_number
+    }
+
+    pub fn previous_line(&self) -> Option<&str> {
+        self.inner.previous_line.as_deref()
+    }
+
+    pub fn set_end(mut self, line_number: usize, column_number: usize) -> Self {
+        let inner = Arc::make_mut(&mut self.inner);
+
+        inner.end_line_number = line_number;
+        inner.end_column_number = column_number;
+        self
+    }
+
+    pub fn set_previous_line(mut self, line: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.inner).previous_line = Some(line.into());
+        self
+    }
+
+    pub fn has_span(&self) -> bool {
+        (self.end_line_number(), self.end_column_number())
+            > (self.line_number(), self.column_number())
+    }
+}
+
+impl Debug for Position {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Position")
+            .field("path", &self.path())
+            .field("line_number", &self.line_number())
+            .field("column_number", &self.column_number())
+            .field("line", &self.line())
+            .field("end_line_number", &self.end_line_number())
+            .field("end_column_number", &self.end_column_number())
+            .field("previous_line", &self.previous_line())
+            .finish()
     }
 }
 
@@ -92,4 +156,17 @@ mod tests {
             "file\n1:2:\t x\n    \t ^"
         );
     }
+
+    #[test]
+    fn display_span() {
+        assert_eq!(
+            format!(
+                "{}",
+                Position::new("file", 2, 3, "  foo(x)")
+                    .set_end(2, 9)
+                    .set_previous_line("f = \\() none {")
+            ),
+            "file\n    \tf = \\() none {\n2:3:\t  foo(x)\n    \t  ^^^^^^"
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 extern crate alloc;
 
+mod string_builder;
+
 use core::hash::{Hash, Hasher};
 use siphasher::sip::SipHasher;
 
@@ -12,6 +14,11 @@ fn _pen_equal_strings(one: ffi::ByteString, other: ffi::ByteString) -> ffi::Bool
     (one.as_slice() == other.as_slice()).into()
 }
 
+#[ffi::bindgen]
+fn _pen_less_than_strings(one: ffi::ByteString, other: ffi::ByteString) -> ffi::Boolean {
+    (one.as_slice() < other.as_slice()).into()
+}
+
 #[ffi::bindgen]
 fn _pen_prelude_combine_hashes(one: ffi::Number, other: ffi::Number) -> ffi::Number {
     f64::from_bits(
@@ -63,6 +70,89 @@ fn _pen_prelude_hash_to_index(
         .into()
 }
 
+macro_rules! convert_integers {
+    ($($name:ident: $from:ty => $to:ty),* $(,)?) => {
+        $(
+            #[ffi::bindgen]
+            fn $name(value: $from) -> $to {
+                value as $to
+            }
+        )*
+    };
+}
+
+// Integers are converted by wrapping around while numbers are converted into
+// integers by rounding toward zero and saturating.
+convert_integers!(
+    _pen_convert_byte_to_i64: u8 => i64,
+    _pen_convert_byte_to_u64: u8 => u64,
+    _pen_convert_i64_to_byte: i64 => u8,
+    _pen_convert_i64_to_u64: i64 => u64,
+    _pen_convert_u64_to_byte: u64 => u8,
+    _pen_convert_u64_to_i64: u64 => i64,
+);
+
+macro_rules! convert_numbers {
+    ($($from_name:ident, $to_name:ident: $type:ty),* $(,)?) => {
+        $(
+            #[ffi::bindgen]
+            fn $from_name(number: ffi::Number) -> $type {
+                f64::from(number) as $type
+            }
+
+            #[ffi::bindgen]
+            fn $to_name(value: $type) -> ffi::Number {
+                (value as f64).into()
+            }
+        )*
+    };
+}
+
+convert_numbers!(
+    _pen_convert_number_to_byte, _pen_convert_byte_to_number: u8,
+    _pen_convert_number_to_i64, _pen_convert_i64_to_number: i64,
+    _pen_convert_number_to_u64, _pen_convert_u64_to_number: u64,
+);
+
+// Integer division by zero results in zero.
+#[ffi::bindgen]
+fn _pen_divide_byte(lhs: u8, rhs: u8) -> u8 {
+    lhs.checked_div(rhs).unwrap_or_default()
+}
+
+#[ffi::bindgen]
+fn _pen_divide_i64(lhs: i64, rhs: i64) -> i64 {
+    if rhs == 0 {
+        0
+    } else {
+        lhs.wrapping_div(rhs)
+    }
+}
+
+#[ffi::bindgen]
+fn _pen_divide_u64(lhs: u64, rhs: u64) -> u64 {
+    lhs.checked_div(rhs).unwrap_or_default()
+}
+
+#[ffi::bindgen]
+fn _pen_remainder_byte(lhs: u8, rhs: u8) -> u8 {
+    lhs.checked_rem(rhs).unwrap_or_default()
+}
+
+#[ffi::bindgen]
+fn _pen_remainder_i64(lhs: i64, rhs: i64) -> i64 {
+    if rhs == 0 {
+        0
+    } else {
+        lhs.wrapping_rem(rhs)
+    }
+}
+
+#[ffi::bindgen]
+fn _pen_remainder_u64(lhs: u64, rhs: u64) -> u64 {
+    lhs.checked_rem(rhs).unwrap_or_default()
+}
+
 fn hash(value: &impl Hash) -> ffi::Number {
     let mut hasher = SipHasher::new();
 
@@ -76,6 +166,20 @@ mod tests {
     use super::*;
     use alloc::vec;
 
+    #[test]
+    fn compare_strings() {
+        assert_eq!(
+            _pen_less_than_strings(ffi::ByteString::default(), vec![0u8].into()),
+            true.into()
+        );
+        assert_eq!(_pen_less_than_strings("ab".into(), "b".into()), true.into());
+        assert_eq!(
+            _pen_less_than_strings("b".into(), "ab".into()),
+            false.into()
+        );
+        assert_eq!(_pen_less_than_strings("a".into(), "a".into()), false.into());
+    }
+
     #[test]
     fn equal_empty_strings() {
         let string = ffi::ByteString::default();
@@ -117,4 +221,35 @@ mod tests {
             false.into()
         );
     }
+
+    #[test]
+    fn convert_number_to_integers() {
+        assert_eq!(_pen_convert_number_to_byte(256.0.into()), 255);
+        assert_eq!(_pen_convert_number_to_i64((-1.5).into()), -1);
+        assert_eq!(_pen_convert_number_to_u64(f64::NAN.into()), 0);
+    }
+
+    #[test]
+    fn divide_integers_by_zero() {
+        assert_eq!(_pen_divide_byte(1, 0), 0);
+        assert_eq!(_pen_divide_i64(1, 0), 0);
+        assert_eq!(_pen_divide_u64(1, 0), 0);
+    }
+
+    #[test]
+    fn divide_minimum_i64() {
+        assert_eq!(_pen_divide_i64(i64::MIN, -1), i64::MIN);
+    }
+
+    #[test]
+    fn calculate_remainders_by_zero() {
+        assert_eq!(_pen_remainder_byte(1, 0), 0);
+        assert_eq!(_pen_remainder_i64(1, 0), 0);
+        assert_eq!(_pen_remainder_u64(1, 0), 0);
+    }
+
+    #[test]
+    fn calculate_remainder_of_minimum_i64() {
+        assert_eq!(_pen_remainder_i64(i64::MIN, -1), 0);
+    }
 }
@@ -0,0 +1,92 @@
+use alloc::{boxed::Box, vec::Vec};
+
+#[repr(C)]
+struct StringBuilder(ffi::Arc<ffi::Any>);
+
+#[ffi::any]
+#[derive(Clone)]
+struct StringBuilderInner {
+    #[allow(clippy::box_collection)]
+    strings: Box<Vec<ffi::ByteString>>,
+}
+
+#[ffi::bindgen]
+fn _pen_string_builder_create(capacity: ffi::Number) -> StringBuilder {
+    StringBuilder(ffi::Arc::new(
+        StringBuilderInner {
+            strings: Vec::with_capacity(f64::from(capacity) as usize).into(),
+        }
+        .into(),
+    ))
+}
+
+#[ffi::bindgen]
+fn _pen_string_builder_append(
+    mut builder: StringBuilder,
+    string: ffi::ByteString,
+) -> StringBuilder {
+    if let Some(any) = ffi::Arc::get_mut(&mut builder.0) {
+        let inner: &mut StringBuilderInner = any.try_into().unwrap();
+
+        inner.strings.push(string);
+
+        return builder;
+    }
+
+    let inner: &StringBuilderInner = (&*builder.0).try_into().unwrap();
+    let mut inner = inner.clone();
+
+    inner.strings.push(string);
+
+    StringBuilder(ffi::Arc::new(inner.into()))
+}
+
+#[ffi::bindgen]
+fn _pen_string_builder_build(builder: StringBuilder) -> ffi::ByteString {
+    let inner: &StringBuilderInner = (&*builder.0).try_into().unwrap();
+    let mut bytes = Vec::with_capacity(
+        inner
+            .strings
+            .iter()
+            .map(|string| string.as_slice().len())
+            .sum(),
+    );
+
+    for string in inner.strings.iter() {
+        bytes.extend_from_slice(string.as_slice());
+    }
+
+    bytes.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_empty_string() {
+        assert_eq!(
+            _pen_string_builder_build(_pen_string_builder_create(0.0.into())),
+            ffi::ByteString::default()
+        );
+    }
+
+    #[test]
+    fn build_string() {
+        let builder = _pen_string_builder_create(2.0.into());
+        let builder = _pen_string_builder_append(builder, "foo".into());
+        let builder = _pen_string_builder_append(builder, "bar".into());
+
+        assert_eq!(_pen_string_builder_build(builder), "foobar".into());
+    }
+
+    #[test]
+    fn append_string_to_shared_builder() {
+        let builder =
+            _pen_string_builder_append(_pen_string_builder_create(1.0.into()), "foo".into());
+        let other = _pen_string_builder_append(StringBuilder(builder.0.clone()), "bar".into());
+
+        assert_eq!(_pen_string_builder_build(builder), "foo".into());
+        assert_eq!(_pen_string_builder_build(other), "foobar".into());
+    }
+}